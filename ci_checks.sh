@@ -7,14 +7,19 @@
 # `cargo install forc-fmt --git https://github.com/FuelLabs/sway --tag v0.38.0 --locked`
 # Note, if you need a custom branch, you can replace `--tag {RELEASE}` with the `--branch {BRANCH_NAME}`.
 
+# Opt-in features of `fuels` that the default feature set leaves out.
+OPTIONAL_FEATURES="accounting accounting-parquet kms experimental tracing config metrics relay verification"
+
 cargo fmt --all -- --check &&
 	forc fmt --check &&
 	forc build --release --terse &&
 	cargo clippy --all-targets &&
+	cargo clippy --all-targets --features "$OPTIONAL_FEATURES" &&
 	forc build --release --terse --json-abi-with-callpaths &&
 	cargo clippy --all-targets --all-features &&
 	cargo test --all-targets --all-features &&
 	cargo test --all-targets --all-features --workspace &&
 	cargo test --all-targets --workspace &&
+	cargo test --all-targets --features "$OPTIONAL_FEATURES" --workspace &&
 	cargo run --bin check-docs &&
 	$(cargo doc |& grep -A 6 "warning: unresolved link to")
@@ -0,0 +1,39 @@
+contract;
+
+use std::execution::run_external;
+
+abi Proxy {
+    #[storage(write)]
+    fn set_proxy_target(new_target: ContractId);
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId>;
+}
+
+#[namespace(SRC14)]
+storage {
+    // sha256("storage_SRC14_0")
+    target: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+}
+
+impl Proxy for Contract {
+    #[storage(write)]
+    fn set_proxy_target(new_target: ContractId) {
+        storage.target.write(new_target);
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        let target = storage.target.read();
+        if target == ContractId::zero() {
+            None
+        } else {
+            Some(target)
+        }
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    run_external(storage.target.read())
+}
@@ -1,7 +1,7 @@
 use fuels::{
     core::codec::EncoderConfig,
     prelude::*,
-    types::{Bits256, SizedAsciiString, U256},
+    types::{Bits256, SizedAsciiString, Token, U256},
 };
 
 #[tokio::test]
@@ -254,3 +254,94 @@ async fn configurable_encoder_config_is_applied() {
             .contains("token limit `1` reached while encoding. Try increasing it"),)
     }
 }
+
+#[tokio::test]
+async fn deployed_bytecode_is_verified_with_its_configurables() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/configurables/out/release/configurables-abi.json"
+    ));
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let binary_path = "sway/contracts/configurables/out/release/configurables.bin";
+    let configurables = MyContractConfigurables::default().with_U8(7)?;
+
+    let contract_id = Contract::load_from(
+        binary_path,
+        LoadConfiguration::default().with_configurables(configurables.clone()),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    // ANCHOR: verify_deployed
+    let audited = Contract::load_from(
+        binary_path,
+        LoadConfiguration::default().with_configurables(configurables),
+    )?;
+
+    let diff = audited.verify_deployed(provider, &contract_id).await?;
+    assert!(diff.matches());
+    // ANCHOR_END: verify_deployed
+
+    let diff = Contract::load_from(binary_path, LoadConfiguration::default())?
+        .verify_deployed(provider, &contract_id)
+        .await?;
+    assert!(!diff.matches());
+    assert_eq!(diff.local_len, diff.deployed_len);
+
+    let unknown_id = Bech32ContractId::from(ContractId::from([1; 32]));
+    assert!(audited
+        .verify_deployed(provider, &unknown_id)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configurables_are_read_back_from_deployed_bytecode() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/configurables/out/release/configurables-abi.json"
+    ));
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let binary_path = "sway/contracts/configurables/out/release/configurables.bin";
+    let configurables = MyContractConfigurables::default().with_U8(7)?;
+
+    let contract_id = Contract::load_from(
+        binary_path,
+        LoadConfiguration::default().with_configurables(configurables.clone()),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    // ANCHOR: decode_configurables
+    let constants = MyContractConfigurables::configurables();
+    assert!(constants.iter().any(|constant| constant.name == "U8"));
+
+    let deployed = provider
+        .contract_bytecode(&contract_id)
+        .await?
+        .expect("was deployed");
+    let deployed_values = MyContractConfigurables::decode_from_binary(&deployed)?;
+    let u8_value = deployed_values
+        .iter()
+        .find(|decoded| decoded.name == "U8")
+        .map(|decoded| &decoded.value);
+    assert_eq!(u8_value, Some(&Token::U8(7)));
+
+    let original = std::fs::read(binary_path)?;
+    let changes = MyContractConfigurables::diff_binaries(&original, &deployed)?;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].name, "U8");
+    assert_eq!(changes[0].before, Token::U8(8));
+    // ANCHOR_END: decode_configurables
+
+    let encoded = fuels::core::Configurables::from(configurables);
+    assert_eq!(encoded.offsets_with_data().len(), 1);
+
+    Ok(())
+}
@@ -1,9 +1,15 @@
 use fuels::{
+    client::{PageDirection, PaginationRequest},
     core::codec::{calldata, encode_fn_selector, DecoderConfig, EncoderConfig},
     prelude::*,
+    programs::calls::{CallTraceBuilder, FrameOutcome, GasProfile},
     tx::ContractParameters,
-    types::{errors::transaction::Reason, Bits256, Identity},
+    types::{
+        errors::transaction::Reason, tx_status::TxStatus, Bits256, GasPriceStrategy, Identity,
+        Token,
+    },
 };
+use futures::StreamExt;
 use tokio::time::Instant;
 
 #[tokio::test]
@@ -109,6 +115,77 @@ async fn test_contract_calling_contract() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn nested_calls_are_traced_from_receipts() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(
+            Contract(
+                name = "LibContract",
+                project = "e2e/sway/contracts/lib_contract"
+            ),
+            Contract(
+                name = "LibContractCaller",
+                project = "e2e/sway/contracts/lib_contract_caller"
+            ),
+        ),
+        Deploy(
+            name = "lib_contract_instance",
+            contract = "LibContract",
+            wallet = "wallet"
+        ),
+        Deploy(
+            name = "contract_caller_instance",
+            contract = "LibContractCaller",
+            wallet = "wallet"
+        ),
+    );
+    let lib_contract_id = lib_contract_instance.contract_id();
+    let caller_id = ContractId::from(contract_caller_instance.contract_id());
+
+    // ANCHOR: call_trace
+    let response = contract_caller_instance
+        .methods()
+        .increment_from_contract(lib_contract_id, 42)
+        .with_contracts(&[&lib_contract_instance])
+        .with_tx_in_response(true)
+        .call()
+        .await?;
+
+    let mut abi_registry = AbiRegistry::new();
+    let abi = std::fs::read_to_string(
+        "sway/contracts/lib_contract_caller/out/release/lib_contract_caller-abi.json",
+    )?;
+    abi_registry.register(caller_id, &abi)?;
+
+    let trace = CallTraceBuilder::from_receipts(&response.receipts)
+        .with_script(response.tx.as_ref().expect("requested with the response"))
+        .with_abi_registry(&abi_registry)
+        .build()?;
+
+    let caller_frame = &trace.root.calls[0];
+    assert_eq!(
+        caller_frame.function.as_deref(),
+        Some("increment_from_contract")
+    );
+    // ANCHOR_END: call_trace
+
+    assert_eq!(caller_frame.contract_id, caller_id);
+    assert_eq!(
+        caller_frame.outcome,
+        FrameOutcome::Returned {
+            value: Some(Token::U64(43))
+        }
+    );
+    assert_eq!(
+        caller_frame.calls[0].contract_id,
+        ContractId::from(lib_contract_id)
+    );
+    assert_eq!(trace.gas_used, Some(response.gas_used));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_reverting_transaction() -> Result<()> {
     setup_program_test!(
@@ -200,6 +277,178 @@ async fn test_multi_call_beginner() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn call_post_conditions_are_checked() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_methods = contract_instance.methods();
+
+    let value = contract_methods
+        .get_single(7)
+        .with_post_condition(|receipts| {
+            receipts
+                .iter()
+                .any(|receipt| matches!(receipt, fuels::tx::Receipt::ReturnData { .. }))
+        })
+        .call()
+        .await?
+        .value;
+    assert_eq!(value, 7);
+
+    let response = contract_methods
+        .get_single(7)
+        .with_post_condition(|_| true)
+        .with_post_condition(|receipts| receipts.is_empty())
+        .simulate()
+        .await;
+
+    assert!(matches!(
+        response,
+        Err(Error::Transaction(Reason::PostConditionFailed { index: 1, ref receipts }))
+            if !receipts.is_empty()
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn call_response_can_include_the_submitted_tx() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_methods = contract_instance.methods();
+
+    let response = contract_methods.get_single(7).call().await?;
+    assert!(response.tx.is_none());
+
+    let response = contract_methods
+        .get_single(7)
+        .with_tx_in_response(true)
+        .call()
+        .await?;
+
+    let chain_id = wallet.try_provider()?.chain_id();
+    let tx = response.tx.as_ref().expect("tx should be included");
+    assert_eq!(Some(tx.id(chain_id)), response.tx_id);
+
+    assert!(!response
+        .tx_bytes()
+        .expect("tx should be included")
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gas_price_strategy_tips_like_recent_transactions() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let contract_methods = contract_instance.methods();
+
+    contract_methods
+        .get_single(7)
+        .with_tx_policies(TxPolicies::default().with_tip(100))
+        .call()
+        .await?;
+    assert_eq!(provider.estimate_tip(100, 10).await?, 100);
+
+    // ANCHOR: gas_price_strategy
+    let strategy = GasPriceStrategy::Percentile {
+        percentile: 100,
+        blocks: 10,
+        block_horizon: 5,
+    };
+
+    let response = contract_methods
+        .get_single(7)
+        .with_gas_price_strategy(strategy)
+        .with_tx_in_response(true)
+        .call()
+        .await?;
+    // ANCHOR_END: gas_price_strategy
+
+    let tx = response.tx.as_ref().expect("tx should be included");
+    assert_eq!(tx.tip(), Some(100));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn simulate_many_returns_results_in_order() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_methods = contract_instance.methods();
+    let calls = (0..6)
+        .map(|value| {
+            let call = contract_methods.get_single(value);
+            if value == 3 {
+                call.with_post_condition(|_| false)
+            } else {
+                call
+            }
+        })
+        .collect();
+
+    let results = fuels::programs::calls::simulate_many(calls, 4).await;
+
+    assert_eq!(results.len(), 6);
+    for (value, result) in (0..6).zip(results) {
+        if value == 3 {
+            assert!(matches!(
+                result,
+                Err(Error::Transaction(Reason::PostConditionFailed { .. }))
+            ));
+        } else {
+            assert_eq!(result?.value, value);
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_pro() -> Result<()> {
     setup_program_test!(
@@ -361,6 +610,137 @@ async fn mult_call_has_same_estimated_and_used_gas() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn estimation_drift_is_reported_after_a_call() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: estimation_report
+    let reports = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let reported = reports.clone();
+
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .with_estimation_report(move |report| reported.lock().unwrap().push(*report))
+        .call()
+        .await?;
+    // ANCHOR_END: estimation_report
+
+    let reports = reports.lock().unwrap();
+    let [report] = reports.as_slice() else {
+        panic!("expected a single report, got {reports:?}")
+    };
+    assert_eq!(report.gas_used, response.gas_used);
+    assert_eq!(Some(report.tx_id), response.tx_id);
+    assert!(report.gas_drift() >= 0.0);
+    assert!(report.fee > 0);
+    assert!(report.to_string().contains(&report.tx_id.to_string()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gas_profile_of_contract_methods() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: gas_profile
+    let methods = contract_instance.methods();
+
+    let mut profile = GasProfile::new();
+    profile
+        .profile("initialize_counter", methods.initialize_counter(42))
+        .await?;
+    profile
+        .profile("increment_counter", methods.increment_counter(10))
+        .await?;
+    profile
+        .profile("get_counter", methods.get_counter())
+        .await?;
+
+    // e.g. read from a file checked in next to the tests
+    let baseline = GasProfile::from_json(&profile.to_json())?;
+    let regressions = profile.regressions(&baseline, 0.05);
+    assert!(regressions.is_empty(), "gas regressions: {regressions:?}");
+    // ANCHOR_END: gas_profile
+
+    assert_eq!(profile.iter().count(), 3);
+    assert!(profile
+        .to_rust_constants()
+        .contains("pub const GET_COUNTER_GAS: u64 = "));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gas_breakdown_of_a_dry_run() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: call_gas_profile
+    let profile = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .profile()
+        .await?;
+
+    let summary = profile.to_string();
+    assert!(summary.starts_with(&format!("total: {} gas", profile.total_gas())));
+    let json = profile.to_json();
+    assert!(json.contains("\"storage_costs\""));
+    // ANCHOR_END: call_gas_profile
+
+    assert!(profile.script_gas_used > 0);
+    assert!(profile.total_gas() > profile.script_gas_used);
+    assert_eq!(profile.calls.len(), 1);
+    assert_eq!(
+        profile.calls[0].function.as_deref(),
+        Some("initialize_counter")
+    );
+
+    // the dry run leaves the state untouched
+    let counter = contract_instance
+        .methods()
+        .get_counter()
+        .simulate()
+        .await?
+        .value;
+    assert_eq!(counter, 0);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn contract_method_call_respects_maturity() -> Result<()> {
     setup_program_test!(
@@ -640,6 +1020,87 @@ async fn test_connect_wallet() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn fee_payer_sponsors_the_call() -> Result<()> {
+    setup_program_test!(
+        Wallets("sponsor"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "sponsor"
+        ),
+    );
+    let provider = sponsor.try_provider()?.clone();
+    let user = WalletUnlocked::new_random(Some(provider));
+    let sponsor_balance = sponsor.get_asset_balance(&AssetId::zeroed()).await?;
+
+    // ANCHOR: fee_payer
+    let response = contract_instance
+        .with_account(user.clone())
+        .methods()
+        .initialize_counter(42)
+        .with_fee_payer(sponsor.clone())
+        .call()
+        .await?;
+    // ANCHOR_END: fee_payer
+
+    assert_eq!(response.value, 42);
+    assert_eq!(user.get_asset_balance(&AssetId::zeroed()).await?, 0);
+    assert!(sponsor.get_asset_balance(&AssetId::zeroed()).await? < sponsor_balance);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn call_history_decodes_past_calls() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+
+    // ANCHOR: call_history
+    let history = contract_instance
+        .call_history(PaginationRequest {
+            cursor: None,
+            results: 100,
+            direction: PageDirection::Forward,
+        })
+        .await?;
+
+    for call in &history.results {
+        println!("{} called {}({:?})", call.tx_id, call.function, call.args);
+    }
+    // ANCHOR_END: call_history
+
+    let call = history
+        .results
+        .iter()
+        .find(|call| Some(call.tx_id) == response.tx_id)
+        .expect("the call should be in the history");
+    assert_eq!(call.function, "initialize_counter");
+    assert_eq!(call.args, Some(vec![("value".to_string(), Token::U64(42))]));
+    assert!(call.succeeded());
+
+    Ok(())
+}
+
 async fn setup_output_variable_estimation_test() -> Result<(
     Vec<WalletUnlocked>,
     [Identity; 3],
@@ -799,6 +1260,74 @@ async fn test_contract_instance_get_balances() -> Result<()> {
     let random_asset_balance = contract_balances.get(random_asset_id).unwrap();
     assert_eq!(*random_asset_balance, amount);
 
+    assert_eq!(
+        contract_instance.get_balance(*random_asset_id).await?,
+        amount
+    );
+    assert_eq!(contract_instance.get_balance(asset_ids[0]).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_instance_balances_can_be_paginated() -> Result<()> {
+    use fuels::client::{PageDirection, PaginationRequest};
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let (coins, asset_ids) = setup_multiple_assets_coins(wallet.address(), 3, 1, 8);
+    let provider = setup_test_provider(coins.clone(), vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    setup_program_test!(
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    for asset_id in &asset_ids[1..] {
+        wallet
+            .force_transfer_to_contract(
+                contract_instance.contract_id(),
+                4,
+                *asset_id,
+                TxPolicies::default(),
+            )
+            .await?;
+    }
+
+    // ANCHOR: contract_balances_paginated
+    let mut balances = vec![];
+    let mut cursor = None;
+    loop {
+        let page = contract_instance
+            .get_balances_paginated(PaginationRequest {
+                cursor,
+                results: 1,
+                direction: PageDirection::Forward,
+            })
+            .await?;
+        balances.extend(page.results);
+
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.cursor;
+    }
+    // ANCHOR_END: contract_balances_paginated
+
+    balances.sort();
+    let mut expected = asset_ids[1..]
+        .iter()
+        .map(|asset_id| (*asset_id, 4))
+        .collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(balances, expected);
+
     Ok(())
 }
 
@@ -986,7 +1515,7 @@ async fn test_contract_call_with_non_default_max_input() -> Result<()> {
 
     let provider = setup_test_provider(coins, vec![], None, Some(chain_config)).await?;
     wallet.set_provider(provider.clone());
-    assert_eq!(consensus_parameters, *provider.consensus_parameters());
+    assert_eq!(consensus_parameters, provider.consensus_parameters());
 
     setup_program_test!(
         Abigen(Contract(
@@ -1278,13 +1807,12 @@ async fn low_level_call() -> Result<()> {
 #[cfg(any(not(feature = "fuel-core-lib"), feature = "rocksdb"))]
 #[test]
 fn db_rocksdb() {
-    use std::{fs, str::FromStr};
+    use std::fs;
 
     use fuels::{
-        accounts::wallet::WalletUnlocked,
         client::{PageDirection, PaginationRequest},
-        crypto::SecretKey,
         prelude::{setup_test_provider, DbType, Error, ViewOnlyAccount, DEFAULT_COIN_AMOUNT},
+        test_helpers::constants::test_wallet,
     };
 
     let temp_dir = tempfile::tempdir().expect("failed to make tempdir");
@@ -1300,12 +1828,7 @@ fn db_rocksdb() {
         .expect("tokio runtime failed")
         .block_on(async {
             let _ = temp_dir;
-            let wallet = WalletUnlocked::new_from_private_key(
-                SecretKey::from_str(
-                    "0x4433d156e8c53bf5b50af07aa95a29436f29a94e0ccc5d58df8e57bdc8583c32",
-                )?,
-                None,
-            );
+            let wallet = test_wallet(0, None);
 
             const NUMBER_OF_ASSETS: u64 = 2;
             let node_config = NodeConfig {
@@ -1348,12 +1871,7 @@ fn db_rocksdb() {
 
             let provider = setup_test_provider(vec![], vec![], Some(node_config), None).await?;
             // the same wallet that was used when rocksdb was built. When we connect it to the provider, we expect it to have the same amount of assets
-            let mut wallet = WalletUnlocked::new_from_private_key(
-                SecretKey::from_str(
-                    "0x4433d156e8c53bf5b50af07aa95a29436f29a94e0ccc5d58df8e57bdc8583c32",
-                )?,
-                None,
-            );
+            let mut wallet = test_wallet(0, None);
 
             wallet.set_provider(provider.clone());
 
@@ -1485,6 +2003,26 @@ async fn test_contract_submit_and_response() -> Result<()> {
     assert_eq!(val_1, 7);
     assert_eq!(val_2, 42);
 
+    // ANCHOR: await_commit
+    let submitted_tx = contract_methods.get(3, 4).submit().await?;
+    let value = submitted_tx
+        .await_commit(std::time::Duration::from_secs(10))
+        .await?
+        .value;
+    // ANCHOR_END: await_commit
+    assert_eq!(value, 7);
+
+    let provider = wallet.try_provider()?;
+    let tx_id = contract_methods.get(5, 6).submit().await?.tx_id();
+    let statuses: Vec<_> = provider
+        .subscribe_tx_status(&tx_id)
+        .await?
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<_>>()?;
+    assert!(matches!(statuses.last(), Some(TxStatus::Success { .. })));
+
     Ok(())
 }
 
@@ -1902,3 +2440,288 @@ async fn contract_call_with_non_zero_base_asset_id_and_tip() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn setup_program_test_funds_configures_and_initializes() -> Result<()> {
+    // ANCHOR: setup_program_test_scenario
+    setup_program_test!(
+        Wallets("alice", "bob"),
+        Abigen(
+            Contract(
+                name = "TokenContract",
+                project = "e2e/sway/contracts/token_ops"
+            ),
+            Contract(
+                name = "ConfigurablesContract",
+                project = "e2e/sway/contracts/configurables"
+            ),
+        ),
+        Fund(
+            to = "bob",
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101",
+            amount = 500,
+            coins = 2
+        ),
+        Fund(to = "bob", amount = 1_000_000),
+        Deploy(name = "token", contract = "TokenContract", wallet = "alice"),
+        Deploy(
+            name = "configurables",
+            contract = "ConfigurablesContract",
+            wallet = "alice",
+            configurables = "ConfigurablesContractConfigurables::default().with_U8(7).unwrap()"
+        ),
+        Fund(to = "token", amount = 100),
+        Setup(call = "token.methods().mint_coins(1_000)"),
+    );
+    // ANCHOR_END: setup_program_test_scenario
+
+    let custom_asset = AssetId::new([1; 32]);
+    assert_eq!(bob.get_asset_balance(&custom_asset).await?, 1_000);
+    assert_eq!(bob.get_asset_balance(&AssetId::zeroed()).await?, 1_000_000);
+
+    assert_eq!(token.get_balance(AssetId::zeroed()).await?, 100);
+    let minted_asset = token.contract_id().asset_id(&Bits256::zeroed());
+    assert_eq!(token.get_balance(minted_asset).await?, 1_000);
+
+    let (_, u8_value, ..) = configurables
+        .methods()
+        .return_configurables()
+        .call()
+        .await?
+        .value;
+    assert_eq!(u8_value, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_is_called_through_a_proxy() -> Result<()> {
+    use fuels::programs::contract::Proxied;
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(
+            Contract(
+                name = "LibContract",
+                project = "e2e/sway/contracts/lib_contract"
+            ),
+            Contract(name = "ProxyContract", project = "e2e/sway/contracts/proxy"),
+        ),
+        Deploy(
+            name = "implementation",
+            contract = "LibContract",
+            wallet = "wallet"
+        ),
+        Deploy(
+            name = "new_implementation",
+            contract = "LibContract",
+            wallet = "wallet"
+        ),
+        Deploy(
+            name = "proxy",
+            contract = "ProxyContract",
+            wallet = "wallet"
+        ),
+    );
+    proxy
+        .methods()
+        .set_proxy_target(implementation.contract_id().into())
+        .call()
+        .await?;
+
+    // ANCHOR: proxied_contract
+    let mut proxied = Proxied::from_target(LibContract::new(
+        proxy.contract_id().clone(),
+        wallet.clone(),
+    ))
+    .await?;
+
+    // the implementation is added to the call
+    let response = proxied.methods().increment(41).call().await?;
+    // ANCHOR_END: proxied_contract
+    assert_eq!(response.value, 42);
+    assert_eq!(proxied.implementation_id(), implementation.contract_id());
+
+    // when
+    proxy
+        .methods()
+        .set_proxy_target(new_implementation.contract_id().into())
+        .call()
+        .await?;
+    // ANCHOR: refresh_proxy_target
+    proxied.refresh_target().await?;
+    // ANCHOR_END: refresh_proxy_target
+
+    // then
+    assert_eq!(
+        proxied.implementation_id(),
+        new_implementation.contract_id()
+    );
+    let response = proxied.methods().increment(1).call().await?;
+    assert_eq!(response.value, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_is_deployed_behind_a_proxy() -> Result<()> {
+    use fuels::programs::contract::{Proxied, ProxyConfig};
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LibContract",
+            project = "e2e/sway/contracts/lib_contract"
+        )),
+    );
+
+    // ANCHOR: deploy_as_proxied
+    let proxy = Contract::load_from(
+        "sway/contracts/proxy/out/release/proxy.bin",
+        LoadConfiguration::default(),
+    )?;
+
+    let deployment = Contract::load_from(
+        "sway/contracts/lib_contract/out/release/lib_contract.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy_as_proxied(&wallet, ProxyConfig::new(proxy))
+    .await?;
+
+    let contract = Proxied::new(
+        LibContract::new(deployment.proxy_id.clone(), wallet.clone()),
+        deployment.implementation_id.clone(),
+    );
+    // ANCHOR_END: deploy_as_proxied
+
+    let response = contract.methods().increment(1).call().await?;
+    assert_eq!(response.value, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy_is_upgraded_and_rolled_back_on_failure() -> Result<()> {
+    use fuels::programs::contract::{ProxyConfig, ProxyContract, UpgradeConfig};
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let load = |salt: [u8; 32]| {
+        Contract::load_from(
+            "sway/contracts/lib_contract/out/release/lib_contract.bin",
+            LoadConfiguration::default().with_salt(salt),
+        )
+    };
+    let proxy = Contract::load_from(
+        "sway/contracts/proxy/out/release/proxy.bin",
+        LoadConfiguration::default(),
+    )?;
+    let deployment = load([0; 32])?
+        .deploy_as_proxied(&wallet, ProxyConfig::new(proxy))
+        .await?;
+
+    // ANCHOR: upgrade_proxy
+    let proxy = ProxyContract::new(deployment.proxy_id.clone(), wallet.clone());
+
+    let upgrade = proxy
+        .upgrade_to(load([1; 32])?, UpgradeConfig::default())
+        .await?;
+    // ANCHOR_END: upgrade_proxy
+    assert_eq!(
+        upgrade.previous_implementation_id,
+        deployment.implementation_id
+    );
+    assert_eq!(proxy.target().await?, upgrade.implementation_id);
+
+    // `require` always reverts
+    let error = proxy
+        .upgrade_to(
+            load([2; 32])?,
+            UpgradeConfig::default().with_verification("require"),
+        )
+        .await
+        .expect_err("the verification fails");
+
+    assert!(error.to_string().contains("rolled back"));
+    assert_eq!(proxy.target().await?, upgrade.implementation_id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_is_deployed_only_if_missing() -> Result<()> {
+    use std::time::Duration;
+
+    use fuels::programs::contract::{DeployDeadlines, DeployResponse};
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let binary_path = "sway/contracts/contract_test/out/release/contract_test.bin";
+
+    // ANCHOR: deploy_if_not_exists
+    let deadlines = DeployDeadlines::default()
+        .with_existence_check(Duration::from_secs(5))
+        .with_deployment(Duration::from_secs(30));
+
+    let response = Contract::load_from(binary_path, LoadConfiguration::default())?
+        .deploy_if_not_exists(&wallet, TxPolicies::default(), deadlines)
+        .await?;
+
+    match &response {
+        DeployResponse::NewlyDeployed { contract_id, tx_id } => {
+            println!("deployed `{contract_id}` in transaction `{tx_id}`")
+        }
+        DeployResponse::AlreadyDeployed { contract_id } => {
+            println!("`{contract_id}` was already deployed")
+        }
+    }
+    // ANCHOR_END: deploy_if_not_exists
+    assert!(response.tx_id().is_some());
+
+    let rerun = Contract::load_from(binary_path, LoadConfiguration::default())?
+        .deploy_if_not_exists(&wallet, TxPolicies::default(), deadlines)
+        .await?;
+
+    assert_eq!(
+        rerun,
+        DeployResponse::AlreadyDeployed {
+            contract_id: response.contract_id().clone()
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn multicall_template_only_encodes_arguments() -> Result<()> {
+    use fuels::programs::calls::MulticallTemplate;
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: multicall_template
+    let contract_methods = contract_instance.methods();
+    let template = MulticallTemplate::new(contract_methods.get(0, 0));
+
+    for round in 0..3u64 {
+        let args = (0..5).map(|i| (round, i));
+        let sums = template.call(args).await?.value;
+
+        assert_eq!(sums, (0..5).map(|i| round + i).collect::<Vec<_>>());
+    }
+    // ANCHOR_END: multicall_template
+
+    let single = MulticallTemplate::new(contract_methods.get_single(0));
+    let (first, second): (u64, u64) = single.with_args([7u64, 42]).call().await?.value;
+    assert_eq!((first, second), (7, 42));
+
+    Ok(())
+}
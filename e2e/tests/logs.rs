@@ -1,7 +1,7 @@
 use fuels::{
     core::codec::DecoderConfig,
     prelude::*,
-    types::{errors::transaction::Reason, AsciiString, Bits256, SizedAsciiString},
+    types::{errors::transaction::Reason, AsciiString, Bits256, SizedAsciiString, Token},
 };
 
 #[tokio::test]
@@ -42,6 +42,100 @@ async fn test_parse_logged_variables() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn logs_are_decoded_into_the_events_enum() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: decode_logs_as
+    let response = contract_instance
+        .methods()
+        .produce_logs_variables()
+        .call()
+        .await?;
+
+    let events = response.decode_logs_as::<LogContractEvents>()?;
+
+    let logged_u64s = events
+        .iter()
+        .filter_map(|event| match event {
+            LogContractEvents::U64(value) => Some(*value),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(logged_u64s, [64]);
+
+    let only_u64s = contract_instance
+        .events()
+        .matching(|event| matches!(event, LogContractEvents::U64(_)))
+        .decode(&response.receipts)?;
+    // ANCHOR_END: decode_logs_as
+
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[0], LogContractEvents::U64(64));
+    assert_eq!(
+        events[2],
+        LogContractEvents::SizedAsciiString4("Fuel".try_into()?)
+    );
+    assert_eq!(events[3], LogContractEvents::U8Array3([1, 2, 3]));
+    assert_eq!(only_u64s, vec![LogContractEvents::U64(64)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn logs_are_read_from_receipts() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_variables()
+        .call()
+        .await?;
+
+    // ANCHOR: receipt_adapters
+    let receipts = &response.receipts;
+
+    let first_log = receipts.logs().next().expect("the contract logs");
+    assert_eq!(
+        first_log.contract_id,
+        contract_instance.contract_id().into()
+    );
+    assert_eq!(first_log.decode::<u64>()?, 64);
+
+    let logged_u64 = receipts
+        .iter()
+        .find_map(|receipt| receipt.decoded_data::<u64>().transpose())
+        .transpose()?;
+    assert_eq!(logged_u64, Some(64));
+
+    assert_eq!(receipts.transfers().count(), 0);
+    // ANCHOR_END: receipt_adapters
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_parse_logs_values() -> Result<()> {
     setup_program_test!(
@@ -412,6 +506,54 @@ async fn test_multi_call_contract_with_contract_logs() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn logs_of_external_contracts_are_decoded_with_an_abi_registry() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "ContractCaller",
+            project = "e2e/sway/logs/contract_with_contract_logs"
+        )),
+        Deploy(
+            name = "contract_caller_instance",
+            contract = "ContractCaller",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_id = Contract::load_from(
+        "./sway/logs/contract_logs/out/release/contract_logs.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    // ANCHOR: abi_registry
+    let mut abi_registry = AbiRegistry::new();
+    abi_registry.register(
+        &contract_id,
+        &std::fs::read_to_string("./sway/logs/contract_logs/out/release/contract_logs-abi.json")?,
+    )?;
+
+    let response = contract_caller_instance
+        .methods()
+        .logs_from_external_contract(contract_id.clone())
+        .with_contract_ids(&[contract_id.clone()])
+        .with_abi_registry(abi_registry)
+        .call()
+        .await?;
+
+    let logs = response.decode_logs();
+    // ANCHOR_END: abi_registry
+
+    assert_eq!(
+        logs.filter_succeeded(),
+        vec!["U64(64)", "U32(32)", "U16(16)", "U8(8)"]
+    );
+
+    Ok(())
+}
+
 fn assert_revert_containing_msg(msg: &str, error: Error) {
     assert!(matches!(error, Error::Transaction(Reason::Reverted { .. })));
     if let Error::Transaction(Reason::Reverted { reason, .. }) = error {
@@ -469,6 +611,98 @@ async fn test_require_log() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn reverts_are_decoded_into_their_parts() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "RequireContract",
+            project = "e2e/sway/contracts/require"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "RequireContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: decoded_revert
+    let error = contract_instance
+        .methods()
+        .require_custom_generic()
+        .call()
+        .await
+        .expect_err("should revert");
+
+    let revert = error.decoded_revert().expect("the call reverted");
+    assert_eq!(revert.contract_id, contract_instance.contract_id().into());
+    assert!(revert
+        .error_type
+        .as_deref()
+        .is_some_and(|error_type| error_type.starts_with("StructDeeplyNestedGeneric")));
+    // ANCHOR_END: decoded_revert
+    assert!(revert.message.contains("StructDeeplyNestedGeneric"));
+    assert!(error
+        .receipts()
+        .is_some_and(|receipts| !receipts.is_empty()));
+
+    let error = contract_instance
+        .methods()
+        .require_primitive()
+        .simulate()
+        .await
+        .expect_err("should revert");
+    let revert = error.decoded_revert().expect("the call reverted");
+    assert_eq!(revert.error_type.as_deref(), Some("u64"));
+    assert_eq!(revert.message, "42");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_calls_are_classified() -> Result<()> {
+    use fuels::programs::calls::{FailureClassifier, FailureKind};
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "RequireContract",
+            project = "e2e/sway/contracts/require"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "RequireContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: check_call
+    let classifier = FailureClassifier::default();
+
+    let failure = contract_instance
+        .methods()
+        .require_string()
+        .check_call(&classifier)
+        .await?
+        .expect("the call reverts");
+
+    // the revert message isn't a permission marker, so the call failed on its own logic
+    assert_eq!(failure.kind, FailureKind::Logic);
+    // ANCHOR_END: check_call
+
+    // the contract reverts with "fuel"
+    let classifier = FailureClassifier::default().with_permission_marker("fuel");
+    let failure = contract_instance
+        .methods()
+        .require_string()
+        .check_call(&classifier)
+        .await?
+        .expect("the call reverts");
+    assert_eq!(failure.kind, FailureKind::Permission);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_require_log_single_contract() -> Result<()> {
     setup_program_test!(
@@ -1593,3 +1827,160 @@ async fn script_heap_log() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn past_events_are_fetched_by_block_range() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let contract_methods = contract_instance.methods();
+
+    contract_methods.produce_logs_variables().call().await?;
+    let first_height = provider.latest_block_height().await?;
+    let response = contract_methods.produce_logs_variables().call().await?;
+    let second_height = provider.latest_block_height().await?;
+
+    // ANCHOR: fetch_events
+    let past_events = contract_instance
+        .events()
+        .matching(|event| matches!(event, LogContractEvents::U64(_)))
+        .from_block(first_height + 1)
+        .to_block(second_height)
+        .fetch()
+        .await?;
+
+    assert_eq!(past_events.len(), 1);
+    assert_eq!(past_events[0].event, LogContractEvents::U64(64));
+    assert_eq!(past_events[0].block_height, second_height);
+    // ANCHOR_END: fetch_events
+    assert_eq!(Some(past_events[0].tx_id), response.tx_id);
+    assert_eq!(past_events[0].block_height, second_height);
+
+    let all_events = contract_instance.events().fetch().await?;
+    assert_eq!(all_events.len(), 8);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn new_logs_are_streamed_and_reorgs_reported() -> Result<()> {
+    use fuels::programs::contract::LogUpdate;
+    use futures::StreamExt;
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let next_height = provider.latest_block_height().await? + 1;
+
+    // ANCHOR: subscribe_logs
+    let mut logs = contract_instance
+        .subscribe_logs::<u64>()?
+        .from_block(next_height)
+        .with_poll_interval(std::time::Duration::from_millis(100));
+    // ANCHOR_END: subscribe_logs
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_variables()
+        .call()
+        .await?;
+
+    // ANCHOR: follow_logs
+    let log = match logs.next().await.expect("subscriptions don't end")? {
+        LogUpdate::Logged(log) => log,
+        LogUpdate::Reorged { from_height } => {
+            panic!("blocks from {from_height} on were replaced")
+        }
+    };
+    // keep `log.cursor` to resume later with `start_after`
+    // ANCHOR_END: follow_logs
+
+    assert_eq!(log.value, 64);
+    assert_eq!(Some(log.tx_id), response.tx_id);
+    assert_eq!(log.cursor.block_height, next_height);
+
+    provider
+        .admin()
+        .expect("node was launched by the test helpers")
+        .regenerate_chain(1)
+        .await?;
+
+    let update = logs.next().await.expect("subscriptions don't end")?;
+    assert_eq!(
+        update,
+        LogUpdate::Reorged {
+            from_height: next_height
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn block_transactions_are_decoded_with_registered_abis() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let response = contract_instance
+        .methods()
+        .produce_logs_variables()
+        .call()
+        .await?;
+    let height = provider.latest_block_height().await?;
+
+    // ANCHOR: block_with_decoded_txs
+    let mut abi_registry = AbiRegistry::new();
+    let abi =
+        std::fs::read_to_string("sway/logs/contract_logs/out/release/contract_logs-abi.json")?;
+    abi_registry.register(contract_instance.contract_id(), &abi)?;
+
+    let block = provider
+        .block_with_decoded_txs(height, &abi_registry)
+        .await?
+        .expect("block exists");
+
+    let tx = block
+        .transactions
+        .iter()
+        .find(|tx| Some(tx.tx_id) == response.tx_id)
+        .expect("the call is in the block");
+    assert_eq!(tx.calls[0].function, "produce_logs_variables");
+    assert_eq!(tx.logs[0].value, Some(Token::U64(64)));
+    // ANCHOR_END: block_with_decoded_txs
+
+    assert_eq!(tx.calls.len(), 1);
+    assert_eq!(tx.calls[0].args, Some(vec![]));
+    assert_eq!(tx.logs.len(), 4);
+
+    Ok(())
+}
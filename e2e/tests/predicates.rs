@@ -1084,3 +1084,71 @@ async fn predicate_with_invalid_data_fails() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "experimental")]
+#[tokio::test]
+async fn multisig_template_is_spent_with_owner_signatures() -> Result<()> {
+    // ANCHOR: predicate_templates
+    let owners = [
+        WalletUnlocked::new_random(None),
+        WalletUnlocked::new_random(None),
+        WalletUnlocked::new_random(None),
+    ];
+    let owner_addresses = owners
+        .iter()
+        .map(|owner| owner.address().clone())
+        .collect::<Vec<_>>();
+
+    let mut predicate = PredicateTemplates::multisig(&owner_addresses, 2)?;
+    // ANCHOR_END: predicate_templates
+
+    let asset_id = AssetId::zeroed();
+    let coins = setup_single_asset_coins(predicate.address(), asset_id, 2, 1000);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    predicate.set_provider(provider.clone());
+    let receiver = WalletUnlocked::new_random(Some(provider.clone()));
+
+    // ANCHOR: spend_multisig_template
+    let inputs = predicate
+        .get_asset_inputs_for_amount(asset_id, 2000, None)
+        .await?;
+    let outputs = vec![Output::change(receiver.address().into(), 0, asset_id)];
+
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(owners[0].clone())?;
+    tb.add_signer(owners[2].clone())?;
+
+    let tx = tb.build(&provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+    // ANCHOR_END: spend_multisig_template
+
+    assert_address_balance(predicate.address(), &provider, asset_id, 0).await;
+    assert!(receiver.get_asset_balance(&asset_id).await? > 0);
+
+    Ok(())
+}
+
+#[cfg(feature = "experimental")]
+#[tokio::test]
+async fn hash_lock_template_is_spent_with_the_preimage() -> Result<()> {
+    let preimage = b"open sesame".to_vec();
+    let predicate = PredicateTemplates::hash_lock(fuels::crypto::Hasher::hash(&preimage));
+
+    // coins and messages are both spent
+    let (provider, _, receiver, receiver_balance, asset_id) =
+        setup_predicate_test(predicate.address(), 2, 2, 100).await?;
+
+    // ANCHOR: spend_hash_lock_template
+    let predicate = predicate.with_data(preimage).with_provider(provider);
+    predicate
+        .transfer(receiver.address(), 350, asset_id, TxPolicies::default())
+        .await?;
+    // ANCHOR_END: spend_hash_lock_template
+
+    assert_eq!(
+        receiver.get_asset_balance(&asset_id).await?,
+        receiver_balance + 350
+    );
+
+    Ok(())
+}
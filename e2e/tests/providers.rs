@@ -96,6 +96,39 @@ async fn test_network_error() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn provider_fails_over_to_the_next_node() -> Result<()> {
+    let start_node = || {
+        FuelService::start(
+            NodeConfig::default(),
+            ChainConfig::default(),
+            StateConfig::default(),
+        )
+    };
+    let primary = start_node().await?;
+    let backup = start_node().await?;
+    let primary_url = primary.bound_address().to_string();
+    let backup_url = backup.bound_address().to_string();
+
+    // ANCHOR: connect_with_failover
+    let policy = FailoverPolicy::default().with_request_timeout(std::time::Duration::from_secs(5));
+    let provider = Provider::connect_with_failover(
+        [&primary_url, &backup_url],
+        policy,
+        ProviderConfig::default(),
+    )
+    .await?;
+    // ANCHOR_END: connect_with_failover
+    assert!(provider.url().contains(&primary_url));
+
+    primary.stop().await.unwrap();
+
+    provider.latest_block_height().await?;
+    assert!(provider.url().contains(&backup_url));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_input_message() -> Result<()> {
     let compare_messages =
@@ -263,6 +296,231 @@ async fn can_set_custom_block_time() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn block_headers_can_be_verified() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::new(Some(2), None, None), None, None)
+            .await?;
+    let provider = wallets[0].try_provider()?;
+
+    wallets[0]
+        .transfer(
+            wallets[1].address(),
+            100,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await?;
+    provider.produce_blocks(2, None).await?;
+
+    // ANCHOR: verify_block_headers
+    let blocks = provider
+        .get_blocks(PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        })
+        .await?
+        .results;
+
+    let headers: Vec<_> = blocks.iter().map(|block| block.header.clone()).collect();
+    fuels::types::block::verify_header_chain(&headers)?;
+    for block in &blocks {
+        block.verify()?;
+    }
+    // ANCHOR_END: verify_block_headers
+
+    assert_eq!(blocks.len(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn admin_client_controls_the_test_node() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let recipient = WalletUnlocked::new_random(Some(provider.clone()));
+
+    // ANCHOR: admin_client
+    let admin = provider
+        .admin()
+        .expect("node was launched by the test helpers");
+
+    admin.fund(recipient.address(), 1_000).await?;
+    admin.set_gas_price(5).await?;
+    provider.produce_blocks(1, None).await?;
+    assert_eq!(provider.latest_gas_price().await?.gas_price, 5);
+
+    // back to genesis, without the funds sent by the faucet
+    admin.reset().await?;
+    // ANCHOR_END: admin_client
+
+    let balance = recipient.get_asset_balance(&AssetId::zeroed()).await?;
+    assert_eq!(balance, 0);
+    assert!(provider.healthy().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_node_can_be_slowed_down_and_its_chain_regenerated() -> Result<()> {
+    // ANCHOR: response_latency
+    let node_config = NodeConfig {
+        response_latency: Some(std::time::Duration::from_millis(300)),
+        ..NodeConfig::default()
+    };
+    let wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), None, None),
+        Some(node_config),
+        None,
+    )
+    .await?;
+    // ANCHOR_END: response_latency
+    let (sender, receiver) = (&wallets[0], &wallets[1]);
+    let provider = sender.try_provider()?;
+
+    let started = std::time::Instant::now();
+    provider.latest_block_height().await?;
+    assert!(started.elapsed() >= std::time::Duration::from_millis(300));
+
+    let (tx_id, _) = sender
+        .transfer(
+            receiver.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+    provider.produce_blocks(3, None).await?;
+    let height = provider.latest_block_height().await?;
+    let replaced_block = provider
+        .block_by_height(height.into())
+        .await?
+        .expect("block exists");
+
+    // ANCHOR: regenerate_chain
+    let admin = provider
+        .admin()
+        .expect("node was launched by the test helpers");
+
+    // the latest two blocks are replaced, the earlier ones are replayed from genesis
+    admin.regenerate_chain(2).await?;
+    // ANCHOR_END: regenerate_chain
+
+    assert_eq!(provider.latest_block_height().await?, height);
+    let new_block = provider
+        .block_by_height(height.into())
+        .await?
+        .expect("block exists");
+    assert_ne!(new_block.id, replaced_block.id);
+    assert!(provider.get_transaction_by_id(&tx_id).await?.is_some());
+    assert!(admin.regenerate_chain(height + 1).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn existing_transaction_can_be_reissued_from_a_template() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::new(Some(2), None, None), None, None)
+            .await?;
+    let (sender, receiver) = (&wallets[0], &wallets[1]);
+    let provider = sender.try_provider()?;
+    let amount = 100;
+    let tx = create_transfer(sender, amount, receiver.address()).await?;
+    let sent_tx_id = tx.id(provider.chain_id());
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .check(None)?;
+    let initial_balance = receiver.get_asset_balance(&AssetId::zeroed()).await?;
+
+    // ANCHOR: transaction_template
+    let mut tb = provider
+        .transaction_template(&sent_tx_id)
+        .await?
+        .expect("transaction is known to the node");
+    // the coins spent by the original transaction are left out
+    assert!(tb.inputs().is_empty());
+
+    tb.add_signer(sender.clone())?;
+    sender.adjust_for_fee(&mut tb, amount).await?;
+    let tx = tb.build(provider).await?;
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .check(None)?;
+    // ANCHOR_END: transaction_template
+
+    let balance = receiver.get_asset_balance(&AssetId::zeroed()).await?;
+    assert_eq!(balance, initial_balance + amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transactions_are_submitted_once_and_confirmed() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::new(Some(2), None, None), None, None)
+            .await?;
+    let provider = wallets[0].try_provider()?;
+    let tx = create_transfer(&wallets[0], 100, wallets[1].address()).await?;
+    let timeout = std::time::Duration::from_secs(5);
+
+    // ANCHOR: submit_and_confirm
+    let outcome = provider.submit_and_confirm(tx.clone(), 1, timeout).await?;
+
+    match outcome {
+        SubmitOutcome::Confirmed { status, .. } => status.check(None)?,
+        SubmitOutcome::TimedOut { tx_id } => println!("`{tx_id}` may still be included later"),
+        SubmitOutcome::Rejected { reason } => println!("rejected: {reason}"),
+    }
+    // ANCHOR_END: submit_and_confirm
+
+    // then asking for more confirmations than there are blocks times out
+    let short_timeout = std::time::Duration::from_millis(300);
+    let outcome = provider
+        .submit_and_confirm(tx.clone(), 3, short_timeout)
+        .await?;
+    assert!(matches!(outcome, SubmitOutcome::TimedOut { .. }));
+
+    // and once the blocks exist, the already included transaction is confirmed without being
+    // submitted again
+    provider.produce_blocks(2, None).await?;
+    let outcome = provider.submit_and_confirm(tx.clone(), 3, timeout).await?;
+    let SubmitOutcome::Confirmed { tx_id, .. } = outcome else {
+        panic!("expected the transaction to be confirmed, got {outcome:?}");
+    };
+    assert_eq!(tx_id, tx.id(provider.chain_id()));
+    assert_eq!(provider.latest_block_height().await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_produce_blocks_at_an_interval() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+    // ANCHOR: produce_blocks_with_interval
+    let start_time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let config = BlockProductionConfig::new(60)
+        .with_start_time(start_time)
+        .with_interval(std::time::Duration::from_secs(1));
+
+    let headers = provider.produce_blocks_with(config).await?;
+    // ANCHOR_END: produce_blocks_with_interval
+
+    assert_eq!(headers.len(), 60);
+    assert_eq!(headers[0].height, 1);
+    assert_eq!(headers[0].time, Some(start_time));
+    assert_eq!(
+        headers[59].time,
+        Some(start_time + Duration::try_seconds(59).unwrap())
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn can_retrieve_latest_block_time() -> Result<()> {
     let provider = setup_test_provider(vec![], vec![], None, None).await?;
@@ -452,6 +710,159 @@ async fn test_amount_and_asset_forwarding() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn multiple_assets_can_be_forwarded_in_one_call() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TokenContract",
+            project = "e2e/sway/contracts/token_ops"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TokenContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_id = contract_instance.contract_id();
+    let contract_methods = contract_instance.methods();
+    let base_asset_id = *wallet.try_provider()?.base_asset_id();
+    let token_id = contract_id.asset_id(&Bits256::zeroed());
+
+    contract_methods.mint_coins(1_000).call().await?;
+    contract_methods
+        .transfer(1_000, token_id, wallet.address().into())
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    // ANCHOR: forward_assets
+    let response = contract_methods
+        .get_msg_amount()
+        .forward_assets([(base_asset_id, 100), (token_id, 200)])?
+        .call::<(u64, u64)>()
+        .await?;
+    // ANCHOR_END: forward_assets
+
+    assert_eq!(response.value, (100, 200));
+    assert_eq!(wallet.get_asset_balance(&token_id).await?, 800);
+
+    let err = contract_methods
+        .get_msg_amount()
+        .forward_assets([(token_id, 1), (token_id, 2)])
+        .expect_err("should fail");
+    assert!(err.to_string().contains("is forwarded more than once"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn asset_supply_adds_up_mints_and_burns() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TokenContract",
+            project = "e2e/sway/contracts/token_ops"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TokenContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let contract_methods = contract_instance.methods();
+    let token_id = contract_instance.contract_id().asset_id(&Bits256::zeroed());
+
+    contract_methods.mint_coins(1_000).call().await?;
+    contract_methods.mint_coins(1_000).call().await?;
+    contract_methods.burn_coins(300).call().await?;
+
+    // ANCHOR: asset_supply
+    let mut supply = provider.asset_supply(token_id).await?;
+    assert_eq!((supply.minted, supply.burned), (2_000, 300));
+    assert_eq!(supply.circulating(), 1_700);
+
+    contract_methods.mint_coins(500).call().await?;
+    // only scans the transactions included since
+    provider.update_asset_supply(&mut supply).await?;
+    assert_eq!(supply.circulating(), 2_200);
+    // ANCHOR_END: asset_supply
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn funding_hook_acquires_the_missing_forwarded_assets() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet", "treasury"),
+        Abigen(Contract(
+            name = "TokenContract",
+            project = "e2e/sway/contracts/token_ops"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TokenContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_methods = contract_instance.methods();
+    let token_id = contract_instance.contract_id().asset_id(&Bits256::zeroed());
+
+    contract_methods.mint_coins(1_000).call().await?;
+    contract_methods
+        .transfer(1_000, token_id, treasury.address().into())
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    // ANCHOR: funding_hook
+    // Sends the missing tokens from a treasury. A real hook would rather swap another asset
+    // of the owner, e.g. with `build_tx` on a call to a DEX.
+    #[derive(Debug)]
+    struct Treasury(WalletUnlocked);
+
+    #[async_trait::async_trait]
+    impl FundingHook for Treasury {
+        async fn preparatory_tx(&self, shortfall: &FundingShortfall) -> Result<ScriptTransaction> {
+            let treasury = &self.0;
+            let inputs = treasury
+                .get_asset_inputs_for_amount(shortfall.asset_id, shortfall.missing(), None)
+                .await?;
+            let outputs = treasury.get_asset_outputs_for_amount(
+                &shortfall.owner,
+                shortfall.asset_id,
+                shortfall.missing(),
+            );
+
+            let mut tb =
+                ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+            treasury.add_witnesses(&mut tb)?;
+            treasury.adjust_for_fee(&mut tb, 0).await?;
+
+            tb.build(treasury.try_provider()?).await
+        }
+    }
+
+    let response = contract_methods
+        .get_msg_amount()
+        .call_params(
+            CallParameters::default()
+                .with_amount(300)
+                .with_asset_id(token_id),
+        )?
+        .with_funding_hook(Treasury(treasury.clone()))
+        .call()
+        .await?;
+    // ANCHOR_END: funding_hook
+
+    assert_eq!(response.value, 300);
+    assert_eq!(wallet.get_asset_balance(&token_id).await?, 0);
+    assert_eq!(treasury.get_asset_balance(&token_id).await?, 700);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_gas_errors() -> Result<()> {
     let mut wallet = WalletUnlocked::new_random(None);
@@ -747,7 +1158,6 @@ async fn test_sway_timestamp() -> Result<()> {
     Ok(())
 }
 
-#[cfg(feature = "coin-cache")]
 async fn create_transfer(
     wallet: &WalletUnlocked,
     amount: u64,
@@ -967,6 +1377,86 @@ async fn can_produce_blocks_with_trig_never() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn pending_transaction_is_replaced_by_one_paying_more() -> Result<()> {
+    let config = NodeConfig {
+        block_production: Trigger::Never,
+        ..NodeConfig::default()
+    };
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), Some(config), None)
+            .await?;
+    let wallet = &wallets[0];
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 100, None)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(wallets[1].address(), base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(provider).await?;
+    let stuck_tx_id = provider.send_transaction(tx).await?;
+
+    // ANCHOR: replace_transaction
+    let tx_id = provider
+        .replace_transaction(&stuck_tx_id, FeeBump::Percent(20), wallet)
+        .await?;
+    // ANCHOR_END: replace_transaction
+    provider.produce_blocks(1, None).await?;
+
+    assert!(matches!(
+        provider.tx_status(&tx_id).await?,
+        TxStatus::Success { .. }
+    ));
+    assert!(!matches!(
+        provider.tx_status(&stuck_tx_id).await?,
+        TxStatus::Success { .. }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_include_transactions_in_a_block_in_a_given_order() -> Result<()> {
+    let config = NodeConfig {
+        block_production: Trigger::Never,
+        ..NodeConfig::default()
+    };
+    let wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(3), None, None),
+        Some(config),
+        None,
+    )
+    .await?;
+    let provider = wallets[0].try_provider()?.clone();
+    let base_asset_id = *provider.base_asset_id();
+
+    let mut ordered_block = OrderedBlock::new();
+    for wallet in &wallets {
+        let inputs = wallet
+            .get_asset_inputs_for_amount(base_asset_id, 100, None)
+            .await?;
+        let outputs =
+            wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, 100);
+        let tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+
+        ordered_block = ordered_block.queue(tb, wallet.clone(), 100);
+    }
+
+    let tx_ids = ordered_block.produce(&provider).await?;
+
+    let block = provider
+        .block_by_height(provider.latest_block_height().await?.into())
+        .await?
+        .expect("block should exist");
+    assert_eq!(tx_ids.len(), 3);
+    assert_eq!(block.transactions[..3], tx_ids);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn can_upload_executor_and_trigger_upgrade() -> Result<()> {
     let mut wallet = WalletUnlocked::new_random(None);
@@ -1091,6 +1581,101 @@ async fn can_setup_static_gas_price() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn fee_preflight_catches_max_fees_below_the_floor() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, 1_000_000);
+    let node_config = NodeConfig {
+        static_gas_price: 100,
+        ..Default::default()
+    };
+    let provider = setup_test_provider(coins, vec![], Some(node_config), None).await?;
+    wallet.set_provider(provider.clone());
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(*provider.base_asset_id(), 100, None)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(
+        &Bech32Address::default(),
+        *provider.base_asset_id(),
+        100,
+    );
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_max_fee(1),
+    );
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(&provider).await?;
+
+    // ANCHOR: fee_preflight
+    let fee_check = provider
+        .check_fee(&tx, &FeePreflight::default().with_tip_floor(50, 10))
+        .await?;
+    assert!(!fee_check.is_sufficient());
+    assert!(matches!(
+        fee_check.warnings[0],
+        FeeWarning::MaxFeeBelowFloor { max_fee: 1, .. }
+    ));
+
+    // or have every transaction checked before it is sent
+    let provider =
+        provider.with_config(ProviderConfig::default().with_fee_preflight(FeePreflight::default()));
+    let err = provider
+        .send_transaction(tx)
+        .await
+        .expect_err("the max fee is below the floor");
+    assert!(err.to_string().contains("fee below current floor"));
+    // ANCHOR_END: fee_preflight
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn middleware_sees_every_request() -> Result<()> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    // ANCHOR: provider_middleware
+    #[derive(Debug, Default)]
+    struct RequestCounter {
+        requests: AtomicUsize,
+        failures: AtomicUsize,
+    }
+
+    #[derive(Debug)]
+    struct CountRequests(Arc<RequestCounter>);
+
+    impl Middleware for CountRequests {
+        fn on_request(&self, _request: &RequestContext) -> std::io::Result<()> {
+            self.0.requests.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn on_response(&self, _request: &RequestContext, response: &ResponseContext) {
+            if !response.is_success() {
+                self.0.failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    let counter = Arc::new(RequestCounter::default());
+    let provider = setup_test_provider(vec![], vec![], None, None)
+        .await?
+        .with_middleware(CountRequests(counter.clone()));
+
+    provider.latest_block_height().await?;
+    provider.node_info().await?;
+    // ANCHOR_END: provider_middleware
+
+    assert_eq!(counter.requests.load(Ordering::Relaxed), 2);
+    assert_eq!(counter.failures.load(Ordering::Relaxed), 0);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn tx_with_witness_data() -> Result<()> {
     use fuel_asm::{op, GTFArgs};
@@ -1163,3 +1748,58 @@ async fn tx_with_witness_data() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "fuel-core-lib"))]
+#[tokio::test]
+async fn node_logs_are_captured() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+    let logs = provider
+        .node_logs()
+        .expect("node was launched by the test helpers");
+
+    assert!(!logs.lines().is_empty());
+    assert!(Provider::connect(provider.url())
+        .await?
+        .node_logs()
+        .is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lazy_provider_defers_reaching_the_node() -> Result<()> {
+    // nothing listens on port 1
+    let offline = Provider::connect_lazy("127.0.0.1:1", ProviderConfig::default())?;
+    assert!(!offline.is_warmed_up());
+    assert!(offline.try_consensus_parameters().is_err());
+    assert!(offline.fetch_consensus_parameters().await.is_err());
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, 1000);
+    let node = setup_test_provider(coins, vec![], None, None).await?;
+
+    // ANCHOR: connect_lazy
+    let provider = Provider::connect_lazy(node.url(), ProviderConfig::default())?;
+
+    // fetches the chain info ahead of the first request needing it
+    provider.warm_up().await?;
+    // ANCHOR_END: connect_lazy
+    assert!(provider.is_warmed_up());
+    assert_eq!(provider.chain_id(), node.chain_id());
+
+    // the SDK fetches the chain info on its own when building transactions
+    let provider = Provider::connect_lazy(node.url(), ProviderConfig::default())?;
+    wallet.set_provider(provider.clone());
+    wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+    assert!(provider.is_warmed_up());
+
+    Ok(())
+}
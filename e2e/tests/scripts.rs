@@ -368,3 +368,37 @@ async fn script_encoder_config_is_applied() {
         ));
     }
 }
+
+#[tokio::test]
+async fn scripts_run_in_process_without_a_node() -> Result<()> {
+    use fuels::{
+        core::{
+            codec::{ABIDecoder, ABIEncoder},
+            traits::{Parameterize, Tokenizable},
+        },
+        types::SizedAsciiString,
+    };
+
+    // ANCHOR: vm_dry_runner
+    let vm = VmDryRunner::default();
+
+    let script = std::fs::read("sway/scripts/basic_script/out/release/basic_script.bin")?;
+    let script_data = ABIEncoder::default().encode(&[1u64.into_token(), 2u32.into_token()])?;
+    let tx = ScriptTransactionBuilder::default()
+        .with_script(script)
+        .with_script_data(script_data)
+        .build(&vm)
+        .await?;
+
+    let receipts = vm.run(tx)?;
+    // ANCHOR_END: vm_dry_runner
+
+    let returned = receipts
+        .iter()
+        .find_map(|receipt| receipt.data())
+        .expect("the script returns a string");
+    let token = ABIDecoder::default().decode(&SizedAsciiString::<5>::param_type(), returned)?;
+    assert_eq!(SizedAsciiString::<5>::from_token(token)?, "hello");
+
+    Ok(())
+}
@@ -105,3 +105,23 @@ async fn storage_load_error_messages() {
         assert_eq!(error.to_string(), expected_error);
     }
 }
+
+#[tokio::test]
+async fn storage_growth_cost_is_charged_by_the_byte() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    // ANCHOR: storage_growth_cost
+    // the cost of ten more slots at the latest gas price
+    let growth = provider.storage_growth_cost(10).await?;
+    // ANCHOR_END: storage_growth_cost
+
+    let new_storage_per_byte = provider
+        .consensus_parameters()
+        .gas_costs()
+        .new_storage_per_byte();
+    // a slot is a 32 byte key and a 32 byte value
+    assert_eq!(growth.gas, 10 * 64 * new_storage_per_byte);
+
+    Ok(())
+}
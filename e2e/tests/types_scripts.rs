@@ -102,6 +102,50 @@ async fn main_function_option_result() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn main_function_err_is_surfaced_as_an_error() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Script(
+            name = "MyScript",
+            project = "e2e/sway/types/scripts/options_results"
+        )),
+        LoadScript(
+            name = "script_instance",
+            script = "MyScript",
+            wallet = "wallet"
+        )
+    );
+
+    let response = script_instance.main(Some(42), None).simulate_ok().await?;
+    assert_eq!(response.value, Some(true));
+
+    // ANCHOR: script_result
+    let err = script_instance
+        .main(None, Some(987))
+        .call_ok()
+        .await
+        .expect_err("the script returns `Err`");
+
+    let script_err = err
+        .script_err::<TestError>()
+        .expect("the script returned `Err`")?;
+    assert_eq!(script_err, TestError::ZimZam("error".try_into()?));
+
+    let decoded = err.decoded_revert().expect("script errors are decoded");
+    assert_eq!(decoded.error_type.as_deref(), Some("TestError"));
+    // ANCHOR_END: script_result
+
+    let err = script_instance
+        .main(None, None)
+        .simulate_ok()
+        .await
+        .expect_err("the script returns `Err`");
+    assert!(err.script_err::<TestError>().is_some());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn main_function_tuple_types() -> Result<()> {
     setup_program_test!(
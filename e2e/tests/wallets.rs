@@ -4,7 +4,7 @@ use fuel_tx::{input::coin::CoinSigned, Input};
 use fuels::{
     prelude::*,
     tx::{TxPointer, UtxoId},
-    types::{output::Output, Bytes32},
+    types::{bech32::FUEL_BECH32_HRP, output::Output, Bytes32},
 };
 
 #[tokio::test]
@@ -394,6 +394,31 @@ async fn transfer_more_than_owned() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn errors_are_prefixed_with_the_wallet_label() -> Result<()> {
+    const AMOUNT: u64 = 1000000;
+    let (wallet_1, wallet_2) = setup_transfer_test(AMOUNT).await?;
+
+    // ANCHOR: wallet_label
+    let relayer = wallet_1.with_label("relayer-3");
+    // ANCHOR_END: wallet_label
+
+    let err = relayer
+        .transfer(
+            wallet_2.address(),
+            AMOUNT * 2,
+            Default::default(),
+            TxPolicies::default(),
+        )
+        .await
+        .expect_err("should not have enough funds");
+
+    assert_eq!(relayer.label(), Some("relayer-3"));
+    assert!(err.to_string().contains("account `relayer-3`: "));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn transfer_coins_of_non_base_asset() -> Result<()> {
     const AMOUNT: u64 = 10000;
@@ -433,6 +458,46 @@ async fn transfer_coins_of_non_base_asset() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn coins_can_be_split_and_consolidated() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+
+    let asset_id = AssetId::from([1; 32usize]);
+    let mut coins = setup_single_asset_coins(wallet.address(), asset_id, 1, 1000);
+    // setup base asset coins to pay tx fees
+    coins.extend(setup_single_asset_coins(
+        wallet.address(),
+        AssetId::zeroed(),
+        1,
+        10000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider);
+
+    // ANCHOR: split_and_consolidate_coins
+    wallet
+        .split_coins(asset_id, 900, 9, TxPolicies::default())
+        .await?;
+
+    let tx_ids = wallet
+        .consolidate_coins(asset_id, 3, TxPolicies::default())
+        .await?;
+    // ANCHOR_END: split_and_consolidate_coins
+
+    // the split left 10 coins of 100, including the change. 8 of them were merged into one
+    assert_eq!(tx_ids.len(), 1);
+    let coins = wallet.get_coins(asset_id).await?;
+    assert_eq!(coins.len(), 3);
+    assert_eq!(wallet.get_asset_balance(&asset_id).await?, 1000);
+
+    let no_tx_ids = wallet
+        .consolidate_coins(asset_id, 3, TxPolicies::default())
+        .await?;
+    assert!(no_tx_ids.is_empty());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_transfer_with_multiple_signatures() -> Result<()> {
     let wallet_config = base_asset_wallet_config(5);
@@ -478,3 +543,64 @@ async fn test_transfer_with_multiple_signatures() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn co_signers_get_their_own_change() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(base_asset_wallet_config(2), None, None).await?;
+    let provider = wallets[0].try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+    let (sender, fee_payer) = (&wallets[0], &wallets[1]);
+    let receiver = Bech32Address::new(FUEL_BECH32_HRP, [1; 32]);
+    let fee_payer_vault = Bech32Address::new(FUEL_BECH32_HRP, [2; 32]);
+
+    // ANCHOR: co_signed_change
+    let amount = 30;
+    let sender_inputs = sender
+        .get_asset_inputs_for_amount(base_asset_id, amount, None)
+        .await?;
+    let sender_change = sender_inputs
+        .iter()
+        .filter_map(|input| input.amount())
+        .sum::<u64>()
+        - amount;
+    let fee_payer_inputs = fee_payer
+        .get_asset_inputs_for_amount(base_asset_id, 1, None)
+        .await?;
+
+    // the change output of the base asset goes to the vault of the fee payer, so the change of
+    // the sender is given back with a coin output
+    let outputs = vec![
+        Output::coin((&receiver).into(), amount, base_asset_id),
+        Output::coin(sender.address().into(), sender_change, base_asset_id),
+    ];
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        [sender_inputs, fee_payer_inputs].concat(),
+        outputs,
+        TxPolicies::default(),
+    )
+    .with_change_address(fee_payer.address(), &fee_payer_vault);
+    tb.add_signer(sender.clone())?;
+    tb.add_signer(fee_payer.clone())?;
+    // ANCHOR_END: co_signed_change
+
+    let tx = tb.build(provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    assert_eq!(
+        provider.get_asset_balance(&receiver, base_asset_id).await?,
+        amount
+    );
+    assert_eq!(
+        sender.get_asset_balance(&base_asset_id).await?,
+        400 - amount
+    );
+    assert!(
+        provider
+            .get_asset_balance(&fee_payer_vault, base_asset_id)
+            .await?
+            > 0
+    );
+
+    Ok(())
+}
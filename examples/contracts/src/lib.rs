@@ -120,6 +120,32 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn precompute_contract_id() -> Result<()> {
+        use fuels::prelude::*;
+
+        let wallet = launch_provider_and_get_wallet().await?;
+
+        // ANCHOR: precompute_contract_id
+        let contract = Contract::load_from(
+            "../../e2e/sway/contracts/contract_test/out/release/contract_test.bin",
+            LoadConfiguration::default(),
+        )?;
+
+        // a salt giving an id that starts with 0x0000
+        let salt = contract.salt_for_target_id(&[0x00, 0x00], 1_000_000)?;
+        let contract = contract.with_salt(salt);
+        let expected_id = contract.precompute_id();
+
+        let contract_id = contract.deploy(&wallet, TxPolicies::default()).await?;
+        // ANCHOR_END: precompute_contract_id
+
+        assert_eq!(contract_id, expected_id);
+        assert!(ContractId::from(&contract_id).starts_with(&[0x00, 0x00]));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn deploy_with_parameters() -> std::result::Result<(), Box<dyn std::error::Error>> {
         use fuels::{prelude::*, tx::StorageSlot, types::Bytes32};
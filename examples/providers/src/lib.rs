@@ -27,7 +27,7 @@ mod tests {
         // ANCHOR_END: connect_to_testnet
 
         let provider = setup_test_provider(vec![], vec![], None, None).await?;
-        let port = provider.url().split(':').last().unwrap();
+        let port = provider.url().split(':').next_back().unwrap();
 
         // ANCHOR: local_node_address
         let _provider = Provider::connect(format!("127.0.0.1:{port}")).await?;
@@ -67,8 +67,30 @@ mod tests {
             .await?
             .with_retry_config(retry_config);
         // ANCHOR_END: configure_retry
+
+        // ANCHOR: configure_provider
+        let polling_config = RetryConfig::new(50, Backoff::Exponential(Duration::from_millis(50)))?
+            .with_jitter(0.2)
+            .with_max_elapsed_time(Duration::from_secs(30));
+        let config = ProviderConfig::default().with_polling_config(polling_config);
+        let provider = provider.with_config(config);
+        // ANCHOR_END: configure_provider
         // ANCHOR_END: setup_test_blockchain
 
+        // ANCHOR: fetch_consensus_parameters
+        let config =
+            ProviderConfig::default().with_consensus_parameters_ttl(Duration::from_secs(60));
+        let provider = provider.with_config(config);
+
+        // answered from the cache until the parameters are a minute old
+        let consensus_parameters = provider.fetch_consensus_parameters().await?;
+        assert_eq!(consensus_parameters.chain_id(), provider.chain_id());
+
+        // e.g. after an upgrade of the chain
+        provider.invalidate_consensus_parameters();
+        let _refetched = provider.fetch_consensus_parameters().await?;
+        // ANCHOR_END: fetch_consensus_parameters
+
         // ANCHOR: get_coins
         let coins = provider
             .get_coins(wallet.address(), *provider.base_asset_id())
@@ -141,6 +141,26 @@ mod tests {
         Ok(())
     }
     #[tokio::test]
+    async fn address_format() -> Result<()> {
+        // ANCHOR: address_format
+        use fuels::types::bech32::{AddressFormat, Bech32Address};
+
+        let address = Bech32Address::new("fuel", [1u8; 32]);
+
+        let hex = address.format_as(AddressFormat::hex());
+        assert_eq!(
+            hex,
+            "0x0101010101010101010101010101010101010101010101010101010101010101"
+        );
+        assert_eq!(
+            address.format_as(AddressFormat::hex().truncated(4)),
+            "0x0101…0101"
+        );
+        // ANCHOR_END: address_format
+
+        Ok(())
+    }
+    #[tokio::test]
     async fn asset_id() -> Result<()> {
         // ANCHOR: asset_id
         use std::str::FromStr;
@@ -203,6 +223,15 @@ mod tests {
         let _identity_from_contract_id = Identity::ContractId(contract_id);
         // ANCHOR_END: contract_id_to_identity
 
+        // ANCHOR: identity_projections
+        let identity = Identity::from(contract_id);
+
+        assert!(identity.is_contract());
+        assert_eq!(identity.as_contract_id(), Some(&contract_id));
+        assert_eq!(identity.as_address(), None);
+        assert_eq!(identity.to_bits256(), Bits256(*contract_id));
+        // ANCHOR_END: identity_projections
+
         // ANCHOR: contract_id_to_str
         let _str_from_contract_id: &str = contract_id.to_string().as_str();
         // ANCHOR_END: contract_id_to_str
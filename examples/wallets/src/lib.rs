@@ -384,6 +384,14 @@ mod tests {
         assert_eq!(proof.recipient, base_layer_address);
         // ANCHOR_END: wallet_withdraw_to_base
 
+        // ANCHOR: relay_payload
+        use fuels::types::withdrawal::build_relay_payload;
+
+        // calldata for `relayMessage` on the base layer `FuelMessagePortal`
+        let calldata = build_relay_payload(&proof)?;
+        // ANCHOR_END: relay_payload
+        assert!(!calldata.is_empty());
+
         Ok(())
     }
 }
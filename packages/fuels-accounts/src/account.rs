@@ -9,20 +9,23 @@ use fuels_core::types::{
     coin::Coin,
     coin_type::CoinType,
     coin_type_id::CoinTypeId,
-    errors::Result,
+    errors::{error, Result},
     input::Input,
     message::Message,
     transaction::{Transaction, TxPolicies},
     transaction_builders::{BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder},
     transaction_response::TransactionResponse,
+    tx_status::TxStatus,
+    CoinSelectionPolicy,
 };
 
 use crate::{
     accounts_utils::{
         adjust_inputs_outputs, available_base_assets_and_amount, calculate_missing_base_amount,
-        extract_message_nonce, split_into_utxo_ids_and_nonces,
+        extract_message_nonce, split_amount, split_into_utxo_ids_and_nonces,
     },
     provider::{Provider, ResourceFilter},
+    resource_manager::resource_ids,
 };
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -31,6 +34,11 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
 
     fn try_provider(&self) -> Result<&Provider>;
 
+    /// A human-readable name for the account, see [`Wallet::with_label`](crate::wallet::Wallet::with_label).
+    fn label(&self) -> Option<&str> {
+        None
+    }
+
     async fn get_transactions(
         &self,
         request: PaginationRequest<String>,
@@ -92,6 +100,30 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
 
         self.try_provider()?.get_spendable_resources(filter).await
     }
+
+    /// Like [`ViewOnlyAccount::get_spendable_resources`], but the coins are selected locally
+    /// according to `policy` instead of by the node. Messages are never selected.
+    async fn get_spendable_coins_with_policy(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        policy: &CoinSelectionPolicy,
+    ) -> Result<Vec<CoinType>> {
+        let excluded_coins = excluded_coins.unwrap_or_default();
+        let coins = self
+            .get_coins(asset_id)
+            .await?
+            .into_iter()
+            .filter(|coin| !excluded_coins.contains(&CoinTypeId::UtxoId(coin.utxo_id)))
+            .collect();
+
+        Ok(policy
+            .select(coins, amount)?
+            .into_iter()
+            .map(CoinType::Coin)
+            .collect())
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -107,6 +139,24 @@ pub trait Account: ViewOnlyAccount {
         excluded_coins: Option<Vec<CoinTypeId>>,
     ) -> Result<Vec<Input>>;
 
+    /// Like [`Account::get_asset_inputs_for_amount`], but the coins are selected according to
+    /// `policy`. Used when the transaction builder has a
+    /// [`CoinSelectionPolicy`](fuels_core::types::CoinSelectionPolicy).
+    async fn get_asset_inputs_for_amount_with_policy(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        policy: &CoinSelectionPolicy,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_coins_with_policy(asset_id, amount, excluded_coins, policy)
+            .await?
+            .into_iter()
+            .map(Input::resource_signed)
+            .collect())
+    }
+
     /// Returns a vector containing the output coin and change output given an asset and amount
     fn get_asset_outputs_for_amount(
         &self,
@@ -131,36 +181,74 @@ pub trait Account: ViewOnlyAccount {
         used_base_amount: u64,
     ) -> Result<()> {
         let provider = self.try_provider()?;
-        let (base_assets, base_amount) =
-            available_base_assets_and_amount(tb, provider.base_asset_id());
+        let base_asset_id = *provider.fetch_consensus_parameters().await?.base_asset_id();
+        let (base_assets, base_amount) = available_base_assets_and_amount(tb, &base_asset_id);
         let missing_base_amount =
             calculate_missing_base_amount(tb, base_amount, used_base_amount, provider).await?;
 
         if missing_base_amount > 0 {
-            let new_base_inputs = self
-                .get_asset_inputs_for_amount(
-                    *provider.base_asset_id(),
-                    missing_base_amount,
-                    Some(base_assets),
-                )
-                .await?;
+            let new_base_inputs = match tb.coin_selection_policy() {
+                Some(policy) => {
+                    self.get_asset_inputs_for_amount_with_policy(
+                        base_asset_id,
+                        missing_base_amount,
+                        Some(base_assets),
+                        policy,
+                    )
+                    .await?
+                }
+                None => {
+                    self.get_asset_inputs_for_amount(
+                        base_asset_id,
+                        missing_base_amount,
+                        Some(base_assets),
+                    )
+                    .await?
+                }
+            };
 
-            adjust_inputs_outputs(
-                tb,
-                new_base_inputs,
-                self.address(),
-                provider.base_asset_id(),
-            );
+            adjust_inputs_outputs(tb, new_base_inputs, self.address(), &base_asset_id);
         };
 
         Ok(())
     }
 
+    /// Pays the fee of a transaction whose other inputs belong to someone else, e.g. to sponsor
+    /// the gas of their call. Adds and signs the base asset inputs covering the fee on top of
+    /// `used_base_amount`. The change goes back to this account, unless the builder already
+    /// has a change output for the base asset.
+    async fn sponsor<Tb: TransactionBuilder + Sync>(
+        &self,
+        tb: &mut Tb,
+        used_base_amount: u64,
+    ) -> Result<()> {
+        self.add_witnesses(tb)?;
+        self.adjust_for_fee(tb, used_base_amount).await
+    }
+
     // Add signatures to the builder if the underlying account is a wallet
     fn add_witnesses<Tb: TransactionBuilder>(&self, _tb: &mut Tb) -> Result<()> {
         Ok(())
     }
 
+    /// Called by the transfer helpers and contract calls once their transaction is committed or
+    /// failed to be, with the resources it spends, so that accounts tracking resources in flight
+    /// can release them.
+    fn release_resources(&self, _ids: &[CoinTypeId]) {}
+
+    /// A copy of the account to build transactions that are only dry run with, so that accounts
+    /// tracking resources in flight don't hold on to the ones such a transaction selects.
+    fn for_dry_run(&self) -> Self {
+        self.clone()
+    }
+
+    /// Called by the transfer helpers, contract calls and deployments right before sending a
+    /// transaction this account added its witnesses to, so that accounts limiting their spending
+    /// can count it. An error stops the transaction from being sent.
+    fn record_spending<T: Transaction>(&self, _tx: &T) -> Result<()> {
+        Ok(())
+    }
+
     /// Transfer funds from this account to another `Address`.
     /// Fails if amount for asset ID is larger than address's spendable coins.
     /// Returns the transaction ID that was sent and the list of receipts.
@@ -172,10 +260,12 @@ pub trait Account: ViewOnlyAccount {
         tx_policies: TxPolicies,
     ) -> Result<(TxId, Vec<Receipt>)> {
         let provider = self.try_provider()?;
+        let base_asset_id = *provider.fetch_consensus_parameters().await?.base_asset_id();
 
         let inputs = self
             .get_asset_inputs_for_amount(asset_id, amount, None)
-            .await?;
+            .await;
+        let inputs = in_account_context(self, inputs)?;
         let outputs = self.get_asset_outputs_for_amount(to, asset_id, amount);
 
         let mut tx_builder =
@@ -183,18 +273,11 @@ pub trait Account: ViewOnlyAccount {
 
         self.add_witnesses(&mut tx_builder)?;
 
-        let used_base_amount = if asset_id == *provider.base_asset_id() {
-            amount
-        } else {
-            0
-        };
+        let used_base_amount = if asset_id == base_asset_id { amount } else { 0 };
         self.adjust_for_fee(&mut tx_builder, used_base_amount)
             .await?;
 
-        let tx = tx_builder.build(provider).await?;
-        let tx_id = tx.id(provider.chain_id());
-
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let (tx_id, tx_status) = build_and_send(self, tx_builder).await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
 
@@ -217,8 +300,6 @@ pub trait Account: ViewOnlyAccount {
         asset_id: AssetId,
         tx_policies: TxPolicies,
     ) -> Result<(String, Vec<Receipt>)> {
-        let provider = self.try_provider()?;
-
         let zeroes = Bytes32::zeroed();
         let plain_contract_id: ContractId = to.into();
 
@@ -253,10 +334,7 @@ pub trait Account: ViewOnlyAccount {
         self.add_witnesses(&mut tb)?;
         self.adjust_for_fee(&mut tb, balance).await?;
 
-        let tx = tb.build(provider).await?;
-
-        let tx_id = tx.id(provider.chain_id());
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let (tx_id, tx_status) = build_and_send(self, tb).await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
 
@@ -273,9 +351,10 @@ pub trait Account: ViewOnlyAccount {
         tx_policies: TxPolicies,
     ) -> Result<(TxId, Nonce, Vec<Receipt>)> {
         let provider = self.try_provider()?;
+        let base_asset_id = *provider.fetch_consensus_parameters().await?.base_asset_id();
 
         let inputs = self
-            .get_asset_inputs_for_amount(*provider.base_asset_id(), amount, None)
+            .get_asset_inputs_for_amount(base_asset_id, amount, None)
             .await?;
 
         let mut tb = ScriptTransactionBuilder::prepare_message_to_output(
@@ -283,16 +362,13 @@ pub trait Account: ViewOnlyAccount {
             amount,
             inputs,
             tx_policies,
-            *provider.base_asset_id(),
+            base_asset_id,
         );
 
         self.add_witnesses(&mut tb)?;
         self.adjust_for_fee(&mut tb, amount).await?;
 
-        let tx = tb.build(provider).await?;
-
-        let tx_id = tx.id(provider.chain_id());
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let (tx_id, tx_status) = build_and_send(self, tb).await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
 
@@ -301,6 +377,141 @@ pub trait Account: ViewOnlyAccount {
 
         Ok((tx_id, nonce, receipts))
     }
+
+    /// Splits `amount` of `asset_id` into `parts` coins of (nearly) equal value owned by this
+    /// account, e.g. to send many transactions in parallel without them competing for the same
+    /// coins. The first coin gets the remainder of the division.
+    /// Returns the transaction ID and the list of receipts.
+    async fn split_coins(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        parts: usize,
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        let consensus_parameters = self.try_provider()?.fetch_consensus_parameters().await?;
+        let base_asset_id = *consensus_parameters.base_asset_id();
+
+        // one output is taken by the change
+        let max_parts = usize::from(consensus_parameters.tx_params().max_outputs()) - 1;
+        if parts > max_parts {
+            return Err(error!(
+                Other,
+                "cannot split into {parts} coins in one transaction, the maximum is {max_parts}"
+            ));
+        }
+        let part_amounts = split_amount(amount, parts)?;
+
+        let inputs = self
+            .get_asset_inputs_for_amount(asset_id, amount, None)
+            .await?;
+        let mut outputs = part_amounts
+            .into_iter()
+            .map(|part| Output::coin(self.address().into(), part, asset_id))
+            .collect::<Vec<_>>();
+        outputs.push(Output::change(self.address().into(), 0, asset_id));
+
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+        self.add_witnesses(&mut tb)?;
+
+        let used_base_amount = if asset_id == base_asset_id { amount } else { 0 };
+        self.adjust_for_fee(&mut tb, used_base_amount).await?;
+
+        let (tx_id, tx_status) = build_and_send(self, tb).await?;
+
+        let receipts = tx_status.take_receipts_checked(None)?;
+
+        Ok((tx_id, receipts))
+    }
+
+    /// Merges the coins of `asset_id` owned by this account until at most `target_count` are
+    /// left, smallest coins first. Sends as many transactions as the chain's input limit
+    /// requires, one after the other. Returns the IDs of the transactions sent, none if the
+    /// account already has at most `target_count` coins.
+    async fn consolidate_coins(
+        &self,
+        asset_id: AssetId,
+        target_count: usize,
+        tx_policies: TxPolicies,
+    ) -> Result<Vec<TxId>> {
+        if target_count == 0 {
+            return Err(error!(Other, "cannot consolidate into zero coins"));
+        }
+
+        let consensus_parameters = self.try_provider()?.fetch_consensus_parameters().await?;
+        // leave room for an input paying the fee
+        let max_inputs = usize::from(consensus_parameters.tx_params().max_inputs()) - 1;
+
+        let mut tx_ids = vec![];
+        loop {
+            let mut coins = self.get_coins(asset_id).await?;
+            if coins.len() <= target_count {
+                break;
+            }
+            coins.sort_by_key(|coin| coin.amount);
+
+            // merging `n` coins into one leaves `n - 1` fewer
+            let batch_size = max_inputs.min(coins.len() - target_count + 1);
+            let batch = &coins[..batch_size];
+            let batch_amount = batch
+                .iter()
+                .fold(0u64, |total, coin| total.saturating_add(coin.amount));
+            let utxo_ids = batch.iter().map(|coin| coin.utxo_id).collect();
+
+            let inputs = self
+                .get_asset_inputs_for_amount_with_policy(
+                    asset_id,
+                    batch_amount,
+                    None,
+                    &CoinSelectionPolicy::Manual(utxo_ids),
+                )
+                .await?;
+            let outputs = vec![Output::change(self.address().into(), 0, asset_id)];
+
+            let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+            self.add_witnesses(&mut tb)?;
+            self.adjust_for_fee(&mut tb, 0).await?;
+
+            let (tx_id, tx_status) = build_and_send(self, tb).await?;
+            tx_status.check(None)?;
+            tx_ids.push(tx_id);
+        }
+
+        Ok(tx_ids)
+    }
+}
+
+/// Builds and sends `tb`, then lets `account` release its inputs whatever the outcome.
+async fn build_and_send<A: Account>(
+    account: &A,
+    tb: ScriptTransactionBuilder,
+) -> Result<(TxId, TxStatus)> {
+    let provider = account.try_provider()?;
+    let inputs = tb.inputs().clone();
+
+    let result = async {
+        let chain_id = provider.fetch_consensus_parameters().await?.chain_id();
+        let tx = tb.build(provider).await?;
+        let tx_id = tx.id(chain_id);
+        account.record_spending(&tx)?;
+        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+
+        Ok((tx_id, tx_status))
+    }
+    .await;
+    account.release_resources(&resource_ids(&inputs));
+
+    in_account_context(account, result)
+}
+
+/// Prefixes the error with the label of `account`, if it has one.
+pub fn in_account_context<T>(account: &impl ViewOnlyAccount, result: Result<T>) -> Result<T> {
+    match account.label() {
+        Some(label) => result.map_err(|err| err.with_context(format!("account `{label}`"))),
+        None => result,
+    }
 }
 
 #[cfg(test)]
@@ -363,8 +574,8 @@ mod tests {
             })
         }
 
-        fn consensus_parameters(&self) -> &ConsensusParameters {
-            &self.c_param
+        fn consensus_parameters(&self) -> ConsensusParameters {
+            self.c_param.clone()
         }
 
         async fn estimate_gas_price(&self, _block_header: u32) -> Result<u64> {
@@ -417,7 +628,7 @@ mod tests {
         tb.add_signer(wallet.clone())?;
         // ANCHOR_END: sign_tb
 
-        let tx = tb.build(&MockDryRunner::default()).await?; // Resolve signatures and add corresponding witness indexes
+        let tx = tb.build(MockDryRunner::default()).await?; // Resolve signatures and add corresponding witness indexes
 
         // Extract the signature from the tx witnesses
         let bytes = <[u8; Signature::LEN]>::try_from(tx.witnesses().first().unwrap().as_ref())?;
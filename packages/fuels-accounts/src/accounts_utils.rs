@@ -110,14 +110,114 @@ pub fn adjust_inputs_outputs(
     });
 
     if !is_base_change_present {
+        let change_to = tb.change_address(address).into();
         tb.outputs_mut()
-            .push(Output::change(address.into(), 0, *base_asset_id));
+            .push(Output::change(change_to, 0, *base_asset_id));
     }
 }
 
+/// Splits `amount` into `parts` nearly equal amounts, the first one taking the remainder.
+pub(crate) fn split_amount(amount: u64, parts: usize) -> Result<Vec<u64>> {
+    let parts_u64 = parts as u64;
+    if parts == 0 || amount < parts_u64 {
+        return Err(error!(
+            Other,
+            "cannot split {amount} into {parts} non-empty coins"
+        ));
+    }
+
+    let part = amount / parts_u64;
+    let mut amounts = vec![part; parts];
+    amounts[0] += amount % parts_u64;
+
+    Ok(amounts)
+}
+
 pub(crate) fn try_provider_error() -> Error {
     error!(
         Other,
         "no provider available. Make sure to use `set_provider`"
     )
 }
+
+/// Makes a [`Signer`](fuels_core::traits::Signer) with `address: Bech32Address` and
+/// `provider: Option<Provider>` fields an [`Account`](crate::Account) whose coins are spent with
+/// its signatures, like a wallet.
+macro_rules! impl_account_for_signer {
+    ($ty: ty) => {
+        impl $ty {
+            pub fn address(&self) -> &::fuels_core::types::bech32::Bech32Address {
+                &self.address
+            }
+
+            pub fn provider(&self) -> Option<&$crate::provider::Provider> {
+                self.provider.as_ref()
+            }
+
+            pub fn set_provider(&mut self, provider: $crate::provider::Provider) {
+                self.provider = Some(provider);
+            }
+        }
+
+        impl $crate::ViewOnlyAccount for $ty {
+            fn address(&self) -> &::fuels_core::types::bech32::Bech32Address {
+                &self.address
+            }
+
+            fn try_provider(
+                &self,
+            ) -> ::fuels_core::types::errors::Result<&$crate::provider::Provider> {
+                self.provider
+                    .as_ref()
+                    .ok_or_else($crate::accounts_utils::try_provider_error)
+            }
+        }
+
+        #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+        impl $crate::Account for $ty {
+            async fn get_asset_inputs_for_amount(
+                &self,
+                asset_id: ::fuels_core::types::AssetId,
+                amount: u64,
+                excluded_coins: Option<Vec<::fuels_core::types::coin_type_id::CoinTypeId>>,
+            ) -> ::fuels_core::types::errors::Result<Vec<::fuels_core::types::input::Input>> {
+                use $crate::ViewOnlyAccount;
+
+                Ok(self
+                    .get_spendable_resources(asset_id, amount, excluded_coins)
+                    .await?
+                    .into_iter()
+                    .map(::fuels_core::types::input::Input::resource_signed)
+                    .collect())
+            }
+
+            fn add_witnesses<Tb: ::fuels_core::types::transaction_builders::TransactionBuilder>(
+                &self,
+                tb: &mut Tb,
+            ) -> ::fuels_core::types::errors::Result<()> {
+                tb.add_signer(self.clone())?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_account_for_signer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_split_evenly() -> Result<()> {
+        assert_eq!(split_amount(10, 3)?, vec![4, 3, 3]);
+        assert_eq!(split_amount(9, 3)?, vec![3, 3, 3]);
+        assert_eq!(split_amount(1, 1)?, vec![1]);
+
+        assert!(split_amount(2, 3).is_err());
+        assert!(split_amount(5, 0).is_err());
+
+        Ok(())
+    }
+}
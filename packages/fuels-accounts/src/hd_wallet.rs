@@ -0,0 +1,140 @@
+use std::{fmt, ops::Range};
+
+use fuel_crypto::SecretKey;
+use fuels_core::types::errors::Result;
+use rand::Rng;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    provider::Provider,
+    wallet::{generate_mnemonic_phrase, WalletUnlocked, DEFAULT_DERIVATION_PATH_PREFIX},
+};
+
+/// Derives any number of accounts from a single BIP-39 mnemonic phrase.
+///
+/// Account `index` uses the BIP-44 path `{prefix}/{index}'/0/0`, where the prefix defaults to
+/// [`DEFAULT_DERIVATION_PATH_PREFIX`]. This matches the accounts derived by the TypeScript SDK and
+/// the Fuel wallets from the same phrase.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct HdWallet {
+    phrase: String,
+    #[zeroize(skip)]
+    path_prefix: String,
+    #[zeroize(skip)]
+    provider: Option<Provider>,
+}
+
+impl HdWallet {
+    pub fn from_mnemonic_phrase(phrase: &str, provider: Option<Provider>) -> Result<Self> {
+        let wallet = Self {
+            phrase: phrase.to_string(),
+            path_prefix: DEFAULT_DERIVATION_PATH_PREFIX.to_string(),
+            provider,
+        };
+        // fail early on invalid phrases
+        wallet.derive_account(0)?;
+
+        Ok(wallet)
+    }
+
+    /// Creates a wallet from a new random phrase of `word_count` words.
+    pub fn generate<R: Rng>(
+        rng: &mut R,
+        word_count: usize,
+        provider: Option<Provider>,
+    ) -> Result<Self> {
+        let phrase = generate_mnemonic_phrase(rng, word_count)?;
+
+        Self::from_mnemonic_phrase(&phrase, provider)
+    }
+
+    /// Replaces the part of the derivation path preceding the account index, e.g. `m/44'/60'`.
+    pub fn with_path_prefix(mut self, path_prefix: impl Into<String>) -> Self {
+        self.path_prefix = path_prefix.into();
+        self
+    }
+
+    pub fn mnemonic_phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+
+    /// The derivation path of account `index`.
+    pub fn path(&self, index: u32) -> String {
+        format!("{}/{index}'/0/0", self.path_prefix)
+    }
+
+    /// Derives account `index`, connected to the wallet's provider if it has one.
+    pub fn derive_account(&self, index: u32) -> Result<WalletUnlocked> {
+        let secret_key =
+            SecretKey::new_from_mnemonic_phrase_with_path(&self.phrase, &self.path(index))?;
+
+        Ok(WalletUnlocked::new_from_private_key(
+            secret_key,
+            self.provider.clone(),
+        ))
+    }
+
+    pub fn derive_accounts(&self, indexes: Range<u32>) -> Result<Vec<WalletUnlocked>> {
+        indexes.map(|index| self.derive_account(index)).collect()
+    }
+}
+
+impl fmt::Debug for HdWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HdWallet")
+            .field("path_prefix", &self.path_prefix)
+            .field("provider", &self.provider)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str =
+        "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+
+    #[test]
+    fn accounts_match_wallets_derived_from_the_same_path() -> Result<()> {
+        let hd_wallet = HdWallet::from_mnemonic_phrase(PHRASE, None)?;
+
+        let accounts = hd_wallet.derive_accounts(0..3)?;
+
+        assert_eq!(
+            accounts[0].address(),
+            WalletUnlocked::new_from_mnemonic_phrase(PHRASE, None)?.address()
+        );
+        for (index, account) in accounts.iter().enumerate() {
+            let path = format!("m/44'/1179993420'/{index}'/0/0");
+            let expected = WalletUnlocked::new_from_mnemonic_phrase_with_path(PHRASE, None, &path)?;
+            assert_eq!(account.address(), expected.address());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_prefix_can_be_changed() -> Result<()> {
+        // the addresses are the ones of `wallet_from_mnemonic_phrase` in the wallet tests
+        let hd_wallet = HdWallet::from_mnemonic_phrase(PHRASE, None)?.with_path_prefix("m/44'/60'");
+
+        let account = hd_wallet.derive_account(1)?;
+
+        assert_eq!(
+            account.address().to_string(),
+            "fuel1ycgervqkfgj06r74z4nwchjmpwd637edgtwfea7mh4hj85n5yavszjk4cc"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_phrases_are_rejected() {
+        assert!(HdWallet::from_mnemonic_phrase("not a mnemonic phrase", None).is_err());
+    }
+}
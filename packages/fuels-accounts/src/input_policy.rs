@@ -0,0 +1,213 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use fuel_tx::UtxoId;
+use fuels_core::types::{
+    coin_type::CoinType,
+    coin_type_id::CoinTypeId,
+    errors::{error_transaction, Error, Result},
+    input::Input,
+    AssetId,
+};
+
+use crate::ViewOnlyAccount;
+
+/// Constrains which coins end up as inputs of a transaction, for users with compliance
+/// requirements on how their funds are spent.
+///
+/// Set on a [`WalletUnlocked`](crate::wallet::WalletUnlocked) with `with_input_policy`, it drives
+/// the wallet's input selection and is checked when the wallet adds its signature to a builder.
+/// For builders assembled by hand, call [`InputPolicy::check_inputs`] before building.
+#[derive(Debug, Clone, Default)]
+pub struct InputPolicy {
+    segregated_assets: Vec<HashSet<AssetId>>,
+    max_inputs: HashMap<AssetId, usize>,
+    preferred_coins: HashSet<UtxoId>,
+}
+
+impl InputPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forbids spending more than one of `asset_ids` in the same transaction. Can be called
+    /// multiple times to add independent groups.
+    pub fn with_segregated_assets(mut self, asset_ids: impl IntoIterator<Item = AssetId>) -> Self {
+        self.segregated_assets.push(asset_ids.into_iter().collect());
+        self
+    }
+
+    /// Limits how many inputs of `asset_id` a single transaction may have.
+    pub fn with_max_inputs(mut self, asset_id: AssetId, max: usize) -> Self {
+        self.max_inputs.insert(asset_id, max);
+        self
+    }
+
+    /// Tags coins to be selected before any other coin of the same asset. Coins that have been
+    /// spent since are ignored.
+    pub fn with_preferred_coins(mut self, utxo_ids: impl IntoIterator<Item = UtxoId>) -> Self {
+        self.preferred_coins.extend(utxo_ids);
+        self
+    }
+
+    /// Checks the segregation and input count rules against the resource inputs of a transaction.
+    pub fn check_inputs(&self, inputs: &[Input], base_asset_id: &AssetId) -> Result<()> {
+        let mut input_counts: HashMap<AssetId, usize> = HashMap::new();
+        for input in inputs {
+            if let Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. } =
+                input
+            {
+                let asset_id = resource.coin_asset_id().unwrap_or(*base_asset_id);
+                *input_counts.entry(asset_id).or_default() += 1;
+            }
+        }
+
+        for (asset_id, count) in &input_counts {
+            self.check_input_count(asset_id, *count)?;
+        }
+
+        for group in &self.segregated_assets {
+            let mut spent = group
+                .iter()
+                .filter(|asset_id| input_counts.contains_key(*asset_id))
+                .collect::<Vec<_>>();
+            if spent.len() > 1 {
+                spent.sort();
+                return Err(policy_violation(format!(
+                    "assets {spent:?} are segregated and cannot be spent together"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_input_count(&self, asset_id: &AssetId, count: usize) -> Result<()> {
+        match self.max_inputs.get(asset_id) {
+            Some(max) if count > *max => Err(policy_violation(format!(
+                "{count} inputs of asset `{asset_id}` exceed the cap of {max}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Selects resources covering `amount` of `asset_id`, starting with the preferred coins.
+    pub(crate) async fn select_resources(
+        &self,
+        account: &impl ViewOnlyAccount,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<CoinType>> {
+        let excluded_coins = excluded_coins.unwrap_or_default();
+
+        let mut selected = vec![];
+        let mut covered = 0u64;
+        if !self.preferred_coins.is_empty() {
+            // like the other coins, those already spent by a pending transaction are skipped
+            let cached_coins = account
+                .try_provider()?
+                .cached_coins(account.address(), asset_id)
+                .await;
+            let mut preferred = account
+                .get_coins(asset_id)
+                .await?
+                .into_iter()
+                .filter(|coin| {
+                    let coin_id = CoinTypeId::UtxoId(coin.utxo_id);
+
+                    self.preferred_coins.contains(&coin.utxo_id)
+                        && !excluded_coins.contains(&coin_id)
+                        && !cached_coins.contains(&coin_id)
+                })
+                .collect::<Vec<_>>();
+            preferred.sort_by_key(|coin| Reverse(coin.amount));
+
+            for coin in preferred {
+                if covered >= amount {
+                    break;
+                }
+                covered = covered.saturating_add(coin.amount);
+                selected.push(CoinType::Coin(coin));
+            }
+        }
+
+        if selected.is_empty() || covered < amount {
+            let excluded_coins = excluded_coins
+                .into_iter()
+                .chain(selected.iter().map(CoinType::id))
+                .collect();
+            let remaining = account
+                .get_spendable_resources(asset_id, amount - covered, Some(excluded_coins))
+                .await?;
+            selected.extend(remaining);
+        }
+
+        self.check_input_count(&asset_id, selected.len())?;
+
+        Ok(selected)
+    }
+}
+
+fn policy_violation(reason: String) -> Error {
+    error_transaction!(Builder, "input policy violated: {reason}")
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::{bech32::Bech32Address, coin::Coin};
+
+    use super::*;
+
+    fn coin_input(seed: u8, asset_id: AssetId) -> Input {
+        Input::resource_signed(CoinType::Coin(Coin {
+            amount: 10,
+            asset_id,
+            utxo_id: UtxoId::new([seed; 32].into(), 0),
+            owner: Bech32Address::new("fuel", [1; 32]),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn segregated_assets_cannot_be_spent_together() -> Result<()> {
+        // given
+        let base_asset_id = AssetId::zeroed();
+        let [usd, eur] = [1, 2].map(|byte| AssetId::from([byte; 32]));
+        let policy = InputPolicy::new().with_segregated_assets([usd, eur]);
+
+        // when
+        let separate = policy.check_inputs(
+            &[coin_input(1, usd), coin_input(2, base_asset_id)],
+            &base_asset_id,
+        );
+        let together =
+            policy.check_inputs(&[coin_input(1, usd), coin_input(2, eur)], &base_asset_id);
+
+        // then
+        separate?;
+        let err = together.expect_err("should fail");
+        assert!(err
+            .to_string()
+            .contains("are segregated and cannot be spent together"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn inputs_per_asset_are_capped() {
+        let base_asset_id = AssetId::zeroed();
+        let policy = InputPolicy::new().with_max_inputs(base_asset_id, 1);
+
+        let err = policy
+            .check_inputs(
+                &[coin_input(1, base_asset_id), coin_input(2, base_asset_id)],
+                &base_asset_id,
+            )
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("exceed the cap of 1"));
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use fuels_core::types::errors::{error, Result};
+use rand::{CryptoRng, Rng};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const DERIVED_KEY_LEN: usize = 32;
+
+/// The key derivation function protecting a keystore file, as defined by the Web3 Secret Storage
+/// (V3) format.
+///
+/// Stronger parameters make brute forcing the password more expensive, but also make saving and
+/// loading the keystore slower.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfParams {
+    /// `n` is `2^log_n`.
+    Scrypt { log_n: u8, r: u32, p: u32 },
+    /// PBKDF2 with HMAC-SHA256.
+    Pbkdf2 { iterations: u32 },
+}
+
+impl Default for KdfParams {
+    /// The parameters used by [`WalletUnlocked::encrypt`](crate::wallet::WalletUnlocked::encrypt).
+    fn default() -> Self {
+        Self::Scrypt {
+            log_n: 13,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+impl KdfParams {
+    fn derive_key(&self, password: &[u8], salt: &[u8]) -> Result<Zeroizing<[u8; DERIVED_KEY_LEN]>> {
+        let mut key = Zeroizing::new([0; DERIVED_KEY_LEN]);
+
+        match *self {
+            Self::Scrypt { log_n, r, p } => {
+                let params = scrypt::Params::new(log_n, r, p, DERIVED_KEY_LEN)
+                    .map_err(|e| error!(Other, "invalid scrypt parameters: {e}"))?;
+                scrypt::scrypt(password, salt, &params, key.as_mut())
+                    .map_err(|e| error!(Other, "scrypt failed: {e}"))?;
+            }
+            Self::Pbkdf2 { iterations } => {
+                if iterations == 0 {
+                    return Err(error!(Other, "pbkdf2 needs at least one iteration"));
+                }
+                pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password, salt, iterations, key.as_mut());
+            }
+        }
+
+        Ok(key)
+    }
+
+    fn to_json(self, salt: &[u8]) -> serde_json::Value {
+        match self {
+            Self::Scrypt { log_n, r, p } => json!({
+                "kdf": "scrypt",
+                "kdfparams": {
+                    "dklen": DERIVED_KEY_LEN,
+                    "n": 1u64 << log_n,
+                    "p": p,
+                    "r": r,
+                    "salt": hex::encode(salt),
+                },
+            }),
+            Self::Pbkdf2 { iterations } => json!({
+                "kdf": "pbkdf2",
+                "kdfparams": {
+                    "c": iterations,
+                    "dklen": DERIVED_KEY_LEN,
+                    "prf": "hmac-sha256",
+                    "salt": hex::encode(salt),
+                },
+            }),
+        }
+    }
+}
+
+/// Encrypts `secret` into a V3 keystore file in `dir`, named after its uuid. Returns the uuid.
+pub(crate) fn encrypt_key<R: Rng + CryptoRng>(
+    dir: impl AsRef<Path>,
+    rng: &mut R,
+    secret: &[u8],
+    password: impl AsRef<[u8]>,
+    kdf: KdfParams,
+) -> Result<String> {
+    let salt: [u8; 32] = rng.gen();
+    let iv: [u8; 16] = rng.gen();
+    let key = kdf.derive_key(password.as_ref(), &salt)?;
+
+    let mut ciphertext = secret.to_vec();
+    Aes128Ctr::new(key[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+
+    let mac = Keccak256::new()
+        .chain_update(&key[16..])
+        .chain_update(&ciphertext)
+        .finalize();
+
+    let uuid = uuid::Builder::from_random_bytes(rng.gen())
+        .into_uuid()
+        .to_string();
+    let mut crypto = kdf.to_json(&salt);
+    crypto["cipher"] = json!("aes-128-ctr");
+    crypto["cipherparams"] = json!({ "iv": hex::encode(iv) });
+    crypto["ciphertext"] = json!(hex::encode(ciphertext));
+    crypto["mac"] = json!(hex::encode(mac));
+    let keystore = json!({
+        "crypto": crypto,
+        "id": uuid,
+        "version": 3,
+    });
+
+    std::fs::write(dir.as_ref().join(&uuid), keystore.to_string())?;
+
+    Ok(uuid)
+}
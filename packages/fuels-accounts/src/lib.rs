@@ -3,8 +3,26 @@ mod account;
 #[cfg(feature = "std")]
 mod accounts_utils;
 #[cfg(feature = "std")]
+pub mod hd_wallet;
+#[cfg(feature = "std")]
+pub mod input_policy;
+#[cfg(feature = "std")]
+pub mod keystore;
+#[cfg(feature = "std")]
+pub mod multisig;
+#[cfg(feature = "std")]
 pub mod provider;
 #[cfg(feature = "std")]
+pub mod remote_signer;
+#[cfg(feature = "std")]
+pub mod resource_manager;
+#[cfg(feature = "std")]
+pub mod signers;
+#[cfg(feature = "std")]
+pub mod spending_policy;
+#[cfg(feature = "std")]
+pub mod utxo_index;
+#[cfg(feature = "std")]
 pub mod wallet;
 
 #[cfg(feature = "std")]
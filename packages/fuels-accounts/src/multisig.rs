@@ -0,0 +1,394 @@
+use std::collections::BTreeMap;
+
+use fuel_crypto::{Message, Signature};
+use fuel_tx::{field::Witnesses, Bytes32, Script};
+use fuel_types::ChainId;
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        coin_type_id::CoinTypeId,
+        errors::{error, error_transaction, Result},
+        input::Input,
+        transaction::{EstimablePredicates, ScriptTransaction, Transaction},
+        transaction_builders::{
+            BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
+        },
+        AssetId, CoinSelectionPolicy, DryRunner,
+    },
+};
+
+use crate::{predicate::Predicate, provider::Provider, Account, ViewOnlyAccount};
+
+/// An account owned by a multisig predicate, spendable once `threshold` of its `signers` have
+/// signed the transaction.
+///
+/// The predicate itself is up to you. The SDK only collects the signatures: they are stored as
+/// `threshold` consecutive witnesses, ordered like the `signers`, starting at the witness index
+/// returned by [`PendingMultisigTransaction::first_signature_index`].
+///
+/// Since the signatures only exist after the transaction is built, the predicate can't be run to
+/// estimate the fee. Transactions spending from a multisig need an explicit `max_fee` policy.
+///
+/// For the same reason, the [`Account`] helpers that sign and send in one go, such as `transfer`,
+/// contract calls and deployments, fail with a multisig. Use it to fund a builder with
+/// `adjust_for_fee` instead.
+#[derive(Debug, Clone)]
+pub struct MultisigAccount {
+    predicate: Predicate,
+    signers: Vec<Bech32Address>,
+    threshold: usize,
+}
+
+impl MultisigAccount {
+    pub fn new(
+        predicate: Predicate,
+        signers: Vec<Bech32Address>,
+        threshold: usize,
+    ) -> Result<Self> {
+        if threshold == 0 || threshold > signers.len() {
+            return Err(error!(
+                Other,
+                "threshold must be between 1 and the number of signers ({}), got {threshold}",
+                signers.len()
+            ));
+        }
+        if let Some(duplicate) = signers
+            .iter()
+            .enumerate()
+            .find_map(|(i, signer)| signers[..i].contains(signer).then_some(signer))
+        {
+            return Err(error!(
+                Other,
+                "signer `{duplicate}` is listed more than once"
+            ));
+        }
+
+        Ok(Self {
+            predicate,
+            signers,
+            threshold,
+        })
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn signers(&self) -> &[Bech32Address] {
+        &self.signers
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.predicate.set_provider(provider);
+    }
+
+    /// Builds `tb` with room for the signatures and returns it for the signers to sign.
+    pub async fn prepare(
+        &self,
+        mut tb: ScriptTransactionBuilder,
+        provider: impl DryRunner,
+    ) -> Result<PendingMultisigTransaction> {
+        if tb.tx_policies.max_fee().is_none() {
+            return Err(error_transaction!(
+                Builder,
+                "multisig transactions need an explicit `max_fee` policy"
+            ));
+        }
+
+        let first_signature_index = tb.witnesses().len();
+        let placeholder = Signature::default();
+        tb.witnesses_mut()
+            .extend((0..self.threshold).map(|_| placeholder.as_ref().into()));
+
+        let chain_id = provider.consensus_parameters().chain_id();
+        let tx = tb.build(provider).await?;
+
+        Ok(PendingMultisigTransaction {
+            tx,
+            chain_id,
+            first_signature_index,
+            signers: self.signers.clone(),
+            threshold: self.threshold,
+            signatures: BTreeMap::new(),
+        })
+    }
+}
+
+/// A built transaction waiting for the signatures of a [`MultisigAccount`].
+///
+/// Signers that are not available locally can sign [`tx_id`](Self::tx_id) on their own and hand
+/// back the signature for [`add_signature`](Self::add_signature).
+#[derive(Debug, Clone)]
+pub struct PendingMultisigTransaction {
+    tx: ScriptTransaction,
+    chain_id: ChainId,
+    first_signature_index: usize,
+    signers: Vec<Bech32Address>,
+    threshold: usize,
+    // keyed by the position of the signer
+    signatures: BTreeMap<usize, Signature>,
+}
+
+impl PendingMultisigTransaction {
+    pub fn tx_id(&self) -> Bytes32 {
+        self.tx.id(self.chain_id)
+    }
+
+    pub fn first_signature_index(&self) -> usize {
+        self.first_signature_index
+    }
+
+    /// Signers that have signed so far.
+    pub fn signed_by(&self) -> Vec<&Bech32Address> {
+        self.signatures
+            .keys()
+            .map(|position| &self.signers[*position])
+            .collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.signatures.len() == self.threshold
+    }
+
+    pub async fn collect_signature(&mut self, signer: &(impl Signer + Send + Sync)) -> Result<()> {
+        let signature = signer.sign(Message::from_bytes(*self.tx_id())).await?;
+
+        self.add_signature(signature)
+    }
+
+    /// Adds a signature of [`tx_id`](Self::tx_id) made elsewhere. The signer is recovered from
+    /// the signature and must be one of the multisig's signers.
+    pub fn add_signature(&mut self, signature: Signature) -> Result<()> {
+        let message = Message::from_bytes(*self.tx_id());
+        let signer = Bech32Address::new(FUEL_BECH32_HRP, signature.recover(&message)?.hash());
+
+        let position = self
+            .signers
+            .iter()
+            .position(|address| address.hash() == signer.hash())
+            .ok_or_else(|| {
+                error_transaction!(Builder, "`{signer}` is not a signer of this multisig")
+            })?;
+
+        if self.signatures.contains_key(&position) {
+            return Err(error_transaction!(Builder, "`{signer}` already signed"));
+        }
+        if self.is_complete() {
+            return Err(error_transaction!(
+                Builder,
+                "already has the {} required signatures",
+                self.threshold
+            ));
+        }
+
+        self.signatures.insert(position, signature);
+
+        Ok(())
+    }
+
+    /// Puts the signatures in place and estimates the predicates, returning the transaction
+    /// ready to be sent.
+    pub async fn finalize(self, provider: impl DryRunner) -> Result<ScriptTransaction> {
+        let mut tx = self.into_signed_tx()?;
+        tx.estimate_predicates(&provider, None).await?;
+
+        Ok(tx)
+    }
+
+    fn into_signed_tx(self) -> Result<ScriptTransaction> {
+        if !self.is_complete() {
+            return Err(error_transaction!(
+                Builder,
+                "only {} of the {} required signatures were collected",
+                self.signatures.len(),
+                self.threshold
+            ));
+        }
+
+        let mut tx: Script = self.tx.into();
+        for (slot, signature) in self.signatures.values().enumerate() {
+            tx.witnesses_mut()[self.first_signature_index + slot] = signature.as_ref().into();
+        }
+
+        Ok(tx.into())
+    }
+}
+
+impl ViewOnlyAccount for MultisigAccount {
+    fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.predicate.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Account for MultisigAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount(asset_id, amount, excluded_coins)
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_with_policy(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        policy: &CoinSelectionPolicy,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount_with_policy(asset_id, amount, excluded_coins, policy)
+            .await
+    }
+
+    /// Always fails: the signatures can only be added after building, see
+    /// [`MultisigAccount::prepare`]. This keeps the transfer helpers, contract calls and
+    /// deployments from sending transactions the signers never signed.
+    fn add_witnesses<Tb: TransactionBuilder>(&self, _tb: &mut Tb) -> Result<()> {
+        Err(error!(
+            Other,
+            "a multisig transaction can't be signed while it is built. Fund it with \
+             `adjust_for_fee`, then `prepare` it and collect the signatures before sending it"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{policies::Policies, Transaction as FuelTransaction};
+
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    fn given_pending_tx(
+        signers: &[WalletUnlocked],
+        threshold: usize,
+    ) -> PendingMultisigTransaction {
+        let placeholders = (0..threshold)
+            .map(|_| Signature::default().as_ref().into())
+            .collect();
+        let tx = FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![],
+            vec![],
+            placeholders,
+        );
+
+        PendingMultisigTransaction {
+            tx: tx.into(),
+            chain_id: ChainId::default(),
+            first_signature_index: 0,
+            signers: signers.iter().map(|s| s.address().clone()).collect(),
+            threshold,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn signatures_are_placed_in_signer_order() -> Result<()> {
+        // given
+        let signers: Vec<_> = (0..3).map(|_| WalletUnlocked::new_random(None)).collect();
+        let mut pending = given_pending_tx(&signers, 2);
+
+        // when
+        pending.collect_signature(&signers[2]).await?;
+        let early = pending.clone().into_signed_tx();
+        pending.collect_signature(&signers[0]).await?;
+        let tx = pending.clone().into_signed_tx()?;
+
+        // then
+        let err = early.expect_err("should fail");
+        assert!(err
+            .to_string()
+            .contains("only 1 of the 2 required signatures were collected"));
+
+        let message = Message::from_bytes(*pending.tx_id());
+        let recovered = tx
+            .witnesses()
+            .iter()
+            .map(|witness| {
+                let signature = <[u8; Signature::LEN]>::try_from(witness.as_ref())
+                    .map(Signature::from_bytes)
+                    .expect("is a signature");
+                signature.recover(&message).map(|key| key.hash())
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        assert_eq!(
+            recovered,
+            vec![signers[0].address().hash(), signers[2].address().hash()]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unknown_and_repeated_signers_are_rejected() -> Result<()> {
+        let signers: Vec<_> = (0..2).map(|_| WalletUnlocked::new_random(None)).collect();
+        let mut pending = given_pending_tx(&signers, 2);
+
+        pending.collect_signature(&signers[0]).await?;
+        let repeated = pending.collect_signature(&signers[0]).await;
+        let unknown = pending
+            .collect_signature(&WalletUnlocked::new_random(None))
+            .await;
+
+        assert!(repeated
+            .expect_err("should fail")
+            .to_string()
+            .contains("already signed"));
+        assert!(unknown
+            .expect_err("should fail")
+            .to_string()
+            .contains("is not a signer of this multisig"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn threshold_must_be_reachable() {
+        let predicate = Predicate::from_code(vec![]);
+        let signers = vec![
+            Bech32Address::default(),
+            Bech32Address::new("fuel", [1; 32]),
+        ];
+
+        assert!(MultisigAccount::new(predicate.clone(), signers.clone(), 0).is_err());
+        assert!(MultisigAccount::new(predicate.clone(), signers.clone(), 3).is_err());
+        assert!(MultisigAccount::new(predicate, signers, 2).is_ok());
+    }
+
+    #[test]
+    fn transactions_cant_be_signed_while_built() -> Result<()> {
+        // given
+        let multisig = MultisigAccount::new(
+            Predicate::from_code(vec![]),
+            vec![Bech32Address::default()],
+            1,
+        )?;
+        let mut tb = ScriptTransactionBuilder::default();
+
+        // when
+        let err = multisig.add_witnesses(&mut tb).expect_err("should fail");
+
+        // then
+        assert!(err.to_string().contains("collect the signatures"));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use std::{fmt::Debug, fs};
 
 #[cfg(feature = "std")]
-use fuels_core::types::{coin_type_id::CoinTypeId, input::Input, AssetId};
+use fuels_core::types::{coin_type_id::CoinTypeId, input::Input, AssetId, CoinSelectionPolicy};
 use fuels_core::{
     error,
     types::{bech32::Bech32Address, errors::Result},
@@ -13,6 +13,12 @@ use crate::accounts_utils::try_provider_error;
 #[cfg(feature = "std")]
 use crate::{provider::Provider, Account, ViewOnlyAccount};
 
+#[cfg(feature = "experimental")]
+mod templates;
+
+#[cfg(feature = "experimental")]
+pub use templates::PredicateTemplates;
+
 #[derive(Debug, Clone)]
 pub struct Predicate {
     address: Bech32Address,
@@ -129,4 +135,21 @@ impl Account for Predicate {
             })
             .collect::<Vec<Input>>())
     }
+
+    async fn get_asset_inputs_for_amount_with_policy(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        policy: &CoinSelectionPolicy,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_coins_with_policy(asset_id, amount, excluded_coins, policy)
+            .await?
+            .into_iter()
+            .map(|resource| {
+                Input::resource_predicate(resource, self.code.clone(), self.data.clone())
+            })
+            .collect())
+    }
 }
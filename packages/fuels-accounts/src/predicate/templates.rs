@@ -0,0 +1,406 @@
+//! Predicates for common spending conditions, assembled in Rust so they can be used without a
+//! Sway toolchain. Their parameters are part of the bytecode, so every set of parameters has its
+//! own predicate address.
+//!
+//! The bytecode is written by hand rather than compiled from Sway and hasn't been audited, hence
+//! the `experimental` feature gating it. Review it before locking funds of value with it.
+
+use fuel_asm::{op, GMArgs, GTFArgs, Instruction, RegId};
+use fuel_tx::Bytes32;
+use fuel_types::Address;
+use fuels_core::{
+    error,
+    types::{bech32::Bech32Address, errors::Result},
+};
+
+use crate::predicate::Predicate;
+
+// the owners of a multisig are tracked as bits of a register
+const MAX_OWNERS: usize = 64;
+
+/// Constructors of the template predicates. Unaudited, see the module documentation.
+///
+/// The signature based templates look for the signatures of their owners among all witnesses of
+/// the transaction, so they are spent by adding the owners as signers of a script transaction,
+/// e.g. with `ScriptTransactionBuilder::add_signer`. They count the witnesses with
+/// `GTFArgs::ScriptWitnessesCount`, so they can't be spent by any other kind of transaction. They don't take predicate data, and the gas
+/// they use doesn't depend on the signatures, which keeps the fee estimated before signing right.
+pub struct PredicateTemplates;
+
+impl PredicateTemplates {
+    /// Spendable with a signature of `owner`, by script transactions only.
+    pub fn single_sig(owner: &Bech32Address) -> Predicate {
+        Predicate::from_code(signatures_code(&[owner.into()], 1, None))
+    }
+
+    /// Spendable with the signatures of `threshold` distinct `owners`, at most 64 of them, by
+    /// script transactions only.
+    pub fn multisig(owners: &[Bech32Address], threshold: usize) -> Result<Predicate> {
+        if owners.len() > MAX_OWNERS {
+            return Err(error!(
+                Other,
+                "a multisig has at most {MAX_OWNERS} owners, got {}",
+                owners.len()
+            ));
+        }
+        if threshold == 0 || threshold > owners.len() {
+            return Err(error!(
+                Other,
+                "threshold must be between 1 and the number of owners ({}), got {threshold}",
+                owners.len()
+            ));
+        }
+        if let Some(duplicate) = owners
+            .iter()
+            .enumerate()
+            .find_map(|(i, owner)| owners[..i].contains(owner).then_some(owner))
+        {
+            // its signature would count twice
+            return Err(error!(
+                Other,
+                "owner `{duplicate}` is listed more than once"
+            ));
+        }
+
+        let owners = owners.iter().map(Address::from).collect::<Vec<_>>();
+
+        Ok(Predicate::from_code(signatures_code(
+            &owners,
+            threshold as u64,
+            None,
+        )))
+    }
+
+    /// Spendable with a signature of `owner` by a script transaction whose maturity is at least
+    /// `unlock_height`. The node doesn't include a transaction before the block height of its
+    /// maturity, so set it with `TxPolicies::with_maturity`.
+    pub fn timelock(owner: &Bech32Address, unlock_height: u32) -> Predicate {
+        Predicate::from_code(signatures_code(&[owner.into()], 1, Some(unlock_height)))
+    }
+
+    /// Spendable by whoever knows a preimage of the SHA-256 `hash`, given as predicate data with
+    /// `Predicate::with_data`. Sending the transaction reveals the preimage, so anyone watching
+    /// the txpool could compete to spend what is left.
+    pub fn hash_lock(hash: Bytes32) -> Predicate {
+        Predicate::from_code(hash_lock_code(hash))
+    }
+}
+
+fn signatures_code(owners: &[Address], threshold: u64, unlock_height: Option<u32>) -> Vec<u8> {
+    let instructions = signatures_instructions(owners.len() as u32, 0, unlock_height.is_some());
+    let data_offset = (instructions.len() * Instruction::SIZE) as u16;
+
+    let data = [
+        threshold.to_be_bytes(),
+        u64::from(unlock_height.unwrap_or_default()).to_be_bytes(),
+    ]
+    .concat();
+
+    signatures_instructions(owners.len() as u32, data_offset, unlock_height.is_some())
+        .into_iter()
+        .collect::<Vec<u8>>()
+        .into_iter()
+        .chain(data)
+        .chain(owners.iter().flat_map(|owner| owner.to_vec()))
+        .collect()
+}
+
+/// Recovers the signer of every witness, marks the owners among them in a bitmask and succeeds
+/// if at least `threshold` bits are set. The data section holds the threshold, the unlock height
+/// and the owner addresses.
+fn signatures_instructions(
+    num_owners: u32,
+    data_offset: u16,
+    timelocked: bool,
+) -> Vec<Instruction> {
+    const DATA: u8 = 0x10;
+    const OWNERS: u8 = 0x11;
+    const NUM_OWNERS: u8 = 0x12;
+    const PUBLIC_KEY: u8 = 0x13;
+    const PUBLIC_KEY_HASH: u8 = 0x14;
+    const NUM_WITNESSES: u8 = 0x15;
+    const WITNESS: u8 = 0x16;
+    const SIGNED: u8 = 0x17;
+    const SIGNATURE: u8 = 0x18;
+    const OWNER: u8 = 0x19;
+    const OWNER_ADDRESS: u8 = 0x1a;
+    const TMP: u8 = 0x1b;
+    const LEN_64: u8 = 0x1c;
+    const LEN_32: u8 = 0x1d;
+    const NUM_SIGNED: u8 = 0x1e;
+    const FAILED: u8 = 0x1f;
+    const MATURITY: u8 = 0x20;
+
+    // instruction indexes jumped to
+    const NEXT_WITNESS: u32 = 9;
+    const NEXT_OWNER: u32 = 16;
+    const OWNERS_DONE: u32 = 24;
+    const WITNESSES_DONE: u32 = 26;
+    const COUNT_SIGNED: u32 = 27;
+    const CHECK: u32 = 34;
+
+    // registers start zeroed
+    let mut instructions = vec![
+        op::move_(PUBLIC_KEY, RegId::SP),
+        op::cfei(64 + 32),
+        op::addi(PUBLIC_KEY_HASH, PUBLIC_KEY, 64),
+        op::addi(DATA, RegId::IS, data_offset),
+        op::addi(OWNERS, DATA, 16),
+        op::movi(NUM_OWNERS, num_owners),
+        op::movi(LEN_64, 64),
+        op::movi(LEN_32, 32),
+        op::gtf(
+            NUM_WITNESSES,
+            RegId::ZERO,
+            GTFArgs::ScriptWitnessesCount.into(),
+        ),
+        // NEXT_WITNESS
+        op::eq(TMP, WITNESS, NUM_WITNESSES),
+        op::jnzi(TMP, WITNESSES_DONE),
+        op::gtf(SIGNATURE, WITNESS, GTFArgs::WitnessData.into()),
+        // the transaction id is at the start of the memory. An invalid signature recovers to a
+        // zeroed key instead of panicking
+        op::eck1(PUBLIC_KEY, SIGNATURE, RegId::ZERO),
+        op::s256(PUBLIC_KEY_HASH, PUBLIC_KEY, LEN_64),
+        op::move_(OWNER, RegId::ZERO),
+        op::move_(OWNER_ADDRESS, OWNERS),
+        // NEXT_OWNER, comparing with every owner so the gas used doesn't depend on the signers
+        op::eq(TMP, OWNER, NUM_OWNERS),
+        op::jnzi(TMP, OWNERS_DONE),
+        op::meq(TMP, PUBLIC_KEY_HASH, OWNER_ADDRESS, LEN_32),
+        op::sll(TMP, TMP, OWNER),
+        op::or(SIGNED, SIGNED, TMP),
+        op::addi(OWNER, OWNER, 1),
+        op::addi(OWNER_ADDRESS, OWNER_ADDRESS, 32),
+        op::ji(NEXT_OWNER),
+        // OWNERS_DONE
+        op::addi(WITNESS, WITNESS, 1),
+        op::ji(NEXT_WITNESS),
+        // WITNESSES_DONE, counting the owners that signed at least once
+        op::move_(OWNER, RegId::ZERO),
+        // COUNT_SIGNED
+        op::eq(TMP, OWNER, NUM_OWNERS),
+        op::jnzi(TMP, CHECK),
+        op::srl(TMP, SIGNED, OWNER),
+        op::andi(TMP, TMP, 1),
+        op::add(NUM_SIGNED, NUM_SIGNED, TMP),
+        op::addi(OWNER, OWNER, 1),
+        op::ji(COUNT_SIGNED),
+        // CHECK
+        op::lw(TMP, DATA, 0),
+        op::lt(FAILED, NUM_SIGNED, TMP),
+    ];
+
+    // a transaction without a maturity makes `gtf` panic, failing the predicate
+    if timelocked {
+        instructions.extend([
+            op::lw(TMP, DATA, 1),
+            op::gtf(MATURITY, RegId::ZERO, GTFArgs::PolicyMaturity.into()),
+            op::lt(TMP, MATURITY, TMP),
+            op::or(FAILED, FAILED, TMP),
+        ]);
+    }
+
+    instructions.extend([op::eq(TMP, FAILED, RegId::ZERO), op::ret(TMP)]);
+
+    instructions
+}
+
+/// Hashes the predicate data of the input being verified and compares it with the hash in the
+/// data section.
+fn hash_lock_code(hash: Bytes32) -> Vec<u8> {
+    const INPUT: u8 = 0x10;
+    const TMP: u8 = 0x11;
+    const PREIMAGE: u8 = 0x12;
+    const PREIMAGE_LEN: u8 = 0x13;
+    const PREIMAGE_HASH: u8 = 0x14;
+    const EXPECTED_HASH: u8 = 0x15;
+    const LEN_32: u8 = 0x16;
+
+    // instruction indexes jumped to
+    const MESSAGE_INPUT: u32 = 6;
+    const COMPARE: u32 = 8;
+    const NUM_INSTRUCTIONS: u16 = 15;
+
+    let instructions = [
+        op::gm_args(INPUT, GMArgs::GetVerifyingPredicate),
+        op::gtf(TMP, INPUT, GTFArgs::InputType.into()),
+        // coins have the input type 0
+        op::jnzi(TMP, MESSAGE_INPUT),
+        op::gtf(PREIMAGE, INPUT, GTFArgs::InputCoinPredicateData.into()),
+        op::gtf(
+            PREIMAGE_LEN,
+            INPUT,
+            GTFArgs::InputCoinPredicateDataLength.into(),
+        ),
+        op::ji(COMPARE),
+        // MESSAGE_INPUT
+        op::gtf(PREIMAGE, INPUT, GTFArgs::InputMessagePredicateData.into()),
+        op::gtf(
+            PREIMAGE_LEN,
+            INPUT,
+            GTFArgs::InputMessagePredicateDataLength.into(),
+        ),
+        // COMPARE
+        op::move_(PREIMAGE_HASH, RegId::SP),
+        op::cfei(32),
+        op::s256(PREIMAGE_HASH, PREIMAGE, PREIMAGE_LEN),
+        op::addi(
+            EXPECTED_HASH,
+            RegId::IS,
+            NUM_INSTRUCTIONS * Instruction::SIZE as u16,
+        ),
+        op::movi(LEN_32, 32),
+        op::meq(TMP, PREIMAGE_HASH, EXPECTED_HASH, LEN_32),
+        op::ret(TMP),
+    ];
+    debug_assert_eq!(instructions.len(), NUM_INSTRUCTIONS as usize);
+
+    instructions
+        .into_iter()
+        .collect::<Vec<u8>>()
+        .into_iter()
+        .chain(hash.iter().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_crypto::{Hasher, Message, SecretKey, Signature};
+    use fuel_tx::{
+        field::Witnesses, policies::Policies, AssetId, ConsensusParameters, Input, TxPointer,
+        UniqueIdentifier, UtxoId,
+    };
+    use fuel_vm::{
+        checked_transaction::{CheckPredicateParams, EstimatePredicates},
+        prelude::MemoryInstance,
+    };
+    use fuels_core::types::{
+        bech32::FUEL_BECH32_HRP,
+        transaction::{ScriptTransaction, ValidatablePredicates},
+    };
+
+    use super::*;
+
+    fn keys(num: usize) -> Vec<SecretKey> {
+        let mut rng = rand::thread_rng();
+
+        (0..num).map(|_| SecretKey::random(&mut rng)).collect()
+    }
+
+    fn address_of(key: &SecretKey) -> Bech32Address {
+        Bech32Address::new(FUEL_BECH32_HRP, Input::owner(&key.public_key()))
+    }
+
+    // verifies the predicate the way the node does, spending it in a transaction with the given
+    // signers and maturity
+    fn spend(predicate: &Predicate, signers: &[&SecretKey], maturity: u32) -> Result<()> {
+        let consensus_parameters = ConsensusParameters::default();
+        let chain_id = consensus_parameters.chain_id();
+        let input = Input::coin_predicate(
+            UtxoId::default(),
+            predicate.address().into(),
+            100,
+            AssetId::zeroed(),
+            TxPointer::default(),
+            0,
+            predicate.code().to_vec(),
+            predicate.data().to_vec(),
+        );
+        let policies = Policies::new()
+            .with_max_fee(0)
+            .with_maturity(maturity.into());
+        let mut tx = fuel_tx::Transaction::script(
+            1_000_000,
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+            vec![],
+            policies,
+            vec![input],
+            vec![],
+            vec![],
+        );
+
+        let message = Message::from_bytes(*tx.id(&chain_id));
+        for key in signers {
+            let signature = Signature::sign(key, &message);
+            tx.witnesses_mut().push(signature.as_ref().to_vec().into());
+        }
+        tx.estimate_predicates(
+            &CheckPredicateParams::from(&consensus_parameters),
+            MemoryInstance::new(),
+        )?;
+
+        ScriptTransaction::from(tx).validate_predicates(&consensus_parameters, maturity)
+    }
+
+    #[test]
+    fn single_sig_needs_the_owner_signature() {
+        let [owner, other] = <[SecretKey; 2]>::try_from(keys(2)).unwrap();
+        let predicate = PredicateTemplates::single_sig(&address_of(&owner));
+
+        assert!(spend(&predicate, &[&owner], 0).is_ok());
+        assert!(spend(&predicate, &[&other, &owner], 0).is_ok());
+        assert!(spend(&predicate, &[&other], 0).is_err());
+        assert!(spend(&predicate, &[], 0).is_err());
+    }
+
+    #[test]
+    fn multisig_needs_threshold_distinct_owners() -> Result<()> {
+        // given
+        let keys = keys(4);
+        let owners = keys[..3].iter().map(address_of).collect::<Vec<_>>();
+        let outsider = &keys[3];
+
+        // when
+        let predicate = PredicateTemplates::multisig(&owners, 2)?;
+
+        // then
+        assert!(spend(&predicate, &[&keys[0], &keys[2]], 0).is_ok());
+        assert!(spend(&predicate, &[&keys[2], outsider, &keys[1]], 0).is_ok());
+        assert!(spend(&predicate, &[&keys[0], &keys[0]], 0).is_err());
+        assert!(spend(&predicate, &[&keys[1], outsider], 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn multisig_parameters_are_checked() {
+        let owners = keys(2).iter().map(address_of).collect::<Vec<_>>();
+        let duplicated = [owners[0].clone(), owners[1].clone(), owners[0].clone()];
+
+        assert!(PredicateTemplates::multisig(&owners, 0).is_err());
+        assert!(PredicateTemplates::multisig(&owners, 3).is_err());
+        assert!(PredicateTemplates::multisig(&duplicated, 2).is_err());
+        assert!(PredicateTemplates::multisig(&vec![owners[0].clone(); 65], 1).is_err());
+    }
+
+    #[test]
+    fn timelock_needs_the_maturity_to_reach_the_unlock_height() {
+        let [owner] = <[SecretKey; 1]>::try_from(keys(1)).unwrap();
+        let predicate = PredicateTemplates::timelock(&address_of(&owner), 10);
+
+        assert!(spend(&predicate, &[&owner], 9).is_err());
+        assert!(spend(&predicate, &[&owner], 10).is_ok());
+    }
+
+    #[test]
+    fn hash_lock_needs_the_preimage() {
+        let predicate = PredicateTemplates::hash_lock(Hasher::hash(b"secret"));
+
+        assert!(spend(&predicate.clone().with_data(b"secret".to_vec()), &[], 0).is_ok());
+        assert!(spend(&predicate.clone().with_data(b"guess".to_vec()), &[], 0).is_err());
+        assert!(spend(&predicate, &[], 0).is_err());
+    }
+
+    #[test]
+    fn parameters_change_the_address() {
+        let owners = keys(2).iter().map(address_of).collect::<Vec<_>>();
+
+        let single_sig = PredicateTemplates::single_sig(&owners[0]);
+        let timelock = PredicateTemplates::timelock(&owners[0], 10);
+        let other_owner = PredicateTemplates::single_sig(&owners[1]);
+
+        assert_ne!(single_sig.address(), timelock.address());
+        assert_ne!(single_sig.address(), other_owner.address());
+    }
+}
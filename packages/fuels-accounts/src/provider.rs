@@ -1,20 +1,43 @@
-use std::{collections::HashMap, fmt::Debug, net::SocketAddr};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Debug,
+    net::SocketAddr,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
+mod asset_supply;
+mod confirmation;
+mod connection;
+mod consensus_parameters_cache;
+#[cfg(feature = "estimation")]
+mod estimation_report;
+mod failover;
+mod fee_check;
+mod middleware;
+mod pending;
 mod retry_util;
 mod retryable_client;
+mod storage_growth;
 mod supported_fuel_core_version;
 mod supported_versions;
 
-#[cfg(feature = "coin-cache")]
-use std::sync::Arc;
-
+pub use asset_supply::AssetSupply;
 use chrono::{DateTime, Utc};
+pub use confirmation::SubmitOutcome;
+pub use connection::{Resolver, SystemResolver};
+use consensus_parameters_cache::ConsensusParametersCache;
+#[cfg(feature = "estimation")]
+pub use estimation_report::EstimationReport;
+pub use failover::FailoverPolicy;
+pub use fee_check::{FeeCheck, FeePreflight, FeeWarning};
 use fuel_core_client::client::{
     pagination::{PageDirection, PaginatedResult, PaginationRequest},
     types::{
         balance::Balance,
         contract::ContractBalance,
         gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::MessageStatus,
     },
 };
 use fuel_core_types::{
@@ -22,32 +45,42 @@ use fuel_core_types::{
     services::executor::TransactionExecutionResult,
 };
 use fuel_tx::{
-    AssetId, ConsensusParameters, Receipt, Transaction as FuelTransaction, TxId, UtxoId,
+    AssetId, ConsensusParameters, ContractId, Receipt, Transaction as FuelTransaction, TxId, UtxoId,
 };
 use fuel_types::{Address, BlockHeight, Bytes32, ChainId, Nonce};
-#[cfg(feature = "coin-cache")]
-use fuels_core::types::coin_type_id::CoinTypeId;
+#[cfg(feature = "estimation")]
+use fuels_core::constants::{
+    DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, DEFAULT_GAS_ESTIMATION_TOLERANCE,
+};
 use fuels_core::{
-    constants::{DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, DEFAULT_GAS_ESTIMATION_TOLERANCE},
+    traits::Signer,
     types::{
         bech32::{Bech32Address, Bech32ContractId},
         block::{Block, Header},
         chain_info::ChainInfo,
         coin::Coin,
         coin_type::CoinType,
-        errors::Result,
+        coin_type_id::CoinTypeId,
+        errors::{error, Result},
+        input::Input,
         message::Message,
         message_proof::MessageProof,
         node_info::NodeInfo,
-        transaction::{Transaction, Transactions},
+        tip_percentile,
+        transaction::{FeeBump, Transaction, TransactionType, Transactions},
+        transaction_builders::ScriptTransactionBuilder,
         transaction_response::TransactionResponse,
         tx_status::TxStatus,
-        DryRun, DryRunner,
+        ContractDependencyGraph, DryRun, DryRunner,
     },
 };
-pub use retry_util::{Backoff, RetryConfig};
+use futures::{Stream, StreamExt};
+pub use middleware::{Middleware, RequestContext, ResponseContext};
+pub use pending::{PendingTransaction, PendingTxSubscription};
+pub use retry_util::{retry, Backoff, RetryConfig, RetryOn};
 pub use supported_fuel_core_version::SUPPORTED_FUEL_CORE_VERSION;
 use tai64::Tai64;
+use tokio::sync::broadcast;
 #[cfg(feature = "coin-cache")]
 use tokio::sync::Mutex;
 
@@ -55,7 +88,7 @@ use tokio::sync::Mutex;
 use crate::coin_cache::CoinsCache;
 use crate::provider::retryable_client::RetryableClient;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 // ANCHOR: transaction_cost
 pub struct TransactionCost {
     pub gas_price: u64,
@@ -112,13 +145,124 @@ impl ResourceFilter {
     }
 }
 
+/// Tunes how aggressively a [`Provider`] talks to the node.
+///
+/// `retry_config` applies to every request sent to the node, while `polling_config` controls
+/// loops that wait for the node to reach some state, such as a transaction being included in a
+/// block. `consensus_parameters_ttl` bounds how long
+/// [`Provider::fetch_consensus_parameters`] serves the parameters it last fetched.
+#[derive(Debug, Clone)]
+pub struct ProviderConfig {
+    retry_config: RetryConfig,
+    polling_config: RetryConfig,
+    consensus_parameters_ttl: Option<Duration>,
+    fee_preflight: Option<FeePreflight>,
+}
+
+impl ProviderConfig {
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    pub fn with_polling_config(mut self, polling_config: RetryConfig) -> Self {
+        self.polling_config = polling_config;
+        self
+    }
+
+    /// Refetches the consensus parameters once they are older than `ttl`. Without it, they are
+    /// only refetched after [`Provider::invalidate_consensus_parameters`].
+    pub fn with_consensus_parameters_ttl(mut self, ttl: Duration) -> Self {
+        self.consensus_parameters_ttl = Some(ttl);
+        self
+    }
+
+    /// Checks the fee of every transaction against the current network conditions before
+    /// sending it, failing with the reasons it may not be included. See
+    /// [`Provider::check_fee`].
+    pub fn with_fee_preflight(mut self, fee_preflight: FeePreflight) -> Self {
+        self.fee_preflight = Some(fee_preflight);
+        self
+    }
+
+    pub fn retry_config(&self) -> &RetryConfig {
+        &self.retry_config
+    }
+
+    pub fn polling_config(&self) -> &RetryConfig {
+        &self.polling_config
+    }
+
+    pub fn consensus_parameters_ttl(&self) -> Option<Duration> {
+        self.consensus_parameters_ttl
+    }
+
+    pub fn fee_preflight(&self) -> Option<&FeePreflight> {
+        self.fee_preflight.as_ref()
+    }
+}
+
+impl Default for ProviderConfig {
+    fn default() -> Self {
+        let polling_config =
+            RetryConfig::new(u32::MAX, Backoff::Linear(Duration::from_millis(100)))
+                .expect("max attempts is not zero")
+                .with_jitter(0.2)
+                .with_max_elapsed_time(Duration::from_secs(60));
+
+        Self {
+            retry_config: Default::default(),
+            polling_config,
+            consensus_parameters_ttl: None,
+            fee_preflight: None,
+        }
+    }
+}
+
+/// Describes blocks to produce with [`Provider::produce_blocks_with`].
+#[derive(Debug, Clone, Default)]
+pub struct BlockProductionConfig {
+    count: u32,
+    start_time: Option<DateTime<Utc>>,
+    interval: Option<Duration>,
+}
+
+impl BlockProductionConfig {
+    pub fn new(count: u32) -> Self {
+        Self {
+            count,
+            ..Default::default()
+        }
+    }
+
+    /// The timestamp of the first produced block.
+    pub fn with_start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Time between consecutive blocks, overriding the node's block time. Block timestamps have a
+    /// precision of one second.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+}
+
 /// Encapsulates common client operations in the SDK.
 /// Note that you may also use `client`, which is an instance
 /// of `FuelClient`, directly, which provides a broader API.
 #[derive(Debug, Clone)]
 pub struct Provider {
     client: RetryableClient,
-    consensus_parameters: ConsensusParameters,
+    // filled when connecting or, for lazy providers, on the first fetch of the chain info
+    consensus_parameters: Arc<ConsensusParametersCache>,
+    // kept apart from the parameters since upgrades don't change it
+    base_asset_id: Arc<OnceLock<AssetId>>,
+    polling_config: RetryConfig,
+    fee_preflight: Option<FeePreflight>,
+    pending_txs: broadcast::Sender<PendingTransaction>,
+    label: Option<Arc<str>>,
     #[cfg(feature = "coin-cache")]
     cache: Arc<Mutex<CoinsCache>>,
 }
@@ -134,33 +278,181 @@ impl Provider {
     }
 
     /// Connects to an existing node at the given address.
+    ///
+    /// IPv6 literals are accepted both bracketed (`[::1]:4000`) and bare (`::1`).
     pub async fn connect(url: impl AsRef<str>) -> Result<Provider> {
-        let client = RetryableClient::connect(&url, Default::default()).await?;
+        Self::connect_with_config(url, Default::default()).await
+    }
+
+    /// Connects to an existing node at the given address, using `config` for all retrying and
+    /// polling done by the `Provider`.
+    pub async fn connect_with_config(
+        url: impl AsRef<str>,
+        config: ProviderConfig,
+    ) -> Result<Provider> {
+        let client = RetryableClient::connect(&url, config.retry_config.clone()).await?;
+
+        Self::from_client(client, config).await
+    }
+
+    /// Prepares a `Provider` for the node at the given address without reaching it, e.g. for
+    /// command line tools that only need the node for some of their commands.
+    ///
+    /// The chain info is fetched on the first request needing it, or ahead of time with
+    /// [`Provider::warm_up`]. Until then, [`Provider::consensus_parameters`],
+    /// [`Provider::chain_id`] and [`Provider::base_asset_id`] panic, use
+    /// [`Provider::fetch_consensus_parameters`] or [`Provider::try_consensus_parameters`]
+    /// instead. Unlike [`Provider::connect`], the version of the node isn't checked.
+    pub fn connect_lazy(url: impl AsRef<str>, config: ProviderConfig) -> Result<Provider> {
+        let client = RetryableClient::connect_lazy(&url, config.retry_config.clone())?;
+
+        Ok(Self::new(
+            client,
+            ConsensusParametersCache::empty(config.consensus_parameters_ttl),
+            config,
+        ))
+    }
+
+    /// Connects to the first reachable node out of `urls`. Requests failing to reach the active
+    /// node are sent to the next one, as described by [`FailoverPolicy`]. All nodes are expected
+    /// to serve the same chain. `config` is used as in [`Provider::connect_with_config`].
+    pub async fn connect_with_failover(
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+        failover_policy: FailoverPolicy,
+        config: ProviderConfig,
+    ) -> Result<Provider> {
+        let client = RetryableClient::connect_with_failover(
+            urls,
+            config.retry_config.clone(),
+            failover_policy,
+        )
+        .await?;
+
+        Self::from_client(client, config).await
+    }
+
+    /// Connects to an existing node at the given address, resolving its host name through
+    /// `resolver` instead of the system resolver.
+    ///
+    /// Requests go to the first reachable address the host resolves to, and fail over to the
+    /// others as described by `failover_policy`. The host is resolved once, when connecting, so
+    /// reconnect to pick up addresses that changed. Requests are sent to the address, which the
+    /// node sees as the `Host` of the request, and only `http` urls are supported since TLS
+    /// verification needs the original host name. `config` is used as in
+    /// [`Provider::connect_with_config`].
+    pub async fn connect_with_resolver(
+        url: impl AsRef<str>,
+        resolver: &impl Resolver,
+        failover_policy: FailoverPolicy,
+        config: ProviderConfig,
+    ) -> Result<Provider> {
+        let client = RetryableClient::connect_with_resolver(
+            &url,
+            resolver,
+            config.retry_config.clone(),
+            failover_policy,
+        )
+        .await?;
+
+        Self::from_client(client, config).await
+    }
+
+    async fn from_client(client: RetryableClient, config: ProviderConfig) -> Result<Provider> {
         let consensus_parameters = client.chain_info().await?.consensus_parameters;
+        let consensus_parameters =
+            ConsensusParametersCache::new(consensus_parameters, config.consensus_parameters_ttl);
+
+        Ok(Self::new(client, consensus_parameters, config))
+    }
 
-        Ok(Self {
+    fn new(
+        client: RetryableClient,
+        consensus_parameters: ConsensusParametersCache,
+        config: ProviderConfig,
+    ) -> Self {
+        let base_asset_id = OnceLock::new();
+        if let Some(consensus_parameters) = consensus_parameters.last() {
+            let _ = base_asset_id.set(*consensus_parameters.base_asset_id());
+        }
+
+        Self {
             client,
-            consensus_parameters,
+            consensus_parameters: Arc::new(consensus_parameters),
+            base_asset_id: Arc::new(base_asset_id),
+            polling_config: config.polling_config,
+            fee_preflight: config.fee_preflight,
+            pending_txs: broadcast::channel(pending::PENDING_TXS_CAPACITY).0,
+            label: None,
             #[cfg(feature = "coin-cache")]
             cache: Default::default(),
-        })
+        }
+    }
+
+    /// Fetches the chain info of a provider made with [`Provider::connect_lazy`], unless it is
+    /// already known. Clones share the chain info, warming up one warms up all of them.
+    pub async fn warm_up(&self) -> Result<()> {
+        self.fetch_consensus_parameters().await.map(|_| ())
+    }
+
+    /// Whether the chain info is known, which is always the case for providers that weren't
+    /// connected lazily.
+    pub fn is_warmed_up(&self) -> bool {
+        self.consensus_parameters.last().is_some()
     }
 
+    /// Names the provider, e.g. after the node or the service using it. The label prefixes the
+    /// errors of sending transactions and is recorded in their tracing spans.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into().into());
+        self
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn in_context<T>(&self, result: Result<T>) -> Result<T> {
+        match &self.label {
+            Some(label) => result.map_err(|err| err.with_context(format!("provider `{label}`"))),
+            None => result,
+        }
+    }
+
+    /// The url of the node the `Provider` currently talks to. With failover, this changes once
+    /// the active node becomes unreachable.
     pub fn url(&self) -> &str {
         self.client.url()
     }
 
     /// Sends a transaction to the underlying Provider's client.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(tx_id = tracing::field::Empty, provider = self.label())
+        )
+    )]
     pub async fn send_transaction_and_await_commit<T: Transaction>(
         &self,
         tx: T,
     ) -> Result<TxStatus> {
+        let result = self.send_and_await_commit(tx).await;
+
+        self.in_context(result)
+    }
+
+    async fn send_and_await_commit<T: Transaction>(&self, tx: T) -> Result<TxStatus> {
         let tx = self.prepare_transaction_for_sending(tx).await?;
-        let tx_status = self
+        let pending_tx = self.pending_tx(&tx);
+        let tx_status: TxStatus = self
             .client
             .submit_and_await_commit(&tx.clone().into())
             .await?
             .into();
+        // the node answers only once the transaction is committed
+        if !matches!(tx_status, TxStatus::SqueezedOut { .. }) {
+            self.announce_pending(pending_tx);
+        }
 
         #[cfg(feature = "coin-cache")]
         if matches!(
@@ -177,37 +469,59 @@ impl Provider {
     }
 
     async fn prepare_transaction_for_sending<T: Transaction>(&self, mut tx: T) -> Result<T> {
-        tx.precompute(&self.chain_id())?;
+        let chain_id = self.fetch_consensus_parameters().await?.chain_id();
+        #[cfg(feature = "tracing")]
+        self.record_tx_id(&tx);
+        tx.precompute(&chain_id)?;
 
-        let chain_info = self.chain_info().await?;
+        let ChainInfo {
+            latest_block,
+            consensus_parameters,
+            ..
+        } = self.chain_info().await?;
         let Header {
             height: latest_block_height,
             state_transition_bytecode_version: latest_chain_executor_version,
             ..
-        } = chain_info.latest_block.header;
-        tx.check(latest_block_height, self.consensus_parameters())?;
+        } = latest_block.header;
+        tx.check(latest_block_height, &consensus_parameters)?;
 
         if tx.is_using_predicates() {
             tx.estimate_predicates(self, Some(latest_chain_executor_version))
                 .await?;
             tx.clone()
-                .validate_predicates(self.consensus_parameters(), latest_block_height)?;
+                .validate_predicates(&consensus_parameters, latest_block_height)?;
         }
 
+        #[cfg(feature = "estimation")]
         self.validate_transaction(tx.clone()).await?;
+        self.run_fee_preflight(&tx).await?;
 
         Ok(tx)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(tx_id = tracing::field::Empty, provider = self.label())
+        )
+    )]
     pub async fn send_transaction<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        let tx = self.prepare_transaction_for_sending(tx).await?;
-        self.submit(tx).await
+        let result = async {
+            let tx = self.prepare_transaction_for_sending(tx).await?;
+            self.submit(tx).await
+        }
+        .await;
+
+        self.in_context(result)
     }
 
     pub async fn await_transaction_commit<T: Transaction>(&self, id: TxId) -> Result<TxStatus> {
         Ok(self.client.await_transaction_commit(&id).await?.into())
     }
 
+    #[cfg(feature = "estimation")]
     async fn validate_transaction<T: Transaction>(&self, tx: T) -> Result<()> {
         let tolerance = 0.0;
         let TransactionCost { gas_used, .. } = self
@@ -221,36 +535,187 @@ impl Provider {
 
     #[cfg(not(feature = "coin-cache"))]
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        Ok(self.client.submit(&tx.into()).await?)
+        let pending_tx = self.pending_tx(&tx);
+        let tx_id = self.client.submit(&tx.into()).await?;
+        self.announce_pending(pending_tx);
+
+        Ok(tx_id)
     }
 
     #[cfg(feature = "coin-cache")]
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
         let used_utxos = tx.used_coins(self.base_asset_id());
+        let pending_tx = self.pending_tx(&tx);
         let tx_id = self.client.submit(&tx.into()).await?;
         self.cache.lock().await.insert_multiple(used_utxos);
+        self.announce_pending(pending_tx);
 
         Ok(tx_id)
     }
 
+    #[cfg(feature = "tracing")]
+    fn record_tx_id<T: Transaction>(&self, tx: &T) {
+        tracing::Span::current().record("tx_id", tracing::field::display(tx.id(self.chain_id())));
+    }
+
+    fn pending_tx<T: Transaction>(&self, tx: &T) -> Option<PendingTransaction> {
+        // skip the work when nobody listens
+        (self.pending_txs.receiver_count() > 0)
+            .then(|| PendingTransaction::new(tx, self.chain_id()))
+    }
+
+    fn announce_pending(&self, pending_tx: Option<PendingTransaction>) {
+        if let Some(pending_tx) = pending_tx {
+            // fails only if all subscribers are gone in the meantime
+            let _ = self.pending_txs.send(pending_tx);
+        }
+    }
+
+    /// Subscribes to the transactions submitted through this provider and its clones, as soon as
+    /// the node accepts them into its pool. Transactions sent with
+    /// [`Provider::send_transaction_and_await_commit`] are only announced once committed, since
+    /// the node doesn't answer earlier. Transactions the node rejects are never announced.
+    ///
+    /// This is not a view of the node's pool: `fuel-core` doesn't expose its contents, so
+    /// transactions submitted by other clients, or by other providers of this process, are not
+    /// seen. Use [`PendingTransaction::involves`] to follow the activity of a single address.
+    pub fn subscribe_submitted_txs(&self) -> PendingTxSubscription {
+        PendingTxSubscription::new(self.pending_txs.subscribe())
+    }
+
+    /// Subscribes to the status changes of the transaction `tx_id`, as pushed by the node. The
+    /// stream ends once the transaction reaches a final status.
+    pub async fn subscribe_tx_status<'a>(
+        &'a self,
+        tx_id: &'a TxId,
+    ) -> Result<impl Stream<Item = Result<TxStatus>> + 'a> {
+        let statuses = self.client.subscribe_transaction_status(tx_id).await?;
+
+        Ok(statuses.map(|status| Ok(status?.into())))
+    }
+
+    /// Waits until the transaction `tx_id` is committed, reverted or squeezed out, through the
+    /// node's status subscription rather than polling. Fails if that takes longer than `timeout`.
+    pub async fn await_final_tx_status(&self, tx_id: &TxId, timeout: Duration) -> Result<TxStatus> {
+        let wait_for_final_status = async {
+            let statuses = self.subscribe_tx_status(tx_id).await?;
+            futures::pin_mut!(statuses);
+
+            while let Some(status) = statuses.next().await {
+                let status = status?;
+                if !matches!(status, TxStatus::Submitted) {
+                    return Ok(status);
+                }
+            }
+
+            Err(error!(
+                Provider,
+                "status subscription of transaction `{tx_id}` ended without a final status"
+            ))
+        };
+
+        tokio::time::timeout(timeout, wait_for_final_status)
+            .await
+            .map_err(|_| {
+                error!(
+                    Provider,
+                    "transaction `{tx_id}` is still pending after {timeout:?}"
+                )
+            })?
+    }
+
     pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
         Ok(self.client.transaction_status(tx_id).await?.into())
     }
 
+    /// Polls the status of the transaction until it is no longer `Submitted`, backing off as
+    /// configured by the provider's polling config.
+    pub async fn poll_tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
+        let status = retry(
+            || self.tx_status(tx_id),
+            &self.polling_config,
+            |status| matches!(status, Ok(TxStatus::Submitted)),
+        )
+        .await?;
+
+        if matches!(status, TxStatus::Submitted) {
+            return Err(error!(
+                Provider,
+                "transaction `{tx_id}` is still pending after polling limits were reached"
+            ));
+        }
+
+        Ok(status)
+    }
+
     pub async fn chain_info(&self) -> Result<ChainInfo> {
-        Ok(self.client.chain_info().await?.into())
+        let chain_info: ChainInfo = self.client.chain_info().await?.into();
+        self.consensus_parameters
+            .set(chain_info.consensus_parameters.clone());
+        // a no-op unless the provider was connected lazily
+        let _ = self
+            .base_asset_id
+            .set(*chain_info.consensus_parameters.base_asset_id());
+
+        Ok(chain_info)
+    }
+
+    /// The consensus parameters last fetched by the `Provider` or its clones, without checking
+    /// whether they are still fresh. Use [`Provider::fetch_consensus_parameters`] to follow
+    /// upgrades of the chain.
+    ///
+    /// # Panics
+    ///
+    /// If the provider was made with [`Provider::connect_lazy`] and isn't warmed up yet.
+    pub fn consensus_parameters(&self) -> ConsensusParameters {
+        self.consensus_parameters
+            .last()
+            .expect("the chain info of a lazily connected `Provider` is only known after `Provider::warm_up`")
+    }
+
+    /// Like [`Provider::consensus_parameters`], but fails instead of panicking if the provider
+    /// was made with [`Provider::connect_lazy`] and isn't warmed up yet.
+    pub fn try_consensus_parameters(&self) -> Result<ConsensusParameters> {
+        self.consensus_parameters.last().ok_or_else(|| {
+            error!(
+                Provider,
+                "the chain info isn't fetched yet, see `Provider::warm_up`"
+            )
+        })
+    }
+
+    /// The current consensus parameters of the chain.
+    ///
+    /// The parameters are cached, and shared by all clones of the `Provider`, so that repeated
+    /// calls don't each query the node. They are refetched once older than the
+    /// `consensus_parameters_ttl` of the [`ProviderConfig`], after
+    /// [`Provider::invalidate_consensus_parameters`], and whenever the chain info is fetched
+    /// anyway. A provider made with [`Provider::connect_lazy`] fetches them on the first call.
+    pub async fn fetch_consensus_parameters(&self) -> Result<ConsensusParameters> {
+        if let Some(consensus_parameters) = self.consensus_parameters.fresh() {
+            return Ok(consensus_parameters);
+        }
+
+        Ok(self.chain_info().await?.consensus_parameters)
     }
 
-    pub fn consensus_parameters(&self) -> &ConsensusParameters {
-        &self.consensus_parameters
+    /// Makes the next [`Provider::fetch_consensus_parameters`] refetch the parameters, e.g.
+    /// after upgrading the chain.
+    pub fn invalidate_consensus_parameters(&self) {
+        self.consensus_parameters.invalidate();
     }
 
+    /// # Panics
+    ///
+    /// If the provider was made with [`Provider::connect_lazy`] and isn't warmed up yet.
     pub fn base_asset_id(&self) -> &AssetId {
-        self.consensus_parameters.base_asset_id()
+        self.base_asset_id
+            .get()
+            .expect("the chain info of a lazily connected `Provider` is only known after `Provider::warm_up`")
     }
 
     pub fn chain_id(&self) -> ChainId {
-        self.consensus_parameters.chain_id()
+        self.consensus_parameters().chain_id()
     }
 
     pub async fn node_info(&self) -> Result<NodeInfo> {
@@ -265,6 +730,48 @@ impl Provider {
         Ok(self.client.estimate_gas_price(block_horizon).await?)
     }
 
+    /// The `percentile` (from 0 to 100) of the tips paid by the transactions in the last `blocks`
+    /// blocks, or 0 if they hold no transactions paying fees. Fetches every transaction of those
+    /// blocks, so keep `blocks` small.
+    pub async fn estimate_tip(&self, percentile: u8, blocks: u32) -> Result<u64> {
+        let mut tips = vec![];
+        let mut remaining = blocks;
+        let mut cursor = None;
+
+        while remaining > 0 {
+            let page = self
+                .get_blocks(PaginationRequest {
+                    cursor,
+                    results: remaining.min(100) as i32,
+                    direction: PageDirection::Backward,
+                })
+                .await?;
+
+            for tx_id in page.results.iter().flat_map(|block| &block.transactions) {
+                let Some(response) = self.get_transaction_by_id(tx_id).await? else {
+                    continue;
+                };
+
+                let tip = match response.transaction {
+                    TransactionType::Script(tx) => tx.tip(),
+                    TransactionType::Create(tx) => tx.tip(),
+                    TransactionType::Upload(tx) => tx.tip(),
+                    TransactionType::Upgrade(tx) => tx.tip(),
+                    TransactionType::Mint(_) => None,
+                };
+                tips.extend(tip);
+            }
+
+            remaining = remaining.saturating_sub(page.results.len() as u32);
+            if !page.has_next_page || page.results.is_empty() {
+                break;
+            }
+            cursor = page.cursor;
+        }
+
+        Ok(tip_percentile(tips, percentile))
+    }
+
     pub async fn dry_run(&self, tx: impl Transaction) -> Result<TxStatus> {
         let [tx_status] = self
             .client
@@ -361,13 +868,14 @@ impl Provider {
     }
 
     async fn request_coins_to_spend(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
+        let base_asset_id = *self.fetch_consensus_parameters().await?.base_asset_id();
         let queries = filter.resource_queries();
 
         let res = self
             .client
             .coins_to_spend(
                 &filter.owner(),
-                queries.spend_query(*self.base_asset_id()),
+                queries.spend_query(base_asset_id),
                 queries.exclusion_query(),
             )
             .await?
@@ -396,15 +904,17 @@ impl Provider {
         &self,
         mut filter: ResourceFilter,
     ) -> Result<Vec<CoinType>> {
-        self.extend_filter_with_cached(&mut filter).await;
+        let base_asset_id = *self.fetch_consensus_parameters().await?.base_asset_id();
+        self.extend_filter_with_cached(&mut filter, base_asset_id)
+            .await;
 
         self.request_coins_to_spend(filter).await
     }
 
     #[cfg(feature = "coin-cache")]
-    async fn extend_filter_with_cached(&self, filter: &mut ResourceFilter) {
+    async fn extend_filter_with_cached(&self, filter: &mut ResourceFilter, base_asset_id: AssetId) {
         let mut cache = self.cache.lock().await;
-        let asset_id = filter.asset_id.unwrap_or(*self.base_asset_id());
+        let asset_id = filter.asset_id.unwrap_or(base_asset_id);
         let used_coins = cache.get_active(&(filter.from.clone(), asset_id));
 
         let excluded_utxos = used_coins
@@ -431,6 +941,30 @@ impl Provider {
             .extend(excluded_message_nonces);
     }
 
+    /// The coins of `address` recently submitted inside a tx, which
+    /// [`Provider::get_spendable_resources`] ignores. Always empty without the `coin-cache`
+    /// feature.
+    #[cfg(feature = "coin-cache")]
+    pub(crate) async fn cached_coins(
+        &self,
+        address: &Bech32Address,
+        asset_id: AssetId,
+    ) -> HashSet<CoinTypeId> {
+        self.cache
+            .lock()
+            .await
+            .get_active(&(address.clone(), asset_id))
+    }
+
+    #[cfg(not(feature = "coin-cache"))]
+    pub(crate) async fn cached_coins(
+        &self,
+        _address: &Bech32Address,
+        _asset_id: AssetId,
+    ) -> HashSet<CoinTypeId> {
+        HashSet::new()
+    }
+
     /// Get the balance of all spendable coins `asset_id` for address `address`. This is different
     /// from getting coins because we are just returning a number (the sum of UTXOs amount) instead
     /// of the UTXOs.
@@ -445,6 +979,18 @@ impl Provider {
             .await?)
     }
 
+    /// The bytecode of the contract `contract_id`, or `None` if no contract is deployed there.
+    pub async fn contract_bytecode(
+        &self,
+        contract_id: &Bech32ContractId,
+    ) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .client
+            .contract(&contract_id.into())
+            .await?
+            .map(|contract| contract.bytecode))
+    }
+
     /// Get the balance of all spendable coins `asset_id` for contract with id `contract_id`.
     pub async fn get_contract_asset_balance(
         &self,
@@ -517,10 +1063,132 @@ impl Provider {
         Ok(balances)
     }
 
+    /// Get a page of the balances of the contract with id `contract_id`. Use this over
+    /// [`Provider::get_contract_balances`] for contracts holding many different assets.
+    pub async fn get_contract_balances_paginated(
+        &self,
+        contract_id: &Bech32ContractId,
+        request: PaginationRequest<String>,
+    ) -> Result<PaginatedResult<(AssetId, u64), String>> {
+        let pr = self
+            .client
+            .contract_balances(&contract_id.into(), request)
+            .await?;
+
+        Ok(PaginatedResult {
+            cursor: pr.cursor,
+            results: pr
+                .results
+                .into_iter()
+                .map(|balance| (balance.asset_id, balance.amount))
+                .collect(),
+            has_next_page: pr.has_next_page,
+            has_previous_page: pr.has_previous_page,
+        })
+    }
+
     pub async fn get_transaction_by_id(&self, tx_id: &TxId) -> Result<Option<TransactionResponse>> {
         Ok(self.client.transaction(tx_id).await?.map(Into::into))
     }
 
+    /// Turns the script transaction `tx_id` back into a builder, see
+    /// [`ScriptTransactionBuilder::from_existing`]. Coins and messages that are no longer
+    /// spendable, because this or another transaction spent them, are left out, so a transaction
+    /// that failed or expired can be issued again once it is funded and signed anew.
+    pub async fn transaction_template(
+        &self,
+        tx_id: &TxId,
+    ) -> Result<Option<ScriptTransactionBuilder>> {
+        let Some(response) = self.get_transaction_by_id(tx_id).await? else {
+            return Ok(None);
+        };
+        let TransactionType::Script(tx) = response.transaction else {
+            return Err(error!(
+                Provider,
+                "transaction `{tx_id}` is not a script transaction"
+            ));
+        };
+
+        let mut tb = ScriptTransactionBuilder::from_existing(&tx);
+        let mut unspent_inputs = Vec::with_capacity(tb.inputs.len());
+        for input in std::mem::take(&mut tb.inputs) {
+            if self.is_unspent(&input).await? {
+                unspent_inputs.push(input);
+            }
+        }
+        tb.inputs = unspent_inputs;
+
+        Ok(Some(tb))
+    }
+
+    /// Replaces the pending transaction `tx_id` with a copy paying the fees `bump` describes,
+    /// signed anew by `signer`. Both spend the same coins, so only one of them can be included:
+    /// the node drops the original from its txpool in favor of the replacement, which tips more.
+    /// Fails if the original was already included.
+    pub async fn replace_transaction(
+        &self,
+        tx_id: &TxId,
+        bump: FeeBump,
+        signer: &(impl Signer + Send + Sync),
+    ) -> Result<TxId> {
+        let response = self
+            .get_transaction_by_id(tx_id)
+            .await?
+            .ok_or_else(|| error!(Provider, "transaction `{tx_id}` not found"))?;
+        if !matches!(
+            response.status,
+            TxStatus::Submitted | TxStatus::SqueezedOut { .. }
+        ) {
+            return Err(error!(
+                Provider,
+                "transaction `{tx_id}` was already included in a block"
+            ));
+        }
+
+        let chain_id = self.fetch_consensus_parameters().await?.chain_id();
+        match response.transaction {
+            TransactionType::Script(mut tx) => {
+                tx.bump_fee(bump, signer, chain_id).await?;
+                self.send_transaction(tx).await
+            }
+            TransactionType::Create(mut tx) => {
+                tx.bump_fee(bump, signer, chain_id).await?;
+                self.send_transaction(tx).await
+            }
+            TransactionType::Upload(mut tx) => {
+                tx.bump_fee(bump, signer, chain_id).await?;
+                self.send_transaction(tx).await
+            }
+            TransactionType::Upgrade(mut tx) => {
+                tx.bump_fee(bump, signer, chain_id).await?;
+                self.send_transaction(tx).await
+            }
+            TransactionType::Mint(_) => Err(error!(
+                Provider,
+                "mint transaction `{tx_id}` cannot be replaced"
+            )),
+        }
+    }
+
+    async fn is_unspent(&self, input: &Input) -> Result<bool> {
+        let (Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. }) =
+            input
+        else {
+            // contracts aren't consumed
+            return Ok(true);
+        };
+
+        let unspent = match resource.id() {
+            CoinTypeId::UtxoId(utxo_id) => self.client.coin(&utxo_id).await?.is_some(),
+            CoinTypeId::Nonce(nonce) => matches!(
+                self.client.message_status(&nonce).await?,
+                MessageStatus::Unspent
+            ),
+        };
+
+        Ok(unspent)
+    }
+
     pub async fn get_transactions(
         &self,
         request: PaginationRequest<String>,
@@ -576,6 +1244,55 @@ impl Provider {
             .into())
     }
 
+    /// Produces the blocks described by `config` and returns their headers.
+    ///
+    /// Without an interval, block times follow the node's configuration, as with
+    /// [`produce_blocks`](Self::produce_blocks). With an interval, blocks are produced one by one
+    /// starting at `start_time`, or one interval after the latest block if not given.
+    pub async fn produce_blocks_with(&self, config: BlockProductionConfig) -> Result<Vec<Header>> {
+        let BlockProductionConfig {
+            count,
+            start_time,
+            interval,
+        } = config;
+
+        let first_height = self.latest_block_height().await? + 1;
+
+        match interval {
+            None => {
+                self.produce_blocks(count, start_time).await?;
+            }
+            Some(interval) => {
+                let interval = chrono::Duration::from_std(interval)
+                    .map_err(|e| error!(Other, "invalid block interval: {e}"))?;
+                let start_time = match start_time {
+                    Some(start_time) => start_time,
+                    None => self.latest_block_time().await?.unwrap_or_else(Utc::now) + interval,
+                };
+
+                for i in 0..count {
+                    let block_time = i32::try_from(i)
+                        .ok()
+                        .and_then(|i| interval.checked_mul(i))
+                        .and_then(|offset| start_time.checked_add_signed(offset))
+                        .ok_or_else(|| error!(Other, "block time out of range for block {i}"))?;
+                    self.produce_blocks(1, Some(block_time)).await?;
+                }
+            }
+        }
+
+        let mut headers = Vec::with_capacity(count as usize);
+        for height in first_height..first_height + count {
+            let block = self
+                .block_by_height(height.into())
+                .await?
+                .ok_or_else(|| error!(Provider, "produced block at height {height} not found"))?;
+            headers.push(block.header);
+        }
+
+        Ok(headers)
+    }
+
     pub async fn block(&self, block_id: &Bytes32) -> Result<Option<Block>> {
         Ok(self.client.block(block_id).await?.map(Into::into))
     }
@@ -599,12 +1316,74 @@ impl Provider {
         })
     }
 
+    /// Builds a graph of which contracts call which from the transactions of the latest
+    /// `block_count` blocks. Only transactions calling, or called by, one of `contract_ids` are
+    /// considered.
+    pub async fn contract_dependency_graph(
+        &self,
+        contract_ids: &[Bech32ContractId],
+        block_count: u32,
+    ) -> Result<ContractDependencyGraph> {
+        let contract_ids = contract_ids
+            .iter()
+            .map(ContractId::from)
+            .collect::<BTreeSet<_>>();
+
+        let mut graph = ContractDependencyGraph::new();
+        let mut remaining = block_count;
+        let mut cursor = None;
+
+        while remaining > 0 {
+            let page = self
+                .get_blocks(PaginationRequest {
+                    cursor,
+                    results: remaining.min(100) as i32,
+                    direction: PageDirection::Backward,
+                })
+                .await?;
+
+            for tx_id in page.results.iter().flat_map(|block| &block.transactions) {
+                let Some(response) = self.get_transaction_by_id(tx_id).await? else {
+                    continue;
+                };
+
+                let receipts = response.status.take_receipts();
+                if ContractDependencyGraph::involves(&receipts, &contract_ids) {
+                    graph.add_receipts(&receipts);
+                }
+            }
+
+            remaining = remaining.saturating_sub(page.results.len() as u32);
+            if !page.has_next_page || page.results.is_empty() {
+                break;
+            }
+            cursor = page.cursor;
+        }
+
+        Ok(graph)
+    }
+
+    #[cfg(feature = "estimation")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                tx_id = tracing::field::Empty,
+                gas_used = tracing::field::Empty,
+                total_fee = tracing::field::Empty
+            )
+        )
+    )]
     pub async fn estimate_transaction_cost<T: Transaction>(
         &self,
         mut tx: T,
         tolerance: Option<f64>,
         block_horizon: Option<u32>,
     ) -> Result<TransactionCost> {
+        let consensus_parameters = self.fetch_consensus_parameters().await?;
+        #[cfg(feature = "tracing")]
+        self.record_tx_id(&tx);
         let block_horizon = block_horizon.unwrap_or(DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON);
         let tolerance = tolerance.unwrap_or(DEFAULT_GAS_ESTIMATION_TOLERANCE);
 
@@ -620,9 +1399,14 @@ impl Provider {
 
         let transaction_fee = tx
             .clone()
-            .fee_checked_from_tx(&self.consensus_parameters, gas_price)
+            .fee_checked_from_tx(&consensus_parameters, gas_price)
             .expect("Error calculating TransactionFee");
 
+        #[cfg(feature = "tracing")]
+        tracing::Span::current()
+            .record("gas_used", gas_used)
+            .record("total_fee", transaction_fee.max_fee());
+
         Ok(TransactionCost {
             gas_price,
             gas_used,
@@ -632,6 +1416,7 @@ impl Provider {
     }
 
     // Increase estimated gas by the provided tolerance
+    #[cfg(feature = "estimation")]
     async fn get_gas_used_with_tolerance<T: Transaction>(
         &self,
         tx: T,
@@ -655,21 +1440,33 @@ impl Provider {
             .unwrap_or(0)
     }
 
+    /// All messages owned by `from`, fetched page by page.
     pub async fn get_messages(&self, from: &Bech32Address) -> Result<Vec<Message>> {
-        let pagination = PaginationRequest {
-            cursor: None,
-            results: 100,
-            direction: PageDirection::Forward,
-        };
+        let owner = from.into();
+        let mut messages = vec![];
+        let mut cursor = None;
 
-        Ok(self
-            .client
-            .messages(Some(&from.into()), pagination)
-            .await?
-            .results
-            .into_iter()
-            .map(Into::into)
-            .collect())
+        loop {
+            let page = self
+                .client
+                .messages(
+                    Some(&owner),
+                    PaginationRequest {
+                        cursor,
+                        results: 100,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await?;
+
+            messages.extend(page.results.into_iter().map(Message::from));
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.cursor;
+        }
+
+        Ok(messages)
     }
 
     pub async fn get_message_proof(
@@ -684,7 +1481,7 @@ impl Provider {
             .message_proof(
                 tx_id,
                 nonce,
-                commit_block_id.map(Into::into),
+                commit_block_id,
                 commit_block_height.map(Into::into),
             )
             .await?
@@ -698,6 +1495,35 @@ impl Provider {
 
         self
     }
+
+    pub fn with_polling_config(mut self, polling_config: RetryConfig) -> Self {
+        self.polling_config = polling_config;
+
+        self
+    }
+
+    /// Adds `middleware` to the ones intercepting the requests of this provider. Requests go
+    /// through the middlewares in the order they were added, responses in the reverse order.
+    ///
+    /// Clones made before this call don't use `middleware`, clones made after it do.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.client.add_middleware(Arc::new(middleware));
+
+        self
+    }
+
+    pub fn with_config(mut self, config: ProviderConfig) -> Self {
+        if config.consensus_parameters_ttl != self.consensus_parameters.ttl() {
+            self.consensus_parameters = Arc::new(
+                self.consensus_parameters
+                    .with_ttl(config.consensus_parameters_ttl),
+            );
+        }
+        self.fee_preflight = config.fee_preflight;
+
+        self.with_retry_config(config.retry_config)
+            .with_polling_config(config.polling_config)
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -705,7 +1531,7 @@ impl DryRunner for Provider {
     async fn dry_run(&self, tx: FuelTransaction) -> Result<DryRun> {
         let [tx_execution_status] = self
             .client
-            .dry_run_opt(&vec![tx], Some(false), Some(0))
+            .dry_run_opt(&[tx], Some(false), Some(0))
             .await?
             .try_into()
             .expect("should have only one element");
@@ -738,10 +1564,18 @@ impl DryRunner for Provider {
         Ok(self.estimate_gas_price(block_horizon).await?.gas_price)
     }
 
-    fn consensus_parameters(&self) -> &ConsensusParameters {
+    async fn estimate_tip(&self, percentile: u8, blocks: u32) -> Result<u64> {
+        Provider::estimate_tip(self, percentile, blocks).await
+    }
+
+    fn consensus_parameters(&self) -> ConsensusParameters {
         self.consensus_parameters()
     }
 
+    async fn fetch_consensus_parameters(&self) -> Result<ConsensusParameters> {
+        Provider::fetch_consensus_parameters(self).await
+    }
+
     async fn maybe_estimate_predicates(
         &self,
         tx: &FuelTransaction,
@@ -0,0 +1,133 @@
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
+use fuel_tx::{AssetId, ContractIdExt, Receipt};
+use fuels_core::types::{errors::Result, tx_status::TxStatus};
+
+use crate::provider::Provider;
+
+// transactions fetched per request while scanning the chain
+const SCAN_PAGE_SIZE: i32 = 100;
+
+/// How much of an asset contracts minted and burned, as of the transactions scanned so far. See
+/// [`Provider::asset_supply`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetSupply {
+    pub asset_id: AssetId,
+    pub minted: u64,
+    pub burned: u64,
+    // where the next scan resumes, `None` before the first one
+    cursor: Option<String>,
+}
+
+impl AssetSupply {
+    /// Nothing minted or burned yet. Pass it to [`Provider::update_asset_supply`] to scan the
+    /// chain from the start.
+    pub fn new(asset_id: AssetId) -> Self {
+        Self {
+            asset_id,
+            minted: 0,
+            burned: 0,
+            cursor: None,
+        }
+    }
+
+    /// The amount minted and not burned since.
+    pub fn circulating(&self) -> u64 {
+        self.minted.saturating_sub(self.burned)
+    }
+
+    fn record(&mut self, receipts: &[Receipt]) {
+        for receipt in receipts {
+            match receipt {
+                Receipt::Mint {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } if contract_id.asset_id(sub_id) == self.asset_id => {
+                    self.minted = self.minted.saturating_add(*val);
+                }
+                Receipt::Burn {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } if contract_id.asset_id(sub_id) == self.asset_id => {
+                    self.burned = self.burned.saturating_add(*val);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Provider {
+    /// Adds up the mints and burns of `asset_id` by going through every transaction of the chain.
+    /// Only assets minted by contracts are covered: coins created at genesis, like most of the
+    /// base asset, don't show up.
+    ///
+    /// Scanning a long chain takes many requests. To keep the supply up to date, hold on to the
+    /// result and pass it to [`Provider::update_asset_supply`], which only scans the transactions
+    /// that came since.
+    pub async fn asset_supply(&self, asset_id: AssetId) -> Result<AssetSupply> {
+        let mut supply = AssetSupply::new(asset_id);
+        self.update_asset_supply(&mut supply).await?;
+
+        Ok(supply)
+    }
+
+    /// Adds the mints and burns of the transactions included since `supply` was last updated.
+    pub async fn update_asset_supply(&self, supply: &mut AssetSupply) -> Result<()> {
+        loop {
+            let page = self
+                .get_transactions(PaginationRequest {
+                    cursor: supply.cursor.clone(),
+                    results: SCAN_PAGE_SIZE,
+                    direction: PageDirection::Forward,
+                })
+                .await?;
+
+            for response in &page.results {
+                // reverted transactions don't change the supply
+                if let TxStatus::Success { receipts } = &response.status {
+                    supply.record(receipts);
+                }
+            }
+            if page.cursor.is_some() {
+                supply.cursor = page.cursor;
+            }
+
+            if !page.has_next_page {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{Bytes32, ContractId};
+
+    use super::*;
+
+    #[test]
+    fn only_receipts_of_the_asset_are_counted() {
+        // given
+        let contract_id = ContractId::from([1; 32]);
+        let sub_id = Bytes32::zeroed();
+        let other_sub_id = Bytes32::from([2; 32]);
+        let mut supply = AssetSupply::new(contract_id.asset_id(&sub_id));
+        let receipts = [
+            Receipt::mint(sub_id, contract_id, 100, 0, 0),
+            Receipt::mint(other_sub_id, contract_id, 1_000, 0, 0),
+            Receipt::burn(sub_id, contract_id, 30, 0, 0),
+        ];
+
+        // when
+        supply.record(&receipts);
+
+        // then
+        assert_eq!(supply.minted, 100);
+        assert_eq!(supply.burned, 30);
+        assert_eq!(supply.circulating(), 70);
+    }
+}
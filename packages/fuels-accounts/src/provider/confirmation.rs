@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use fuel_tx::TxId;
+use fuel_types::BlockHeight;
+use fuels_core::types::{errors::Result, transaction::Transaction, tx_status::TxStatus};
+use tokio::time::Instant;
+
+use crate::provider::Provider;
+
+/// How a transaction sent with [`Provider::submit_and_confirm`] ended up.
+#[derive(Debug, Clone)]
+pub enum SubmitOutcome {
+    /// The transaction is in a block with at least the requested number of confirmations. The
+    /// `status` is either `Success` or `Revert`, since reverted transactions are included as well.
+    Confirmed {
+        tx_id: TxId,
+        block_height: BlockHeight,
+        status: TxStatus,
+    },
+    /// The transaction wasn't confirmed in time. It can still be included later: submitting the
+    /// same transaction again resumes waiting for it without sending it twice.
+    TimedOut { tx_id: TxId },
+    /// The node refused the transaction or dropped it from its pool.
+    Rejected { reason: String },
+}
+
+enum Inclusion {
+    Unknown,
+    Pending,
+    Dropped {
+        reason: String,
+    },
+    Included {
+        block_height: BlockHeight,
+        status: TxStatus,
+    },
+}
+
+impl Provider {
+    /// Sends `tx` and waits until it is buried under `confirmations` blocks, or `timeout` elapses.
+    ///
+    /// The transaction is looked up by id before being sent, so calling this again with the same
+    /// transaction, e.g. after a timeout or a connection failure, never submits it twice. It is
+    /// also looked up after a failed submission, in case the node got it but the response was
+    /// lost. Until the confirmations are reached, every poll checks the transaction again, so a
+    /// transaction dropped from its block by a reorg is waited for, or resubmitted, anew.
+    ///
+    /// Both `0` and `1` confirmations return as soon as the transaction is included. Errors are
+    /// only returned when the transaction fails the local checks or the node can't be reached.
+    pub async fn submit_and_confirm<T: Transaction>(
+        &self,
+        tx: T,
+        confirmations: u32,
+        timeout: Duration,
+    ) -> Result<SubmitOutcome> {
+        let deadline = Instant::now() + timeout;
+        let tx_id = tx.id(self.fetch_consensus_parameters().await?.chain_id());
+        let mut prepared_tx = None;
+        let mut attempt = 0;
+
+        loop {
+            match self.inclusion(&tx_id).await? {
+                Inclusion::Unknown => {
+                    let tx = match prepared_tx.take() {
+                        Some(tx) => tx,
+                        None => self.prepare_transaction_for_sending(tx.clone()).await?,
+                    };
+
+                    let submission = self.submit(tx.clone()).await;
+                    prepared_tx = Some(tx);
+
+                    if let Err(err) = submission {
+                        if matches!(self.inclusion(&tx_id).await?, Inclusion::Unknown) {
+                            return Ok(SubmitOutcome::Rejected {
+                                reason: err.to_string(),
+                            });
+                        }
+                    }
+                }
+                Inclusion::Pending => {}
+                Inclusion::Dropped { reason } => {
+                    #[cfg(feature = "coin-cache")]
+                    self.cache
+                        .lock()
+                        .await
+                        .remove_items(tx.used_coins(self.base_asset_id()));
+
+                    return Ok(SubmitOutcome::Rejected { reason });
+                }
+                Inclusion::Included {
+                    block_height,
+                    status,
+                } => {
+                    let latest_block_height = self.latest_block_height().await?;
+                    if confirmations_of(*block_height, latest_block_height) >= confirmations {
+                        #[cfg(feature = "coin-cache")]
+                        if matches!(status, TxStatus::Revert { .. }) {
+                            self.cache
+                                .lock()
+                                .await
+                                .remove_items(tx.used_coins(self.base_asset_id()));
+                        }
+
+                        return Ok(SubmitOutcome::Confirmed {
+                            tx_id,
+                            block_height,
+                            status,
+                        });
+                    }
+                }
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(SubmitOutcome::TimedOut { tx_id });
+            }
+            let wait = self.polling_config.wait_duration(attempt);
+            tokio::time::sleep(wait.min(deadline - now)).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+
+    async fn inclusion(&self, tx_id: &TxId) -> Result<Inclusion> {
+        let Some(response) = self.get_transaction_by_id(tx_id).await? else {
+            return Ok(Inclusion::Unknown);
+        };
+
+        let inclusion = match (response.status, response.block_height) {
+            (TxStatus::SqueezedOut { reason }, _) => Inclusion::Dropped { reason },
+            (status @ (TxStatus::Success { .. } | TxStatus::Revert { .. }), Some(block_height)) => {
+                Inclusion::Included {
+                    block_height,
+                    status,
+                }
+            }
+            _ => Inclusion::Pending,
+        };
+
+        Ok(inclusion)
+    }
+}
+
+/// The number of blocks, the including one counted, since the transaction was included.
+fn confirmations_of(included_at: u32, latest_block_height: u32) -> u32 {
+    latest_block_height
+        .saturating_sub(included_at)
+        .saturating_add(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn including_block_counts_as_a_confirmation() {
+        assert_eq!(confirmations_of(5, 5), 1);
+        assert_eq!(confirmations_of(5, 7), 3);
+        // the node's view of the chain lags behind the transaction lookup
+        assert_eq!(confirmations_of(5, 4), 1);
+    }
+}
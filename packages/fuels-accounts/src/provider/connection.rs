@@ -0,0 +1,291 @@
+use std::{
+    fmt::Debug,
+    net::{IpAddr, Ipv6Addr, SocketAddr},
+};
+
+use fuels_core::types::errors::{error, Result};
+use itertools::Itertools;
+
+/// Resolves the host name of a node url into the socket addresses the node can be reached at.
+///
+/// Implement this trait to bypass the system resolver, e.g. to pin the node to a known address
+/// in environments where DNS is flaky.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::net::SocketAddr;
+/// use fuels_accounts::provider::Resolver;
+/// use fuels_core::types::errors::Result;
+///
+/// #[derive(Debug)]
+/// struct PinnedResolver(SocketAddr);
+///
+/// #[async_trait::async_trait]
+/// impl Resolver for PinnedResolver {
+///     async fn resolve(&self, _host: &str, _port: u16) -> Result<Vec<SocketAddr>> {
+///         Ok(vec![self.0])
+///     }
+/// }
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait Resolver: Debug + Send + Sync {
+    async fn resolve(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>>;
+}
+
+/// Resolves host names using the operating system's resolver.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Resolver for SystemResolver {
+    async fn resolve(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+        let addrs = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| error!(Provider, "could not resolve `{host}`: {e}"))?;
+
+        Ok(addrs.collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NodeUrl<'a> {
+    scheme: &'a str,
+    host: &'a str,
+    port: Option<u16>,
+    path: &'a str,
+}
+
+impl<'a> NodeUrl<'a> {
+    fn parse(url: &'a str) -> Result<Self> {
+        let (scheme, rest) = url.split_once("://").unwrap_or(("http", url));
+        let (authority, path) = rest
+            .find('/')
+            .map(|idx| rest.split_at(idx))
+            .unwrap_or((rest, ""));
+
+        let (host, port) = if authority.parse::<Ipv6Addr>().is_ok() {
+            (authority, None)
+        } else if let Some(bracketed) = authority.strip_prefix('[') {
+            let (host, remainder) = bracketed
+                .split_once(']')
+                .ok_or_else(|| error!(Provider, "invalid IPv6 host in url `{url}`"))?;
+            (host, remainder.strip_prefix(':'))
+        } else {
+            match authority.rsplit_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            }
+        };
+
+        if host.is_empty() {
+            return Err(error!(Provider, "missing host in url `{url}`"));
+        }
+
+        let port = port
+            .map(|port| {
+                port.parse::<u16>()
+                    .map_err(|_| error!(Provider, "invalid port `{port}` in url `{url}`"))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            scheme,
+            host,
+            port,
+            path,
+        })
+    }
+
+    fn default_port(&self) -> u16 {
+        match self.scheme {
+            "https" => 443,
+            _ => 80,
+        }
+    }
+
+    fn render(&self, host: &str) -> String {
+        let host = if host.parse::<Ipv6Addr>().is_ok() {
+            format!("[{host}]")
+        } else {
+            host.to_string()
+        };
+        let port = self.port.map(|port| format!(":{port}")).unwrap_or_default();
+
+        format!("{}://{host}{port}{}", self.scheme, self.path)
+    }
+}
+
+/// Brings `url` into a form the underlying client accepts. Most notably this wraps bare IPv6
+/// literals (e.g. `::1`) in brackets.
+pub(crate) fn normalize_url(url: &str) -> Result<String> {
+    let node_url = NodeUrl::parse(url)?;
+
+    Ok(node_url.render(node_url.host))
+}
+
+/// Replaces the host of `url` with each of the addresses returned by `resolver`, in the order
+/// they were returned. Urls whose host is already an IP literal are returned as is.
+pub(crate) async fn resolve_url(url: &str, resolver: &impl Resolver) -> Result<Vec<String>> {
+    let node_url = NodeUrl::parse(url)?;
+
+    if node_url.host.parse::<IpAddr>().is_ok() {
+        return Ok(vec![node_url.render(node_url.host)]);
+    }
+
+    if node_url.scheme != "http" {
+        return Err(error!(
+            Provider,
+            "custom host resolution is only supported for `http` urls, got `{url}`. \
+            TLS needs the original host name to verify the node's certificate"
+        ));
+    }
+
+    let port = node_url.port.unwrap_or_else(|| node_url.default_port());
+    let urls = resolver
+        .resolve(node_url.host, port)
+        .await?
+        .into_iter()
+        .map(|addr| format!("{}://{addr}{}", node_url.scheme, node_url.path))
+        .unique()
+        .collect::<Vec<_>>();
+
+    if urls.is_empty() {
+        return Err(error!(
+            Provider,
+            "resolver returned no address for `{}`", node_url.host
+        ));
+    }
+
+    Ok(urls)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct FixedResolver(Vec<SocketAddr>);
+
+    #[async_trait::async_trait]
+    impl Resolver for FixedResolver {
+        async fn resolve(&self, _host: &str, _port: u16) -> Result<Vec<SocketAddr>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn parses_urls_with_and_without_scheme() -> Result<()> {
+        assert_eq!(
+            NodeUrl::parse("https://testnet.fuel.network/v1/graphql")?,
+            NodeUrl {
+                scheme: "https",
+                host: "testnet.fuel.network",
+                port: None,
+                path: "/v1/graphql",
+            }
+        );
+        assert_eq!(
+            NodeUrl::parse("localhost:4000")?,
+            NodeUrl {
+                scheme: "http",
+                host: "localhost",
+                port: Some(4000),
+                path: "",
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_ipv6_literals() -> Result<()> {
+        assert_eq!(NodeUrl::parse("http://[::1]:4000")?.host, "::1");
+        assert_eq!(NodeUrl::parse("http://[::1]:4000")?.port, Some(4000));
+        assert_eq!(NodeUrl::parse("::1")?.host, "::1");
+        assert_eq!(NodeUrl::parse("::1")?.port, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bare_ipv6_literals_are_bracketed() -> Result<()> {
+        assert_eq!(normalize_url("::1")?, "http://[::1]");
+        assert_eq!(normalize_url("[::1]:4000")?, "http://[::1]:4000");
+        assert_eq!(normalize_url("127.0.0.1:4000")?, "http://127.0.0.1:4000");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_port_is_rejected() {
+        let err = NodeUrl::parse("localhost:port").expect_err("should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "provider: invalid port `port` in url `localhost:port`"
+        );
+    }
+
+    #[tokio::test]
+    async fn host_is_replaced_by_every_resolved_address() -> Result<()> {
+        // given
+        let first = SocketAddr::new(Ipv4Addr::new(10, 0, 0, 7).into(), 4000);
+        let second = SocketAddr::new(Ipv6Addr::LOCALHOST.into(), 4000);
+        let resolver = FixedResolver(vec![first, second, first]);
+
+        // when
+        let urls = resolve_url("http://fuel-node:4000/v1/graphql", &resolver).await?;
+
+        // then
+        assert_eq!(
+            urls,
+            vec![
+                "http://10.0.0.7:4000/v1/graphql",
+                "http://[::1]:4000/v1/graphql"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resolving_to_no_address_fails() {
+        let resolver = FixedResolver(vec![]);
+
+        let err = resolve_url("http://fuel-node:4000", &resolver)
+            .await
+            .expect_err("should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "provider: resolver returned no address for `fuel-node`"
+        );
+    }
+
+    #[tokio::test]
+    async fn ip_literals_skip_resolution() -> Result<()> {
+        let resolver = FixedResolver(vec![]);
+
+        let urls = resolve_url("http://[::1]:4000", &resolver).await?;
+
+        assert_eq!(urls, vec!["http://[::1]:4000"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resolution_of_https_urls_is_rejected() {
+        let resolver = FixedResolver(vec![]);
+
+        let err = resolve_url("https://fuel-node", &resolver)
+            .await
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("custom host resolution is only supported for `http` urls"));
+    }
+}
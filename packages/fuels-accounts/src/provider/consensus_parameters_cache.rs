@@ -0,0 +1,138 @@
+use std::sync::Mutex;
+
+use fuel_tx::ConsensusParameters;
+use tokio::time::{Duration, Instant};
+
+/// The consensus parameters of the chain, as last fetched by a `Provider` and shared by its
+/// clones.
+#[derive(Debug)]
+pub(crate) struct ConsensusParametersCache {
+    // `None` keeps the parameters until they are invalidated
+    ttl: Option<Duration>,
+    entry: Mutex<Option<Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    consensus_parameters: ConsensusParameters,
+    // `None` once invalidated
+    fetched_at: Option<Instant>,
+}
+
+impl ConsensusParametersCache {
+    pub fn new(consensus_parameters: ConsensusParameters, ttl: Option<Duration>) -> Self {
+        let cache = Self::empty(ttl);
+        cache.set(consensus_parameters);
+
+        cache
+    }
+
+    /// A cache to fill on the first fetch, e.g. for a lazily connected `Provider`.
+    pub fn empty(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// The cached parameters, unless they expired or were invalidated.
+    pub fn fresh(&self) -> Option<ConsensusParameters> {
+        let entry = self.lock();
+        let entry = entry.as_ref()?;
+
+        let expired = match (entry.fetched_at, self.ttl) {
+            (None, _) => true,
+            (Some(fetched_at), Some(ttl)) => fetched_at.elapsed() >= ttl,
+            (Some(_), None) => false,
+        };
+        (!expired).then(|| entry.consensus_parameters.clone())
+    }
+
+    /// The last fetched parameters, even if they expired or were invalidated.
+    pub fn last(&self) -> Option<ConsensusParameters> {
+        self.lock()
+            .as_ref()
+            .map(|entry| entry.consensus_parameters.clone())
+    }
+
+    pub fn set(&self, consensus_parameters: ConsensusParameters) {
+        *self.lock() = Some(Entry {
+            consensus_parameters,
+            fetched_at: Some(Instant::now()),
+        });
+    }
+
+    /// Makes the parameters stale, so that they are refetched on the next fetch.
+    pub fn invalidate(&self) {
+        if let Some(entry) = self.lock().as_mut() {
+            entry.fetched_at = None;
+        }
+    }
+
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// A cache with the same parameters, expiring after `ttl` instead.
+    pub fn with_ttl(&self, ttl: Option<Duration>) -> Self {
+        let entry = self.lock().as_ref().map(|entry| Entry {
+            consensus_parameters: entry.consensus_parameters.clone(),
+            fetched_at: entry.fetched_at,
+        });
+
+        Self {
+            ttl,
+            entry: Mutex::new(entry),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<Entry>> {
+        self.entry
+            .lock()
+            .expect("consensus parameters cache lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn parameters_expire_after_the_ttl() {
+        tokio::time::pause();
+        let cache = ConsensusParametersCache::new(
+            ConsensusParameters::default(),
+            Some(Duration::from_secs(10)),
+        );
+
+        assert!(cache.fresh().is_some());
+
+        tokio::time::advance(Duration::from_secs(11)).await;
+        assert!(cache.fresh().is_none());
+        assert!(cache.last().is_some());
+
+        cache.set(ConsensusParameters::default());
+        assert!(cache.fresh().is_some());
+
+        cache.invalidate();
+        assert!(cache.fresh().is_none());
+    }
+
+    #[test]
+    fn parameters_without_ttl_stay_until_invalidated() {
+        let cache = ConsensusParametersCache::new(ConsensusParameters::default(), None);
+
+        assert!(cache.fresh().is_some());
+        cache.invalidate();
+        assert!(cache.fresh().is_none());
+        assert!(cache.last().is_some());
+    }
+
+    #[test]
+    fn an_empty_cache_has_no_parameters() {
+        let cache = ConsensusParametersCache::empty(None);
+
+        assert!(cache.fresh().is_none());
+        assert!(cache.last().is_none());
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+use fuel_tx::{Receipt, TxId};
+use fuels_core::types::{errors::Result, transaction::Transaction, FeeCalculator};
+
+use crate::provider::{Provider, TransactionCost};
+
+/// How the estimated cost of a transaction compares to what it cost once executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EstimationReport {
+    pub tx_id: TxId,
+    /// The estimate made before sending the transaction.
+    pub estimated: TransactionCost,
+    /// The gas used by the executed script.
+    pub gas_used: u64,
+    /// The gas price of the latest block when the report was made. The block including the
+    /// transaction may have charged a different one.
+    pub latest_gas_price: u64,
+    /// The fee for `gas_used` at `latest_gas_price`.
+    pub fee: u64,
+}
+
+impl EstimationReport {
+    /// By how much the gas was overestimated, relative to the gas used. Negative when it was
+    /// underestimated.
+    pub fn gas_drift(&self) -> f64 {
+        drift(self.estimated.gas_used, self.gas_used)
+    }
+
+    /// By how much the fee was overestimated, relative to the fee for the gas used at the latest
+    /// gas price. Negative when it was underestimated.
+    pub fn fee_drift(&self) -> f64 {
+        drift(self.estimated.total_fee, self.fee)
+    }
+}
+
+impl Display for EstimationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "transaction `{}`: estimated {} gas and a fee of {} at gas price {}, used {} gas and \
+             would pay {} at the latest gas price {} (gas drift {:+.1}%, fee drift {:+.1}%)",
+            self.tx_id,
+            self.estimated.gas_used,
+            self.estimated.total_fee,
+            self.estimated.gas_price,
+            self.gas_used,
+            self.fee,
+            self.latest_gas_price,
+            self.gas_drift() * 100.0,
+            self.fee_drift() * 100.0,
+        )
+    }
+}
+
+fn drift(estimated: u64, actual: u64) -> f64 {
+    if actual == 0 {
+        return if estimated == 0 { 0.0 } else { f64::INFINITY };
+    }
+
+    (estimated as f64 - actual as f64) / actual as f64
+}
+
+impl Provider {
+    /// Compares the `estimated` cost of `tx` with what it used according to its `receipts`. Meant
+    /// for monitoring the quality of the estimates, see `CallHandler::with_estimation_report` for
+    /// contract and script calls.
+    pub async fn estimation_report<T: Transaction>(
+        &self,
+        tx: &T,
+        estimated: TransactionCost,
+        receipts: &[Receipt],
+    ) -> Result<EstimationReport> {
+        let gas_used = receipts
+            .iter()
+            .rev()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let consensus_parameters = self.fetch_consensus_parameters().await?;
+        let latest_gas_price = self.latest_gas_price().await?.gas_price;
+        let fee = FeeCalculator::new(&consensus_parameters).fee_for_gas_used(
+            tx,
+            gas_used,
+            latest_gas_price,
+        );
+
+        Ok(EstimationReport {
+            tx_id: tx.id(consensus_parameters.chain_id()),
+            estimated,
+            gas_used,
+            latest_gas_price,
+            fee,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_is_relative_to_the_actual_usage() {
+        assert_eq!(drift(110, 100), 0.1);
+        assert_eq!(drift(90, 100), -0.1);
+        assert_eq!(drift(0, 0), 0.0);
+        assert_eq!(drift(5, 0), f64::INFINITY);
+    }
+}
@@ -0,0 +1,107 @@
+use std::{error::Error, io, time::Duration};
+
+/// Controls how a [`Provider`](crate::provider::Provider) connected to several nodes through
+/// [`Provider::connect_with_failover`](crate::provider::Provider::connect_with_failover) moves
+/// between them.
+///
+/// Requests go to the active node, retried as configured by the provider's `RetryConfig`. If they
+/// still fail with a connection error or a timeout, the request is sent to the next node, which
+/// becomes the active one for all later requests of the provider and its clones. Errors returned
+/// by a reachable node, e.g. a rejected transaction, are not retried elsewhere.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use fuels_accounts::provider::FailoverPolicy;
+///
+/// let policy = FailoverPolicy::default().with_request_timeout(Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FailoverPolicy {
+    request_timeout: Option<Duration>,
+}
+
+impl FailoverPolicy {
+    /// Gives up on requests not answered within `request_timeout` and treats them as connection
+    /// errors. Mind that it also bounds the time spent waiting for a transaction to be committed.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = Some(request_timeout);
+        self
+    }
+
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+}
+
+/// Whether `err` means the node could not be reached, as opposed to the node answering with an
+/// error.
+///
+/// The client reports transport failures as `io::ErrorKind::Other` wrapping the error of the
+/// HTTP client, which in turn carries the `io::Error` of the socket that failed. Errors answered
+/// by the node are plain messages without a source, so they are never taken for transport
+/// failures, whatever they say.
+pub(crate) fn is_connection_error(err: &io::Error) -> bool {
+    let mut transport_errors =
+        std::iter::successors(err.get_ref().map(|e| e as &(dyn Error + 'static)), |&e| {
+            e.source()
+        });
+
+    is_connection_error_kind(err.kind()) || transport_errors.any(|e| e.is::<io::Error>())
+}
+
+fn is_connection_error_kind(kind: io::ErrorKind) -> bool {
+    use io::ErrorKind::*;
+
+    matches!(
+        kind,
+        ConnectionRefused
+            | ConnectionReset
+            | ConnectionAborted
+            | NotConnected
+            | BrokenPipe
+            | TimedOut
+            | UnexpectedEof
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::*;
+
+    // stands in for the error of the HTTP client, which keeps the socket error as its source
+    #[derive(Debug)]
+    struct SendError(io::Error);
+
+    impl fmt::Display for SendError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "error sending request")
+        }
+    }
+
+    impl Error for SendError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn transport_failures_are_connection_errors() {
+        // given
+        let refused = io::Error::from(io::ErrorKind::ConnectionRefused);
+        let wrapped = io::Error::other(SendError(io::Error::other("failed to lookup address")));
+        let graphql = io::Error::other(
+            "Response errors; Validity(InsufficientFeeAmount { expected: 10, provided: 1 })",
+        );
+        let mentions_connection = io::Error::other("Response errors; connection limit reached");
+
+        // then
+        assert!(is_connection_error(&refused));
+        assert!(is_connection_error(&wrapped));
+        assert!(!is_connection_error(&graphql));
+        assert!(!is_connection_error(&mentions_connection));
+    }
+}
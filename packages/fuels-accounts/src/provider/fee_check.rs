@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use fuels_core::types::{
+    errors::{error, Result},
+    transaction::Transaction,
+};
+
+use crate::provider::Provider;
+
+/// What [`Provider::check_fee`] compares a transaction's fee against. By default only the max fee
+/// is checked against the latest gas price.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeePreflight {
+    // percentile of the tips paid in the given number of most recent blocks
+    tip_floor: Option<(u8, u32)>,
+}
+
+impl FeePreflight {
+    /// Also requires the tip to be at least the `percentile` (from 0 to 100) of the tips paid in
+    /// the last `blocks` blocks, which is what gets a transaction ahead when blocks are full.
+    pub fn with_tip_floor(mut self, percentile: u8, blocks: u32) -> Self {
+        self.tip_floor = Some((percentile, blocks));
+        self
+    }
+}
+
+/// Why the fee of a transaction may keep it from being included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeWarning {
+    /// The max fee doesn't cover the fee at the latest gas price, so the node rejects the
+    /// transaction.
+    MaxFeeBelowFloor {
+        max_fee: u64,
+        required: u64,
+        gas_price: u64,
+    },
+    /// The tip is lower than what recent transactions paid, so the transaction may wait in the
+    /// txpool while the network is congested.
+    TipBelowFloor {
+        tip: u64,
+        floor: u64,
+        percentile: u8,
+    },
+}
+
+impl Display for FeeWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MaxFeeBelowFloor {
+                max_fee,
+                required,
+                gas_price,
+            } => write!(
+                f,
+                "fee below current floor: max fee {max_fee} doesn't cover the {required} \
+                 required at gas price {gas_price}"
+            ),
+            Self::TipBelowFloor {
+                tip,
+                floor,
+                percentile,
+            } => write!(
+                f,
+                "tip below current floor: {tip} is less than the {floor} paid by the \
+                 {percentile}th percentile of recent transactions"
+            ),
+        }
+    }
+}
+
+/// The result of [`Provider::check_fee`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeCheck {
+    /// The gas price of the latest block.
+    pub gas_price: u64,
+    /// The max fee the transaction needs at `gas_price`, its tip included.
+    pub required_max_fee: u64,
+    pub warnings: Vec<FeeWarning>,
+}
+
+impl FeeCheck {
+    fn new(
+        max_fee: u64,
+        required_max_fee: u64,
+        gas_price: u64,
+        tip: u64,
+        tip_floor: Option<(u8, u64)>,
+    ) -> Self {
+        let mut warnings = vec![];
+
+        if max_fee < required_max_fee {
+            warnings.push(FeeWarning::MaxFeeBelowFloor {
+                max_fee,
+                required: required_max_fee,
+                gas_price,
+            });
+        }
+
+        if let Some((percentile, floor)) = tip_floor {
+            if tip < floor {
+                warnings.push(FeeWarning::TipBelowFloor {
+                    tip,
+                    floor,
+                    percentile,
+                });
+            }
+        }
+
+        Self {
+            gas_price,
+            required_max_fee,
+            warnings,
+        }
+    }
+
+    pub fn is_sufficient(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+impl Provider {
+    /// Compares the max fee and tip of `tx` with the current network conditions, as described by
+    /// `preflight`, without sending it. Enable [`ProviderConfig::with_fee_preflight`] to have
+    /// every transaction checked before it is sent.
+    ///
+    /// [`ProviderConfig::with_fee_preflight`]: crate::provider::ProviderConfig::with_fee_preflight
+    pub async fn check_fee<T: Transaction>(
+        &self,
+        tx: &T,
+        preflight: &FeePreflight,
+    ) -> Result<FeeCheck> {
+        let gas_price = self.latest_gas_price().await?.gas_price;
+        let required_max_fee = tx
+            .fee_checked_from_tx(&self.fetch_consensus_parameters().await?, gas_price)
+            .ok_or_else(|| error!(Provider, "fee calculation overflowed"))?
+            .max_fee();
+
+        let tip_floor = match preflight.tip_floor {
+            Some((percentile, blocks)) => {
+                Some((percentile, self.estimate_tip(percentile, blocks).await?))
+            }
+            None => None,
+        };
+
+        Ok(FeeCheck::new(
+            tx.max_fee().unwrap_or_default(),
+            required_max_fee,
+            gas_price,
+            tx.tip().unwrap_or_default(),
+            tip_floor,
+        ))
+    }
+
+    pub(crate) async fn run_fee_preflight<T: Transaction>(&self, tx: &T) -> Result<()> {
+        let Some(preflight) = &self.fee_preflight else {
+            return Ok(());
+        };
+
+        let fee_check = self.check_fee(tx, preflight).await?;
+        if !fee_check.is_sufficient() {
+            let warnings = fee_check
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(error!(Provider, "{warnings}"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fees_are_compared_to_the_floors() {
+        let sufficient = FeeCheck::new(100, 100, 1, 5, Some((50, 5)));
+        assert!(sufficient.is_sufficient());
+
+        let insufficient = FeeCheck::new(99, 100, 1, 4, Some((50, 5)));
+        assert_eq!(
+            insufficient.warnings,
+            vec![
+                FeeWarning::MaxFeeBelowFloor {
+                    max_fee: 99,
+                    required: 100,
+                    gas_price: 1
+                },
+                FeeWarning::TipBelowFloor {
+                    tip: 4,
+                    floor: 5,
+                    percentile: 50
+                }
+            ]
+        );
+
+        let without_tip_floor = FeeCheck::new(100, 100, 1, 0, None);
+        assert!(without_tip_floor.is_sufficient());
+    }
+}
@@ -0,0 +1,42 @@
+use std::{fmt::Debug, io, time::Duration};
+
+/// Intercepts the requests a [`Provider`](crate::provider::Provider) sends to the node, e.g. to
+/// record metrics, log, or throttle them. Added with
+/// [`Provider::with_middleware`](crate::provider::Provider::with_middleware).
+///
+/// Both hooks run for every attempt, so a request retried by the `RetryConfig` or sent to another
+/// node on failover is seen once per attempt. Hooks are called synchronously on the task sending
+/// the request and should return quickly.
+pub trait Middleware: Debug + Send + Sync {
+    /// Called before the request is sent. Returning an error aborts the attempt with that error,
+    /// which is retried if the `RetryConfig` retries it.
+    fn on_request(&self, _request: &RequestContext) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Called once the node answered or the attempt failed.
+    fn on_response(&self, _request: &RequestContext, _response: &ResponseContext) {}
+}
+
+/// A request about to be sent to the node.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestContext<'a> {
+    /// The GraphQL operation, named after the `FuelClient` method sending it, e.g. `submit`.
+    pub operation: &'static str,
+    /// The url of the node the request is sent to.
+    pub url: &'a str,
+}
+
+/// The outcome of a request sent to the node.
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseContext<'a> {
+    pub elapsed: Duration,
+    /// `None` if the node answered successfully.
+    pub error: Option<&'a io::Error>,
+}
+
+impl ResponseContext<'_> {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
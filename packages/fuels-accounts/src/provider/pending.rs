@@ -0,0 +1,136 @@
+use fuel_tx::{Output, TxId};
+use fuel_types::ChainId;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    transaction::{extract_owner_or_recipient, Transaction},
+};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+// how many pending transactions a slow subscriber can fall behind before missing some
+pub(crate) const PENDING_TXS_CAPACITY: usize = 1024;
+
+/// A transaction that was submitted but is not known to be included yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub tx_id: TxId,
+    /// The owners of the coins and the recipients of the messages spent by the transaction.
+    pub senders: Vec<Bech32Address>,
+    /// The addresses receiving coins from the transaction, change included.
+    pub recipients: Vec<Bech32Address>,
+}
+
+impl PendingTransaction {
+    pub(crate) fn new<T: Transaction>(tx: &T, chain_id: ChainId) -> Self {
+        let mut senders = vec![];
+        for sender in tx.inputs().iter().filter_map(extract_owner_or_recipient) {
+            if !senders.contains(&sender) {
+                senders.push(sender);
+            }
+        }
+
+        let mut recipients = vec![];
+        for output in tx.outputs() {
+            let (Output::Coin { to, .. } | Output::Change { to, .. } | Output::Variable { to, .. }) =
+                output
+            else {
+                continue;
+            };
+            let recipient = Bech32Address::from(*to);
+            if !recipients.contains(&recipient) {
+                recipients.push(recipient);
+            }
+        }
+
+        Self {
+            tx_id: tx.id(chain_id),
+            senders,
+            recipients,
+        }
+    }
+
+    /// Whether `address` sends or receives assets in this transaction.
+    pub fn involves(&self, address: &Bech32Address) -> bool {
+        self.senders.contains(address) || self.recipients.contains(address)
+    }
+}
+
+/// Yields the transactions submitted through a [`Provider`](crate::provider::Provider), see
+/// [`Provider::subscribe_submitted_txs`](crate::provider::Provider::subscribe_submitted_txs).
+#[derive(Debug)]
+pub struct PendingTxSubscription {
+    receiver: broadcast::Receiver<PendingTransaction>,
+}
+
+impl PendingTxSubscription {
+    pub(crate) fn new(receiver: broadcast::Receiver<PendingTransaction>) -> Self {
+        Self { receiver }
+    }
+
+    /// Waits for the next submitted transaction. Transactions submitted while the subscriber
+    /// lagged too far behind are skipped. Returns `None` once the provider and all of its clones
+    /// are dropped.
+    pub async fn next(&mut self) -> Option<PendingTransaction> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(pending_tx) => return Some(pending_tx),
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{policies::Policies, Input, Transaction as FuelTransaction, UtxoId};
+    use fuel_types::{Address, AssetId};
+    use fuels_core::types::transaction::ScriptTransaction;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pending_transactions_list_who_is_involved() {
+        // given
+        let sender = Address::from([1; 32]);
+        let recipient = Address::from([2; 32]);
+        let input = Input::coin_signed(
+            UtxoId::default(),
+            sender,
+            10,
+            AssetId::zeroed(),
+            Default::default(),
+            0,
+        );
+        let outputs = vec![
+            Output::coin(recipient, 6, AssetId::zeroed()),
+            Output::change(sender, 0, AssetId::zeroed()),
+        ];
+        let tx: ScriptTransaction = FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![input],
+            outputs,
+            vec![],
+        )
+        .into();
+        let (sender_channel, receiver) = broadcast::channel(PENDING_TXS_CAPACITY);
+        let mut subscription = PendingTxSubscription::new(receiver);
+
+        // when
+        sender_channel
+            .send(PendingTransaction::new(&tx, ChainId::default()))
+            .expect("has a subscriber");
+        drop(sender_channel);
+
+        // then
+        let pending_tx = subscription.next().await.expect("was sent");
+        assert_eq!(pending_tx.tx_id, tx.id(ChainId::default()));
+        assert_eq!(pending_tx.senders, vec![sender.into()]);
+        assert_eq!(pending_tx.recipients, vec![recipient.into(), sender.into()]);
+        assert!(pending_tx.involves(&Bech32Address::from(recipient)));
+        assert!(!pending_tx.involves(&Bech32Address::from(Address::from([3; 32]))));
+        assert_eq!(subscription.next().await, None);
+    }
+}
@@ -1,6 +1,10 @@
-use std::{fmt::Debug, future::Future, num::NonZeroU32, time::Duration};
+use std::{fmt::Debug, future::Future, io, num::NonZeroU32, time::Duration};
 
 use fuels_core::types::errors::{error, Result};
+use rand::Rng;
+use tokio::time::Instant;
+
+use super::failover;
 
 /// A set of strategies to control retry intervals between attempts.
 ///
@@ -42,13 +46,41 @@ impl Default for Backoff {
 impl Backoff {
     pub fn wait_duration(&self, attempt: u32) -> Duration {
         match self {
-            Backoff::Linear(base_duration) => *base_duration * (attempt + 1),
-            Backoff::Exponential(base_duration) => *base_duration * 2u32.pow(attempt),
+            Backoff::Linear(base_duration) => base_duration.saturating_mul(attempt + 1),
+            Backoff::Exponential(base_duration) => {
+                base_duration.saturating_mul(2u32.saturating_pow(attempt))
+            }
             Backoff::Fixed(interval) => *interval,
         }
     }
 }
 
+/// Which failed requests a [`RetryConfig`] retries.
+///
+/// The node reports all errors the same way, so by default every failed request is retried, even
+/// one rejected for good, e.g. a transaction failing to verify.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RetryOn {
+    /// Retries every failed request.
+    #[default]
+    AnyError,
+    /// Retries requests that didn't reach the node or timed out, but not errors returned by the
+    /// node.
+    ConnectionErrors,
+    /// Retries the requests for which the function returns `true`.
+    Custom(fn(&io::Error) -> bool),
+}
+
+impl RetryOn {
+    pub fn should_retry(&self, err: &io::Error) -> bool {
+        match self {
+            Self::AnyError => true,
+            Self::ConnectionErrors => failover::is_connection_error(err),
+            Self::Custom(should_retry) => should_retry(err),
+        }
+    }
+}
+
 /// Configuration for controlling retry behavior.
 ///
 /// The `RetryConfig` struct encapsulates the configuration parameters for controlling the retry behavior
@@ -59,24 +91,34 @@ impl Backoff {
 ///
 /// - `max_attempts`: The maximum number of attempts before giving up.
 /// - `interval`: The chosen interval strategy from the `Backoff` enum.
+/// - `jitter`: The fraction of each interval that is randomly shaved off, see [`RetryConfig::with_jitter`].
+/// - `max_elapsed_time`: The time after which no new attempts are started, regardless of `max_attempts`.
+/// - `retry_on`: Which failed requests to the node are retried, see [`RetryOn`].
 ///
 /// # Examples
 ///
 /// ```rust
 /// use std::num::NonZeroUsize;
 /// use std::time::Duration;
-/// use fuels_accounts::provider::{Backoff, RetryConfig};
+/// use fuels_accounts::provider::{Backoff, RetryConfig, RetryOn};
 ///
 /// let max_attempts = 5;
 /// let interval_strategy = Backoff::Exponential(Duration::from_secs(1));
 ///
-/// let retry_config = RetryConfig::new(max_attempts, interval_strategy).unwrap();
+/// let retry_config = RetryConfig::new(max_attempts, interval_strategy)
+///     .unwrap()
+///     .with_jitter(0.2)
+///     .with_max_elapsed_time(Duration::from_secs(30))
+///     .with_retry_on(RetryOn::ConnectionErrors);
 /// ```
 // ANCHOR: retry_config
 #[derive(Clone, Debug)]
 pub struct RetryConfig {
     max_attempts: NonZeroU32,
     interval: Backoff,
+    jitter: f64,
+    max_elapsed_time: Option<Duration>,
+    retry_on: RetryOn,
 }
 // ANCHOR_END: retry_config
 
@@ -88,8 +130,49 @@ impl RetryConfig {
         Ok(RetryConfig {
             max_attempts,
             interval,
+            jitter: 0.0,
+            max_elapsed_time: None,
+            retry_on: RetryOn::default(),
         })
     }
+
+    /// Shortens every interval by a random amount of up to `jitter` times its length, so that
+    /// clients that started retrying at the same time don't keep hitting the node in lockstep.
+    /// `jitter` is clamped between `0.0` (no jitter) and `1.0` (anywhere between no wait and the
+    /// full interval).
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Stops retrying once the next attempt would start later than `max_elapsed_time` after the
+    /// first one.
+    pub fn with_max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
+    /// Restricts which failed requests to the node are retried. Only applies to the requests
+    /// sent by the `Provider`, not to the `should_retry` condition given to [`retry`].
+    pub fn with_retry_on(mut self, retry_on: RetryOn) -> Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    pub fn retry_on(&self) -> RetryOn {
+        self.retry_on
+    }
+
+    /// The time to wait after the given (zero based) failed attempt, jitter included.
+    pub fn wait_duration(&self, attempt: u32) -> Duration {
+        let interval = self.interval.wait_duration(attempt);
+        if self.jitter == 0.0 {
+            return interval;
+        }
+
+        let shortening = rand::thread_rng().gen_range(0.0..=self.jitter);
+        interval.mul_f64(1.0 - shortening)
+    }
 }
 
 impl Default for RetryConfig {
@@ -97,6 +180,9 @@ impl Default for RetryConfig {
         Self {
             max_attempts: NonZeroU32::new(1).expect("should not fail"),
             interval: Default::default(),
+            jitter: 0.0,
+            max_elapsed_time: None,
+            retry_on: RetryOn::default(),
         }
     }
 }
@@ -122,7 +208,10 @@ impl Default for RetryConfig {
 /// Returns `Err(Error)` if the maximum number of attempts is reached and the action
 /// still fails. If a retryable error occurs during the attempts, the error will
 /// be returned if the `should_retry` condition allows further retries.
-pub(crate) async fn retry<Fut, T, ShouldRetry>(
+///
+/// The same utility drives the SDK's polling loops, e.g. waiting for a transaction to be
+/// included, so that custom retry logic can share their backoff behaviour.
+pub async fn retry<Fut, T, ShouldRetry>(
     mut action: impl FnMut() -> Fut,
     retry_config: &RetryConfig,
     should_retry: ShouldRetry,
@@ -131,25 +220,48 @@ where
     Fut: Future<Output = T>,
     ShouldRetry: Fn(&T) -> bool,
 {
-    let mut last_result = None;
+    let start = Instant::now();
+    let mut attempt = 0;
 
-    for attempt in 0..retry_config.max_attempts.into() {
+    loop {
         let result = action().await;
 
-        if should_retry(&result) {
-            last_result = Some(result)
-        } else {
+        if !should_retry(&result) || attempt + 1 >= retry_config.max_attempts.get() {
             return result;
         }
 
-        tokio::time::sleep(retry_config.interval.wait_duration(attempt)).await;
-    }
+        let wait = retry_config.wait_duration(attempt);
+        let out_of_time = retry_config
+            .max_elapsed_time
+            .is_some_and(|max_elapsed_time| start.elapsed() + wait > max_elapsed_time);
+        if out_of_time {
+            return result;
+        }
 
-    last_result.expect("should not happen")
+        tokio::time::sleep(wait).await;
+        attempt += 1;
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io;
+
+    use crate::provider::RetryOn;
+
+    #[test]
+    fn retry_on_selects_the_errors_to_retry() {
+        let unreachable = io::Error::from(io::ErrorKind::ConnectionRefused);
+        let rejected = io::Error::other("Response errors; InputNotFound");
+
+        assert!(RetryOn::AnyError.should_retry(&rejected));
+        assert!(RetryOn::ConnectionErrors.should_retry(&unreachable));
+        assert!(!RetryOn::ConnectionErrors.should_retry(&rejected));
+        assert!(
+            RetryOn::Custom(|e| e.to_string().contains("InputNotFound")).should_retry(&rejected)
+        );
+    }
+
     mod retry_until {
         use std::time::{Duration, Instant};
 
@@ -331,5 +443,42 @@ mod tests {
 
             Ok(())
         }
+
+        #[tokio::test]
+        async fn retry_stops_after_max_elapsed_time() -> Result<()> {
+            // given
+            let number_of_attempts = Mutex::new(0usize);
+
+            let will_always_fail = || async {
+                *number_of_attempts.lock().await += 1;
+                Result::<()>::Err(error!(Other, "error"))
+            };
+
+            let retry_options = RetryConfig::new(100, Backoff::Fixed(Duration::from_millis(40)))?
+                .with_max_elapsed_time(Duration::from_millis(100));
+
+            // when
+            let _ = retry_util::retry(will_always_fail, &retry_options, |_res: &_| true).await;
+
+            // then
+            assert_eq!(*number_of_attempts.lock().await, 3);
+
+            Ok(())
+        }
+
+        #[test]
+        fn jitter_only_shortens_the_interval() -> Result<()> {
+            let interval = Duration::from_millis(100);
+            let retry_options = RetryConfig::new(3, Backoff::Fixed(interval))?.with_jitter(0.5);
+
+            for attempt in 0..100 {
+                let wait = retry_options.wait_duration(attempt);
+
+                assert!(wait <= interval);
+                assert!(wait >= interval / 2);
+            }
+
+            Ok(())
+        }
     }
 }
@@ -1,21 +1,39 @@
-use std::{future::Future, io};
+use std::{
+    future::Future,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
 use fuel_core_client::client::{
     pagination::{PaginatedResult, PaginationRequest},
     types::{
         gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::MessageStatus,
         primitives::{BlockId, TransactionId},
-        Balance, Block, ChainInfo, Coin, CoinType, ContractBalance, Message, MessageProof,
-        NodeInfo, TransactionResponse, TransactionStatus,
+        Balance, Block, ChainInfo, Coin, CoinType, Contract, ContractBalance, Message,
+        MessageProof, NodeInfo, TransactionResponse, TransactionStatus,
     },
     FuelClient,
 };
 use fuel_core_types::services::executor::TransactionExecutionStatus;
 use fuel_tx::{Transaction, TxId, UtxoId};
 use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
-use fuels_core::types::errors::{error, Error, Result};
+use fuels_core::{
+    metrics,
+    types::errors::{error, Error, Result},
+};
+use futures::Stream;
 
-use super::supported_versions::{self, VersionCompatibility};
+use super::{
+    connection::{self, Resolver},
+    failover::{self, FailoverPolicy},
+    middleware::{Middleware, RequestContext, ResponseContext},
+    supported_versions::{self, VersionCompatibility},
+};
 use crate::provider::{retry_util, RetryConfig};
 
 #[derive(Debug, thiserror::Error)]
@@ -33,27 +51,138 @@ impl From<RequestError> for Error {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct RetryableClient {
+struct Endpoint {
     client: FuelClient,
     url: String,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RetryableClient {
+    // never empty
+    endpoints: Vec<Endpoint>,
+    // index of the endpoint requests are sent to first, shared with the clones
+    active: Arc<AtomicUsize>,
     retry_config: RetryConfig,
+    failover_policy: FailoverPolicy,
+    // applied in order to requests and in reverse order to responses
+    middlewares: Arc<Vec<Arc<dyn Middleware>>>,
     prepend_warning: Option<String>,
 }
 
 impl RetryableClient {
     pub(crate) async fn connect(url: impl AsRef<str>, retry_config: RetryConfig) -> Result<Self> {
         let url = url.as_ref().to_string();
-        let client = FuelClient::new(&url).map_err(|e| error!(Provider, "{e}"))?;
+        let client = FuelClient::new(connection::normalize_url(&url)?)
+            .map_err(|e| error!(Provider, "{e}"))?;
+
+        Self::new(client, url, retry_config).await
+    }
+
+    /// Connects to the first reachable node out of `urls`, falling back to the others as
+    /// described by `failover_policy`.
+    pub(crate) async fn connect_with_failover(
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+        retry_config: RetryConfig,
+        failover_policy: FailoverPolicy,
+    ) -> Result<Self> {
+        let endpoints = urls
+            .into_iter()
+            .map(|url| {
+                let url = url.as_ref().to_string();
+                let client = FuelClient::new(connection::normalize_url(&url)?)
+                    .map_err(|e| error!(Provider, "{e}"))?;
+
+                Ok(Endpoint { client, url })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::connect_to_endpoints(endpoints, retry_config, failover_policy).await
+    }
+
+    /// Connects to the first reachable address the host of `url` resolves to through `resolver`,
+    /// falling back to the other addresses as described by `failover_policy`.
+    pub(crate) async fn connect_with_resolver(
+        url: impl AsRef<str>,
+        resolver: &impl Resolver,
+        retry_config: RetryConfig,
+        failover_policy: FailoverPolicy,
+    ) -> Result<Self> {
+        let endpoints = connection::resolve_url(url.as_ref(), resolver)
+            .await?
+            .into_iter()
+            .map(|url| {
+                let client = FuelClient::new(&url).map_err(|e| error!(Provider, "{e}"))?;
+
+                Ok(Endpoint { client, url })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::connect_to_endpoints(endpoints, retry_config, failover_policy).await
+    }
+
+    async fn connect_to_endpoints(
+        endpoints: Vec<Endpoint>,
+        retry_config: RetryConfig,
+        failover_policy: FailoverPolicy,
+    ) -> Result<Self> {
+        let mut errors = vec![];
+        for (index, endpoint) in endpoints.iter().enumerate() {
+            match endpoint.client.node_info().await {
+                Ok(node_info) => {
+                    return Ok(Self {
+                        prepend_warning: Self::version_compatibility_warning(&node_info)?,
+                        endpoints,
+                        active: Arc::new(AtomicUsize::new(index)),
+                        retry_config,
+                        failover_policy,
+                        middlewares: Default::default(),
+                    });
+                }
+                Err(e) => errors.push(format!("`{}`: {e}", endpoint.url)),
+            }
+        }
+
+        if errors.is_empty() {
+            return Err(error!(Provider, "no node urls were given"));
+        }
+
+        Err(error!(
+            Provider,
+            "none of the nodes could be reached: {}",
+            errors.join(", ")
+        ))
+    }
+
+    /// Like [`Self::connect`], without checking that the node is reachable and supported.
+    pub(crate) fn connect_lazy(url: impl AsRef<str>, retry_config: RetryConfig) -> Result<Self> {
+        let url = url.as_ref().to_string();
+        let client = FuelClient::new(connection::normalize_url(&url)?)
+            .map_err(|e| error!(Provider, "{e}"))?;
 
+        Ok(Self::with_endpoint(client, url, retry_config, None))
+    }
+
+    async fn new(client: FuelClient, url: String, retry_config: RetryConfig) -> Result<Self> {
         let node_info = client.node_info().await?;
         let warning = Self::version_compatibility_warning(&node_info)?;
 
-        Ok(Self {
-            client,
+        Ok(Self::with_endpoint(client, url, retry_config, warning))
+    }
+
+    fn with_endpoint(
+        client: FuelClient,
+        url: String,
+        retry_config: RetryConfig,
+        prepend_warning: Option<String>,
+    ) -> Self {
+        Self {
+            endpoints: vec![Endpoint { client, url }],
+            active: Default::default(),
             retry_config,
-            url,
-            prepend_warning: warning,
-        })
+            failover_policy: Default::default(),
+            middlewares: Default::default(),
+            prepend_warning,
+        }
     }
 
     fn version_compatibility_warning(node_info: &NodeInfo) -> Result<Option<String>> {
@@ -80,80 +209,212 @@ impl RetryableClient {
         Ok(msg)
     }
 
+    /// The url of the node requests are currently sent to.
     pub(crate) fn url(&self) -> &str {
-        &self.url
+        &self.endpoints[self.active.load(Ordering::Relaxed)].url
     }
 
     pub(crate) fn set_retry_config(&mut self, retry_config: RetryConfig) {
         self.retry_config = retry_config;
     }
 
-    async fn wrap<T, Fut>(&self, action: impl Fn() -> Fut) -> RequestResult<T>
+    pub(crate) fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        Arc::make_mut(&mut self.middlewares).push(middleware);
+    }
+
+    async fn wrap<'a, T, Fut>(
+        &'a self,
+        operation: &'static str,
+        action: impl Fn(&'a FuelClient) -> Fut,
+    ) -> RequestResult<T>
     where
         Fut: Future<Output = io::Result<T>>,
     {
-        retry_util::retry(action, &self.retry_config, |result| result.is_err())
-            .await
-            .map_err(|e| {
-                let msg = if let Some(warning) = &self.prepend_warning {
-                    format!("{warning}. {e}")
-                } else {
-                    e.to_string()
-                };
-                RequestError::IO(msg)
-            })
+        let first = self.active.load(Ordering::Relaxed);
+        let mut last_error = None;
+
+        for offset in 0..self.endpoints.len() {
+            let index = (first + offset) % self.endpoints.len();
+            let endpoint = &self.endpoints[index];
+
+            let result = retry_util::retry(
+                || self.attempt(operation, endpoint, &action),
+                &self.retry_config,
+                |result| {
+                    result
+                        .as_ref()
+                        .is_err_and(|e| self.retry_config.retry_on().should_retry(e))
+                },
+            )
+            .await;
+
+            match result {
+                Err(e) if failover::is_connection_error(&e) => last_error = Some(e),
+                result => {
+                    if index != first {
+                        self.active.store(index, Ordering::Relaxed);
+                    }
+
+                    return result.map_err(|e| self.request_error(e));
+                }
+            }
+        }
+
+        Err(self.request_error(last_error.expect("there is at least one endpoint")))
+    }
+
+    async fn attempt<'a, T, Fut>(
+        &self,
+        operation: &'static str,
+        endpoint: &'a Endpoint,
+        action: &impl Fn(&'a FuelClient) -> Fut,
+    ) -> io::Result<T>
+    where
+        Fut: Future<Output = io::Result<T>>,
+    {
+        let context = RequestContext {
+            operation,
+            url: &endpoint.url,
+        };
+        for middleware in self.middlewares.iter() {
+            middleware.on_request(&context)?;
+        }
+
+        let started = Instant::now();
+        let request = action(&endpoint.client);
+        let result = match self.failover_policy.request_timeout() {
+            Some(timeout) => tokio::time::timeout(timeout, request)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("request to `{}` timed out after {timeout:?}", endpoint.url),
+                    ))
+                }),
+            None => request.await,
+        };
+
+        let elapsed = started.elapsed();
+        metrics::record_histogram(
+            metrics::REQUEST_DURATION,
+            &[("operation", operation)],
+            elapsed.as_secs_f64(),
+        );
+        metrics::increment_counter(
+            metrics::REQUESTS,
+            &[("operation", operation), metrics::outcome(&result)],
+            1,
+        );
+
+        let response = ResponseContext {
+            elapsed,
+            error: result.as_ref().err(),
+        };
+        for middleware in self.middlewares.iter().rev() {
+            middleware.on_response(&context, &response);
+        }
+
+        result
+    }
+
+    async fn measured<T>(
+        counter: &'static str,
+        duration_histogram: &'static str,
+        request: impl Future<Output = RequestResult<T>>,
+    ) -> RequestResult<T> {
+        let started = Instant::now();
+        let result = request.await;
+
+        metrics::record_histogram(duration_histogram, &[], started.elapsed().as_secs_f64());
+        metrics::increment_counter(counter, &[metrics::outcome(&result)], 1);
+
+        result
+    }
+
+    fn request_error(&self, e: io::Error) -> RequestError {
+        let msg = if let Some(warning) = &self.prepend_warning {
+            format!("{warning}. {e}")
+        } else {
+            e.to_string()
+        };
+
+        RequestError::IO(msg)
     }
 
     // DELEGATION START
     pub async fn health(&self) -> RequestResult<bool> {
-        self.wrap(|| self.client.health()).await
+        self.wrap("health", |client| client.health()).await
     }
 
     pub async fn transaction(&self, id: &TxId) -> RequestResult<Option<TransactionResponse>> {
-        self.wrap(|| self.client.transaction(id)).await
+        self.wrap("transaction", |client| client.transaction(id))
+            .await
     }
 
     pub(crate) async fn chain_info(&self) -> RequestResult<ChainInfo> {
-        self.wrap(|| self.client.chain_info()).await
+        self.wrap("chain_info", |client| client.chain_info()).await
     }
 
     pub async fn await_transaction_commit(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.await_transaction_commit(id)).await
+        self.wrap("await_transaction_commit", |client| {
+            client.await_transaction_commit(id)
+        })
+        .await
+    }
+
+    pub async fn subscribe_transaction_status<'a>(
+        &'a self,
+        id: &'a TxId,
+    ) -> RequestResult<impl Stream<Item = io::Result<TransactionStatus>> + 'a> {
+        self.wrap("subscribe_transaction_status", |client| {
+            client.subscribe_transaction_status(id)
+        })
+        .await
     }
 
     pub async fn submit_and_await_commit(
         &self,
         tx: &Transaction,
     ) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.submit_and_await_commit(tx)).await
+        let request = self.wrap("submit_and_await_commit", |client| {
+            client.submit_and_await_commit(tx)
+        });
+
+        Self::measured(metrics::SUBMISSIONS, metrics::SUBMISSION_DURATION, request).await
     }
 
     pub async fn submit(&self, tx: &Transaction) -> RequestResult<TransactionId> {
-        self.wrap(|| self.client.submit(tx)).await
+        let request = self.wrap("submit", |client| client.submit(tx));
+
+        Self::measured(metrics::SUBMISSIONS, metrics::SUBMISSION_DURATION, request).await
     }
 
     pub async fn transaction_status(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.transaction_status(id)).await
+        self.wrap("transaction_status", |client| client.transaction_status(id))
+            .await
     }
 
     pub async fn node_info(&self) -> RequestResult<NodeInfo> {
-        self.wrap(|| self.client.node_info()).await
+        self.wrap("node_info", |client| client.node_info()).await
     }
 
     pub async fn latest_gas_price(&self) -> RequestResult<LatestGasPrice> {
-        self.wrap(|| self.client.latest_gas_price()).await
+        self.wrap("latest_gas_price", |client| client.latest_gas_price())
+            .await
     }
 
     pub async fn estimate_gas_price(&self, block_horizon: u32) -> RequestResult<EstimateGasPrice> {
-        self.wrap(|| self.client.estimate_gas_price(block_horizon))
-            .await
-            .map(Into::into)
+        self.wrap("estimate_gas_price", |client| {
+            client.estimate_gas_price(block_horizon)
+        })
+        .await
+        .map(Into::into)
     }
 
     pub async fn estimate_predicates(&self, tx: &Transaction) -> RequestResult<Transaction> {
-        self.wrap(|| async {
+        self.wrap("estimate_predicates", |client| async move {
             let mut new_tx = tx.clone();
-            self.client.estimate_predicates(&mut new_tx).await?;
+            client.estimate_predicates(&mut new_tx).await?;
             Ok(new_tx)
         })
         .await
@@ -163,7 +424,9 @@ impl RetryableClient {
         &self,
         tx: &[Transaction],
     ) -> RequestResult<Vec<TransactionExecutionStatus>> {
-        self.wrap(|| self.client.dry_run(tx)).await
+        let request = self.wrap("dry_run", |client| client.dry_run(tx));
+
+        Self::measured(metrics::DRY_RUNS, metrics::DRY_RUN_DURATION, request).await
     }
 
     pub async fn dry_run_opt(
@@ -172,8 +435,11 @@ impl RetryableClient {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
     ) -> RequestResult<Vec<TransactionExecutionStatus>> {
-        self.wrap(|| self.client.dry_run_opt(tx, utxo_validation, gas_price))
-            .await
+        let request = self.wrap("dry_run_opt", |client| {
+            client.dry_run_opt(tx, utxo_validation, gas_price)
+        });
+
+        Self::measured(metrics::DRY_RUNS, metrics::DRY_RUN_DURATION, request).await
     }
 
     pub async fn coins(
@@ -182,8 +448,14 @@ impl RetryableClient {
         asset_id: Option<&AssetId>,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Coin, String>> {
-        self.wrap(move || self.client.coins(owner, asset_id, request.clone()))
-            .await
+        self.wrap("coins", move |client| {
+            client.coins(owner, asset_id, request.clone())
+        })
+        .await
+    }
+
+    pub async fn coin(&self, id: &UtxoId) -> RequestResult<Option<Coin>> {
+        self.wrap("coin", |client| client.coin(id)).await
     }
 
     pub async fn coins_to_spend(
@@ -192,15 +464,19 @@ impl RetryableClient {
         spend_query: Vec<(AssetId, u64, Option<u32>)>,
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
     ) -> RequestResult<Vec<Vec<CoinType>>> {
-        self.wrap(move || {
-            self.client
-                .coins_to_spend(owner, spend_query.clone(), excluded_ids.clone())
+        self.wrap("coins_to_spend", move |client| {
+            client.coins_to_spend(owner, spend_query.clone(), excluded_ids.clone())
         })
         .await
     }
 
     pub async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> RequestResult<u64> {
-        self.wrap(|| self.client.balance(owner, asset_id)).await
+        self.wrap("balance", |client| client.balance(owner, asset_id))
+            .await
+    }
+
+    pub async fn contract(&self, id: &ContractId) -> RequestResult<Option<Contract>> {
+        self.wrap("contract", |client| client.contract(id)).await
     }
 
     pub async fn contract_balance(
@@ -208,7 +484,10 @@ impl RetryableClient {
         id: &ContractId,
         asset: Option<&AssetId>,
     ) -> RequestResult<u64> {
-        self.wrap(|| self.client.contract_balance(id, asset)).await
+        self.wrap("contract_balance", |client| {
+            client.contract_balance(id, asset)
+        })
+        .await
     }
 
     pub async fn contract_balances(
@@ -216,8 +495,10 @@ impl RetryableClient {
         contract: &ContractId,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<ContractBalance, String>> {
-        self.wrap(|| self.client.contract_balances(contract, request.clone()))
-            .await
+        self.wrap("contract_balances", |client| {
+            client.contract_balances(contract, request.clone())
+        })
+        .await
     }
 
     pub async fn balances(
@@ -225,7 +506,7 @@ impl RetryableClient {
         owner: &Address,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Balance, String>> {
-        self.wrap(|| self.client.balances(owner, request.clone()))
+        self.wrap("balances", |client| client.balances(owner, request.clone()))
             .await
     }
 
@@ -233,8 +514,10 @@ impl RetryableClient {
         &self,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<TransactionResponse, String>> {
-        self.wrap(|| self.client.transactions(request.clone()))
-            .await
+        self.wrap("transactions", |client| {
+            client.transactions(request.clone())
+        })
+        .await
     }
 
     pub async fn transactions_by_owner(
@@ -242,8 +525,10 @@ impl RetryableClient {
         owner: &Address,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<TransactionResponse, String>> {
-        self.wrap(|| self.client.transactions_by_owner(owner, request.clone()))
-            .await
+        self.wrap("transactions_by_owner", |client| {
+            client.transactions_by_owner(owner, request.clone())
+        })
+        .await
     }
 
     pub async fn produce_blocks(
@@ -251,26 +536,27 @@ impl RetryableClient {
         blocks_to_produce: u32,
         start_timestamp: Option<u64>,
     ) -> RequestResult<BlockHeight> {
-        self.wrap(|| {
-            self.client
-                .produce_blocks(blocks_to_produce, start_timestamp)
+        self.wrap("produce_blocks", |client| {
+            client.produce_blocks(blocks_to_produce, start_timestamp)
         })
         .await
     }
 
     pub async fn block(&self, id: &BlockId) -> RequestResult<Option<Block>> {
-        self.wrap(|| self.client.block(id)).await
+        self.wrap("block", |client| client.block(id)).await
     }
 
     pub async fn block_by_height(&self, height: BlockHeight) -> RequestResult<Option<Block>> {
-        self.wrap(|| self.client.block_by_height(height)).await
+        self.wrap("block_by_height", |client| client.block_by_height(height))
+            .await
     }
 
     pub async fn blocks(
         &self,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Block, String>> {
-        self.wrap(|| self.client.blocks(request.clone())).await
+        self.wrap("blocks", |client| client.blocks(request.clone()))
+            .await
     }
 
     pub async fn messages(
@@ -278,7 +564,12 @@ impl RetryableClient {
         owner: Option<&Address>,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Message, String>> {
-        self.wrap(|| self.client.messages(owner, request.clone()))
+        self.wrap("messages", |client| client.messages(owner, request.clone()))
+            .await
+    }
+
+    pub async fn message_status(&self, nonce: &Nonce) -> RequestResult<MessageStatus> {
+        self.wrap("message_status", |client| client.message_status(nonce))
             .await
     }
 
@@ -290,9 +581,8 @@ impl RetryableClient {
         commit_block_id: Option<&BlockId>,
         commit_block_height: Option<BlockHeight>,
     ) -> RequestResult<Option<MessageProof>> {
-        self.wrap(|| {
-            self.client
-                .message_proof(transaction_id, nonce, commit_block_id, commit_block_height)
+        self.wrap("message_proof", |client| {
+            client.message_proof(transaction_id, nonce, commit_block_id, commit_block_height)
         })
         .await
     }
@@ -0,0 +1,14 @@
+use fuels_core::types::{errors::Result, FeeCalculator, FeeComponent};
+
+use crate::provider::Provider;
+
+impl Provider {
+    /// The gas charged for writing `new_slots` slots a contract doesn't have yet, and its fee at
+    /// the latest gas price. See [`FeeCalculator::storage_growth`].
+    pub async fn storage_growth_cost(&self, new_slots: u64) -> Result<FeeComponent> {
+        let consensus_parameters = self.fetch_consensus_parameters().await?;
+        let gas_price = self.latest_gas_price().await?.gas_price;
+
+        Ok(FeeCalculator::new(&consensus_parameters).storage_growth(new_slots, gas_price))
+    }
+}
@@ -0,0 +1,344 @@
+use std::{fmt, str::FromStr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Error, Result},
+    },
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{accounts_utils::impl_account_for_signer, provider::Provider};
+
+/// How long the user has to approve a pairing or signing request in their wallet app.
+pub const DEFAULT_APPROVAL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Requests sent to the external wallet app. Serialized as JSON objects tagged with a `type` field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteSignerRequest {
+    /// Asks the wallet to connect to the dapp. The wallet should show `pairing_code` so the user
+    /// can compare it with the one shown by the dapp.
+    Pair {
+        session: String,
+        dapp_name: String,
+        pairing_code: String,
+    },
+    /// Asks the wallet to sign `message`, hex encoded.
+    Sign { session: String, message: String },
+}
+
+/// Responses of the external wallet app.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteSignerResponse {
+    Paired { address: String },
+    Signed { signature: String },
+    Rejected { reason: String },
+}
+
+/// Delivers requests to the external wallet app and waits for its response, e.g. over a
+/// WebSocket or by long-polling a relay server.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait RemoteSignerTransport: Send + Sync + 'static {
+    async fn request(&self, request: RemoteSignerRequest) -> Result<RemoteSignerResponse>;
+}
+
+/// A [`Signer`] that forwards signing requests to a wallet app controlled by the end user, so
+/// the private key never reaches the dapp backend.
+///
+/// Every signature is checked against the address the wallet paired with.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    transport: Arc<dyn RemoteSignerTransport>,
+    session: String,
+    address: Bech32Address,
+    approval_timeout: Duration,
+    provider: Option<Provider>,
+}
+
+impl RemoteSigner {
+    /// Starts a pairing session with the wallet app and waits for the user to approve it.
+    ///
+    /// `on_pairing_code` is called with the code the wallet app will show, before waiting for the
+    /// approval, so it can be displayed to the user.
+    pub async fn pair(
+        transport: impl RemoteSignerTransport,
+        dapp_name: impl Into<String>,
+        on_pairing_code: impl FnOnce(&str),
+    ) -> Result<Self> {
+        Self::pair_with_timeout(
+            transport,
+            dapp_name,
+            on_pairing_code,
+            DEFAULT_APPROVAL_TIMEOUT,
+        )
+        .await
+    }
+
+    pub async fn pair_with_timeout(
+        transport: impl RemoteSignerTransport,
+        dapp_name: impl Into<String>,
+        on_pairing_code: impl FnOnce(&str),
+        approval_timeout: Duration,
+    ) -> Result<Self> {
+        let mut rng = rand::thread_rng();
+        let session = rng
+            .gen::<[u8; 16]>()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        let pairing_code = format!("{:06}", rng.gen_range(0..1_000_000));
+
+        on_pairing_code(&pairing_code);
+
+        let transport = Arc::new(transport);
+        let request = RemoteSignerRequest::Pair {
+            session: session.clone(),
+            dapp_name: dapp_name.into(),
+            pairing_code,
+        };
+
+        let address = match request_with_timeout(&*transport, request, approval_timeout).await? {
+            RemoteSignerResponse::Paired { address } => Bech32Address::from_str(&address)?,
+            response => return Err(unexpected_response("pairing", response)),
+        };
+
+        Ok(Self {
+            transport,
+            session,
+            address,
+            approval_timeout,
+            provider: None,
+        })
+    }
+
+    pub fn with_approval_timeout(mut self, timeout: Duration) -> Self {
+        self.approval_timeout = timeout;
+        self
+    }
+
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+}
+
+async fn request_with_timeout(
+    transport: &dyn RemoteSignerTransport,
+    request: RemoteSignerRequest,
+    timeout: Duration,
+) -> Result<RemoteSignerResponse> {
+    tokio::time::timeout(timeout, transport.request(request))
+        .await
+        .map_err(|_| error!(Other, "remote signer did not respond within {timeout:?}"))?
+}
+
+fn unexpected_response(operation: &str, response: RemoteSignerResponse) -> Error {
+    match response {
+        RemoteSignerResponse::Rejected { reason } => {
+            error!(Other, "remote signer rejected {operation}: {reason}")
+        }
+        response => error!(
+            Other,
+            "unexpected remote signer response to {operation}: {response:?}"
+        ),
+    }
+}
+
+impl fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("session", &self.session)
+            .field("address", &self.address)
+            .field("approval_timeout", &self.approval_timeout)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for RemoteSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let request = RemoteSignerRequest::Sign {
+            session: self.session.clone(),
+            message: message.to_string(),
+        };
+
+        let signature =
+            match request_with_timeout(&*self.transport, request, self.approval_timeout).await? {
+                RemoteSignerResponse::Signed { signature } => Signature::from_str(&signature)
+                    .map_err(|e| error!(Other, "invalid signature from remote signer: {e}"))?,
+                response => return Err(unexpected_response("signing", response)),
+            };
+
+        let signer = signature.recover(&message)?;
+        if signer.hash() != self.address.hash() {
+            return Err(error!(
+                Other,
+                "remote signer signed with a key not belonging to `{}`", self.address
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+impl_account_for_signer!(RemoteSigner);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use fuel_types::Bytes32;
+
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    struct WalletApp {
+        wallet: WalletUnlocked,
+        paired_address: Bech32Address,
+        approve_signing: bool,
+        requests: Arc<Mutex<Vec<RemoteSignerRequest>>>,
+    }
+
+    impl WalletApp {
+        fn new(approve_signing: bool) -> Self {
+            let wallet = WalletUnlocked::new_random(None);
+            Self {
+                paired_address: wallet.address().clone(),
+                wallet,
+                approve_signing,
+                requests: Default::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RemoteSignerTransport for WalletApp {
+        async fn request(&self, request: RemoteSignerRequest) -> Result<RemoteSignerResponse> {
+            self.requests.lock().unwrap().push(request.clone());
+
+            let response = match request {
+                RemoteSignerRequest::Pair { .. } => RemoteSignerResponse::Paired {
+                    address: self.paired_address.to_string(),
+                },
+                RemoteSignerRequest::Sign { .. } if !self.approve_signing => {
+                    RemoteSignerResponse::Rejected {
+                        reason: "user declined".to_string(),
+                    }
+                }
+                RemoteSignerRequest::Sign { message, .. } => {
+                    let message = Bytes32::from_str(&message).map_err(|e| error!(Other, "{e}"))?;
+                    let message = Message::from_bytes(*message);
+                    let signature = self.wallet.sign(message).await?;
+                    RemoteSignerResponse::Signed {
+                        signature: signature.to_string(),
+                    }
+                }
+            };
+
+            Ok(response)
+        }
+    }
+
+    #[tokio::test]
+    async fn pairs_and_signs_through_the_transport() -> Result<()> {
+        // given
+        let app = WalletApp::new(true);
+        let expected_address = app.wallet.address().clone();
+        let requests = app.requests.clone();
+
+        let mut shown_code = String::new();
+        let signer =
+            RemoteSigner::pair(app, "my dapp", |code| shown_code = code.to_string()).await?;
+
+        // when
+        let message = Message::new("Hello there!".as_bytes());
+        let signature = signer.sign(message).await?;
+
+        // then
+        assert_eq!(signer.address(), &expected_address);
+        assert_eq!(signature.recover(&message)?.hash(), expected_address.hash());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            requests[0],
+            RemoteSignerRequest::Pair {
+                session: signer.session().to_string(),
+                dapp_name: "my dapp".to_string(),
+                pairing_code: shown_code,
+            }
+        );
+        assert_eq!(
+            requests[1],
+            RemoteSignerRequest::Sign {
+                session: signer.session().to_string(),
+                message: message.to_string(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejected_signing_is_an_error() -> Result<()> {
+        let signer = RemoteSigner::pair(WalletApp::new(false), "my dapp", |_| {}).await?;
+
+        let err = signer
+            .sign(Message::new("Hello there!".as_bytes()))
+            .await
+            .expect_err("should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "remote signer rejected signing: user declined"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signatures_must_match_the_paired_address() -> Result<()> {
+        // given
+        let mut app = WalletApp::new(true);
+        app.paired_address = WalletUnlocked::new_random(None).address().clone();
+        let signer = RemoteSigner::pair(app, "my dapp", |_| {}).await?;
+
+        // when
+        let err = signer
+            .sign(Message::new("Hello there!".as_bytes()))
+            .await
+            .expect_err("should fail");
+
+        // then
+        assert!(err
+            .to_string()
+            .contains("remote signer signed with a key not belonging to"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn messages_are_tagged_json() -> Result<()> {
+        let request = RemoteSignerRequest::Sign {
+            session: "abc".to_string(),
+            message: "00".to_string(),
+        };
+
+        let json = serde_json::to_string(&request)?;
+
+        assert_eq!(json, r#"{"type":"sign","session":"abc","message":"00"}"#);
+        assert_eq!(serde_json::from_str::<RemoteSignerRequest>(&json)?, request);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use fuel_tx::Input as FuelInput;
+use fuels_core::types::{coin_type_id::CoinTypeId, input::Input};
+use tokio::time::{Duration, Instant};
+
+/// Tracks the coins and messages an account selected for transactions that are still being built
+/// or waiting to be committed, so that transactions built concurrently don't spend them again.
+///
+/// Set on a [`WalletUnlocked`](crate::wallet::WalletUnlocked) with `with_resource_manager`, every
+/// resource the wallet selects is reserved and excluded from later selections, except when building
+/// a transaction that is only dry run. Reservations are released once the transaction sent by the
+/// [`Account`](crate::Account) helpers or a contract call is committed or has failed, and expire
+/// after `ttl` for transactions sent by other means. Clones share the reservations.
+#[derive(Debug, Clone)]
+pub struct ResourceManager {
+    ttl: Duration,
+    reserved: Arc<Mutex<HashMap<CoinTypeId, Instant>>>,
+}
+
+impl Default for ResourceManager {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(60))
+    }
+}
+
+impl ResourceManager {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            reserved: Default::default(),
+        }
+    }
+
+    pub fn reserve(&self, ids: impl IntoIterator<Item = CoinTypeId>) {
+        let expires_at = Instant::now() + self.ttl;
+        self.lock()
+            .extend(ids.into_iter().map(|id| (id, expires_at)));
+    }
+
+    pub fn release(&self, ids: impl IntoIterator<Item = CoinTypeId>) {
+        let mut reserved = self.lock();
+        for id in ids {
+            reserved.remove(&id);
+        }
+    }
+
+    /// Releases the resources spent by `inputs`.
+    pub fn release_inputs(&self, inputs: &[Input]) {
+        self.release(resource_ids(inputs));
+    }
+
+    /// The resources currently reserved.
+    pub fn reserved(&self) -> Vec<CoinTypeId> {
+        let now = Instant::now();
+        let mut reserved = self.lock();
+        reserved.retain(|_, expires_at| *expires_at > now);
+
+        reserved.keys().cloned().collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CoinTypeId, Instant>> {
+        self.reserved
+            .lock()
+            .expect("resource manager lock poisoned")
+    }
+}
+
+/// The coins and messages spent by the inputs of a transaction builder.
+pub fn resource_ids(inputs: &[Input]) -> Vec<CoinTypeId> {
+    inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. } => {
+                Some(resource.id())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The coins and messages spent by the inputs of a built transaction.
+pub fn spent_resource_ids(inputs: &[FuelInput]) -> Vec<CoinTypeId> {
+    inputs
+        .iter()
+        .filter_map(|input| {
+            if input.is_coin() {
+                input.utxo_id().copied().map(CoinTypeId::UtxoId)
+            } else {
+                input.nonce().copied().map(CoinTypeId::Nonce)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::UtxoId;
+    use fuel_types::{Bytes32, Nonce};
+
+    use super::*;
+
+    fn ids() -> (CoinTypeId, CoinTypeId) {
+        (
+            CoinTypeId::UtxoId(UtxoId::new(Bytes32::from([1u8; 32]), 0)),
+            CoinTypeId::Nonce(Nonce::new([2u8; 32])),
+        )
+    }
+
+    #[test]
+    fn reservations_are_shared_and_released() {
+        let manager = ResourceManager::default();
+        let clone = manager.clone();
+        let (coin, message) = ids();
+
+        manager.reserve([coin.clone(), message.clone()]);
+        clone.release([message]);
+
+        assert_eq!(manager.reserved(), vec![coin]);
+    }
+
+    #[test]
+    fn only_coins_and_messages_are_spent_resources() {
+        let (coin, message) = ids();
+        let CoinTypeId::UtxoId(utxo_id) = coin.clone() else {
+            unreachable!()
+        };
+        let CoinTypeId::Nonce(nonce) = message.clone() else {
+            unreachable!()
+        };
+        let inputs = [
+            FuelInput::coin_signed(
+                utxo_id,
+                Default::default(),
+                1,
+                Default::default(),
+                Default::default(),
+                0,
+            ),
+            FuelInput::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+            FuelInput::message_coin_signed(Default::default(), Default::default(), 1, nonce, 0),
+        ];
+
+        let ids = spent_resource_ids(&inputs);
+
+        assert_eq!(ids, vec![coin, message]);
+    }
+
+    #[tokio::test]
+    async fn reservations_expire() {
+        tokio::time::pause();
+        let manager = ResourceManager::new(Duration::from_secs(10));
+        let (coin, message) = ids();
+
+        manager.reserve([coin]);
+        tokio::time::advance(Duration::from_secs(12)).await;
+        manager.reserve([message.clone()]);
+
+        assert_eq!(manager.reserved(), vec![message]);
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(feature = "kms")]
+mod kms;
+
+#[cfg(feature = "kms")]
+pub use kms::*;
@@ -0,0 +1,189 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use aws_sdk_kms::{
+    config::Region,
+    primitives::Blob,
+    types::{KeySpec, MessageType, SigningAlgorithmSpec},
+    Client,
+};
+use fuel_crypto::{Hasher, Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+    },
+};
+use k256::{elliptic_curve::sec1::ToEncodedPoint, pkcs8::DecodePublicKey};
+
+use crate::{accounts_utils::impl_account_for_signer, provider::Provider};
+
+/// A [`Signer`] whose key is an AWS KMS asymmetric `ECC_SECG_P256K1` key. The private key never
+/// leaves KMS, every signature is a `Sign` request against it.
+#[derive(Clone)]
+pub struct KmsSigner {
+    client: Client,
+    key_id: String,
+    address: Bech32Address,
+    provider: Option<Provider>,
+}
+
+impl KmsSigner {
+    /// Uses the credentials found in the environment to reach KMS in `region`.
+    pub async fn new(key_arn: impl Into<String>, region: impl Into<String>) -> Result<Self> {
+        let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(Region::new(region.into()))
+            .load()
+            .await;
+
+        Self::with_client(Client::new(&config), key_arn).await
+    }
+
+    /// Uses an already configured client, e.g. one pointing at a local KMS mock.
+    pub async fn with_client(client: Client, key_id: impl Into<String>) -> Result<Self> {
+        let key_id = key_id.into();
+
+        let response = client
+            .get_public_key()
+            .key_id(&key_id)
+            .send()
+            .await
+            .map_err(|e| error!(Other, "failed to fetch public key of `{key_id}`: {e}"))?;
+
+        if response.key_spec() != Some(&KeySpec::EccSecgP256K1) {
+            return Err(error!(
+                Other,
+                "KMS key `{key_id}` is not a secp256k1 key: {:?}",
+                response.key_spec()
+            ));
+        }
+        let der = response
+            .public_key()
+            .ok_or_else(|| error!(Other, "KMS returned no public key for `{key_id}`"))?;
+
+        Ok(Self {
+            client,
+            address: address_from_der(der.as_ref())?,
+            key_id,
+            provider: None,
+        })
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+}
+
+fn address_from_der(der: &[u8]) -> Result<Bech32Address> {
+    let public_key = k256::PublicKey::from_public_key_der(der)
+        .map_err(|e| error!(Other, "invalid public key from KMS: {e}"))?;
+
+    // the uncompressed encoding without its leading 0x04 tag is Fuel's public key
+    let encoded = public_key.to_encoded_point(false);
+
+    Ok(Bech32Address::new(
+        FUEL_BECH32_HRP,
+        Hasher::hash(&encoded.as_bytes()[1..]),
+    ))
+}
+
+/// KMS returns DER encoded signatures without a recovery id. Fuel expects the compact form with
+/// a low `s` and the recovery id stored in the top bit of `s`, so both candidates are tried.
+fn to_fuel_signature(der: &[u8], message: &Message, address: &Bech32Address) -> Result<Signature> {
+    let signature = k256::ecdsa::Signature::from_der(der)
+        .map_err(|e| error!(Other, "invalid signature from KMS: {e}"))?;
+    let signature = signature.normalize_s().unwrap_or(signature);
+    let bytes: [u8; Signature::LEN] = signature.to_bytes().into();
+
+    [0, 0x80]
+        .into_iter()
+        .map(|recovery_bit| {
+            let mut bytes = bytes;
+            bytes[32] |= recovery_bit;
+            Signature::from_bytes(bytes)
+        })
+        .find(|candidate| {
+            candidate
+                .recover(message)
+                .is_ok_and(|public_key| public_key.hash() == address.hash())
+        })
+        .ok_or_else(|| error!(Other, "KMS signature does not match `{address}`"))
+}
+
+impl fmt::Debug for KmsSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KmsSigner")
+            .field("key_id", &self.key_id)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for KmsSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let response = self
+            .client
+            .sign()
+            .key_id(&self.key_id)
+            .message(Blob::new(message.as_ref().to_vec()))
+            .message_type(MessageType::Digest)
+            .signing_algorithm(SigningAlgorithmSpec::EcdsaSha256)
+            .send()
+            .await
+            .map_err(|e| error!(Other, "KMS failed to sign with `{}`: {e}", self.key_id))?;
+
+        let der = response
+            .signature()
+            .ok_or_else(|| error!(Other, "KMS returned no signature"))?;
+
+        to_fuel_signature(der.as_ref(), &message, &self.address)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+impl_account_for_signer!(KmsSigner);
+
+#[cfg(test)]
+mod tests {
+    use k256::{
+        ecdsa::{signature::hazmat::PrehashSigner, SigningKey},
+        pkcs8::EncodePublicKey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn der_signatures_are_converted_to_recoverable_ones() -> Result<()> {
+        // given
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let public_key_der = k256::PublicKey::from(signing_key.verifying_key())
+            .to_public_key_der()
+            .expect("is encodable");
+        let address = address_from_der(public_key_der.as_bytes())?;
+        let other_address = Bech32Address::new(FUEL_BECH32_HRP, [0; 32]);
+
+        // enough messages for both recovery ids to come up
+        for nonce in 0..16u8 {
+            let message = Message::new([nonce]);
+            let signature: k256::ecdsa::Signature = signing_key
+                .sign_prehash(message.as_ref())
+                .expect("can sign");
+            let der = signature.to_der();
+
+            // when
+            let converted = to_fuel_signature(der.as_bytes(), &message, &address)?;
+
+            // then
+            assert_eq!(converted.recover(&message)?.hash(), address.hash());
+            assert!(to_fuel_signature(der.as_bytes(), &message, &other_address).is_err());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,499 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{NaiveDate, Utc};
+use fuel_tx::{Input as FuelInput, Output};
+use fuels_core::types::{
+    bech32::{Bech32Address, Bech32ContractId},
+    errors::{error, error_transaction, Error, Result},
+    input::Input,
+    Address, AssetId,
+};
+use serde::{Deserialize, Serialize};
+
+/// Client-side guardrails checked every time a [`WalletUnlocked`](crate::wallet::WalletUnlocked)
+/// adds its signature to a transaction builder. Violations make building the transaction fail.
+///
+/// A transaction counts towards the daily limits once the transfer helpers of the wallet, a
+/// contract call or a deployment are about to send it, see
+/// [`Account::record_spending`](crate::Account::record_spending). It is counted before being
+/// sent so that failing to update the ledger never hides a transaction that went out; a
+/// transaction the node then rejects still counts. Transactions sent by other means aren't
+/// counted, and transactions built at the same time are checked against the same totals.
+///
+/// Meant as a defense-in-depth layer for hot wallets, not as a replacement for on-chain checks:
+/// signing a builder directly through `add_signer` bypasses the policy.
+///
+/// The amount a transaction spends is the sum of the wallet's inputs of an asset minus the coin
+/// outputs sending that asset back to the wallet. Assets a script moves around cannot be told
+/// apart from change, so the whole input counts, making the checks conservative. Fee inputs added
+/// after signing, e.g. by `adjust_for_fee`, are not counted.
+#[derive(Debug, Clone, Default)]
+pub struct SpendingPolicy {
+    max_per_tx: HashMap<AssetId, u64>,
+    max_per_day: HashMap<AssetId, u64>,
+    allowed_addresses: Option<HashSet<Address>>,
+    allowed_contracts: Option<HashSet<Bech32ContractId>>,
+    ledger_path: Option<PathBuf>,
+}
+
+impl SpendingPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits how much of `asset_id` a single transaction may spend.
+    pub fn with_max_per_tx(mut self, asset_id: AssetId, amount: u64) -> Self {
+        self.max_per_tx.insert(asset_id, amount);
+        self
+    }
+
+    /// Limits how much of `asset_id` may be spent per UTC day.
+    pub fn with_max_per_day(mut self, asset_id: AssetId, amount: u64) -> Self {
+        self.max_per_day.insert(asset_id, amount);
+        self
+    }
+
+    /// Only allows sending coins to the allow-listed addresses. Once an address is allowed,
+    /// transactions with variable outputs are rejected since their recipients are only known
+    /// after execution.
+    pub fn with_allowed_address(mut self, address: impl Into<Address>) -> Self {
+        self.allowed_addresses
+            .get_or_insert_with(Default::default)
+            .insert(address.into());
+        self
+    }
+
+    /// Only allows interacting with the allow-listed contracts.
+    pub fn with_allowed_contract(mut self, contract_id: impl Into<Bech32ContractId>) -> Self {
+        self.allowed_contracts
+            .get_or_insert_with(Default::default)
+            .insert(contract_id.into());
+        self
+    }
+
+    /// Persists the daily totals in the file at `path` so that they survive restarts.
+    /// Without it the totals are kept in memory only.
+    pub fn with_ledger_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ledger_path = Some(path.into());
+        self
+    }
+}
+
+/// Totals spent during `day`, keyed by the hex encoded asset id.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct DailyLedger {
+    day: String,
+    spent: HashMap<String, u64>,
+}
+
+impl DailyLedger {
+    fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| error!(Other, "could not parse spending ledger {path:?}: {e}"))
+    }
+
+    fn store(&self, path: &Path) -> Result<()> {
+        let contents = serde_json::to_string(self)
+            .map_err(|e| error!(Other, "could not serialize spending ledger: {e}"))?;
+
+        Ok(fs::write(path, contents)?)
+    }
+
+    fn roll_over(&mut self, today: NaiveDate) {
+        let today = today.to_string();
+        if self.day != today {
+            self.day = today;
+            self.spent.clear();
+        }
+    }
+
+    fn spent(&self, asset_id: &AssetId) -> u64 {
+        self.spent
+            .get(&asset_id.to_string())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn record(&mut self, asset_id: &AssetId, amount: u64) {
+        let total = self.spent.entry(asset_id.to_string()).or_default();
+        *total = total.saturating_add(amount);
+    }
+}
+
+/// A [`SpendingPolicy`] together with the totals spent today.
+#[derive(Debug)]
+pub(crate) struct SpendingGuard {
+    policy: SpendingPolicy,
+    ledger: Mutex<DailyLedger>,
+}
+
+impl SpendingGuard {
+    pub(crate) fn new(policy: SpendingPolicy) -> Result<Self> {
+        let ledger = match &policy.ledger_path {
+            Some(path) => DailyLedger::load(path)?,
+            None => DailyLedger::default(),
+        };
+
+        Ok(Self {
+            policy,
+            ledger: Mutex::new(ledger),
+        })
+    }
+
+    /// Checks the transaction against the policy and the totals spent today. Its own spending
+    /// is only added to them by [`SpendingGuard::record`].
+    pub(crate) fn check(
+        &self,
+        owner: &Bech32Address,
+        base_asset_id: &AssetId,
+        inputs: &[Input],
+        outputs: &[Output],
+    ) -> Result<()> {
+        self.check_destinations(owner, inputs, outputs)?;
+
+        let resources = inputs.iter().filter_map(|input| match input {
+            Input::ResourceSigned { resource } => Some((
+                Address::from(resource.owner()),
+                resource.coin_asset_id().unwrap_or(*base_asset_id),
+                resource.amount(),
+            )),
+            _ => None,
+        });
+        let spent = spent_amounts(owner, resources, outputs);
+
+        for (asset_id, amount) in &spent {
+            if let Some(max) = self.policy.max_per_tx.get(asset_id) {
+                if amount > max {
+                    return Err(policy_violation(format!(
+                        "spending {amount} of asset `{asset_id}` exceeds the per-transaction limit of {max}"
+                    )));
+                }
+            }
+        }
+
+        let mut ledger = self.ledger.lock().expect("spending ledger lock poisoned");
+        ledger.roll_over(Utc::now().date_naive());
+
+        for (asset_id, amount) in &spent {
+            if let Some(max) = self.policy.max_per_day.get(asset_id) {
+                let spent_today = ledger.spent(asset_id);
+                if spent_today.saturating_add(*amount) > *max {
+                    return Err(policy_violation(format!(
+                        "spending {amount} of asset `{asset_id}` exceeds the daily limit of {max}, {spent_today} already spent today"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the spending of a transaction about to be sent to the daily totals.
+    pub(crate) fn record(
+        &self,
+        owner: &Bech32Address,
+        base_asset_id: &AssetId,
+        inputs: &[FuelInput],
+        outputs: &[Output],
+    ) -> Result<()> {
+        let resources = inputs.iter().filter_map(|input| {
+            Some((
+                *input.input_owner()?,
+                *input.asset_id(base_asset_id)?,
+                input.amount()?,
+            ))
+        });
+        let spent = spent_amounts(owner, resources, outputs);
+
+        let mut ledger = self.ledger.lock().expect("spending ledger lock poisoned");
+        ledger.roll_over(Utc::now().date_naive());
+
+        for (asset_id, amount) in &spent {
+            ledger.record(asset_id, *amount);
+        }
+
+        if let Some(path) = &self.policy.ledger_path {
+            ledger.store(path)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_destinations(
+        &self,
+        owner: &Bech32Address,
+        inputs: &[Input],
+        outputs: &[Output],
+    ) -> Result<()> {
+        if let Some(allowed_contracts) = &self.policy.allowed_contracts {
+            let disallowed_contract = inputs.iter().find_map(|input| match input {
+                Input::Contract { contract_id, .. }
+                    if !allowed_contracts.contains(&Bech32ContractId::from(*contract_id)) =>
+                {
+                    Some(contract_id)
+                }
+                _ => None,
+            });
+
+            if let Some(contract_id) = disallowed_contract {
+                return Err(policy_violation(format!(
+                    "contract `{contract_id}` is not allow-listed"
+                )));
+            }
+        }
+
+        if let Some(allowed_addresses) = &self.policy.allowed_addresses {
+            let owner = Address::from(owner);
+
+            for output in outputs {
+                match output {
+                    Output::Coin { to, .. } | Output::Change { to, .. }
+                        if *to != owner && !allowed_addresses.contains(to) =>
+                    {
+                        return Err(policy_violation(format!(
+                            "address `{to}` is not allow-listed"
+                        )));
+                    }
+                    Output::Variable { .. } => {
+                        return Err(policy_violation(
+                            "variable outputs are not allowed when destination addresses are allow-listed"
+                                .to_string(),
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What `owner` spends out of the `resources` of a transaction, given as their owner, asset and
+/// amount.
+fn spent_amounts(
+    owner: &Bech32Address,
+    resources: impl IntoIterator<Item = (Address, AssetId, u64)>,
+    outputs: &[Output],
+) -> HashMap<AssetId, u64> {
+    let owner = Address::from(owner);
+    let mut spent = HashMap::new();
+
+    for (resource_owner, asset_id, amount) in resources {
+        if resource_owner == owner {
+            let total = spent.entry(asset_id).or_insert(0u64);
+            *total = total.saturating_add(amount);
+        }
+    }
+
+    for output in outputs {
+        if let Output::Coin {
+            to,
+            amount,
+            asset_id,
+        } = output
+        {
+            if *to == owner {
+                if let Some(total) = spent.get_mut(asset_id) {
+                    *total = total.saturating_sub(*amount);
+                }
+            }
+        }
+    }
+
+    spent.retain(|_, amount| *amount > 0);
+
+    spent
+}
+
+fn policy_violation(reason: String) -> Error {
+    error_transaction!(Builder, "spending policy violated: {reason}")
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::UtxoId;
+    use fuels_core::types::{coin::Coin, coin_type::CoinType, ContractId};
+
+    use super::*;
+
+    fn owner() -> Bech32Address {
+        Bech32Address::new("fuel", [1; 32])
+    }
+
+    fn coin_input(amount: u64, asset_id: AssetId) -> Input {
+        Input::resource_signed(CoinType::Coin(Coin {
+            amount,
+            asset_id,
+            utxo_id: UtxoId::new([amount as u8; 32].into(), 0),
+            owner: owner(),
+            ..Default::default()
+        }))
+    }
+
+    fn submitted_coin_input(amount: u64, asset_id: AssetId) -> FuelInput {
+        FuelInput::coin_signed(
+            UtxoId::new([amount as u8; 32].into(), 0),
+            Address::from(&owner()),
+            amount,
+            asset_id,
+            Default::default(),
+            0,
+        )
+    }
+
+    fn contract_input(contract_id: ContractId) -> Input {
+        Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )
+    }
+
+    #[test]
+    fn coins_returned_to_the_owner_are_not_spent() {
+        let asset_id = AssetId::from([2; 32]);
+        let resources = [
+            (Address::from(&owner()), asset_id, 100),
+            (Address::from([9; 32]), asset_id, 70),
+        ];
+        let outputs = vec![
+            Output::coin(Address::from([9; 32]), 30, asset_id),
+            Output::coin(Address::from(&owner()), 50, asset_id),
+        ];
+
+        let spent = spent_amounts(&owner(), resources, &outputs);
+
+        assert_eq!(spent, HashMap::from([(asset_id, 50)]));
+    }
+
+    #[test]
+    fn per_tx_limit_is_enforced() -> Result<()> {
+        // given
+        let asset_id = AssetId::zeroed();
+        let guard = SpendingGuard::new(SpendingPolicy::new().with_max_per_tx(asset_id, 100))?;
+
+        // when
+        let within_limit = guard.check(&owner(), &asset_id, &[coin_input(100, asset_id)], &[]);
+        let over_limit = guard.check(&owner(), &asset_id, &[coin_input(101, asset_id)], &[]);
+
+        // then
+        within_limit?;
+        let err = over_limit.expect_err("should fail");
+        assert!(err
+            .to_string()
+            .contains("exceeds the per-transaction limit of 100"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn daily_limit_is_persisted() -> Result<()> {
+        // given
+        let asset_id = AssetId::zeroed();
+        let dir = tempfile::tempdir()?;
+        let policy = SpendingPolicy::new()
+            .with_max_per_day(asset_id, 150)
+            .with_ledger_path(dir.path().join("ledger.json"));
+
+        SpendingGuard::new(policy.clone())?.record(
+            &owner(),
+            &asset_id,
+            &[submitted_coin_input(100, asset_id)],
+            &[],
+        )?;
+
+        // when
+        let restarted_guard = SpendingGuard::new(policy)?;
+        let result = restarted_guard.check(&owner(), &asset_id, &[coin_input(60, asset_id)], &[]);
+
+        // then
+        let err = result.expect_err("should fail");
+        assert!(err.to_string().contains("100 already spent today"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_submitted_transactions_count_towards_the_daily_limit() -> Result<()> {
+        // given
+        let asset_id = AssetId::zeroed();
+        let guard = SpendingGuard::new(SpendingPolicy::new().with_max_per_day(asset_id, 150))?;
+        let inputs = [coin_input(100, asset_id)];
+
+        // when
+        let built_twice = [
+            guard.check(&owner(), &asset_id, &inputs, &[]),
+            guard.check(&owner(), &asset_id, &inputs, &[]),
+        ];
+        guard.record(
+            &owner(),
+            &asset_id,
+            &[submitted_coin_input(100, asset_id)],
+            &[],
+        )?;
+        let after_submit = guard.check(&owner(), &asset_id, &inputs, &[]);
+
+        // then
+        assert!(built_twice.iter().all(Result::is_ok));
+        let err = after_submit.expect_err("should fail");
+        assert!(err.to_string().contains("100 already spent today"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn destinations_must_be_allow_listed() -> Result<()> {
+        let allowed_address = Address::from([3; 32]);
+        let allowed_contract = ContractId::from([4; 32]);
+        let guard = SpendingGuard::new(
+            SpendingPolicy::new()
+                .with_allowed_address(allowed_address)
+                .with_allowed_contract(allowed_contract),
+        )?;
+        let base_asset_id = AssetId::zeroed();
+
+        guard.check(
+            &owner(),
+            &base_asset_id,
+            &[contract_input(allowed_contract)],
+            &[Output::coin(allowed_address, 1, base_asset_id)],
+        )?;
+
+        let unknown_contract = guard.check(
+            &owner(),
+            &base_asset_id,
+            &[contract_input(ContractId::from([5; 32]))],
+            &[],
+        );
+        assert!(unknown_contract
+            .expect_err("should fail")
+            .to_string()
+            .contains("is not allow-listed"));
+
+        let unknown_address = guard.check(
+            &owner(),
+            &base_asset_id,
+            &[],
+            &[Output::coin(Address::from([6; 32]), 1, base_asset_id)],
+        );
+        assert!(unknown_address
+            .expect_err("should fail")
+            .to_string()
+            .contains("is not allow-listed"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,455 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
+use fuel_tx::{Output, UtxoId};
+use fuel_types::{Bytes32, ChainId};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    coin::{Coin, CoinStatus},
+    coin_type_id::CoinTypeId,
+    errors::{error, Result},
+    transaction::{Transaction, TransactionType},
+    transaction_response::TransactionResponse,
+    tx_status::TxStatus,
+    Address, AssetId, CoinSelectionPolicy,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::provider::Provider;
+
+const PAGE_SIZE: i32 = 100;
+
+/// A local index of the coins owned by an address, kept up to date by replaying the owner's
+/// transactions since the last sync.
+///
+/// The first sync downloads all coins of the owner. Later syncs only fetch the transactions that
+/// happened since, so selecting inputs for wallets owning thousands of coins doesn't query the
+/// node for them every time. With [`UtxoIndex::persistent`] the index is stored as a JSON file
+/// after every sync, so restarts resume from where they left off.
+///
+/// Only coins are indexed, messages are not.
+#[derive(Debug)]
+pub struct UtxoIndex {
+    owner: Bech32Address,
+    path: Option<PathBuf>,
+    state: Mutex<IndexState>,
+}
+
+#[derive(Debug, Default)]
+struct IndexState {
+    initialized: bool,
+    cursor: Option<String>,
+    coins: HashMap<UtxoId, Coin>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredIndex {
+    owner: [u8; 32],
+    cursor: Option<String>,
+    coins: Vec<StoredCoin>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredCoin {
+    tx_id: [u8; 32],
+    output_index: u16,
+    asset_id: [u8; 32],
+    amount: u64,
+    block_created: u32,
+}
+
+impl UtxoIndex {
+    pub fn in_memory(owner: Bech32Address) -> Self {
+        Self {
+            owner,
+            path: None,
+            state: Default::default(),
+        }
+    }
+
+    /// Loads the index stored at `path`, if any, and stores it there after every sync.
+    ///
+    /// Fails if the file cannot be read or belongs to another owner.
+    pub fn persistent(owner: Bech32Address, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let state = if path.exists() {
+            load(&path, &owner)?
+        } else {
+            IndexState::default()
+        };
+
+        Ok(Self {
+            owner,
+            path: Some(path),
+            state: Mutex::new(state),
+        })
+    }
+
+    pub fn owner(&self) -> &Bech32Address {
+        &self.owner
+    }
+
+    /// Brings the index up to date with the chain.
+    pub async fn sync(&self, provider: &Provider) -> Result<()> {
+        let mut state = self.state.lock().await;
+
+        if !state.initialized {
+            // take the cursor before the snapshot: transactions landing in between are replayed,
+            // which is harmless as applying a transaction twice has no effect
+            state.cursor = self.latest_cursor(provider).await?;
+            state.coins = self.all_coins(provider).await?;
+            state.initialized = true;
+        }
+
+        let chain_id = provider.chain_id();
+        loop {
+            let page = provider
+                .get_transactions_by_owner(
+                    &self.owner,
+                    PaginationRequest {
+                        cursor: state.cursor.clone(),
+                        results: PAGE_SIZE,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await?;
+
+            for response in &page.results {
+                apply(&mut state.coins, &self.owner, response, chain_id);
+            }
+            if page.cursor.is_some() {
+                state.cursor = page.cursor;
+            }
+            if !page.has_next_page {
+                break;
+            }
+        }
+
+        if let Some(path) = &self.path {
+            store(path, &self.owner, &state)?;
+        }
+
+        Ok(())
+    }
+
+    async fn latest_cursor(&self, provider: &Provider) -> Result<Option<String>> {
+        let page = provider
+            .get_transactions_by_owner(
+                &self.owner,
+                PaginationRequest {
+                    cursor: None,
+                    results: 1,
+                    direction: PageDirection::Backward,
+                },
+            )
+            .await?;
+
+        Ok(page.cursor)
+    }
+
+    async fn all_coins(&self, provider: &Provider) -> Result<HashMap<UtxoId, Coin>> {
+        let mut coins = HashMap::new();
+        for asset_id in provider.get_balances(&self.owner).await?.keys() {
+            let asset_id = asset_id
+                .parse()
+                .map_err(|e| error!(Other, "invalid asset id `{asset_id}`: {e}"))?;
+            for coin in provider.get_coins(&self.owner, asset_id).await? {
+                coins.insert(coin.utxo_id, coin);
+            }
+        }
+
+        Ok(coins)
+    }
+
+    pub async fn coins(&self, asset_id: AssetId) -> Vec<Coin> {
+        self.state
+            .lock()
+            .await
+            .coins
+            .values()
+            .filter(|coin| coin.asset_id == asset_id)
+            .cloned()
+            .collect()
+    }
+
+    pub async fn balance(&self, asset_id: AssetId) -> u64 {
+        self.coins(asset_id)
+            .await
+            .iter()
+            .map(|coin| coin.amount)
+            .sum()
+    }
+
+    /// Selects coins of `asset_id` covering `amount` from the indexed coins, according to
+    /// `policy`. Doesn't sync the index.
+    pub async fn select_coins(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: &[CoinTypeId],
+        policy: &CoinSelectionPolicy,
+    ) -> Result<Vec<Coin>> {
+        let candidates = self
+            .coins(asset_id)
+            .await
+            .into_iter()
+            .filter(|coin| !excluded_coins.contains(&CoinTypeId::UtxoId(coin.utxo_id)))
+            .collect();
+
+        policy.select(candidates, amount)
+    }
+}
+
+/// Removes the coins `response` spent and adds the ones it created for `owner`.
+fn apply(
+    coins: &mut HashMap<UtxoId, Coin>,
+    owner: &Bech32Address,
+    response: &TransactionResponse,
+    chain_id: ChainId,
+) {
+    let reverted = match response.status {
+        TxStatus::Success { .. } => false,
+        TxStatus::Revert { .. } => true,
+        TxStatus::Submitted | TxStatus::SqueezedOut { .. } => return,
+    };
+    let Some((tx_id, inputs, outputs)) = inputs_and_outputs(&response.transaction, chain_id) else {
+        return;
+    };
+    let owner_address = Address::from(owner);
+
+    for input in inputs {
+        if let (Some(utxo_id), Some(input_owner)) = (input.utxo_id(), input.input_owner()) {
+            if *input_owner == owner_address {
+                coins.remove(utxo_id);
+            }
+        }
+    }
+
+    for (index, output) in outputs.iter().enumerate() {
+        let (to, amount, asset_id) = match *output {
+            Output::Change {
+                to,
+                amount,
+                asset_id,
+            } => (to, amount, asset_id),
+            // a reverted transaction only creates its change outputs
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } if !reverted => (to, amount, asset_id),
+            _ => continue,
+        };
+        if to != owner_address || amount == 0 {
+            continue;
+        }
+
+        let utxo_id = UtxoId::new(tx_id, index as u16);
+        coins.insert(
+            utxo_id,
+            Coin {
+                amount,
+                block_created: response.block_height.map(Into::into).unwrap_or_default(),
+                asset_id,
+                utxo_id,
+                owner: owner.clone(),
+                status: CoinStatus::Unspent,
+            },
+        );
+    }
+}
+
+fn inputs_and_outputs(
+    tx: &TransactionType,
+    chain_id: ChainId,
+) -> Option<(Bytes32, &[fuel_tx::Input], &[Output])> {
+    match tx {
+        TransactionType::Script(tx) => Some((tx.id(chain_id), tx.inputs(), tx.outputs())),
+        TransactionType::Create(tx) => Some((tx.id(chain_id), tx.inputs(), tx.outputs())),
+        TransactionType::Upload(tx) => Some((tx.id(chain_id), tx.inputs(), tx.outputs())),
+        TransactionType::Upgrade(tx) => Some((tx.id(chain_id), tx.inputs(), tx.outputs())),
+        TransactionType::Mint(_) => None,
+    }
+}
+
+fn load(path: &Path, owner: &Bech32Address) -> Result<IndexState> {
+    let contents = fs::read_to_string(path)?;
+    let stored: StoredIndex = serde_json::from_str(&contents)
+        .map_err(|e| error!(Other, "could not parse utxo index {path:?}: {e}"))?;
+
+    if stored.owner != *owner.hash() {
+        return Err(error!(
+            Other,
+            "utxo index {path:?} belongs to another owner than `{owner}`"
+        ));
+    }
+
+    let coins = stored
+        .coins
+        .into_iter()
+        .map(|stored| {
+            let utxo_id = UtxoId::new(stored.tx_id.into(), stored.output_index);
+            let coin = Coin {
+                amount: stored.amount,
+                block_created: stored.block_created,
+                asset_id: stored.asset_id.into(),
+                utxo_id,
+                owner: owner.clone(),
+                status: CoinStatus::Unspent,
+            };
+            (utxo_id, coin)
+        })
+        .collect();
+
+    Ok(IndexState {
+        initialized: true,
+        cursor: stored.cursor,
+        coins,
+    })
+}
+
+fn store(path: &Path, owner: &Bech32Address, state: &IndexState) -> Result<()> {
+    let stored = StoredIndex {
+        owner: *owner.hash(),
+        cursor: state.cursor.clone(),
+        coins: state
+            .coins
+            .values()
+            .map(|coin| StoredCoin {
+                tx_id: **coin.utxo_id.tx_id(),
+                output_index: coin.utxo_id.output_index(),
+                asset_id: *coin.asset_id,
+                amount: coin.amount,
+                block_created: coin.block_created,
+            })
+            .collect(),
+    };
+    let contents = serde_json::to_string(&stored)
+        .map_err(|e| error!(Other, "could not serialize utxo index: {e}"))?;
+
+    // write to a temporary file first so a crash never leaves a truncated index behind
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    Ok(fs::rename(tmp_path, path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{policies::Policies, Transaction as FuelTransaction};
+    use fuels_core::types::transaction::ScriptTransaction;
+
+    use super::*;
+
+    fn coin(owner: &Bech32Address, seed: u8, amount: u64) -> Coin {
+        Coin {
+            amount,
+            asset_id: AssetId::zeroed(),
+            utxo_id: UtxoId::new([seed; 32].into(), 0),
+            owner: owner.clone(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn transactions_move_coins_in_and_out() {
+        // given
+        let owner = Bech32Address::new("fuel", [1; 32]);
+        let spent = coin(&owner, 1, 10);
+        let mut coins = HashMap::from([(spent.utxo_id, spent.clone())]);
+
+        let input = fuel_tx::Input::coin_signed(
+            spent.utxo_id,
+            (&owner).into(),
+            spent.amount,
+            spent.asset_id,
+            Default::default(),
+            0,
+        );
+        let outputs = vec![
+            Output::coin(Address::from([2; 32]), 6, AssetId::zeroed()),
+            Output::change((&owner).into(), 4, AssetId::zeroed()),
+        ];
+        let tx: ScriptTransaction = FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![input],
+            outputs,
+            vec![],
+        )
+        .into();
+        let response = TransactionResponse {
+            transaction: TransactionType::Script(tx.clone()),
+            status: TxStatus::Success { receipts: vec![] },
+            block_height: Some(3.into()),
+            time: None,
+        };
+
+        // when
+        apply(&mut coins, &owner, &response, ChainId::default());
+        // applying twice changes nothing
+        apply(&mut coins, &owner, &response, ChainId::default());
+
+        // then
+        let change_id = UtxoId::new(tx.id(ChainId::default()), 1);
+        assert_eq!(coins.len(), 1);
+        assert_eq!(coins[&change_id].amount, 4);
+        assert_eq!(coins[&change_id].block_created, 3);
+    }
+
+    #[tokio::test]
+    async fn indexed_coins_are_selected_and_persisted() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("index.json");
+        let owner = Bech32Address::new("fuel", [1; 32]);
+
+        let index = UtxoIndex::persistent(owner.clone(), &path)?;
+        {
+            let mut state = index.state.lock().await;
+            state.initialized = true;
+            for (seed, amount) in [(1, 5), (2, 50), (3, 20)] {
+                let coin = coin(&owner, seed, amount);
+                state.coins.insert(coin.utxo_id, coin);
+            }
+            store(&path, &owner, &state)?;
+        }
+
+        let selected = index
+            .select_coins(
+                AssetId::zeroed(),
+                60,
+                &[],
+                &CoinSelectionPolicy::LargestFirst,
+            )
+            .await?;
+        let reloaded = UtxoIndex::persistent(owner, &path)?;
+
+        assert_eq!(
+            selected.iter().map(|coin| coin.amount).collect::<Vec<_>>(),
+            vec![50, 20]
+        );
+        assert!(index
+            .select_coins(
+                AssetId::zeroed(),
+                76,
+                &[],
+                &CoinSelectionPolicy::LargestFirst
+            )
+            .await
+            .is_err());
+        assert_eq!(reloaded.balance(AssetId::zeroed()).await, 75);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt, ops, path::Path};
+use std::{fmt, ops, path::Path, sync::Arc};
 
 use async_trait::async_trait;
 use elliptic_curve::rand_core;
@@ -7,17 +7,28 @@ use fuels_core::{
     traits::Signer,
     types::{
         bech32::{Bech32Address, FUEL_BECH32_HRP},
+        coin_type::CoinType,
         coin_type_id::CoinTypeId,
         errors::{error, Result},
         input::Input,
+        transaction::Transaction,
         transaction_builders::TransactionBuilder,
-        AssetId,
+        AssetId, CoinSelectionPolicy, Identity,
     },
 };
 use rand::{CryptoRng, Rng};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+use crate::{
+    accounts_utils::{split_into_utxo_ids_and_nonces, try_provider_error},
+    input_policy::InputPolicy,
+    keystore::{self, KdfParams},
+    provider::{Provider, ResourceFilter},
+    resource_manager::ResourceManager,
+    spending_policy::{SpendingGuard, SpendingPolicy},
+    utxo_index::UtxoIndex,
+    Account, ViewOnlyAccount,
+};
 
 pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
 
@@ -34,6 +45,7 @@ pub struct Wallet {
     /// from the first 32 bytes of SHA-256 hash of the wallet's public key.
     pub(crate) address: Bech32Address,
     provider: Option<Provider>,
+    label: Option<Arc<str>>,
 }
 
 /// A `WalletUnlocked` is equivalent to a [`Wallet`] whose private key is known and stored
@@ -46,12 +58,40 @@ pub struct WalletUnlocked {
     #[zeroize(skip)]
     wallet: Wallet,
     pub(crate) private_key: SecretKey,
+    #[zeroize(skip)]
+    spending_guard: Option<Arc<SpendingGuard>>,
+    #[zeroize(skip)]
+    input_policy: Option<InputPolicy>,
+    #[zeroize(skip)]
+    utxo_index: Option<Arc<UtxoIndex>>,
+    #[zeroize(skip)]
+    coin_selection_policy: Option<CoinSelectionPolicy>,
+    #[zeroize(skip)]
+    resource_manager: Option<ResourceManager>,
+    #[zeroize(skip)]
+    reserves_resources: bool,
 }
 
 impl Wallet {
     /// Construct a Wallet from its given public address.
     pub fn from_address(address: Bech32Address, provider: Option<Provider>) -> Self {
-        Self { address, provider }
+        Self {
+            address,
+            provider,
+            label: None,
+        }
+    }
+
+    /// Names the wallet, e.g. after the role it plays in a service. The label prefixes the
+    /// errors of the transactions the wallet sends and is recorded in the tracing spans of its
+    /// contract calls.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into().into());
+        self
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
     }
 
     pub fn provider(&self) -> Option<&Provider> {
@@ -74,6 +114,12 @@ impl Wallet {
         WalletUnlocked {
             wallet: self,
             private_key,
+            spending_guard: None,
+            input_policy: None,
+            utxo_index: None,
+            coin_selection_policy: None,
+            resource_manager: None,
+            reserves_resources: true,
         }
     }
 }
@@ -86,6 +132,10 @@ impl ViewOnlyAccount for Wallet {
     fn try_provider(&self) -> Result<&Provider> {
         self.provider.as_ref().ok_or_else(try_provider_error)
     }
+
+    fn label(&self) -> Option<&str> {
+        self.label()
+    }
 }
 
 impl WalletUnlocked {
@@ -103,6 +153,159 @@ impl WalletUnlocked {
         self.wallet.set_provider(provider);
     }
 
+    /// See [`Wallet::with_label`].
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.wallet.label = Some(label.into().into());
+        self
+    }
+
+    /// Enforces `policy` on every transaction this wallet adds its witness to. Building a
+    /// transaction that violates the policy fails. Transactions count towards the daily totals
+    /// once submitted, see [`SpendingPolicy`]. Clones of the wallet share the daily totals.
+    ///
+    /// Fails if the policy's ledger file exists but cannot be read.
+    pub fn with_spending_policy(mut self, policy: SpendingPolicy) -> Result<Self> {
+        self.spending_guard = Some(Arc::new(SpendingGuard::new(policy)?));
+
+        Ok(self)
+    }
+
+    /// Selects the wallet's inputs according to `policy` and rejects builders violating it when
+    /// the wallet adds its witness.
+    ///
+    /// Since the policy selects the inputs itself, fails if the wallet already has a UTXO index
+    /// or a coin selection policy.
+    pub fn with_input_policy(mut self, policy: InputPolicy) -> Result<Self> {
+        if self.utxo_index.is_some() || self.coin_selection_policy.is_some() {
+            return Err(error!(
+                Other,
+                "an input policy can't be combined with a UTXO index or a coin selection policy"
+            ));
+        }
+        self.input_policy = Some(policy);
+
+        Ok(self)
+    }
+
+    /// Selects the wallet's coins from `index` instead of querying the node for them. The index is
+    /// synced before every selection. Messages are not indexed and thus never selected.
+    ///
+    /// Fails if the index belongs to another address or the wallet has an input policy.
+    pub fn with_utxo_index(mut self, index: Arc<UtxoIndex>) -> Result<Self> {
+        if self.input_policy.is_some() {
+            return Err(error!(
+                Other,
+                "a UTXO index can't be combined with an input policy"
+            ));
+        }
+        if index.owner() != self.address() {
+            return Err(error!(
+                Other,
+                "utxo index of `{}` cannot be used by `{}`",
+                index.owner(),
+                self.address()
+            ));
+        }
+        self.utxo_index = Some(index);
+
+        Ok(self)
+    }
+
+    /// Selects the wallet's coins locally according to `policy` instead of leaving it to the
+    /// node. A policy set on the transaction builder takes precedence. Messages are never
+    /// selected by a policy.
+    ///
+    /// Fails if the wallet has an input policy.
+    pub fn with_coin_selection_policy(mut self, policy: CoinSelectionPolicy) -> Result<Self> {
+        if self.input_policy.is_some() {
+            return Err(error!(
+                Other,
+                "a coin selection policy can't be combined with an input policy"
+            ));
+        }
+        self.coin_selection_policy = Some(policy);
+
+        Ok(self)
+    }
+
+    /// Reserves the resources the wallet selects in `manager` until the transaction spending them
+    /// is done, so that transactions built concurrently don't select them again.
+    pub fn with_resource_manager(mut self, manager: ResourceManager) -> Self {
+        self.resource_manager = Some(manager);
+        self
+    }
+
+    async fn select_resources(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        coin_selection: Option<&CoinSelectionPolicy>,
+    ) -> Result<Vec<CoinType>> {
+        let Some(manager) = &self.resource_manager else {
+            return self
+                .select_unreserved_resources(asset_id, amount, excluded_coins, coin_selection)
+                .await;
+        };
+
+        let mut excluded_coins = excluded_coins.unwrap_or_default();
+        excluded_coins.extend(manager.reserved());
+        let resources = self
+            .select_unreserved_resources(asset_id, amount, Some(excluded_coins), coin_selection)
+            .await?;
+        if self.reserves_resources {
+            manager.reserve(resources.iter().map(CoinType::id));
+        }
+
+        Ok(resources)
+    }
+
+    async fn select_unreserved_resources(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        coin_selection: Option<&CoinSelectionPolicy>,
+    ) -> Result<Vec<CoinType>> {
+        match (&self.input_policy, &self.utxo_index, coin_selection) {
+            (Some(_), _, Some(_)) => Err(error!(
+                Other,
+                "the coin selection policy of the builder can't be used by a wallet with an input policy"
+            )),
+            (Some(policy), _, None) => {
+                policy
+                    .select_resources(self, asset_id, amount, excluded_coins)
+                    .await
+            }
+            (None, Some(index), coin_selection) => {
+                index.sync(self.try_provider()?).await?;
+                let coins = index
+                    .select_coins(
+                        asset_id,
+                        amount,
+                        &excluded_coins.unwrap_or_default(),
+                        &coin_selection.cloned().unwrap_or_default(),
+                    )
+                    .await?;
+
+                Ok(coins.into_iter().map(CoinType::Coin).collect())
+            }
+            (None, None, Some(coin_selection)) => {
+                self.get_spendable_coins_with_policy(
+                    asset_id,
+                    amount,
+                    excluded_coins,
+                    coin_selection,
+                )
+                .await
+            }
+            (None, None, None) => {
+                self.get_spendable_resources(asset_id, amount, excluded_coins)
+                    .await
+            }
+        }
+    }
+
     /// Creates a new wallet with a random private key.
     pub fn new_random(provider: Option<Provider>) -> Self {
         let mut rng = rand::thread_rng();
@@ -162,14 +365,23 @@ impl WalletUnlocked {
     /// Encrypts the wallet's private key with the given password and saves it
     /// to the given path.
     pub fn encrypt<P, S>(&self, dir: P, password: S) -> Result<String>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+    {
+        self.save_keystore(dir, password, KdfParams::default())
+    }
+
+    /// Like [`WalletUnlocked::encrypt`], but with the key derivation function and its parameters
+    /// of your choosing. Returns the uuid the keystore file is named after.
+    pub fn save_keystore<P, S>(&self, dir: P, password: S, kdf: KdfParams) -> Result<String>
     where
         P: AsRef<Path>,
         S: AsRef<[u8]>,
     {
         let mut rng = rand::thread_rng();
 
-        eth_keystore::encrypt_key(dir, &mut rng, *self.private_key, password, None)
-            .map_err(|e| error!(Other, "{e}"))
+        keystore::encrypt_key(dir, &mut rng, self.private_key.as_ref(), password, kdf)
     }
 
     /// Recreates a wallet from an encrypted JSON wallet given the provided path and password.
@@ -190,6 +402,7 @@ impl WalletUnlocked {
     }
 }
 
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl ViewOnlyAccount for WalletUnlocked {
     fn address(&self) -> &Bech32Address {
         self.wallet.address()
@@ -198,6 +411,35 @@ impl ViewOnlyAccount for WalletUnlocked {
     fn try_provider(&self) -> Result<&Provider> {
         self.provider.as_ref().ok_or_else(try_provider_error)
     }
+
+    fn label(&self) -> Option<&str> {
+        self.wallet.label()
+    }
+
+    /// Excludes the resources reserved by the wallet's [`ResourceManager`], if any.
+    async fn get_spendable_resources(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<CoinType>> {
+        let mut excluded_coins = excluded_coins.unwrap_or_default();
+        if let Some(manager) = &self.resource_manager {
+            excluded_coins.extend(manager.reserved());
+        }
+        let (excluded_utxos, excluded_message_nonces) =
+            split_into_utxo_ids_and_nonces(Some(excluded_coins));
+
+        let filter = ResourceFilter {
+            from: self.address().clone(),
+            asset_id: Some(asset_id),
+            amount,
+            excluded_utxos,
+            excluded_message_nonces,
+        };
+
+        self.try_provider()?.get_spendable_resources(filter).await
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -212,19 +454,83 @@ impl Account for WalletUnlocked {
         amount: u64,
         excluded_coins: Option<Vec<CoinTypeId>>,
     ) -> Result<Vec<Input>> {
-        Ok(self
-            .get_spendable_resources(asset_id, amount, excluded_coins)
-            .await?
+        let resources = self
+            .select_resources(
+                asset_id,
+                amount,
+                excluded_coins,
+                self.coin_selection_policy.as_ref(),
+            )
+            .await?;
+
+        Ok(resources
             .into_iter()
             .map(Input::resource_signed)
             .collect::<Vec<Input>>())
     }
 
+    async fn get_asset_inputs_for_amount_with_policy(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+        policy: &CoinSelectionPolicy,
+    ) -> Result<Vec<Input>> {
+        let resources = self
+            .select_resources(asset_id, amount, excluded_coins, Some(policy))
+            .await?;
+
+        Ok(resources.into_iter().map(Input::resource_signed).collect())
+    }
+
     fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        if let Some(policy) = &self.input_policy {
+            let base_asset_id = *self
+                .try_provider()?
+                .try_consensus_parameters()?
+                .base_asset_id();
+            policy.check_inputs(tb.inputs(), &base_asset_id)?;
+        }
+
+        if let Some(guard) = &self.spending_guard {
+            let base_asset_id = *self
+                .try_provider()?
+                .try_consensus_parameters()?
+                .base_asset_id();
+            guard.check(self.address(), &base_asset_id, tb.inputs(), tb.outputs())?;
+        }
+
         tb.add_signer(self.clone())?;
 
         Ok(())
     }
+
+    fn release_resources(&self, ids: &[CoinTypeId]) {
+        if let Some(manager) = &self.resource_manager {
+            manager.release(ids.iter().cloned());
+        }
+    }
+
+    /// Still excludes the resources reserved by the wallet's [`ResourceManager`], but doesn't
+    /// reserve the ones it selects.
+    fn for_dry_run(&self) -> Self {
+        let mut wallet = self.clone();
+        wallet.reserves_resources = false;
+
+        wallet
+    }
+
+    fn record_spending<T: Transaction>(&self, tx: &T) -> Result<()> {
+        let Some(guard) = &self.spending_guard else {
+            return Ok(());
+        };
+        let base_asset_id = *self
+            .try_provider()?
+            .try_consensus_parameters()?
+            .base_asset_id();
+
+        guard.record(self.address(), &base_asset_id, tx.inputs(), tx.outputs())
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -241,10 +547,23 @@ impl Signer for WalletUnlocked {
     }
 }
 
+impl From<&Wallet> for Identity {
+    fn from(wallet: &Wallet) -> Self {
+        wallet.address().into()
+    }
+}
+
+impl From<&WalletUnlocked> for Identity {
+    fn from(wallet: &WalletUnlocked) -> Self {
+        wallet.address().into()
+    }
+}
+
 impl fmt::Debug for Wallet {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Wallet")
             .field("address", &self.address)
+            .field("label", &self.label)
             .finish()
     }
 }
@@ -293,6 +612,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn keystore_round_trips_with_custom_kdf_params() -> Result<()> {
+        let dir = tempdir()?;
+        let wallet = WalletUnlocked::new_random(None);
+
+        for kdf in [
+            KdfParams::Scrypt {
+                log_n: 10,
+                r: 8,
+                p: 1,
+            },
+            KdfParams::Pbkdf2 { iterations: 1024 },
+        ] {
+            // when
+            let uuid = wallet.save_keystore(&dir, "password", kdf)?;
+            let path = dir.path().join(uuid);
+
+            // then
+            let recovered_wallet = WalletUnlocked::load_keystore(&path, "password", None)?;
+            assert_eq!(wallet.address(), recovered_wallet.address());
+            assert!(WalletUnlocked::load_keystore(&path, "wrong", None).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_kdf_params_are_rejected() {
+        let wallet = WalletUnlocked::new_random(None);
+
+        let result = wallet.save_keystore(
+            tempdir().expect("can create tempdir"),
+            "password",
+            KdfParams::Pbkdf2 { iterations: 0 },
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn input_policy_cant_be_combined_with_other_selections() -> Result<()> {
+        // given
+        let wallet = WalletUnlocked::new_random(None);
+        let index = Arc::new(UtxoIndex::in_memory(wallet.address().clone()));
+
+        // when
+        let with_policy = wallet.clone().with_input_policy(InputPolicy::new())?;
+
+        // then
+        assert!(with_policy.clone().with_utxo_index(index.clone()).is_err());
+        assert!(with_policy
+            .with_coin_selection_policy(CoinSelectionPolicy::SmallestFirst)
+            .is_err());
+        assert!(wallet
+            .clone()
+            .with_utxo_index(index)?
+            .with_input_policy(InputPolicy::new())
+            .is_err());
+        assert!(wallet
+            .with_coin_selection_policy(CoinSelectionPolicy::SmallestFirst)?
+            .with_input_policy(InputPolicy::new())
+            .is_err());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn mnemonic_generation() -> Result<()> {
         let mnemonic = generate_mnemonic_phrase(&mut rand::thread_rng(), 12)?;
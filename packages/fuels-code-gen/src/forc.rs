@@ -0,0 +1,217 @@
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::error::{error, Result};
+
+/// Environment variable that, if set, points to the `forc` binary to use.
+pub const FORC_PATH_ENV: &str = "FORC";
+
+/// Paths of the artifacts produced by building a Sway project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForcArtifacts {
+    pub bin: PathBuf,
+    pub abi: PathBuf,
+    /// Only produced for contracts.
+    pub storage_slots: PathBuf,
+}
+
+/// Builds a Sway project by invoking `forc build`.
+///
+/// ```no_run
+/// use fuels_code_gen::forc::ForcBuild;
+///
+/// # fn main() -> fuels_code_gen::error::Result<()> {
+/// let artifacts = ForcBuild::new("sway/contracts/counter")
+///     .with_release(false)
+///     .with_args(["--experimental-new-encoding"])
+///     .run()?;
+///
+/// println!("binary written to {}", artifacts.bin.display());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForcBuild {
+    project_dir: PathBuf,
+    forc_path: Option<PathBuf>,
+    release: bool,
+    args: Vec<OsString>,
+}
+
+impl ForcBuild {
+    pub fn new(project_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            project_dir: project_dir.into(),
+            forc_path: None,
+            release: true,
+            args: vec![],
+        }
+    }
+
+    /// Uses the `forc` binary at `path` instead of locating one with [`locate_forc`].
+    pub fn with_forc_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.forc_path = Some(path.into());
+        self
+    }
+
+    /// Builds with the `release` profile if `true` (default), `debug` otherwise.
+    pub fn with_release(mut self, release: bool) -> Self {
+        self.release = release;
+        self
+    }
+
+    /// Passes additional arguments to `forc build`, e.g. to enable experimental features.
+    pub fn with_args(mut self, args: impl IntoIterator<Item = impl Into<OsString>>) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn profile(&self) -> &'static str {
+        if self.release {
+            "release"
+        } else {
+            "debug"
+        }
+    }
+
+    /// Runs `forc build` and returns the paths of the produced artifacts.
+    pub fn run(&self) -> Result<ForcArtifacts> {
+        let forc = match &self.forc_path {
+            Some(path) => path.clone(),
+            None => locate_forc()?,
+        };
+
+        let mut command = Command::new(&forc);
+        command.arg("build").arg("--path").arg(&self.project_dir);
+        if self.release {
+            command.arg("--release");
+        }
+        command.args(&self.args);
+
+        let output = command
+            .output()
+            .map_err(|e| error!("could not run `{}`: {e}", forc.display()))?;
+
+        if !output.status.success() {
+            return Err(error!(
+                "`forc build` failed for project {:?}: {}",
+                self.project_dir,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        self.artifacts()
+    }
+
+    /// The paths the artifacts are written to, whether the project was built or not.
+    pub fn artifacts(&self) -> Result<ForcArtifacts> {
+        let name = project_name(&self.project_dir)?;
+        let out_dir = self.project_dir.join("out").join(self.profile());
+
+        Ok(ForcArtifacts {
+            bin: out_dir.join(format!("{name}.bin")),
+            abi: out_dir.join(format!("{name}-abi.json")),
+            storage_slots: out_dir.join(format!("{name}-storage_slots.json")),
+        })
+    }
+}
+
+/// Finds the `forc` binary, first through the [`FORC_PATH_ENV`] environment variable and then in
+/// `PATH`.
+pub fn locate_forc() -> Result<PathBuf> {
+    if let Some(path) = env::var_os(FORC_PATH_ENV) {
+        return Ok(PathBuf::from(path));
+    }
+
+    which::which("forc")
+        .map_err(|_| error!("no `forc` in PATH. Install it with `fuelup` or set `{FORC_PATH_ENV}`"))
+}
+
+/// Reads the project name from `Forc.toml`, falling back to the name of the directory.
+fn project_name(project_dir: &Path) -> Result<String> {
+    let manifest = project_dir.join("Forc.toml");
+    if let Ok(contents) = fs::read_to_string(manifest) {
+        if let Some(name) = manifest_project_name(&contents) {
+            return Ok(name);
+        }
+    }
+
+    project_dir
+        .canonicalize()?
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(ToString::to_string)
+        .ok_or_else(|| error!("could not determine the name of project {project_dir:?}"))
+}
+
+fn manifest_project_name(manifest: &str) -> Option<String> {
+    let mut in_project_section = false;
+
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_project_section = line == "[project]";
+            continue;
+        }
+
+        if !in_project_section {
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "name" {
+                return Some(value.trim().trim_matches('"').to_string());
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_name_is_read_from_the_manifest() {
+        let manifest = r#"
+            [project]
+            authors = ["Fuel Labs <contact@fuel.sh>"]
+            entry = "main.sw"
+            license = "Apache-2.0"
+            name = "counter"
+
+            [dependencies]
+            name = "not_the_project"
+        "#;
+
+        assert_eq!(manifest_project_name(manifest), Some("counter".to_string()));
+        assert_eq!(manifest_project_name("[dependencies]\nname = \"a\""), None);
+    }
+
+    #[test]
+    fn artifacts_follow_forc_output_layout() -> Result<()> {
+        let project_dir = env::temp_dir();
+        let name = project_name(&project_dir)?;
+
+        let artifacts = ForcBuild::new(&project_dir)
+            .with_release(false)
+            .artifacts()?;
+
+        let out_dir = project_dir.join("out").join("debug");
+        assert_eq!(
+            artifacts,
+            ForcArtifacts {
+                bin: out_dir.join(format!("{name}.bin")),
+                abi: out_dir.join(format!("{name}-abi.json")),
+                storage_slots: out_dir.join(format!("{name}-storage_slots.json")),
+            }
+        );
+
+        Ok(())
+    }
+}
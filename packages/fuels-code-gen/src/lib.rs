@@ -1,5 +1,7 @@
 pub use program_bindings::*;
 
 pub mod error;
+#[cfg(feature = "forc")]
+pub mod forc;
 mod program_bindings;
 pub mod utils;
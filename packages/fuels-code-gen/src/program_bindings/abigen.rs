@@ -1,4 +1,7 @@
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
 pub use abigen_target::{Abi, AbigenTarget, ProgramType};
 use fuel_abi_types::abi::full_program::FullTypeDeclaration;
@@ -31,7 +34,7 @@ impl Abigen {
     /// # Arguments
     ///
     /// * `targets`: `AbigenTargets` detailing which ABI to generate bindings
-    /// for, and of what nature (Contract, Script or Predicate).
+    ///   for, and of what nature (Contract, Script or Predicate).
     /// * `no_std`: don't use the Rust std library.
     pub fn generate(targets: Vec<AbigenTarget>, no_std: bool) -> Result<TokenStream> {
         let generated_code = Self::generate_code(no_std, targets)?;
@@ -98,7 +101,12 @@ impl Abigen {
 
         let recompile_trigger =
             Self::generate_macro_recompile_trigger(target.source.path.as_ref(), no_std);
-        let types = generate_types(&target.source.abi.types, shared_types, no_std)?;
+        let types = generate_types(
+            &target.source.abi.types,
+            shared_types,
+            &target.type_substitutions,
+            no_std,
+        )?;
         let bindings = generate_bindings(target, no_std)?;
         Ok(recompile_trigger
             .merge(types)
@@ -126,7 +134,12 @@ impl Abigen {
         shared_types: HashSet<FullTypeDeclaration>,
         no_std: bool,
     ) -> Result<GeneratedCode> {
-        let types = generate_types(&shared_types, &HashSet::default(), no_std)?;
+        let types = generate_types(
+            &shared_types,
+            &HashSet::default(),
+            &HashMap::default(),
+            no_std,
+        )?;
 
         if types.is_empty() {
             Ok(Default::default())
@@ -151,7 +164,7 @@ impl Abigen {
     /// # Arguments
     ///
     /// * `all_custom_types`: types from all ABIs whose bindings are being
-    /// generated.
+    ///   generated.
     fn filter_shared_types<'a>(
         all_custom_types: impl IntoIterator<Item = &'a FullTypeDeclaration>,
     ) -> HashSet<FullTypeDeclaration> {
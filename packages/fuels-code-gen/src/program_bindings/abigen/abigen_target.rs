@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     convert::TryFrom,
     env, fs,
     path::{Path, PathBuf},
@@ -8,13 +9,17 @@ use std::{
 use fuel_abi_types::abi::full_program::FullProgramABI;
 use proc_macro2::Ident;
 
-use crate::error::{error, Error, Result};
+use crate::{
+    error::{error, Error, Result},
+    utils::TypePath,
+};
 
 #[derive(Debug, Clone)]
 pub struct AbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub(crate) program_type: ProgramType,
+    pub(crate) type_substitutions: HashMap<TypePath, TypePath>,
 }
 
 impl AbigenTarget {
@@ -23,9 +28,25 @@ impl AbigenTarget {
             name,
             source,
             program_type,
+            type_substitutions: HashMap::default(),
         }
     }
 
+    /// Uses the Rust type at `substitute` wherever the ABI uses the type `original` instead of
+    /// generating a binding for it. `original` is either the full path of the type in the ABI,
+    /// e.g. `my_lib::FixedPoint`, or just its name. `substitute` has to be absolute, e.g.
+    /// `::my_crate::Decimal` or `crate::Decimal`.
+    pub fn with_type_substitution(mut self, original: &str, substitute: &str) -> Result<Self> {
+        let original = TypePath::new(original)
+            .map_err(|e| error!("invalid type to substitute `{original}`: ").combine(e))?;
+        let substitute = TypePath::new(substitute)
+            .map_err(|e| error!("invalid substitute type `{substitute}`: ").combine(e))?;
+
+        self.type_substitutions.insert(original, substitute);
+
+        Ok(self)
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
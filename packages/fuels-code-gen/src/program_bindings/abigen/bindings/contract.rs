@@ -9,7 +9,7 @@ use crate::{
         abigen::{
             bindings::function_generator::FunctionGenerator,
             configurables::generate_code_for_configurable_constants,
-            logs::log_formatters_instantiation_code,
+            logs::{generate_events_enum, log_formatters_instantiation_code},
         },
         generated_code::GeneratedCode,
     },
@@ -31,6 +31,10 @@ pub(crate) fn contract_bindings(
     let methods_name = ident(&format!("{name}Methods"));
 
     let contract_functions = expand_functions(&abi.functions)?;
+    let function_signatures = function_signatures(&abi.functions)?;
+
+    let events_name = ident(&format!("{name}Events"));
+    let events_enum = generate_events_enum(&events_name, &abi.logged_types)?;
 
     let configuration_struct_name = ident(&format!("{name}Configurables"));
     let constant_configuration_code =
@@ -89,12 +93,75 @@ pub(crate) fn contract_bindings(
                                   .map_err(::std::convert::Into::into)
             }
 
+            pub async fn get_balance(&self, asset_id: ::fuels::types::AssetId) -> ::fuels::types::errors::Result<u64> {
+                ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
+                                  .get_contract_asset_balance(&self.contract_id, asset_id)
+                                  .await
+            }
+
+            pub async fn get_balances_paginated(
+                &self,
+                request: ::fuels::client::PaginationRequest<::std::string::String>,
+            ) -> ::fuels::types::errors::Result<::fuels::client::PaginatedResult<(::fuels::types::AssetId, u64), ::std::string::String>> {
+                ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
+                                  .get_contract_balances_paginated(&self.contract_id, request)
+                                  .await
+            }
+
+            /// The signatures of the contract methods, as declared in the ABI.
+            pub fn function_signatures() -> ::std::vec::Vec<::fuels::programs::calls::FunctionSignature> {
+                ::std::vec![#(#function_signatures),*]
+            }
+
+            /// Calls made to this contract by past transactions, decoded using the ABI.
+            pub async fn call_history(
+                &self,
+                request: ::fuels::client::PaginationRequest<::std::string::String>,
+            ) -> ::fuels::types::errors::Result<::fuels::client::PaginatedResult<::fuels::programs::calls::HistoricalCall, ::std::string::String>> {
+                let provider = ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?;
+
+                ::fuels::programs::calls::call_history(
+                    provider,
+                    &self.contract_id,
+                    &Self::function_signatures(),
+                    request,
+                )
+                .await
+            }
+
+            /// The events this contract logs, decoded into one enum.
+            pub fn events(&self) -> ::fuels::programs::contract::ContractEvents<#events_name> {
+                let events = ::fuels::programs::contract::ContractEvents::new(
+                    self.contract_id.clone(),
+                    self.log_decoder.clone(),
+                );
+
+                match ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account) {
+                    ::core::result::Result::Ok(provider) => events.with_provider(provider.clone()),
+                    ::core::result::Result::Err(_) => events,
+                }
+            }
+
+            /// Follows the values of type `T` this contract logs in new blocks.
+            pub fn subscribe_logs<T>(&self) -> ::fuels::types::errors::Result<::fuels::programs::contract::LogSubscription<T>>
+            where
+                T: ::fuels::core::traits::Tokenizable + ::fuels::core::traits::Parameterize + ::core::marker::Send + ::core::marker::Sync + 'static,
+            {
+                let provider = ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?;
+
+                ::core::result::Result::Ok(::fuels::programs::contract::LogSubscription::new(
+                    provider.clone(),
+                    self.log_decoder.clone(),
+                ))
+            }
+
             pub fn methods(&self) -> #methods_name<A> {
                 #methods_name {
                     contract_id: self.contract_id.clone(),
                     account: self.account.clone(),
                     log_decoder: self.log_decoder.clone(),
                     encoder_config: self.encoder_config.clone(),
+                    contract_ids: ::std::vec::Vec::new(),
                 }
             }
         }
@@ -105,6 +172,8 @@ pub(crate) fn contract_bindings(
             account: A,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            // dependencies added to every call, e.g. the implementation behind a proxy
+            contract_ids: ::std::vec::Vec<::fuels::types::bech32::Bech32ContractId>,
         }
 
         impl<A: ::fuels::accounts::Account> #methods_name<A> {
@@ -123,14 +192,48 @@ pub(crate) fn contract_bindings(
             }
         }
 
+        impl<A: ::fuels::accounts::Account> ::core::convert::From<&#name<A>> for ::fuels::types::Identity {
+            fn from(instance: &#name<A>) -> Self {
+                Self::ContractId(instance.contract_id().into())
+            }
+        }
+
+        impl<A: ::fuels::accounts::Account>
+            ::fuels::programs::contract::ProxyCompatible for #name<A>
+        {
+            type Methods = #methods_name<A>;
+            type Account = A;
+
+            fn methods_with_contract_ids(
+                &self,
+                contract_ids: &[::fuels::types::bech32::Bech32ContractId],
+            ) -> Self::Methods {
+                let mut methods = self.methods();
+                methods.contract_ids = contract_ids.to_vec();
+
+                methods
+            }
+
+            fn account(&self) -> Self::Account {
+                self.account.clone()
+            }
+        }
+
+        #events_enum
+
         #constant_configuration_code
     };
 
     // All publicly available types generated above should be listed here.
-    let type_paths = [name, &methods_name, &configuration_struct_name]
-        .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
-        .into_iter()
-        .collect();
+    let type_paths = [
+        name,
+        &methods_name,
+        &events_name,
+        &configuration_struct_name,
+    ]
+    .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
+    .into_iter()
+    .collect();
 
     Ok(GeneratedCode::new(code, type_paths, no_std))
 }
@@ -145,6 +248,13 @@ fn expand_functions(functions: &[FullABIFunction]) -> Result<TokenStream> {
         })
 }
 
+fn function_signatures(functions: &[FullABIFunction]) -> Result<Vec<TokenStream>> {
+    functions
+        .iter()
+        .map(|fun| Ok(FunctionGenerator::new(fun)?.signature()))
+        .collect()
+}
+
 /// Transforms a function defined in [`FullABIFunction`] into a [`TokenStream`]
 /// that represents that same function signature as a Rust-native function
 /// declaration.
@@ -171,6 +281,7 @@ pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
                 #is_payable,
                 self.encoder_config.clone(),
             )
+            .with_contract_ids(&self.contract_ids)
     };
     generator.set_body(body);
 
@@ -188,7 +299,10 @@ mod tests {
     use pretty_assertions::assert_eq;
     use quote::quote;
 
-    use crate::{error::Result, program_bindings::abigen::bindings::contract::expand_fn};
+    use crate::{
+        error::Result,
+        program_bindings::abigen::bindings::contract::{expand_fn, function_signatures},
+    };
 
     #[test]
     fn expand_contract_method_simple_abi() -> Result<()> {
@@ -371,6 +485,7 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_contract_ids(&self.contract_ids)
             }
         };
 
@@ -428,6 +543,7 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_contract_ids(&self.contract_ids)
             }
         };
 
@@ -436,6 +552,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn function_signatures_use_the_abi_names() -> Result<()> {
+        // given
+        let the_function = ABIFunction {
+            inputs: vec![TypeApplication {
+                name: String::from("type"),
+                type_id: 1,
+                ..Default::default()
+            }],
+            name: "set_flag".to_string(),
+            ..Default::default()
+        };
+        let types = [
+            (
+                0,
+                TypeDeclaration {
+                    type_id: 0,
+                    type_field: String::from("()"),
+                    ..Default::default()
+                },
+            ),
+            (
+                1,
+                TypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("bool"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        // when
+        let result =
+            function_signatures(&[FullABIFunction::from_counterpart(&the_function, &types)?])?;
+
+        // then
+        let expected = quote! {
+            ::fuels::programs::calls::FunctionSignature {
+                name: "set_flag",
+                inputs: ::std::vec![("type", <::core::primitive::bool as ::fuels::core::traits::Parameterize>::param_type())],
+            }
+        };
+        assert_eq!(result[0].to_string(), expected.to_string());
+
+        Ok(())
+    }
+
     #[test]
     fn expand_contract_method_complex() -> Result<()> {
         // given
@@ -547,6 +712,7 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_contract_ids(&self.contract_ids)
             }
         };
 
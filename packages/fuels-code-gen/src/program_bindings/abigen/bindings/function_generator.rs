@@ -15,6 +15,8 @@ use crate::{
 pub(crate) struct FunctionGenerator {
     name: String,
     args: Components,
+    // the names of the arguments in the ABI, which `args` may have renamed, e.g. keywords
+    abi_arg_names: Vec<String>,
     output_type: TokenStream,
     body: TokenStream,
     docs: Vec<String>,
@@ -34,6 +36,11 @@ impl FunctionGenerator {
         Ok(Self {
             name: fun.name().to_string(),
             args,
+            abi_arg_names: fun
+                .inputs()
+                .iter()
+                .map(|input| input.name.clone())
+                .collect(),
             output_type: output_type.to_token_stream(),
             body: Default::default(),
             docs: vec![],
@@ -60,6 +67,26 @@ impl FunctionGenerator {
         quote! {::fuels::core::codec::encode_fn_selector(#name)}
     }
 
+    /// The name and the inputs of the function as a `FunctionSignature`, used to decode calls to
+    /// it from the script data of past transactions.
+    pub fn signature(&self) -> TokenStream {
+        let name = &self.name;
+        let inputs = self
+            .abi_arg_names
+            .iter()
+            .zip(self.args.iter())
+            .map(|(arg_name, (_, ty))| {
+                quote! {(#arg_name, <#ty as ::fuels::core::traits::Parameterize>::param_type())}
+            });
+
+        quote! {
+            ::fuels::programs::calls::FunctionSignature {
+                name: #name,
+                inputs: ::std::vec![#(#inputs),*],
+            }
+        }
+    }
+
     pub fn tokenized_args(&self) -> TokenStream {
         let arg_names = self.args.iter().map(|(name, ty)| {
             get_equivalent_bech32_type(ty)
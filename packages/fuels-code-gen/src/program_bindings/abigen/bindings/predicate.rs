@@ -65,11 +65,78 @@ fn expand_fn(fn_abi: &FullABIFunction) -> Result<TokenStream> {
         ::fuels::types::errors::Result<::std::vec::Vec<u8>>
     };
 
+    // keep the docs of the predicate's `main` after our own so they show up on hover
+    let mut docs = vec!["Encode the predicate arguments".to_string()];
+    let main_docs = fn_abi.doc_strings()?;
+    if !main_docs.is_empty() {
+        docs.push(String::new());
+        docs.extend(main_docs);
+    }
+
     generator
-        .set_docs(vec!["Encode the predicate arguments".to_string()])
+        .set_docs(docs)
         .set_name("encode_data".to_string())
         .set_output_type(output_type)
         .set_body(body);
 
     Ok(generator.generate())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use fuel_abi_types::abi::{
+        full_program::FullABIFunction,
+        program::{ABIFunction, Attribute, TypeApplication, TypeDeclaration},
+    };
+    use pretty_assertions::assert_eq;
+    use quote::quote;
+
+    use crate::{error::Result, program_bindings::abigen::bindings::predicate::expand_fn};
+
+    #[test]
+    fn main_docs_are_appended_to_the_encoder_docs() -> Result<()> {
+        let the_function = ABIFunction {
+            inputs: vec![TypeApplication {
+                name: String::from("owner_sig"),
+                type_id: 1,
+                ..Default::default()
+            }],
+            name: "main".to_string(),
+            output: TypeApplication {
+                name: String::from(""),
+                type_id: 1,
+                ..Default::default()
+            },
+            attributes: Some(vec![Attribute {
+                name: "doc-comment".to_string(),
+                arguments: vec!["Checks the owner signed".to_string()],
+            }]),
+        };
+        let types = [(
+            1,
+            TypeDeclaration {
+                type_id: 1,
+                type_field: String::from("bool"),
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let result = expand_fn(&FullABIFunction::from_counterpart(&the_function, &types)?)?;
+
+        let expected = quote! {
+            #[doc = "Encode the predicate arguments"]
+            #[doc = ""]
+            #[doc = "Checks the owner signed"]
+            pub fn encode_data(&self, owner_sig: ::core::primitive::bool) -> ::fuels::types::errors::Result<::std::vec::Vec<u8>> {
+                self.encoder.encode(&[::fuels::core::traits::Tokenizable::into_token(owner_sig)])
+            }
+        };
+        assert_eq!(result.to_string(), expected.to_string());
+
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
         abigen::{
             bindings::{function_generator::FunctionGenerator, utils::extract_main_fn},
             configurables::generate_code_for_configurable_constants,
-            logs::log_formatters_instantiation_code,
+            logs::{generate_events_enum, log_formatters_instantiation_code},
         },
         generated_code::GeneratedCode,
     },
@@ -34,6 +34,9 @@ pub(crate) fn script_bindings(
         &abi.logged_types,
     );
 
+    let events_name = ident(&format!("{name}Events"));
+    let events_enum = generate_events_enum(&events_name, &abi.logged_types)?;
+
     let configuration_struct_name = ident(&format!("{name}Configurables"));
     let constant_configuration_code =
         generate_code_for_configurable_constants(&configuration_struct_name, &abi.configurables)?;
@@ -92,11 +95,13 @@ pub(crate) fn script_bindings(
             #main_function
         }
 
+        #events_enum
+
         #constant_configuration_code
     };
 
     // All publicly available types generated above should be listed here.
-    let type_paths = [name, &configuration_struct_name]
+    let type_paths = [name, &events_name, &configuration_struct_name]
         .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
         .into_iter()
         .collect();
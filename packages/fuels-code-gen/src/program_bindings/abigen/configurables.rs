@@ -11,6 +11,7 @@ use crate::{
 #[derive(Debug)]
 pub(crate) struct ResolvedConfigurable {
     pub name: Ident,
+    pub constant_name: String,
     pub ttype: ResolvedType,
     pub offset: u64,
 }
@@ -20,6 +21,7 @@ impl ResolvedConfigurable {
         let type_application = &configurable.application;
         Ok(ResolvedConfigurable {
             name: safe_ident(&format!("with_{}", configurable.name)),
+            constant_name: configurable.name.clone(),
             ttype: TypeResolver::default().resolve(type_application)?,
             offset: configurable.offset,
         })
@@ -61,6 +63,7 @@ fn generate_struct_impl(
     resolved_configurables: &[ResolvedConfigurable],
 ) -> TokenStream {
     let builder_methods = generate_builder_methods(resolved_configurables);
+    let introspection_methods = generate_introspection_methods(resolved_configurables);
 
     quote! {
         impl #configurable_struct_name {
@@ -72,6 +75,8 @@ fn generate_struct_impl(
             }
 
             #builder_methods
+
+            #introspection_methods
         }
     }
 }
@@ -82,6 +87,7 @@ fn generate_builder_methods(resolved_configurables: &[ResolvedConfigurable]) ->
              name,
              ttype,
              offset,
+             ..
          }| {
             let encoder_code = generate_encoder_code(ttype);
             quote! {
@@ -101,6 +107,57 @@ fn generate_builder_methods(resolved_configurables: &[ResolvedConfigurable]) ->
     }
 }
 
+fn generate_introspection_methods(resolved_configurables: &[ResolvedConfigurable]) -> TokenStream {
+    let constants = resolved_configurables.iter().map(
+        |ResolvedConfigurable {
+             constant_name,
+             ttype,
+             offset,
+             ..
+         }| {
+            quote! {
+                ::fuels::core::ConfigurableConstant {
+                    name: #constant_name.to_string(),
+                    offset: #offset,
+                    param_type: <#ttype as ::fuels::core::traits::Parameterize>::param_type(),
+                }
+            }
+        },
+    );
+
+    quote! {
+        /// The configurable constants declared in the ABI, with their offsets and types.
+        pub fn configurables() -> ::std::vec::Vec<::fuels::core::ConfigurableConstant> {
+            ::std::vec![#(#constants),*]
+        }
+
+        /// Reads the values of the configurable constants out of `binary`, e.g. the bytecode of a
+        /// deployed contract.
+        pub fn decode_from_binary(
+            binary: &[u8],
+        ) -> ::fuels::types::errors::Result<::std::vec::Vec<::fuels::core::DecodedConfigurable>> {
+            ::fuels::core::decode_configurables(
+                &Self::configurables(),
+                binary,
+                ::std::default::Default::default(),
+            )
+        }
+
+        /// The configurable constants whose values differ between two binaries of the program.
+        pub fn diff_binaries(
+            before: &[u8],
+            after: &[u8],
+        ) -> ::fuels::types::errors::Result<::std::vec::Vec<::fuels::core::ConfigurableChange>> {
+            ::fuels::core::diff_configurables(
+                &Self::configurables(),
+                before,
+                after,
+                ::std::default::Default::default(),
+            )
+        }
+    }
+}
+
 fn generate_encoder_code(ttype: &ResolvedType) -> TokenStream {
     quote! {
         self.encoder.encode(&[
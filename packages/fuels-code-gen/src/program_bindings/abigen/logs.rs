@@ -1,8 +1,15 @@
+use std::collections::HashSet;
+
 use fuel_abi_types::abi::full_program::FullLoggedType;
-use proc_macro2::TokenStream;
+use inflector::Inflector;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::program_bindings::resolved_type::TypeResolver;
+use crate::{
+    error::Result,
+    program_bindings::resolved_type::{GenericType, ResolvedType, TypeResolver},
+    utils::ident,
+};
 
 pub(crate) fn log_formatters_instantiation_code(
     contract_id: TokenStream,
@@ -51,3 +58,129 @@ fn generate_log_id_log_formatter_pairs(resolved_logs: &[ResolvedLog]) -> Vec<Tok
         })
         .collect()
 }
+
+/// Generates an enum with a variant for each type the program logs, along with its
+/// `LoggedEvents` implementation, so that all logs can be decoded in one go.
+pub(crate) fn generate_events_enum(
+    events_name: &Ident,
+    logged_types: &[FullLoggedType],
+) -> Result<TokenStream> {
+    let mut seen_types = HashSet::new();
+    let mut taken_names = HashSet::new();
+    let mut variants = vec![];
+    for logged_type in logged_types {
+        let resolved_type = TypeResolver::default().resolve(&logged_type.application)?;
+        // the same type can be logged in many places
+        if !seen_types.insert(resolved_type.to_string()) {
+            continue;
+        }
+
+        let base_name = variant_name(&resolved_type);
+        let mut name = base_name.clone();
+        let mut suffix = 2;
+        while !taken_names.insert(name.clone()) {
+            name = format!("{base_name}{suffix}");
+            suffix += 1;
+        }
+
+        variants.push((ident(&name), resolved_type));
+    }
+
+    let variant_decls = variants
+        .iter()
+        .map(|(name, ttype)| quote! { #name(#ttype) });
+    let decoders = variants.iter().map(|(name, ttype)| {
+        quote! {
+            if type_id == ::std::any::TypeId::of::<#ttype>() {
+                let decoded = ::fuels::core::codec::try_from_bytes::<#ttype>(data, decoder_config)
+                    .map(Self::#name);
+
+                return ::core::option::Option::Some(decoded);
+            }
+        }
+    });
+
+    Ok(quote! {
+        /// The values the program logs, one variant per logged type.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #events_name {
+            #(#variant_decls),*
+        }
+
+        impl ::fuels::core::codec::LoggedEvents for #events_name {
+            #[allow(unused_variables)]
+            fn decode_event(
+                type_id: ::std::any::TypeId,
+                decoder_config: ::fuels::core::codec::DecoderConfig,
+                data: &[u8],
+            ) -> ::core::option::Option<::fuels::types::errors::Result<Self>> {
+                #(#decoders)*
+
+                ::core::option::Option::None
+            }
+        }
+    })
+}
+
+/// Names a variant after its type, e.g. `U64` for `u64` or `U8Array3` for `[u8; 3]`.
+fn variant_name(resolved_type: &ResolvedType) -> String {
+    match resolved_type {
+        ResolvedType::Unit => "Unit".to_string(),
+        ResolvedType::Primitive(path) => path
+            .ident()
+            .map(|ident| ident.to_string().to_pascal_case())
+            .unwrap_or_default(),
+        ResolvedType::StructOrEnum { path, generics } => {
+            let name = path.ident().map(ToString::to_string).unwrap_or_default();
+
+            generics
+                .iter()
+                .fold(name, |name, generic| name + &variant_name(generic))
+        }
+        ResolvedType::Array(element, len) => format!("{}Array{len}", variant_name(element)),
+        ResolvedType::Tuple(elements) => {
+            elements.iter().fold("Tuple".to_string(), |name, element| {
+                name + &variant_name(element)
+            })
+        }
+        ResolvedType::Generic(GenericType::Named(name)) => name.to_string().to_pascal_case(),
+        ResolvedType::Generic(GenericType::Constant(value)) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TypePath;
+
+    fn primitive(name: &str) -> ResolvedType {
+        ResolvedType::Primitive(TypePath::new(format!("::core::primitive::{name}")).unwrap())
+    }
+
+    #[test]
+    fn variants_are_named_after_their_types() {
+        let sized_string = ResolvedType::StructOrEnum {
+            path: TypePath::new("::fuels::types::SizedAsciiString").unwrap(),
+            generics: vec![ResolvedType::Generic(GenericType::Constant(4))],
+        };
+        let generic_struct = ResolvedType::StructOrEnum {
+            path: TypePath::new("StructWithGeneric").unwrap(),
+            generics: vec![primitive("u8")],
+        };
+
+        assert_eq!(variant_name(&primitive("u64")), "U64");
+        assert_eq!(variant_name(&sized_string), "SizedAsciiString4");
+        assert_eq!(variant_name(&generic_struct), "StructWithGenericU8");
+        assert_eq!(
+            variant_name(&ResolvedType::Array(Box::new(primitive("u8")), 3)),
+            "U8Array3"
+        );
+        assert_eq!(
+            variant_name(&ResolvedType::Tuple(vec![
+                primitive("u8"),
+                primitive("bool")
+            ])),
+            "TupleU8Bool"
+        );
+    }
+}
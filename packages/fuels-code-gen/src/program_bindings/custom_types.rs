@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use fuel_abi_types::abi::full_program::FullTypeDeclaration;
 use itertools::Itertools;
@@ -28,17 +28,21 @@ mod utils;
 ///
 /// * `types`: Types you wish to generate Rust code for.
 /// * `shared_types`: Types that are shared between multiple
-///                   contracts/scripts/predicates and thus generated elsewhere.
+///   contracts/scripts/predicates and thus generated elsewhere.
+/// * `substitutions`: Types replaced by user-provided Rust types, keyed by their path or name.
 pub(crate) fn generate_types<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>>(
     types: T,
     shared_types: &HashSet<FullTypeDeclaration>,
+    substitutions: &HashMap<TypePath, TypePath>,
     no_std: bool,
 ) -> Result<GeneratedCode> {
     types
         .into_iter()
         .filter(|ttype| !should_skip_codegen(ttype))
         .map(|ttype: &FullTypeDeclaration| {
-            if shared_types.contains(ttype) {
+            if let Some(substitute) = find_substitute(ttype, substitutions) {
+                reexport_the_substitute(ttype, substitute, no_std)
+            } else if shared_types.contains(ttype) {
                 reexport_the_shared_type(ttype, no_std)
             } else if ttype.is_struct_type() {
                 expand_custom_struct(ttype, no_std)
@@ -77,6 +81,40 @@ fn reexport_the_shared_type(ttype: &FullTypeDeclaration, no_std: bool) -> Result
     Ok(GeneratedCode::new(the_reexport, Default::default(), no_std).wrap_in_mod(type_mod))
 }
 
+fn find_substitute<'a>(
+    ttype: &FullTypeDeclaration,
+    substitutions: &'a HashMap<TypePath, TypePath>,
+) -> Option<&'a TypePath> {
+    let type_path = ttype.custom_type_path().ok()?;
+
+    substitutions.get(&type_path).or_else(|| {
+        let name = type_path.ident()?;
+        substitutions.get(&name.into())
+    })
+}
+
+/// Generates `pub use substitute as TypeName;` in place of `ttype`, so that the bindings referring
+/// to `ttype` use the substitute instead.
+fn reexport_the_substitute(
+    ttype: &FullTypeDeclaration,
+    substitute: &TypePath,
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    let type_path = ttype
+        .custom_type_path()
+        .expect("This must be a custom type due to the previous filter step");
+    let type_ident = type_path
+        .ident()
+        .expect("The type path must have at least one part");
+
+    let the_reexport = quote! {pub use #substitute as #type_ident;};
+
+    Ok(
+        GeneratedCode::new(the_reexport, HashSet::from([type_ident.into()]), no_std)
+            .wrap_in_mod(type_path.parent()),
+    )
+}
+
 // Checks whether the given type should not have code generated for it. This
 // is mainly because the corresponding type in Rust already exists --
 // e.g. the contract's Vec type is mapped to std::vec::Vec from the Rust
@@ -760,7 +798,8 @@ mod tests {
         let shared_types = HashSet::from([type_decl.clone()]);
 
         // when
-        let generated_code = generate_types(&[type_decl], &shared_types, false).unwrap();
+        let generated_code =
+            generate_types(&[type_decl], &shared_types, &HashMap::default(), false).unwrap();
 
         // then
         let expected_code = quote! {
@@ -783,4 +822,43 @@ mod tests {
 
         assert_eq!(generated_code.code().to_string(), expected_code.to_string());
     }
+
+    #[test]
+    fn substituted_types_reexport_the_substitute() {
+        // given
+        let type_decl = FullTypeDeclaration {
+            type_field: "struct some_lib::FixedPoint".to_string(),
+            components: vec![],
+            type_parameters: vec![],
+        };
+        let substitutions = HashMap::from([(
+            TypePath::new("FixedPoint").unwrap(),
+            TypePath::new("::my_crate::Decimal").unwrap(),
+        )]);
+
+        // when
+        let generated_code =
+            generate_types(&[type_decl], &HashSet::default(), &substitutions, false).unwrap();
+
+        // then
+        let expected_code = quote! {
+            #[allow(clippy::too_many_arguments)]
+            #[no_implicit_prelude]
+            pub mod some_lib {
+                use ::core::{
+                    clone::Clone,
+                    convert::{Into, TryFrom, From},
+                    iter::IntoIterator,
+                    iter::Iterator,
+                    marker::Sized,
+                    panic,
+                };
+
+                use ::std::{string::ToString, format, vec, default::Default};
+                pub use ::my_crate::Decimal as FixedPoint;
+            }
+        };
+
+        assert_eq!(generated_code.code().to_string(), expected_code.to_string());
+    }
 }
@@ -0,0 +1,461 @@
+//! Flattens balances and the asset movements found in receipts into records, and writes them as
+//! CSV or, with the `accounting-parquet` feature, as Parquet, ready for spreadsheets and
+//! accounting tools.
+
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use fuel_tx::{Receipt, TxId};
+use fuel_types::{AssetId, ContractId};
+
+use crate::types::{
+    errors::{error, Result},
+    Identity,
+};
+
+/// The type of the values in a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellType {
+    Text,
+    Integer,
+}
+
+/// A value of a record. `None` integers are left empty in CSV and null in Parquet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Text(String),
+    Integer(Option<u64>),
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Integer(Some(value)) => write!(f, "{value}"),
+            Self::Integer(None) => Ok(()),
+        }
+    }
+}
+
+/// A row of an export. Implement it to export records of your own with [`write_csv`] and
+/// `write_parquet`.
+pub trait Record {
+    /// The names and types of the columns, in the order of [`Record::cells`].
+    const COLUMNS: &'static [(&'static str, CellType)];
+
+    fn cells(&self) -> Vec<Cell>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferKind {
+    /// Coins forwarded to a contract along with a call.
+    Call,
+    /// A transfer to a contract.
+    Transfer,
+    /// A transfer to an address.
+    TransferOut,
+    Mint,
+    Burn,
+    /// Base asset sent to another chain through a message.
+    Withdrawal,
+}
+
+impl TransferKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Call => "call",
+            Self::Transfer => "transfer",
+            Self::TransferOut => "transfer_out",
+            Self::Mint => "mint",
+            Self::Burn => "burn",
+            Self::Withdrawal => "withdrawal",
+        }
+    }
+}
+
+/// An amount of an asset moved by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferRecord {
+    pub tx_id: TxId,
+    pub block_height: Option<u32>,
+    pub kind: TransferKind,
+    /// `None` for coins sent by the script of the transaction, and for mints.
+    pub from: Option<Identity>,
+    /// `None` for burns.
+    pub to: Option<Identity>,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+impl TransferRecord {
+    /// The asset movements among `receipts`, which are those of the transaction `tx_id`. Only
+    /// successful transactions move assets, so leave out the receipts of reverted ones.
+    ///
+    /// Withdrawals carry no asset id, they always move the `base_asset_id` of the chain.
+    pub fn from_receipts(
+        tx_id: TxId,
+        block_height: Option<u32>,
+        receipts: &[Receipt],
+        base_asset_id: AssetId,
+    ) -> Vec<Self> {
+        // contracts called or transferring coins from the script have a zeroed id
+        let contract =
+            |id: &ContractId| (*id != ContractId::zeroed()).then_some(Identity::ContractId(*id));
+
+        receipts
+            .iter()
+            .filter_map(|receipt| {
+                let (kind, from, to, asset_id, amount) = match receipt {
+                    Receipt::Call {
+                        id,
+                        to,
+                        amount,
+                        asset_id,
+                        ..
+                    } if *amount > 0 => (
+                        TransferKind::Call,
+                        contract(id),
+                        Some(Identity::ContractId(*to)),
+                        *asset_id,
+                        *amount,
+                    ),
+                    Receipt::Transfer {
+                        id,
+                        to,
+                        amount,
+                        asset_id,
+                        ..
+                    } => (
+                        TransferKind::Transfer,
+                        contract(id),
+                        Some(Identity::ContractId(*to)),
+                        *asset_id,
+                        *amount,
+                    ),
+                    Receipt::TransferOut {
+                        id,
+                        to,
+                        amount,
+                        asset_id,
+                        ..
+                    } => (
+                        TransferKind::TransferOut,
+                        contract(id),
+                        Some(Identity::Address(*to)),
+                        *asset_id,
+                        *amount,
+                    ),
+                    Receipt::Mint {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => (
+                        TransferKind::Mint,
+                        None,
+                        Some(Identity::ContractId(*contract_id)),
+                        fuel_tx::ContractIdExt::asset_id(contract_id, sub_id),
+                        *val,
+                    ),
+                    Receipt::Burn {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => (
+                        TransferKind::Burn,
+                        Some(Identity::ContractId(*contract_id)),
+                        None,
+                        fuel_tx::ContractIdExt::asset_id(contract_id, sub_id),
+                        *val,
+                    ),
+                    Receipt::MessageOut {
+                        sender,
+                        recipient,
+                        amount,
+                        ..
+                    } if *amount > 0 => (
+                        TransferKind::Withdrawal,
+                        Some(Identity::Address(*sender)),
+                        Some(Identity::Address(*recipient)),
+                        base_asset_id,
+                        *amount,
+                    ),
+                    _ => return None,
+                };
+
+                Some(Self {
+                    tx_id,
+                    block_height,
+                    kind,
+                    from,
+                    to,
+                    asset_id,
+                    amount,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Record for TransferRecord {
+    const COLUMNS: &'static [(&'static str, CellType)] = &[
+        ("tx_id", CellType::Text),
+        ("block_height", CellType::Integer),
+        ("kind", CellType::Text),
+        ("from", CellType::Text),
+        ("to", CellType::Text),
+        ("asset_id", CellType::Text),
+        ("amount", CellType::Integer),
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Text(format!("{:#x}", self.tx_id)),
+            Cell::Integer(self.block_height.map(u64::from)),
+            Cell::Text(self.kind.as_str().to_string()),
+            Cell::Text(self.from.as_ref().map(identity_hex).unwrap_or_default()),
+            Cell::Text(self.to.as_ref().map(identity_hex).unwrap_or_default()),
+            Cell::Text(format!("{:#x}", self.asset_id)),
+            Cell::Integer(Some(self.amount)),
+        ]
+    }
+}
+
+/// The balance of an asset held by an address or a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceRecord {
+    pub owner: Identity,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+impl BalanceRecord {
+    /// One record per asset of `balances`, as returned by `Provider::get_balances` or
+    /// `Provider::get_contract_balances`. The records are sorted by asset id.
+    pub fn from_balances<K: Display>(
+        owner: Identity,
+        balances: impl IntoIterator<Item = (K, u64)>,
+    ) -> Result<Vec<Self>> {
+        let mut records = balances
+            .into_iter()
+            .map(|(asset_id, amount)| {
+                let asset_id = AssetId::from_str(&asset_id.to_string())
+                    .map_err(|e| error!(Other, "invalid asset id `{asset_id}`: {e}"))?;
+
+                Ok(Self {
+                    owner,
+                    asset_id,
+                    amount,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        records.sort_by_key(|record| record.asset_id);
+
+        Ok(records)
+    }
+}
+
+impl Record for BalanceRecord {
+    const COLUMNS: &'static [(&'static str, CellType)] = &[
+        ("owner", CellType::Text),
+        ("asset_id", CellType::Text),
+        ("amount", CellType::Integer),
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Text(identity_hex(&self.owner)),
+            Cell::Text(format!("{:#x}", self.asset_id)),
+            Cell::Integer(Some(self.amount)),
+        ]
+    }
+}
+
+fn identity_hex(identity: &Identity) -> String {
+    match identity {
+        Identity::Address(address) => format!("{address:#x}"),
+        Identity::ContractId(contract_id) => format!("{contract_id:#x}"),
+    }
+}
+
+/// Writes `records` as CSV, preceded by a header row.
+pub fn write_csv<R: Record>(records: &[R], writer: impl Write) -> Result<()> {
+    let to_error = |e: csv::Error| error!(IO, "cannot write CSV: {e}");
+    let mut writer = csv::Writer::from_writer(writer);
+
+    writer
+        .write_record(R::COLUMNS.iter().map(|(name, _)| name))
+        .map_err(to_error)?;
+    for record in records {
+        writer
+            .write_record(record.cells().iter().map(Cell::to_string))
+            .map_err(to_error)?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| error!(IO, "cannot write CSV: {e}"))
+}
+
+/// Writes `records` as a Parquet file holding a single row group. Text columns are stored as
+/// UTF-8 strings and integer columns as nullable `UInt64`.
+#[cfg(feature = "accounting-parquet")]
+pub fn write_parquet<R: Record>(records: &[R], writer: impl Write + Send) -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, RecordBatch, StringArray, UInt64Array};
+    use arrow_schema::{DataType, Field, Schema};
+    use parquet::arrow::ArrowWriter;
+
+    let to_error = |e: &dyn std::fmt::Display| error!(IO, "cannot write Parquet: {e}");
+
+    let schema = Arc::new(Schema::new(
+        R::COLUMNS
+            .iter()
+            .map(|(name, cell_type)| {
+                let data_type = match cell_type {
+                    CellType::Text => DataType::Utf8,
+                    CellType::Integer => DataType::UInt64,
+                };
+
+                Field::new(*name, data_type, true)
+            })
+            .collect::<Vec<_>>(),
+    ));
+
+    let rows = records.iter().map(Record::cells).collect::<Vec<_>>();
+    let columns =
+        R::COLUMNS
+            .iter()
+            .enumerate()
+            .map(|(column, (_, cell_type))| -> ArrayRef {
+                match cell_type {
+                    CellType::Text => Arc::new(StringArray::from_iter(rows.iter().map(|row| {
+                        match &row[column] {
+                            Cell::Text(text) => Some(text.as_str()),
+                            Cell::Integer(_) => None,
+                        }
+                    }))),
+                    CellType::Integer => Arc::new(UInt64Array::from_iter(rows.iter().map(|row| {
+                        match &row[column] {
+                            Cell::Integer(value) => *value,
+                            Cell::Text(_) => None,
+                        }
+                    }))),
+                }
+            })
+            .collect::<Vec<_>>();
+
+    let batch = RecordBatch::try_new(schema.clone(), columns).map_err(|e| to_error(&e))?;
+    let mut writer = ArrowWriter::try_new(writer, schema, None).map_err(|e| to_error(&e))?;
+    writer.write(&batch).map_err(|e| to_error(&e))?;
+    writer.close().map_err(|e| to_error(&e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use fuel_tx::Bytes32;
+    use fuel_types::Address;
+
+    use super::*;
+
+    #[test]
+    fn asset_movements_are_taken_from_receipts() {
+        // given
+        let tx_id = TxId::from([1; 32]);
+        let contract_id = ContractId::from([2; 32]);
+        let recipient = Address::from([3; 32]);
+        let asset_id = AssetId::from([4; 32]);
+        let base_asset_id = AssetId::from([5; 32]);
+        let receipts = [
+            Receipt::call(
+                ContractId::zeroed(),
+                contract_id,
+                0,
+                asset_id,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ),
+            Receipt::transfer_out(contract_id, recipient, 100, asset_id, 0, 0),
+            Receipt::mint(Bytes32::zeroed(), contract_id, 50, 0, 0),
+            Receipt::ret(contract_id, 0, 0, 0),
+            Receipt::message_out(
+                &Bytes32::zeroed(),
+                0,
+                Address::zeroed(),
+                recipient,
+                20,
+                vec![],
+            ),
+        ];
+
+        // when
+        let records = TransferRecord::from_receipts(tx_id, Some(7), &receipts, base_asset_id);
+
+        // then
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                TransferKind::TransferOut,
+                TransferKind::Mint,
+                TransferKind::Withdrawal
+            ]
+        );
+        assert_eq!(records[0].from, Some(Identity::ContractId(contract_id)));
+        assert_eq!(records[0].to, Some(Identity::Address(recipient)));
+        assert_eq!(records[0].amount, 100);
+        assert_eq!(records[1].from, None);
+        assert_eq!(records[2].asset_id, base_asset_id);
+    }
+
+    #[test]
+    fn csv_has_a_header_and_a_row_per_record() -> Result<()> {
+        // given
+        let owner = Identity::Address(Address::zeroed());
+        let balances = HashMap::from([(format!("{:#x}", AssetId::zeroed()), 10)]);
+        let records = BalanceRecord::from_balances(owner, balances)?;
+
+        // when
+        let mut csv = vec![];
+        write_csv(&records, &mut csv)?;
+
+        // then
+        let zeroes = format!("0x{}", "0".repeat(64));
+        let expected = format!("owner,asset_id,amount\n{zeroes},{zeroes},10\n");
+        assert_eq!(String::from_utf8(csv).unwrap(), expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "accounting-parquet")]
+    #[test]
+    fn parquet_is_written() -> Result<()> {
+        let records = TransferRecord::from_receipts(
+            TxId::zeroed(),
+            None,
+            &[Receipt::burn(
+                Bytes32::zeroed(),
+                ContractId::zeroed(),
+                5,
+                0,
+                0,
+            )],
+            AssetId::zeroed(),
+        );
+
+        let mut parquet = vec![];
+        write_parquet(&records, &mut parquet)?;
+
+        assert!(parquet.starts_with(b"PAR1") && parquet.ends_with(b"PAR1"));
+
+        Ok(())
+    }
+}
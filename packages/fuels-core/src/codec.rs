@@ -1,13 +1,19 @@
+mod abi_compliance;
 mod abi_decoder;
 mod abi_encoder;
+mod abi_registry;
 mod function_selector;
 mod logs;
+mod stream_decoder;
 mod utils;
 
+pub use abi_compliance::*;
 pub use abi_decoder::*;
 pub use abi_encoder::*;
+pub use abi_registry::*;
 pub use function_selector::*;
 pub use logs::*;
+pub use stream_decoder::*;
 
 use crate::{
     traits::{Parameterize, Tokenizable},
@@ -0,0 +1,393 @@
+use std::{collections::HashMap, fmt::Display};
+
+use fuel_abi_types::abi::program::{ProgramABI, TypeApplication, TypeDeclaration};
+
+use crate::{
+    codec::DecoderConfig,
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+    },
+};
+
+/// Where in the ABI a finding was made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiLocation {
+    FunctionInput { function: String, input: String },
+    FunctionOutput { function: String },
+    LoggedType { log_id: String },
+    Configurable { name: String },
+}
+
+impl Display for AbiLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionInput { function, input } => {
+                write!(f, "input `{input}` of function `{function}`")
+            }
+            Self::FunctionOutput { function } => write!(f, "output of function `{function}`"),
+            Self::LoggedType { log_id } => write!(f, "logged type with id `{log_id}`"),
+            Self::Configurable { name } => write!(f, "configurable `{name}`"),
+        }
+    }
+}
+
+/// ABI features whose support depends on where they are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiFeature {
+    /// The type could not be mapped to a type known by the SDK.
+    TypeResolution,
+    /// `Vec`, `Bytes`, `String`, `raw_slice` or `str`, possibly nested in other types.
+    HeapType,
+    /// An enum with a variant that is itself an enum.
+    NestedEnum,
+    /// The type nests deeper than the default `DecoderConfig` allows.
+    NestingDepth,
+}
+
+impl Display for AbiFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::TypeResolution => "type resolution",
+            Self::HeapType => "heap types",
+            Self::NestedEnum => "nested enums",
+            Self::NestingDepth => "nesting depth",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFinding {
+    pub location: AbiLocation,
+    pub feature: AbiFeature,
+    pub supported: bool,
+    pub note: String,
+}
+
+impl Display for AbiFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = if self.supported {
+            "supported"
+        } else {
+            "unsupported"
+        };
+        write!(
+            f,
+            "[{status}] {} in {}: {}",
+            self.feature, self.location, self.note
+        )
+    }
+}
+
+/// Describes which features of an ABI are supported by this version of the SDK.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbiComplianceReport {
+    pub findings: Vec<AbiFinding>,
+}
+
+impl AbiComplianceReport {
+    pub fn is_fully_supported(&self) -> bool {
+        self.findings.iter().all(|finding| finding.supported)
+    }
+
+    pub fn unsupported(&self) -> impl Iterator<Item = &AbiFinding> {
+        self.findings.iter().filter(|finding| !finding.supported)
+    }
+}
+
+impl Display for AbiComplianceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for finding in &self.findings {
+            writeln!(f, "{finding}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Analyzes a JSON ABI and reports which of the features it uses are supported by this version
+/// of the SDK. Meant to be run in CI to catch unsupported patterns before they fail at runtime.
+///
+/// ```
+/// use fuels_core::codec::abi_compliance_report;
+///
+/// # fn main() -> fuels_core::types::errors::Result<()> {
+/// let abi = r#"{"types": [], "functions": [], "loggedTypes": [], "messagesTypes": [], "configurables": []}"#;
+/// let report = abi_compliance_report(abi)?;
+///
+/// assert!(report.is_fully_supported());
+/// # Ok(())
+/// # }
+/// ```
+pub fn abi_compliance_report(json_abi: &str) -> Result<AbiComplianceReport> {
+    let abi: ProgramABI = serde_json::from_str(json_abi)
+        .map_err(|e| error!(Codec, "failed to parse JSON ABI: {e}"))?;
+
+    let type_lookup = abi
+        .types
+        .iter()
+        .map(|decl| (decl.type_id, decl.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let mut locations = vec![];
+    for function in &abi.functions {
+        for input in &function.inputs {
+            let location = AbiLocation::FunctionInput {
+                function: function.name.clone(),
+                input: input.name.clone(),
+            };
+            locations.push((location, input));
+        }
+        let location = AbiLocation::FunctionOutput {
+            function: function.name.clone(),
+        };
+        locations.push((location, &function.output));
+    }
+    for logged_type in abi.logged_types.iter().flatten() {
+        let location = AbiLocation::LoggedType {
+            log_id: logged_type.log_id.clone(),
+        };
+        locations.push((location, &logged_type.application));
+    }
+    for configurable in abi.configurables.iter().flatten() {
+        let location = AbiLocation::Configurable {
+            name: configurable.name.clone(),
+        };
+        locations.push((location, &configurable.application));
+    }
+
+    let findings = locations
+        .into_iter()
+        .flat_map(|(location, application)| analyze(location, application, &type_lookup))
+        .collect();
+
+    Ok(AbiComplianceReport { findings })
+}
+
+fn analyze(
+    location: AbiLocation,
+    application: &TypeApplication,
+    type_lookup: &HashMap<usize, TypeDeclaration>,
+) -> Vec<AbiFinding> {
+    let param_type = match ParamType::try_from_type_application(application, type_lookup) {
+        Ok(param_type) => param_type,
+        Err(e) => {
+            return vec![AbiFinding {
+                location,
+                feature: AbiFeature::TypeResolution,
+                supported: false,
+                note: e.to_string(),
+            }]
+        }
+    };
+
+    let mut findings = vec![];
+
+    if contains_heap_type(&param_type) {
+        let (supported, note) = match location {
+            AbiLocation::Configurable { .. } => (
+                false,
+                "configurables are written at fixed offsets in the binary, so their size must be known in advance",
+            ),
+            _ => (true, "encoded and decoded dynamically"),
+        };
+        findings.push(AbiFinding {
+            location: location.clone(),
+            feature: AbiFeature::HeapType,
+            supported,
+            note: note.to_string(),
+        });
+    }
+
+    if contains_nested_enum(&param_type) {
+        findings.push(AbiFinding {
+            location: location.clone(),
+            feature: AbiFeature::NestedEnum,
+            supported: true,
+            note: "enum variants are decoded recursively".to_string(),
+        });
+    }
+
+    let depth = nesting_depth(&param_type);
+    let max_depth = DecoderConfig::default().max_depth;
+    if depth > max_depth {
+        findings.push(AbiFinding {
+            location,
+            feature: AbiFeature::NestingDepth,
+            supported: false,
+            note: format!(
+                "depth {depth} exceeds the default `DecoderConfig::max_depth` of {max_depth}. Use a `DecoderConfig` with a `max_depth` of at least {depth}"
+            ),
+        });
+    }
+
+    findings
+}
+
+fn children(param_type: &ParamType) -> Vec<&ParamType> {
+    match param_type {
+        ParamType::Array(inner, _) | ParamType::Vector(inner) => vec![inner.as_ref()],
+        ParamType::Tuple(elements) => elements.iter().collect(),
+        ParamType::Struct { fields, .. } => fields.iter().map(|(_, field)| field).collect(),
+        ParamType::Enum { enum_variants, .. } => enum_variants.param_types().collect(),
+        _ => vec![],
+    }
+}
+
+fn contains_heap_type(param_type: &ParamType) -> bool {
+    matches!(
+        param_type,
+        ParamType::Vector(_)
+            | ParamType::Bytes
+            | ParamType::String
+            | ParamType::RawSlice
+            | ParamType::StringSlice
+    ) || children(param_type).into_iter().any(contains_heap_type)
+}
+
+fn contains_nested_enum(param_type: &ParamType) -> bool {
+    let is_enum = |param_type: &ParamType| matches!(param_type, ParamType::Enum { .. });
+
+    let nested_here = is_enum(param_type) && children(param_type).into_iter().any(is_enum);
+
+    nested_here || children(param_type).into_iter().any(contains_nested_enum)
+}
+
+/// Mirrors how the decoder counts depth: entering a struct, array, tuple, enum or vector
+/// increases it.
+fn nesting_depth(param_type: &ParamType) -> usize {
+    let children_depth = children(param_type)
+        .into_iter()
+        .map(nesting_depth)
+        .max()
+        .unwrap_or_default();
+
+    match param_type {
+        ParamType::Array(..)
+        | ParamType::Vector(_)
+        | ParamType::Tuple(_)
+        | ParamType::Struct { .. }
+        | ParamType::Enum { .. } => children_depth + 1,
+        _ => children_depth,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"{
+        "types": [
+            { "typeId": 0, "type": "()", "components": [], "typeParameters": null },
+            { "typeId": 1, "type": "u64", "components": null, "typeParameters": null },
+            {
+                "typeId": 2,
+                "type": "enum Inner",
+                "components": [
+                    { "name": "A", "type": 1, "typeArguments": null },
+                    { "name": "B", "type": 0, "typeArguments": null }
+                ],
+                "typeParameters": null
+            },
+            {
+                "typeId": 3,
+                "type": "enum Outer",
+                "components": [
+                    { "name": "Nested", "type": 2, "typeArguments": null },
+                    { "name": "Plain", "type": 1, "typeArguments": null }
+                ],
+                "typeParameters": null
+            },
+            { "typeId": 4, "type": "struct std::bytes::Bytes", "components": [], "typeParameters": null },
+            { "typeId": 5, "type": "unknown type", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [ { "name": "arg", "type": 4, "typeArguments": null } ],
+                "name": "nested",
+                "output": { "name": "", "type": 3, "typeArguments": null },
+                "attributes": null
+            },
+            {
+                "inputs": [],
+                "name": "unknown",
+                "output": { "name": "", "type": 5, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": [
+            {
+                "name": "BYTES",
+                "configurableType": { "name": "", "type": 4, "typeArguments": null },
+                "offset": 100
+            },
+            {
+                "name": "NUMBER",
+                "configurableType": { "name": "", "type": 1, "typeArguments": null },
+                "offset": 108
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn reports_features_per_location() -> Result<()> {
+        // when
+        let report = abi_compliance_report(ABI)?;
+
+        // then
+        let summary = report
+            .findings
+            .iter()
+            .map(|finding| (&finding.location, finding.feature, finding.supported))
+            .collect::<Vec<_>>();
+
+        let input = AbiLocation::FunctionInput {
+            function: "nested".to_string(),
+            input: "arg".to_string(),
+        };
+        let output = AbiLocation::FunctionOutput {
+            function: "nested".to_string(),
+        };
+        let unknown_output = AbiLocation::FunctionOutput {
+            function: "unknown".to_string(),
+        };
+        let configurable = AbiLocation::Configurable {
+            name: "BYTES".to_string(),
+        };
+        assert_eq!(
+            summary,
+            vec![
+                (&input, AbiFeature::HeapType, true),
+                (&output, AbiFeature::NestedEnum, true),
+                (&unknown_output, AbiFeature::TypeResolution, false),
+                (&configurable, AbiFeature::HeapType, false),
+            ]
+        );
+        assert!(!report.is_fully_supported());
+        assert_eq!(report.unsupported().count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nesting_depth_is_counted_like_the_decoder() {
+        let param_type = ParamType::Vector(Box::new(ParamType::Array(
+            Box::new(ParamType::Tuple(vec![ParamType::U8, ParamType::Bool])),
+            2,
+        )));
+
+        assert_eq!(nesting_depth(&param_type), 3);
+        assert_eq!(nesting_depth(&ParamType::U64), 0);
+    }
+
+    #[test]
+    fn invalid_json_is_rejected() {
+        let err = abi_compliance_report("not an abi").expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .starts_with("codec: failed to parse JSON ABI"));
+    }
+}
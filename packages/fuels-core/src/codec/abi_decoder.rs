@@ -1,10 +1,13 @@
 mod bounded_decoder;
 mod decode_as_debug_str;
 
+pub(crate) use bounded_decoder::is_not_enough_bytes;
+
 use crate::{
     codec::abi_decoder::{
         bounded_decoder::BoundedDecoder, decode_as_debug_str::decode_as_debug_str,
     },
+    metrics,
     types::{errors::Result, param_types::ParamType, Token},
 };
 
@@ -44,7 +47,7 @@ impl ABIDecoder {
     /// # Arguments
     ///
     /// * `param_type`: The `ParamType` of the type we expect is encoded
-    ///                  inside `bytes`.
+    ///   inside `bytes`.
     /// * `bytes`:       The bytes to be used in the decoding process.
     /// # Examples
     ///
@@ -60,7 +63,24 @@ impl ABIDecoder {
     /// assert_eq!(u64::from_token(token).unwrap(), 7u64);
     /// ```
     pub fn decode(&self, param_type: &ParamType, bytes: &[u8]) -> Result<Token> {
-        BoundedDecoder::new(self.config).decode(param_type, bytes)
+        let token = BoundedDecoder::new(self.config).decode(param_type, bytes)?;
+        metrics::increment_counter(metrics::BYTES_DECODED, &[], bytes.len() as u64);
+
+        Ok(token)
+    }
+
+    /// Same as `decode` but also returns how many bytes, from the start of `bytes`, the token was
+    /// decoded from. Useful when `bytes` holds more than one value.
+    pub fn decode_with_bytes_read(
+        &self,
+        param_type: &ParamType,
+        bytes: &[u8],
+    ) -> Result<(Token, usize)> {
+        let (token, bytes_read) =
+            BoundedDecoder::new(self.config).decode_with_bytes_read(param_type, bytes)?;
+        metrics::increment_counter(metrics::BYTES_DECODED, &[], bytes_read as u64);
+
+        Ok((token, bytes_read))
     }
 
     /// Same as `decode` but decodes multiple `ParamType`s in one go.
@@ -78,7 +98,10 @@ impl ABIDecoder {
     /// assert_eq!(tokens, vec![Token::U8(7), Token::U8(8)]);
     /// ```
     pub fn decode_multiple(&self, param_types: &[ParamType], bytes: &[u8]) -> Result<Vec<Token>> {
-        BoundedDecoder::new(self.config).decode_multiple(param_types, bytes)
+        let tokens = BoundedDecoder::new(self.config).decode_multiple(param_types, bytes)?;
+        metrics::increment_counter(metrics::BYTES_DECODED, &[], bytes.len() as u64);
+
+        Ok(tokens)
     }
 
     /// Decodes `bytes` following the schema described in `param_type` into its respective debug
@@ -87,7 +110,7 @@ impl ABIDecoder {
     /// # Arguments
     ///
     /// * `param_type`: The `ParamType` of the type we expect is encoded
-    ///                  inside `bytes`.
+    ///   inside `bytes`.
     /// * `bytes`:       The bytes to be used in the decoding process.
     /// # Examples
     ///
@@ -231,6 +254,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn bytes_read_include_the_length_prefix() -> Result<()> {
+        let data = [0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i', 255];
+
+        let (_, bytes_read) =
+            ABIDecoder::default().decode_with_bytes_read(&ParamType::StringSlice, &data)?;
+
+        assert_eq!(bytes_read, 10);
+
+        Ok(())
+    }
+
     #[test]
     fn decode_string() -> Result<()> {
         let data = [
@@ -7,7 +7,7 @@ use crate::{
     },
     constants::WORD_SIZE,
     types::{
-        errors::{error, Result},
+        errors::{error, Error, Result},
         param_types::{EnumVariants, NamedParamType, ParamType},
         StaticStringToken, Token, U256,
     },
@@ -46,6 +46,16 @@ impl BoundedDecoder {
         self.decode_param(param_type, bytes).map(|x| x.token)
     }
 
+    /// Same as `decode` but also returns how many bytes the token was decoded from.
+    pub(crate) fn decode_with_bytes_read(
+        &mut self,
+        param_type: &ParamType,
+        bytes: &[u8],
+    ) -> Result<(Token, usize)> {
+        self.decode_param(param_type, bytes)
+            .map(|decoded| (decoded.token, decoded.bytes_read))
+    }
+
     pub(crate) fn decode_multiple(
         &mut self,
         param_types: &[ParamType],
@@ -215,7 +225,7 @@ impl BoundedDecoder {
 
         Ok(Decoded {
             token: Token::StringSlice(StaticStringToken::new(decoded, None)),
-            bytes_read: bytes.len(),
+            bytes_read: LENGTH_BYTES_SIZE + bytes.len(),
         })
     }
 
@@ -346,10 +356,18 @@ fn peek_discriminant(bytes: &[u8]) -> Result<u64> {
     Ok(u64::from_be_bytes(*slice))
 }
 
+const NOT_ENOUGH_BYTES: &str = "not enough bytes";
+
+/// Whether decoding failed only because the input ended too early, i.e. more bytes could make it
+/// succeed.
+pub(crate) fn is_not_enough_bytes(err: &Error) -> bool {
+    matches!(err, Error::Codec(msg) if msg.starts_with(NOT_ENOUGH_BYTES))
+}
+
 fn peek(data: &[u8], len: usize) -> Result<&[u8]> {
     (len <= data.len()).then(|| &data[..len]).ok_or(error!(
         Codec,
-        "tried to read `{len}` bytes but only had `{}` remaining!",
+        "{NOT_ENOUGH_BYTES}: tried to read `{len}` bytes but only had `{}` remaining!",
         data.len()
     ))
 }
@@ -366,7 +384,7 @@ fn skip(slice: &[u8], num_bytes: usize) -> Result<&[u8]> {
         .then_some(&slice[num_bytes..])
         .ok_or(error!(
             Codec,
-            "tried to consume `{num_bytes}` bytes but only had `{}` remaining!",
+            "{NOT_ENOUGH_BYTES}: tried to consume `{num_bytes}` bytes but only had `{}` remaining!",
             slice.len()
         ))
 }
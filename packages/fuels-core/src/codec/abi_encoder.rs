@@ -4,6 +4,7 @@ use std::default::Default;
 
 use crate::{
     codec::abi_encoder::bounded_encoder::BoundedEncoder,
+    metrics,
     types::{errors::Result, Token},
 };
 
@@ -41,7 +42,10 @@ impl ABIEncoder {
     /// Encodes `Token`s following the ABI specs defined
     /// [here](https://github.com/FuelLabs/fuel-specs/blob/master/specs/protocol/abi.md)
     pub fn encode(&self, tokens: &[Token]) -> Result<Vec<u8>> {
-        BoundedEncoder::new(self.config).encode(tokens)
+        let encoded = BoundedEncoder::new(self.config).encode(tokens)?;
+        metrics::increment_counter(metrics::BYTES_ENCODED, &[], encoded.len() as u64);
+
+        Ok(encoded)
     }
 }
 
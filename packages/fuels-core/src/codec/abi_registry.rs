@@ -0,0 +1,306 @@
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, PoisonError, RwLock},
+};
+
+use fuel_abi_types::abi::program::ProgramABI;
+use fuel_types::ContractId;
+
+use crate::{
+    codec::{ABIDecoder, DecoderConfig},
+    types::{
+        errors::{error, Result},
+        param_types::{NamedParamType, ParamType},
+        Token,
+    },
+};
+
+/// A contract method as declared in a JSON ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    pub name: String,
+    pub inputs: Vec<NamedParamType>,
+    pub output: ParamType,
+}
+
+/// The parts of a contract's JSON ABI needed to make sense of its calls and logs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractAbi {
+    functions: HashMap<String, AbiFunction>,
+    logged_types: HashMap<u64, ParamType>,
+}
+
+impl ContractAbi {
+    pub fn from_json(json_abi: &str) -> Result<Self> {
+        let abi: ProgramABI = serde_json::from_str(json_abi)
+            .map_err(|e| error!(Codec, "failed to parse JSON ABI: {e}"))?;
+
+        let type_lookup = abi
+            .types
+            .iter()
+            .map(|decl| (decl.type_id, decl.clone()))
+            .collect::<HashMap<_, _>>();
+
+        let functions = abi
+            .functions
+            .iter()
+            .map(|function| {
+                let inputs = function
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let param_type = ParamType::try_from_type_application(input, &type_lookup)?;
+                        Ok((input.name.clone(), param_type))
+                    })
+                    .collect::<Result<_>>()?;
+
+                let function = AbiFunction {
+                    name: function.name.clone(),
+                    inputs,
+                    output: ParamType::try_from_type_application(&function.output, &type_lookup)?,
+                };
+
+                Ok((function.name.clone(), function))
+            })
+            .collect::<Result<_>>()?;
+
+        let logged_types = abi
+            .logged_types
+            .iter()
+            .flatten()
+            .map(|logged_type| {
+                let log_id = logged_type.log_id.parse::<u64>().map_err(|_| {
+                    error!(Codec, "log id `{}` is not a number", logged_type.log_id)
+                })?;
+
+                Ok((
+                    log_id,
+                    ParamType::try_from_type_application(&logged_type.application, &type_lookup)?,
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            functions,
+            logged_types,
+        })
+    }
+
+    pub fn function(&self, name: &str) -> Option<&AbiFunction> {
+        self.functions.get(name)
+    }
+
+    pub fn logged_type(&self, log_id: u64) -> Option<&ParamType> {
+        self.logged_types.get(&log_id)
+    }
+}
+
+/// The ABIs of the contracts whose calls and logs should be decoded, keyed by contract id. Useful
+/// to make sense of transactions that weren't made through contract bindings, e.g. when
+/// going through the blocks of a chain.
+///
+/// Besides the registries created with [`AbiRegistry::new`], there is one for the whole process,
+/// filled with [`AbiRegistry::register_globally`]. Every registry falls back to it for the
+/// contracts it doesn't know, as do the log decoders of the contract bindings, so the logs and
+/// revert messages of external contracts are decoded without registering them per call.
+#[derive(Debug, Clone, Default)]
+pub struct AbiRegistry {
+    contracts: HashMap<ContractId, ContractAbi>,
+    decoder_config: DecoderConfig,
+}
+
+impl AbiRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
+        self.decoder_config = decoder_config;
+        self
+    }
+
+    /// Registers the JSON ABI of the contract `contract_id`, replacing any ABI registered for it
+    /// before.
+    pub fn register(&mut self, contract_id: impl Into<ContractId>, json_abi: &str) -> Result<()> {
+        let abi = ContractAbi::from_json(json_abi)?;
+        self.contracts.insert(contract_id.into(), abi);
+
+        Ok(())
+    }
+
+    /// Registers the JSON ABI of the contract `contract_id` in the registry of the whole process.
+    pub fn register_globally(contract_id: impl Into<ContractId>, json_abi: &str) -> Result<()> {
+        let abi = ContractAbi::from_json(json_abi)?;
+        global_registry()
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contracts
+            .insert(contract_id.into(), abi);
+
+        Ok(())
+    }
+
+    /// A copy of the registry of the whole process.
+    pub fn global() -> Self {
+        global_registry()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The ABI registered for `contract_id` in this registry. Unlike the decoding methods, this
+    /// doesn't look into the global registry.
+    pub fn contract(&self, contract_id: &ContractId) -> Option<&ContractAbi> {
+        self.contracts.get(contract_id)
+    }
+
+    /// Runs `f` on the ABI of `contract_id`, found in this registry or else in the global one.
+    fn with_contract<R>(
+        &self,
+        contract_id: &ContractId,
+        f: impl FnOnce(&ContractAbi, DecoderConfig) -> Option<R>,
+    ) -> Option<R> {
+        if let Some(abi) = self.contract(contract_id) {
+            return f(abi, self.decoder_config);
+        }
+
+        let global = global_registry()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let abi = global.contract(contract_id)?;
+
+        f(abi, self.decoder_config)
+    }
+
+    /// Decodes the arguments of a call to `function` of `contract_id`, paired with their names.
+    /// `None` if the contract or the function isn't known.
+    pub fn decode_call_args(
+        &self,
+        contract_id: &ContractId,
+        function: &str,
+        encoded_args: &[u8],
+    ) -> Option<Result<Vec<(String, Token)>>> {
+        self.with_contract(contract_id, |abi, decoder_config| {
+            let function = abi.function(function)?;
+            let (names, param_types): (Vec<_>, Vec<_>) = function.inputs.iter().cloned().unzip();
+
+            let args = ABIDecoder::new(decoder_config)
+                .decode_multiple(&param_types, encoded_args)
+                .map(|tokens| names.into_iter().zip(tokens).collect());
+
+            Some(args)
+        })
+    }
+
+    /// Decodes the value returned by `function` of `contract_id`. `None` if the contract or the
+    /// function isn't known.
+    pub fn decode_output(
+        &self,
+        contract_id: &ContractId,
+        function: &str,
+        data: &[u8],
+    ) -> Option<Result<Token>> {
+        self.with_contract(contract_id, |abi, decoder_config| {
+            let function = abi.function(function)?;
+
+            Some(ABIDecoder::new(decoder_config).decode(&function.output, data))
+        })
+    }
+
+    /// Decodes a value logged by `contract_id` with the log id `log_id`. `None` if the contract
+    /// or the log id isn't known.
+    pub fn decode_log(
+        &self,
+        contract_id: &ContractId,
+        log_id: u64,
+        data: &[u8],
+    ) -> Option<Result<Token>> {
+        self.with_contract(contract_id, |abi, decoder_config| {
+            let param_type = abi.logged_type(log_id)?;
+
+            Some(ABIDecoder::new(decoder_config).decode(param_type, data))
+        })
+    }
+}
+
+fn global_registry() -> &'static RwLock<AbiRegistry> {
+    static GLOBAL_REGISTRY: OnceLock<RwLock<AbiRegistry>> = OnceLock::new();
+    GLOBAL_REGISTRY.get_or_init(Default::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"{
+        "types": [
+            {"typeId": 0, "type": "()", "components": [], "typeParameters": null},
+            {"typeId": 1, "type": "bool", "components": null, "typeParameters": null},
+            {"typeId": 2, "type": "u64", "components": null, "typeParameters": null}
+        ],
+        "functions": [
+            {
+                "inputs": [
+                    {"name": "amount", "type": 2, "typeArguments": null},
+                    {"name": "burn", "type": 1, "typeArguments": null}
+                ],
+                "name": "transfer",
+                "output": {"name": "", "type": 0, "typeArguments": null},
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [
+            {"logId": "7", "loggedType": {"name": "", "type": 2, "typeArguments": null}}
+        ],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn calls_and_logs_of_registered_contracts_are_decoded() -> Result<()> {
+        // given
+        let contract_id = ContractId::from([1; 32]);
+        let mut registry = AbiRegistry::new();
+        registry.register(contract_id, ABI)?;
+        let encoded_args = [42u64.to_be_bytes().as_slice(), &[1]].concat();
+
+        // when
+        let args = registry.decode_call_args(&contract_id, "transfer", &encoded_args);
+        let log = registry.decode_log(&contract_id, 7, &5u64.to_be_bytes());
+
+        // then
+        let expected_args = vec![
+            ("amount".to_string(), Token::U64(42)),
+            ("burn".to_string(), Token::Bool(true)),
+        ];
+        assert_eq!(args.transpose()?, Some(expected_args));
+        assert_eq!(log.transpose()?, Some(Token::U64(5)));
+
+        let unknown_contract = ContractId::from([2; 32]);
+        assert!(registry
+            .decode_log(&unknown_contract, 7, &5u64.to_be_bytes())
+            .is_none());
+        assert!(registry
+            .decode_call_args(&contract_id, "mint", &encoded_args)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn contracts_missing_from_a_registry_are_looked_up_globally() -> Result<()> {
+        // given
+        let contract_id = ContractId::from([9; 32]);
+        AbiRegistry::register_globally(contract_id, ABI)?;
+
+        // when
+        let log = AbiRegistry::new().decode_log(&contract_id, 7, &5u64.to_be_bytes());
+
+        // then
+        assert_eq!(log.transpose()?, Some(Token::U64(5)));
+        assert!(AbiRegistry::new().contract(&contract_id).is_none());
+        assert!(AbiRegistry::global().contract(&contract_id).is_some());
+
+        Ok(())
+    }
+}
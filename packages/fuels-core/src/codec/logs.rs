@@ -3,12 +3,13 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{Debug, Formatter},
     iter::FilterMap,
+    sync::Arc,
 };
 
 use fuel_tx::{ContractId, Receipt};
 
 use crate::{
-    codec::{ABIDecoder, DecoderConfig},
+    codec::{ABIDecoder, AbiRegistry, DecoderConfig},
     traits::{Parameterize, Tokenizable},
     types::errors::{error, Error, Result},
 };
@@ -17,6 +18,7 @@ use crate::{
 pub struct LogFormatter {
     formatter: fn(DecoderConfig, &[u8]) -> Result<String>,
     type_id: TypeId,
+    type_name: &'static str,
 }
 
 impl LogFormatter {
@@ -24,6 +26,7 @@ impl LogFormatter {
         Self {
             formatter: Self::format_log::<T>,
             type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
         }
     }
 
@@ -43,16 +46,34 @@ impl LogFormatter {
     pub fn format(&self, decoder_config: DecoderConfig, bytes: &[u8]) -> Result<String> {
         (self.formatter)(decoder_config, bytes)
     }
+
+    /// The name of the logged type without its path, e.g. `MyError` or `Vec<u64>`.
+    pub fn type_name(&self) -> String {
+        short_type_name(self.type_name)
+    }
 }
 
 impl Debug for LogFormatter {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("LogFormatter")
             .field("type_id", &self.type_id)
+            .field("type_name", &self.type_name)
             .finish()
     }
 }
 
+/// All the types a program logs, gathered into one type, e.g. the `Events` enum generated by
+/// `abigen!`. See [`LogDecoder::decode_logs_as`].
+pub trait LoggedEvents: Sized {
+    /// Decodes `data` as the type with the id `type_id`, or returns `None` if `Self` doesn't
+    /// cover that type.
+    fn decode_event(
+        type_id: TypeId,
+        decoder_config: DecoderConfig,
+        data: &[u8],
+    ) -> Option<Result<Self>>;
+}
+
 /// Holds a unique log ID
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct LogId(ContractId, String);
@@ -63,6 +84,8 @@ pub struct LogDecoder {
     /// A mapping of LogId and param-type
     log_formatters: HashMap<LogId, LogFormatter>,
     decoder_config: DecoderConfig,
+    /// Consulted for the logs no formatter is known for, falling back to the global registry.
+    abi_registry: Option<Arc<AbiRegistry>>,
 }
 
 #[derive(Debug)]
@@ -91,6 +114,7 @@ impl LogDecoder {
         Self {
             log_formatters,
             decoder_config: Default::default(),
+            abi_registry: None,
         }
     }
 
@@ -99,6 +123,13 @@ impl LogDecoder {
         self
     }
 
+    /// Decodes the logs of the contracts this decoder wasn't made for with the ABIs of
+    /// `abi_registry`, instead of only the ones registered globally.
+    pub fn set_abi_registry(&mut self, abi_registry: AbiRegistry) -> &mut Self {
+        self.abi_registry = Some(Arc::new(abi_registry));
+        self
+    }
+
     /// Get all logs results from the given receipts as `Result<String>`
     pub fn decode_logs(&self, receipts: &[Receipt]) -> LogResult {
         let results = receipts
@@ -111,28 +142,54 @@ impl LogDecoder {
     }
 
     fn format_log(&self, log_id: &LogId, data: &[u8]) -> Result<String> {
-        self.log_formatters
-            .get(log_id)
-            .ok_or_else(|| {
-                error!(
-                    Codec,
-                    "missing log formatter for log_id: `{:?}`, data: `{:?}`. \
-                     Consider adding external contracts using `with_contracts()`",
-                    log_id,
-                    data
-                )
-            })
-            .and_then(|log_formatter| log_formatter.format(self.decoder_config, data))
+        if let Some(log_formatter) = self.log_formatters.get(log_id) {
+            return log_formatter.format(self.decoder_config, data);
+        }
+
+        self.format_with_abi_registry(log_id, data).ok_or_else(|| {
+            error!(
+                Codec,
+                "missing log formatter for log_id: `{:?}`, data: `{:?}`. \
+                 Consider adding external contracts using `with_contracts()` \
+                 or registering their ABI in an `AbiRegistry`",
+                log_id,
+                data
+            )
+        })?
     }
 
-    pub(crate) fn decode_last_log(&self, receipts: &[Receipt]) -> Result<String> {
-        receipts
+    /// Logs decoded through an ABI registry are formatted as tokens, their Rust type not being
+    /// known.
+    fn format_with_abi_registry(&self, log_id: &LogId, data: &[u8]) -> Option<Result<String>> {
+        let LogId(contract_id, log_id) = log_id;
+        let log_id = log_id.parse().ok()?;
+
+        let token = match &self.abi_registry {
+            Some(abi_registry) => abi_registry.decode_log(contract_id, log_id, data),
+            None => AbiRegistry::default().decode_log(contract_id, log_id, data),
+        }?;
+
+        Some(token.map(|token| token.to_string()))
+    }
+
+    /// Decodes the last log, along with the name of its type if it was decoded by a formatter.
+    pub(crate) fn decode_last_log_with_type_name(
+        &self,
+        receipts: &[Receipt],
+    ) -> Result<(String, Option<String>)> {
+        let (log_id, data) = receipts
             .iter()
             .rev()
             .extract_log_id_and_data()
             .next()
-            .ok_or_else(|| error!(Codec, "no receipts found for decoding last log"))
-            .and_then(|(log_id, data)| self.format_log(&log_id, &data))
+            .ok_or_else(|| error!(Codec, "no receipts found for decoding last log"))?;
+
+        let type_name = self
+            .log_formatters
+            .get(&log_id)
+            .map(LogFormatter::type_name);
+
+        Ok((self.format_log(&log_id, &data)?, type_name))
     }
 
     pub(crate) fn decode_last_two_logs(&self, receipts: &[Receipt]) -> Result<(String, String)> {
@@ -182,8 +239,25 @@ impl LogDecoder {
             .collect()
     }
 
+    /// Decodes the logs found in `receipts` into `E`, in the order they were logged. Logs of
+    /// types `E` doesn't cover, or that no formatter is known for, are skipped.
+    pub fn decode_logs_as<E: LoggedEvents>(&self, receipts: &[Receipt]) -> Result<Vec<E>> {
+        receipts
+            .iter()
+            .extract_log_id_and_data()
+            .filter_map(|(log_id, data)| {
+                let log_formatter = self.log_formatters.get(&log_id)?;
+
+                E::decode_event(log_formatter.type_id, self.decoder_config, &data)
+            })
+            .collect()
+    }
+
     pub fn merge(&mut self, log_decoder: LogDecoder) {
         self.log_formatters.extend(log_decoder.log_formatters);
+        if self.abi_registry.is_none() {
+            self.abi_registry = log_decoder.abi_registry;
+        }
     }
 }
 
@@ -210,6 +284,25 @@ impl<'a, I: Iterator<Item = &'a Receipt>> ExtractLogIdData for I {
     }
 }
 
+/// Strips the module paths from a name given by `std::any::type_name`, including the ones of
+/// generic arguments.
+pub fn short_type_name(type_name: &str) -> String {
+    let mut short = String::with_capacity(type_name.len());
+    let mut segment = String::new();
+    for c in type_name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+
+    short
+}
+
 pub fn log_formatters_lookup(
     log_id_log_formatter_pairs: Vec<(String, LogFormatter)>,
     contract_id: ContractId,
@@ -219,3 +312,133 @@ pub fn log_formatters_lookup(
         .map(|(id, log_formatter)| (LogId(contract_id, id), log_formatter))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Events {
+        Flag(bool),
+        Amount(u64),
+    }
+
+    impl LoggedEvents for Events {
+        fn decode_event(
+            type_id: TypeId,
+            decoder_config: DecoderConfig,
+            data: &[u8],
+        ) -> Option<Result<Self>> {
+            let decode = |param_type| ABIDecoder::new(decoder_config).decode(&param_type, data);
+
+            if type_id == TypeId::of::<bool>() {
+                Some(
+                    decode(bool::param_type())
+                        .and_then(bool::from_token)
+                        .map(Self::Flag),
+                )
+            } else if type_id == TypeId::of::<u64>() {
+                Some(
+                    decode(u64::param_type())
+                        .and_then(u64::from_token)
+                        .map(Self::Amount),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    fn log_data(contract_id: ContractId, log_id: u64, data: Vec<u8>) -> Receipt {
+        Receipt::log_data_with_len(
+            contract_id,
+            0,
+            log_id,
+            0,
+            data.len() as u64,
+            Default::default(),
+            0,
+            0,
+            Some(data),
+        )
+    }
+
+    #[test]
+    fn logs_are_decoded_into_one_type() -> Result<()> {
+        // given
+        let contract_id = ContractId::from([1; 32]);
+        let decoder = LogDecoder::new(log_formatters_lookup(
+            vec![
+                ("1".to_string(), LogFormatter::new::<bool>()),
+                ("2".to_string(), LogFormatter::new::<u64>()),
+                ("3".to_string(), LogFormatter::new::<u8>()),
+            ],
+            contract_id,
+        ));
+        let receipts = [
+            log_data(contract_id, 2, 7u64.to_be_bytes().to_vec()),
+            log_data(contract_id, 3, vec![1]),
+            log_data(ContractId::from([2; 32]), 2, 9u64.to_be_bytes().to_vec()),
+            log_data(contract_id, 1, vec![1]),
+        ];
+
+        // when
+        let events = decoder.decode_logs_as::<Events>(&receipts)?;
+
+        // then
+        assert_eq!(events, vec![Events::Amount(7), Events::Flag(true)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn logs_of_external_contracts_are_decoded_with_the_abi_registry() -> Result<()> {
+        // given
+        let contract_id = ContractId::from([1; 32]);
+        let external_id = ContractId::from([3; 32]);
+        let mut abi_registry = AbiRegistry::new();
+        abi_registry.register(
+            external_id,
+            r#"{
+                "types": [{"typeId": 0, "type": "u64", "components": null, "typeParameters": null}],
+                "functions": [],
+                "loggedTypes": [
+                    {"logId": "5", "loggedType": {"name": "", "type": 0, "typeArguments": null}}
+                ],
+                "messagesTypes": [],
+                "configurables": []
+            }"#,
+        )?;
+        let mut decoder = LogDecoder::new(log_formatters_lookup(
+            vec![("2".to_string(), LogFormatter::new::<u64>())],
+            contract_id,
+        ));
+        decoder.set_abi_registry(abi_registry);
+
+        let receipts = [
+            log_data(contract_id, 2, 7u64.to_be_bytes().to_vec()),
+            log_data(external_id, 5, 9u64.to_be_bytes().to_vec()),
+            log_data(ContractId::from([4; 32]), 5, 9u64.to_be_bytes().to_vec()),
+        ];
+
+        // when
+        let logs = decoder.decode_logs(&receipts);
+
+        // then
+        assert_eq!(logs.filter_succeeded(), vec!["7", "U64(9)"]);
+        assert_eq!(logs.filter_failed().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_names_are_shortened() {
+        assert_eq!(short_type_name("u64"), "u64");
+        assert_eq!(short_type_name("my_crate::errors::MyError"), "MyError");
+        assert_eq!(
+            short_type_name("alloc::vec::Vec<my_crate::Pair<u8, my_crate::Id>>"),
+            "Vec<Pair<u8, Id>>"
+        );
+        assert_eq!(LogFormatter::new::<Vec<u64>>().type_name(), "Vec<u64>");
+    }
+}
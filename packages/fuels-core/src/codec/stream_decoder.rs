@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+
+use crate::{
+    codec::{is_not_enough_bytes, ABIDecoder, DecoderConfig},
+    traits::{Parameterize, Tokenizable},
+    types::errors::Result,
+};
+
+/// Decodes a single `T` from the start of `bytes` using the default [`DecoderConfig`]. Bytes
+/// following the value are ignored.
+///
+/// Handy for values read out of receipts, storage dumps or message payloads:
+///
+/// ```
+/// use fuels_core::codec::decode_as;
+///
+/// let bytes = [0, 0, 0, 0, 0, 0, 0, 7, 255];
+///
+/// assert_eq!(decode_as::<u64>(&bytes).unwrap(), 7);
+/// ```
+pub fn decode_as<T: Parameterize + Tokenizable>(bytes: &[u8]) -> Result<T> {
+    decode_prefix_as(bytes, DecoderConfig::default()).map(|(value, _)| value)
+}
+
+/// Decodes a single `T` from the start of `bytes`, returning it together with the number of bytes
+/// it was encoded in.
+pub fn decode_prefix_as<T: Parameterize + Tokenizable>(
+    bytes: &[u8],
+    config: DecoderConfig,
+) -> Result<(T, usize)> {
+    let (token, bytes_read) =
+        ABIDecoder::new(config).decode_with_bytes_read(&T::param_type(), bytes)?;
+
+    Ok((T::from_token(token)?, bytes_read))
+}
+
+/// Decodes consecutive `T`s from bytes that arrive in chunks.
+///
+/// A value is only returned once all of its bytes have been pushed. Running out of bytes is not
+/// an error, but any other decoding failure is, since more bytes would not fix it.
+#[derive(Debug, Clone)]
+pub struct StreamDecoder<T> {
+    config: DecoderConfig,
+    buffer: Vec<u8>,
+    _value: PhantomData<T>,
+}
+
+impl<T: Parameterize + Tokenizable> Default for StreamDecoder<T> {
+    fn default() -> Self {
+        Self::new(DecoderConfig::default())
+    }
+}
+
+impl<T: Parameterize + Tokenizable> StreamDecoder<T> {
+    pub fn new(config: DecoderConfig) -> Self {
+        Self {
+            config,
+            buffer: vec![],
+            _value: PhantomData,
+        }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Decodes the next value, or returns `None` if its bytes haven't all arrived yet.
+    pub fn next_value(&mut self) -> Result<Option<T>> {
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+
+        match decode_prefix_as(&self.buffer, self.config) {
+            Ok((value, bytes_read)) => {
+                self.buffer.drain(..bytes_read);
+                Ok(Some(value))
+            }
+            Err(err) if is_not_enough_bytes(&err) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Bytes pushed but not yet decoded.
+    pub fn remaining(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codec::{ABIEncoder, EncoderConfig},
+        types::Bits256,
+    };
+
+    #[test]
+    fn values_are_decoded_once_complete() -> Result<()> {
+        // given
+        let encoded = ABIEncoder::new(EncoderConfig::default()).encode(&[
+            (7u64, "fuel".to_string()).into_token(),
+            (8u64, "labs".to_string()).into_token(),
+        ])?;
+        let mut decoder = StreamDecoder::<(u64, String)>::default();
+
+        // when
+        let mut decoded = vec![];
+        for chunk in encoded.chunks(5) {
+            decoder.push(chunk);
+            while let Some(value) = decoder.next_value()? {
+                decoded.push(value);
+            }
+        }
+
+        // then
+        assert_eq!(
+            decoded,
+            vec![(7, "fuel".to_string()), (8, "labs".to_string())]
+        );
+        assert!(decoder.remaining().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn prefix_decoding_reports_bytes_read() -> Result<()> {
+        let mut bytes = vec![0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i'];
+        bytes.extend([1; 32]);
+
+        let (string, bytes_read) = decode_prefix_as::<String>(&bytes, DecoderConfig::default())?;
+        let bits = decode_as::<Bits256>(&bytes[bytes_read..])?;
+
+        assert_eq!(string, "hi");
+        assert_eq!(bytes_read, 10);
+        assert_eq!(bits, Bits256([1; 32]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bytes_are_an_error_even_if_incomplete() {
+        let mut decoder = StreamDecoder::<String>::default();
+        decoder.push(&[0, 0, 0, 0, 0, 0, 0, 2, 0xff, 0xff]);
+
+        assert!(decoder.next_value().is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! Loading settings such as `TxPolicies` or `CallParameters` from TOML or from environment
+//! variables, so they can be tuned per deployment without recompiling.
+
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+use crate::types::errors::{error, Result};
+
+/// Loaders available on every deserializable type. Fields left out keep their defaults for the
+/// SDK's settings, unknown fields are rejected to catch typos.
+pub trait LoadConfig: DeserializeOwned {
+    /// Parses `toml`, e.g. `tip = 10` and `max_fee = 1000` for `TxPolicies`.
+    fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|e| error!(Other, "invalid configuration: {e}"))
+    }
+
+    fn from_toml_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let toml = std::fs::read_to_string(path).map_err(|e| {
+            error!(
+                Other,
+                "cannot read configuration file `{}`: {e}",
+                path.display()
+            )
+        })?;
+
+        Self::from_toml(&toml)
+    }
+
+    /// Reads the variables named `{prefix}_{FIELD}`, e.g. `FUELS_TX_TIP` for the `tip` of
+    /// `TxPolicies` with the prefix `FUELS_TX`. Values are read as TOML values, falling back to
+    /// strings, so `10` is a number and `0x…` an asset id.
+    fn from_env(prefix: &str) -> Result<Self> {
+        let table: toml::Table = std::env::vars()
+            .filter_map(|(name, value)| {
+                let field = name.strip_prefix(prefix)?.strip_prefix('_')?;
+
+                Some((field.to_lowercase(), parse_env_value(&value)))
+            })
+            .collect();
+
+        toml::Value::Table(table).try_into().map_err(|e| {
+            error!(
+                Other,
+                "invalid configuration in `{prefix}_*` variables: {e}"
+            )
+        })
+    }
+}
+
+impl<T: DeserializeOwned> LoadConfig for T {}
+
+fn parse_env_value(value: &str) -> toml::Value {
+    format!("value = {value}")
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{transaction::TxPolicies, transaction_builders::VariableOutputPolicy};
+
+    #[test]
+    fn tx_policies_are_loaded_from_toml() -> Result<()> {
+        // given
+        let toml = r#"
+            tip = 10
+            max_fee = 1000
+        "#;
+
+        // when
+        let policies = TxPolicies::from_toml(toml)?;
+
+        // then
+        assert_eq!(policies.tip(), Some(10));
+        assert_eq!(policies.max_fee(), Some(1000));
+        assert_eq!(policies.script_gas_limit(), None);
+        assert!(TxPolicies::from_toml("tipp = 10").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tx_policies_are_loaded_from_env() -> Result<()> {
+        // given
+        std::env::set_var("FUELS_CONFIG_TEST_SCRIPT_GAS_LIMIT", "50000");
+        std::env::set_var("FUELS_CONFIG_TEST_MATURITY", "12");
+
+        // when
+        let policies = TxPolicies::from_env("FUELS_CONFIG_TEST")?;
+
+        // then
+        assert_eq!(policies.script_gas_limit(), Some(50_000));
+        assert_eq!(policies.maturity(), Some(12));
+        assert_eq!(policies.tip(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn enums_use_snake_case() -> Result<()> {
+        let policy = VariableOutputPolicy::from_toml("exactly = 3")?;
+
+        assert!(matches!(policy, VariableOutputPolicy::Exactly(3)));
+
+        Ok(())
+    }
+}
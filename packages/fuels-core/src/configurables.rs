@@ -0,0 +1,200 @@
+use crate::{
+    codec::{ABIDecoder, DecoderConfig},
+    error,
+    types::{errors::Result, param_types::ParamType, Token},
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Configurables {
+    offsets_with_data: Vec<(u64, Vec<u8>)>,
+}
+
+impl Configurables {
+    pub fn new(offsets_with_data: Vec<(u64, Vec<u8>)>) -> Self {
+        Self { offsets_with_data }
+    }
+
+    /// The encoded values that will be written into the binary, along with their offsets.
+    pub fn offsets_with_data(&self) -> &[(u64, Vec<u8>)] {
+        &self.offsets_with_data
+    }
+
+    pub fn update_constants_in(&self, binary: &mut [u8]) {
+        for (offset, data) in &self.offsets_with_data {
+            let offset = *offset as usize;
+            binary[offset..offset + data.len()].copy_from_slice(data)
+        }
+    }
+}
+
+/// A configurable constant declared in the ABI of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigurableConstant {
+    pub name: String,
+    /// Where the value of the constant starts in the binary.
+    pub offset: u64,
+    pub param_type: ParamType,
+}
+
+impl ConfigurableConstant {
+    /// Reads the value of the constant out of `binary`.
+    pub fn decode_from(&self, binary: &[u8], decoder_config: DecoderConfig) -> Result<Token> {
+        let bytes = usize::try_from(self.offset)
+            .ok()
+            .and_then(|offset| binary.get(offset..))
+            .ok_or_else(|| {
+                error!(
+                    Codec,
+                    "configurable `{}` is at offset {} but the binary is only {} bytes long",
+                    self.name,
+                    self.offset,
+                    binary.len()
+                )
+            })?;
+
+        let (token, _) =
+            ABIDecoder::new(decoder_config).decode_with_bytes_read(&self.param_type, bytes)?;
+
+        Ok(token)
+    }
+}
+
+/// The value a binary holds for a configurable constant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedConfigurable {
+    pub name: String,
+    pub offset: u64,
+    pub value: Token,
+}
+
+/// A configurable constant that has different values in two binaries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigurableChange {
+    pub name: String,
+    pub offset: u64,
+    pub before: Token,
+    pub after: Token,
+}
+
+/// Reads the values of `constants` out of `binary`, e.g. to check which constants a deployed
+/// contract was built with.
+pub fn decode_configurables(
+    constants: &[ConfigurableConstant],
+    binary: &[u8],
+    decoder_config: DecoderConfig,
+) -> Result<Vec<DecodedConfigurable>> {
+    constants
+        .iter()
+        .map(|constant| {
+            Ok(DecodedConfigurable {
+                name: constant.name.clone(),
+                offset: constant.offset,
+                value: constant.decode_from(binary, decoder_config)?,
+            })
+        })
+        .collect()
+}
+
+/// The constants whose values differ between the `before` and `after` binaries.
+pub fn diff_configurables(
+    constants: &[ConfigurableConstant],
+    before: &[u8],
+    after: &[u8],
+    decoder_config: DecoderConfig,
+) -> Result<Vec<ConfigurableChange>> {
+    let mut changes = vec![];
+    for constant in constants {
+        let before = constant.decode_from(before, decoder_config)?;
+        let after = constant.decode_from(after, decoder_config)?;
+
+        if before != after {
+            changes.push(ConfigurableChange {
+                name: constant.name.clone(),
+                offset: constant.offset,
+                before,
+                after,
+            });
+        }
+    }
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constants() -> Vec<ConfigurableConstant> {
+        vec![
+            ConfigurableConstant {
+                name: "FLAG".to_string(),
+                offset: 2,
+                param_type: ParamType::Bool,
+            },
+            ConfigurableConstant {
+                name: "LIMIT".to_string(),
+                offset: 3,
+                param_type: ParamType::U64,
+            },
+        ]
+    }
+
+    #[test]
+    fn constants_are_decoded_from_their_offsets() -> Result<()> {
+        // given
+        let binary = [9, 9, 1, 0, 0, 0, 0, 0, 0, 0, 5, 9];
+
+        // when
+        let decoded = decode_configurables(&constants(), &binary, DecoderConfig::default())?;
+
+        // then
+        assert_eq!(
+            decoded,
+            vec![
+                DecodedConfigurable {
+                    name: "FLAG".to_string(),
+                    offset: 2,
+                    value: Token::Bool(true),
+                },
+                DecodedConfigurable {
+                    name: "LIMIT".to_string(),
+                    offset: 3,
+                    value: Token::U64(5),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_changed_constants_are_reported() -> Result<()> {
+        // given
+        let before = [9, 9, 1, 0, 0, 0, 0, 0, 0, 0, 5];
+        let mut after = before;
+        Configurables::new(vec![(3, 7u64.to_be_bytes().to_vec())]).update_constants_in(&mut after);
+
+        // when
+        let changes = diff_configurables(&constants(), &before, &after, DecoderConfig::default())?;
+
+        // then
+        assert_eq!(
+            changes,
+            vec![ConfigurableChange {
+                name: "LIMIT".to_string(),
+                offset: 3,
+                before: Token::U64(5),
+                after: Token::U64(7),
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn offsets_past_the_binary_are_rejected() {
+        let result = constants()[1].decode_from(&[0; 3], DecoderConfig::default());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,119 @@
+//! Counters and histograms recorded by the SDK, reported to the [`MetricsSink`] installed with
+//! [`set_metrics_sink`]. Recording compiles to nothing unless the `metrics` feature is enabled.
+//!
+//! Names follow the Prometheus conventions: counters end in `_total`, durations are in seconds.
+
+/// Dry runs sent to the node. Labels: `outcome` (`ok` or `error`).
+pub const DRY_RUNS: &str = "fuels_dry_runs_total";
+/// How long dry runs took.
+pub const DRY_RUN_DURATION: &str = "fuels_dry_run_duration_seconds";
+/// Transactions submitted to the node. Labels: `outcome` (`ok` or `error`).
+pub const SUBMISSIONS: &str = "fuels_submissions_total";
+/// How long submissions took, including waiting for the commit when it is awaited.
+pub const SUBMISSION_DURATION: &str = "fuels_submission_duration_seconds";
+/// Requests sent to the node, each retry counted on its own. Labels: `operation`, `outcome`.
+pub const REQUESTS: &str = "fuels_requests_total";
+/// How long requests to the node took. Labels: `operation`.
+pub const REQUEST_DURATION: &str = "fuels_request_duration_seconds";
+/// Calls estimated again after discovering contracts missing from their inputs.
+pub const ESTIMATION_RETRIES: &str = "fuels_estimation_retries_total";
+/// Bytes produced by the ABI encoder.
+pub const BYTES_ENCODED: &str = "fuels_bytes_encoded_total";
+/// Bytes decoded by the ABI decoder.
+pub const BYTES_DECODED: &str = "fuels_bytes_decoded_total";
+
+/// Receives the metrics recorded by the SDK, e.g. to forward them to a Prometheus registry or an
+/// OpenTelemetry meter. Labels are given as `(name, value)` pairs.
+#[cfg(feature = "metrics")]
+pub trait MetricsSink: Send + Sync {
+    fn increment_counter(&self, name: &'static str, labels: &[(&'static str, &str)], value: u64);
+
+    fn record_histogram(&self, name: &'static str, labels: &[(&'static str, &str)], value: f64);
+}
+
+#[cfg(feature = "metrics")]
+static METRICS_SINK: std::sync::OnceLock<Box<dyn MetricsSink>> = std::sync::OnceLock::new();
+
+/// Installs the sink receiving the metrics of the whole process. It can only be set once.
+#[cfg(feature = "metrics")]
+pub fn set_metrics_sink(sink: impl MetricsSink + 'static) -> crate::types::errors::Result<()> {
+    METRICS_SINK
+        .set(Box::new(sink))
+        .map_err(|_| crate::types::errors::error!(Other, "a metrics sink is already installed"))
+}
+
+#[cfg(feature = "metrics")]
+pub fn increment_counter(name: &'static str, labels: &[(&'static str, &str)], value: u64) {
+    if let Some(sink) = METRICS_SINK.get() {
+        sink.increment_counter(name, labels, value);
+    }
+}
+
+#[cfg(feature = "metrics")]
+pub fn record_histogram(name: &'static str, labels: &[(&'static str, &str)], value: f64) {
+    if let Some(sink) = METRICS_SINK.get() {
+        sink.record_histogram(name, labels, value);
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+#[inline(always)]
+pub fn increment_counter(_name: &'static str, _labels: &[(&'static str, &str)], _value: u64) {}
+
+#[cfg(not(feature = "metrics"))]
+#[inline(always)]
+pub fn record_histogram(_name: &'static str, _labels: &[(&'static str, &str)], _value: f64) {}
+
+/// The `outcome` label of a result.
+pub fn outcome<T, E>(result: &Result<T, E>) -> (&'static str, &'static str) {
+    ("outcome", if result.is_ok() { "ok" } else { "error" })
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    type Recorded = Arc<Mutex<Vec<(&'static str, Vec<(&'static str, String)>, f64)>>>;
+
+    struct RecordingSink(Recorded);
+
+    impl MetricsSink for RecordingSink {
+        fn increment_counter(
+            &self,
+            name: &'static str,
+            labels: &[(&'static str, &str)],
+            value: u64,
+        ) {
+            self.record_histogram(name, labels, value as f64)
+        }
+
+        fn record_histogram(
+            &self,
+            name: &'static str,
+            labels: &[(&'static str, &str)],
+            value: f64,
+        ) {
+            let labels = labels
+                .iter()
+                .map(|(name, value)| (*name, value.to_string()))
+                .collect();
+            self.0.lock().unwrap().push((name, labels, value));
+        }
+    }
+
+    #[test]
+    fn metrics_reach_the_installed_sink() {
+        let recorded = Recorded::default();
+        set_metrics_sink(RecordingSink(recorded.clone())).unwrap();
+
+        increment_counter(SUBMISSIONS, &[outcome(&Ok::<_, ()>(()))], 1);
+        record_histogram(SUBMISSION_DURATION, &[], 0.5);
+
+        let recorded = recorded.lock().unwrap();
+        assert!(recorded.contains(&(SUBMISSIONS, vec![("outcome", "ok".to_string())], 1.0)));
+        assert!(recorded.contains(&(SUBMISSION_DURATION, vec![], 0.5)));
+        assert!(set_metrics_sink(RecordingSink(Recorded::default())).is_err());
+    }
+}
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use fuel_crypto::{Message, Signature};
 
-use crate::types::{bech32::Bech32Address, errors::Result};
+use crate::types::{
+    bech32::{Bech32Address, FUEL_BECH32_HRP},
+    errors::Result,
+};
 
 /// Trait for signing transactions and messages
 ///
@@ -12,3 +15,49 @@ pub trait Signer: 'static {
     async fn sign(&self, message: Message) -> Result<Signature>;
     fn address(&self) -> &Bech32Address;
 }
+
+/// Recovers the address behind a [`Signature`], e.g. to check who signed a transaction received
+/// from someone else before sending it.
+pub trait SignatureExt {
+    /// The address whose key signed `message`. A signature of a different message recovers to
+    /// some unrelated address, so compare the result with the expected signer.
+    fn recover_address(&self, message: &Message) -> Result<Bech32Address>;
+}
+
+impl SignatureExt for Signature {
+    fn recover_address(&self, message: &Message) -> Result<Bech32Address> {
+        let public_key = self.recover(message)?;
+
+        Ok(Bech32Address::new(FUEL_BECH32_HRP, public_key.hash()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+
+    #[test]
+    fn recovers_the_address_of_the_signer() -> Result<()> {
+        // given
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, secret_key.public_key().hash());
+        let message = Message::new("signed message");
+        let signature = Signature::sign(&secret_key, &message);
+
+        // when
+        let recovered = signature.recover_address(&message)?;
+        let recovered_other = signature.recover_address(&Message::new("another message"))?;
+
+        // then
+        assert_eq!(recovered, address);
+        assert_ne!(recovered_other, address);
+
+        Ok(())
+    }
+}
@@ -8,15 +8,29 @@ pub use crate::types::{core::*, token::*, wrappers::*};
 use crate::{error, types::errors::Result};
 
 pub mod bech32;
+mod coin_selection;
+mod contract_graph;
 mod core;
 mod dry_runner;
 pub mod errors;
+mod fee_calculator;
+mod gas_price_strategy;
+pub mod hashing;
 pub mod param_types;
+mod receipts;
 mod token;
 pub mod transaction_builders;
 pub mod tx_status;
+mod versioned;
+pub mod withdrawal;
 mod wrappers;
+pub use coin_selection::*;
+pub use contract_graph::*;
 pub use dry_runner::*;
+pub use fee_calculator::*;
+pub use gas_price_strategy::*;
+pub use receipts::*;
+pub use versioned::*;
 
 pub type ByteArray = [u8; 8];
 pub type Selector = Vec<u8>;
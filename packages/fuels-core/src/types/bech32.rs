@@ -15,6 +15,85 @@ use crate::types::{
 // Fuel Network human-readable part for bech32 encoding
 pub const FUEL_BECH32_HRP: &str = "fuel";
 
+/// How [`Bech32Address::format_as`] and [`Bech32ContractId::format_as`] format an id. `Display`
+/// always uses bech32.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressFormat {
+    encoding: AddressEncoding,
+    prefixed: bool,
+    truncated_to: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AddressEncoding {
+    Bech32,
+    Hex,
+}
+
+impl AddressFormat {
+    /// `fuel1…`, the default.
+    pub const fn bech32() -> Self {
+        Self {
+            encoding: AddressEncoding::Bech32,
+            prefixed: true,
+            truncated_to: None,
+        }
+    }
+
+    /// Lowercase hex prefixed with `0x`, as shown by block explorers.
+    pub const fn hex() -> Self {
+        Self {
+            encoding: AddressEncoding::Hex,
+            ..Self::bech32()
+        }
+    }
+
+    /// Leaves out the `0x` of hex. Has no effect on bech32.
+    pub const fn without_prefix(mut self) -> Self {
+        self.prefixed = false;
+        self
+    }
+
+    /// Only shows the first and the last `chars` characters after the `0x` or the `fuel1`. Handy
+    /// for logs, but truncated ids can't be parsed back.
+    pub const fn truncated(mut self, chars: usize) -> Self {
+        self.truncated_to = Some(chars);
+        self
+    }
+
+    fn format(&self, hrp: &str, hash: &Bytes32) -> String {
+        let (prefix, body) = match self.encoding {
+            AddressEncoding::Bech32 => {
+                let encoding = bech32::encode(hrp, hash.to_base32(), Bech32m).unwrap();
+                let separator = hrp.len() + 1;
+
+                (
+                    encoding[..separator].to_string(),
+                    encoding[separator..].to_string(),
+                )
+            }
+            AddressEncoding::Hex => ("0x".to_string(), hex::encode(hash)),
+        };
+        let prefix: &str = match self.encoding {
+            AddressEncoding::Hex if !self.prefixed => "",
+            _ => &prefix,
+        };
+
+        match self.truncated_to {
+            Some(chars) if body.len() > 2 * chars => {
+                format!("{prefix}{}…{}", &body[..chars], &body[body.len() - chars..])
+            }
+            _ => format!("{prefix}{body}"),
+        }
+    }
+}
+
+impl Default for AddressFormat {
+    fn default() -> Self {
+        Self::bech32()
+    }
+}
+
 /// Generate type represented in the Bech32 format,
 /// consisting of a human-readable part (hrp) and a hash (e.g. pubkey-, contract hash)
 macro_rules! bech32type {
@@ -40,6 +119,11 @@ macro_rules! bech32type {
             pub fn hrp(&self) -> &str {
                 &self.hrp
             }
+
+            /// Formats the id in `format`, e.g. as hex to match block explorers.
+            pub fn format_as(&self, format: AddressFormat) -> String {
+                format.format(&self.hrp, &self.hash)
+            }
         }
 
         impl Default for $i {
@@ -212,6 +296,33 @@ mod test {
         }
     }
 
+    #[test]
+    fn ids_are_formatted_as_configured() {
+        let address = Bech32Address::from_str(
+            "fuel1dved7k25uxadatl7l5kql309jnw07dcn4t3a6x9hm9nxyjcpqqns50p7n2",
+        )
+        .unwrap();
+        let hex = "0x6b32df5954e1badeaffefd2c0fc5e594dcff3713aae3dd18b7d966624b010027";
+
+        assert_eq!(address.format_as(AddressFormat::hex()), hex);
+        assert_eq!(
+            address.format_as(AddressFormat::hex().without_prefix()),
+            &hex[2..]
+        );
+        assert_eq!(
+            address.format_as(AddressFormat::hex().truncated(4)),
+            "0x6b32…0027"
+        );
+        assert_eq!(
+            address.format_as(AddressFormat::bech32().truncated(6)),
+            "fuel1dved7k…50p7n2"
+        );
+        assert_eq!(
+            address.format_as(AddressFormat::bech32()),
+            address.to_string()
+        );
+    }
+
     #[test]
     fn test_from_invalid_bech32_string() {
         {
@@ -0,0 +1,210 @@
+#![cfg(feature = "std")]
+
+use std::cmp::Reverse;
+
+use fuel_tx::UtxoId;
+
+use crate::types::{
+    coin::Coin,
+    errors::{error, Result},
+};
+
+// how many branches `BranchAndBound` explores before settling for the best match found so far
+const BNB_MAX_TRIES: usize = 100_000;
+
+/// How an account picks the coins funding a transaction out of the ones it owns.
+///
+/// Without a policy, the selection is left to the node. A policy makes the SDK fetch the coins and
+/// select them locally. Only coins are selected, messages are never spent by a policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CoinSelectionPolicy {
+    /// Spends the largest coins first, minimizing the number of inputs.
+    #[default]
+    LargestFirst,
+    /// Spends the smallest coins first, consolidating dust.
+    SmallestFirst,
+    /// Searches for the set of coins exceeding the amount by the least, ideally matching it
+    /// exactly so the change is zero. Falls back to the best set found if the search is cut short.
+    BranchAndBound,
+    /// Spends exactly these coins, which must cover the amount.
+    Manual(Vec<UtxoId>),
+}
+
+impl CoinSelectionPolicy {
+    /// Selects coins out of `coins` covering `amount`.
+    pub fn select(&self, mut coins: Vec<Coin>, amount: u64) -> Result<Vec<Coin>> {
+        let selected = match self {
+            Self::LargestFirst => {
+                coins.sort_by_key(|coin| Reverse(coin.amount));
+                take_until_covered(coins, amount)
+            }
+            Self::SmallestFirst => {
+                coins.sort_by_key(|coin| coin.amount);
+                take_until_covered(coins, amount)
+            }
+            Self::BranchAndBound => {
+                coins.sort_by_key(|coin| Reverse(coin.amount));
+                branch_and_bound(&coins, amount)
+                    .map(|picked| picked.into_iter().map(|i| coins[i].clone()).collect())
+                    .unwrap_or_default()
+            }
+            Self::Manual(utxo_ids) => utxo_ids
+                .iter()
+                .map(|utxo_id| {
+                    coins
+                        .iter()
+                        .find(|coin| coin.utxo_id == *utxo_id)
+                        .cloned()
+                        .ok_or_else(|| error!(Other, "coin `{utxo_id}` is not spendable"))
+                })
+                .collect::<Result<_>>()?,
+        };
+
+        let total = selected
+            .iter()
+            .fold(0u64, |total, coin| total.saturating_add(coin.amount));
+        if total < amount {
+            return Err(error!(
+                Other,
+                "selected coins only cover {total} of the requested {amount}"
+            ));
+        }
+
+        Ok(selected)
+    }
+}
+
+fn take_until_covered(coins: Vec<Coin>, amount: u64) -> Vec<Coin> {
+    let mut covered = 0u64;
+    coins
+        .into_iter()
+        .take_while(|coin| {
+            let needed = covered < amount;
+            covered = covered.saturating_add(coin.amount);
+            needed
+        })
+        .collect()
+}
+
+/// Returns the indexes of the coins, sorted by descending amount, whose sum exceeds `amount` by
+/// the least.
+fn branch_and_bound(coins: &[Coin], amount: u64) -> Option<Vec<usize>> {
+    struct Search {
+        amounts: Vec<u128>,
+        // remaining[i] is the sum of the amounts from i onward
+        remaining: Vec<u128>,
+        target: u128,
+        tries: usize,
+        best: Option<(u128, Vec<usize>)>,
+        current: Vec<usize>,
+    }
+
+    impl Search {
+        fn explore(&mut self, index: usize, sum: u128) {
+            if self.tries >= BNB_MAX_TRIES || matches!(self.best, Some((0, _))) {
+                return;
+            }
+            self.tries += 1;
+
+            if sum >= self.target {
+                let excess = sum - self.target;
+                if self.best.as_ref().map_or(true, |(best, _)| excess < *best) {
+                    self.best = Some((excess, self.current.clone()));
+                }
+                return;
+            }
+            if index == self.amounts.len() || sum + self.remaining[index] < self.target {
+                return;
+            }
+            if let Some((best, _)) = &self.best {
+                // the amounts are sorted, so even adding the smallest one overshoots more than
+                // the best match
+                if sum + self.amounts[self.amounts.len() - 1] > self.target + best {
+                    return;
+                }
+            }
+
+            self.current.push(index);
+            self.explore(index + 1, sum + self.amounts[index]);
+            self.current.pop();
+            self.explore(index + 1, sum);
+        }
+    }
+
+    let amounts = coins
+        .iter()
+        .map(|coin| u128::from(coin.amount))
+        .collect::<Vec<_>>();
+    let mut remaining = vec![0; amounts.len() + 1];
+    for i in (0..amounts.len()).rev() {
+        remaining[i] = remaining[i + 1] + amounts[i];
+    }
+
+    let mut search = Search {
+        amounts,
+        remaining,
+        target: u128::from(amount),
+        tries: 0,
+        best: None,
+        current: vec![],
+    };
+    search.explore(0, 0);
+
+    search.best.map(|(_, picked)| picked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coins(amounts: &[u64]) -> Vec<Coin> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(i, amount)| Coin {
+                amount: *amount,
+                utxo_id: UtxoId::new([i as u8; 32].into(), 0),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn amounts(coins: &[Coin]) -> Vec<u64> {
+        coins.iter().map(|coin| coin.amount).collect()
+    }
+
+    #[test]
+    fn coins_are_selected_according_to_the_policy() -> Result<()> {
+        let available = coins(&[5, 40, 1, 30, 12]);
+
+        let largest = CoinSelectionPolicy::LargestFirst.select(available.clone(), 42)?;
+        let smallest = CoinSelectionPolicy::SmallestFirst.select(available.clone(), 42)?;
+        let bnb = CoinSelectionPolicy::BranchAndBound.select(available.clone(), 42)?;
+        let manual =
+            CoinSelectionPolicy::Manual(vec![available[0].utxo_id]).select(available.clone(), 5)?;
+
+        assert_eq!(amounts(&largest), vec![40, 30]);
+        assert_eq!(amounts(&smallest), vec![1, 5, 12, 30]);
+        assert_eq!(amounts(&bnb), vec![30, 12]);
+        assert_eq!(amounts(&manual), vec![5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn selection_fails_when_coins_dont_cover_the_amount() {
+        let available = coins(&[5, 40]);
+
+        for policy in [
+            CoinSelectionPolicy::LargestFirst,
+            CoinSelectionPolicy::SmallestFirst,
+            CoinSelectionPolicy::BranchAndBound,
+            CoinSelectionPolicy::Manual(vec![available[0].utxo_id]),
+        ] {
+            assert!(policy.select(available.clone(), 46).is_err());
+        }
+
+        let unknown = CoinSelectionPolicy::Manual(vec![UtxoId::new([9; 32].into(), 0)]);
+        assert!(unknown.select(available, 1).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use fuel_tx::{ContractId, Receipt};
+
+use crate::types::bech32::Bech32ContractId;
+
+/// Which contracts call which, built from the `Call` receipts of executed transactions.
+///
+/// Calls made directly by a script are recorded as entry points rather than edges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractDependencyGraph {
+    edges: BTreeMap<ContractId, BTreeMap<ContractId, u64>>,
+    entry_points: BTreeMap<ContractId, u64>,
+}
+
+/// A caller calling a callee `calls` times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractDependency {
+    pub caller: ContractId,
+    pub callee: ContractId,
+    pub calls: u64,
+}
+
+impl ContractDependencyGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the calls found in the receipts of a single transaction.
+    pub fn add_receipts(&mut self, receipts: &[Receipt]) {
+        for receipt in receipts {
+            let Receipt::Call { id, to, .. } = receipt else {
+                continue;
+            };
+
+            if *id == ContractId::zeroed() {
+                *self.entry_points.entry(*to).or_default() += 1;
+            } else {
+                *self.edges.entry(*id).or_default().entry(*to).or_default() += 1;
+            }
+        }
+    }
+
+    /// All contracts seen, either as a caller or as a callee.
+    pub fn contracts(&self) -> BTreeSet<ContractId> {
+        self.edges
+            .iter()
+            .flat_map(|(caller, callees)| std::iter::once(caller).chain(callees.keys()))
+            .chain(self.entry_points.keys())
+            .copied()
+            .collect()
+    }
+
+    pub fn dependencies(&self) -> impl Iterator<Item = ContractDependency> + '_ {
+        self.edges.iter().flat_map(|(caller, callees)| {
+            callees.iter().map(|(callee, calls)| ContractDependency {
+                caller: *caller,
+                callee: *callee,
+                calls: *calls,
+            })
+        })
+    }
+
+    /// Contracts called directly by scripts and how many times.
+    pub fn entry_points(&self) -> &BTreeMap<ContractId, u64> {
+        &self.entry_points
+    }
+
+    pub fn callees(&self, contract_id: &ContractId) -> BTreeSet<ContractId> {
+        self.edges
+            .get(contract_id)
+            .map(|callees| callees.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn callers(&self, contract_id: &ContractId) -> BTreeSet<ContractId> {
+        self.edges
+            .iter()
+            .filter(|(_, callees)| callees.contains_key(contract_id))
+            .map(|(caller, _)| *caller)
+            .collect()
+    }
+
+    /// Every contract `contract_id` reaches, directly or through other contracts, in
+    /// breadth-first order. Pass them to `with_contract_ids` when calling `contract_id`.
+    pub fn transitive_callees(&self, contract_id: &ContractId) -> Vec<Bech32ContractId> {
+        let mut visited = BTreeSet::from([*contract_id]);
+        let mut queue = VecDeque::from([*contract_id]);
+        let mut reached = vec![];
+
+        while let Some(current) = queue.pop_front() {
+            for callee in self.callees(&current) {
+                if visited.insert(callee) {
+                    reached.push(callee.into());
+                    queue.push_back(callee);
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// Whether any call in `receipts` involves one of `contract_ids`.
+    pub fn involves(receipts: &[Receipt], contract_ids: &BTreeSet<ContractId>) -> bool {
+        receipts.iter().any(|receipt| match receipt {
+            Receipt::Call { id, to, .. } => contract_ids.contains(id) || contract_ids.contains(to),
+            _ => false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::AssetId;
+
+    use super::*;
+
+    fn call(from: ContractId, to: ContractId) -> Receipt {
+        Receipt::call(from, to, 0, AssetId::zeroed(), 0, 0, 0, 0, 0)
+    }
+
+    #[test]
+    fn graph_is_built_from_call_receipts() {
+        // given
+        let script = ContractId::zeroed();
+        let [router, pool, token] = [1, 2, 3].map(|byte| ContractId::new([byte; 32]));
+
+        let mut graph = ContractDependencyGraph::new();
+
+        // when
+        graph.add_receipts(&[call(script, router), call(router, pool), call(pool, token)]);
+        graph.add_receipts(&[call(script, router), call(router, pool)]);
+
+        // then
+        assert_eq!(graph.contracts(), BTreeSet::from([router, pool, token]));
+        assert_eq!(graph.entry_points(), &BTreeMap::from([(router, 2)]));
+        assert_eq!(
+            graph.dependencies().collect::<Vec<_>>(),
+            vec![
+                ContractDependency {
+                    caller: router,
+                    callee: pool,
+                    calls: 2
+                },
+                ContractDependency {
+                    caller: pool,
+                    callee: token,
+                    calls: 1
+                },
+            ]
+        );
+        assert_eq!(graph.callers(&token), BTreeSet::from([pool]));
+        assert_eq!(
+            graph.transitive_callees(&router),
+            vec![Bech32ContractId::from(pool), Bech32ContractId::from(token)]
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use fuel_types::AssetId;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use fuel_types::{AssetId, Bytes32};
 use fuels_macros::{Parameterize, Tokenizable, TryFrom};
 
-use crate::types::errors::Result;
+use crate::types::{
+    errors::{error, Result},
+    hashing,
+};
 
 // A simple wrapper around [u8; 32] representing the `b256` type. Exists
 // mainly so that we may differentiate `Parameterize` and `Tokenizable`
@@ -18,17 +22,54 @@ impl Bits256 {
     /// Create a new `Bits256` from a string representation of a hex.
     /// Accepts both `0x` prefixed and non-prefixed hex strings.
     pub fn from_hex_str(hex: &str) -> Result<Self> {
-        let hex = if let Some(stripped_hex) = hex.strip_prefix("0x") {
-            stripped_hex
-        } else {
-            hex
-        };
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+
+        if digits.len() != 64 {
+            return Err(error!(
+                Other,
+                "`{hex}` is not a 32 byte hex string: expected 64 hex digits, got {}",
+                digits.len()
+            ));
+        }
 
         let mut bytes = [0u8; 32];
-        hex::decode_to_slice(hex, &mut bytes as &mut [u8])?;
+        hex::decode_to_slice(digits, &mut bytes as &mut [u8])
+            .map_err(|err| error!(Other, "`{hex}` is not a valid hex string: {err}"))?;
 
         Ok(Bits256(bytes))
     }
+
+    /// Create a new `Bits256` from its standard, padded base64 encoding.
+    pub fn from_base64(base64: &str) -> Result<Self> {
+        let bytes = BASE64
+            .decode(base64)
+            .map_err(|err| error!(Other, "`{base64}` is not valid base64: {err}"))?;
+
+        let bytes: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+            error!(
+                Other,
+                "`{base64}` decodes to {} bytes, expected 32",
+                bytes.len()
+            )
+        })?;
+
+        Ok(Bits256(bytes))
+    }
+
+    /// The SHA-256 hash of `data`, as used by `std::hash::sha256` in Sway.
+    pub fn sha256(data: impl AsRef<[u8]>) -> Self {
+        Self(hashing::sha256(data).into())
+    }
+
+    /// The Keccak-256 hash of `data`, as used by `std::hash::keccak256` in Sway.
+    pub fn keccak256(data: impl AsRef<[u8]>) -> Self {
+        Self(hashing::keccak256(data).into())
+    }
+
+    /// The `0x` prefixed hex representation.
+    pub fn to_hex_str(&self) -> String {
+        format!("0x{}", hex::encode(self.0))
+    }
 }
 
 impl From<AssetId> for Bits256 {
@@ -37,6 +78,18 @@ impl From<AssetId> for Bits256 {
     }
 }
 
+impl From<Bytes32> for Bits256 {
+    fn from(value: Bytes32) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Bits256> for Bytes32 {
+    fn from(value: Bits256) -> Self {
+        Bytes32::new(value.0)
+    }
+}
+
 // A simple wrapper around [Bits256; 2] representing the `B512` type.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable, TryFrom)]
 #[FuelsCorePath = "crate"]
@@ -120,6 +173,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn hex_and_base64_parsing_explain_failures() {
+        let too_short = Bits256::from_hex_str("0x0101").expect_err("should fail");
+        let not_hex = Bits256::from_hex_str(&"zz".repeat(32)).expect_err("should fail");
+        let wrong_length = Bits256::from_base64("AQID").expect_err("should fail");
+
+        assert!(too_short
+            .to_string()
+            .contains("expected 64 hex digits, got 4"));
+        assert!(not_hex.to_string().contains("is not a valid hex string"));
+        assert!(wrong_length
+            .to_string()
+            .contains("decodes to 3 bytes, expected 32"));
+    }
+
+    #[test]
+    fn hashing_matches_sway() {
+        // ANCHOR: bits256_hashing
+        let sha = Bits256::sha256("fuel");
+        let keccak = Bits256::keccak256([1u8, 2, 3]);
+
+        // `Bytes32` and `Bits256` convert into each other
+        let sha: Bytes32 = sha.into();
+        // ANCHOR_END: bits256_hashing
+
+        assert_eq!(sha, hashing::sha256("fuel"));
+        assert_eq!(Bytes32::from(keccak), hashing::keccak256([1u8, 2, 3]));
+    }
+
+    #[test]
+    fn parsing_roundtrips() -> Result<()> {
+        let bits = Bits256([7; 32]);
+
+        assert_eq!(Bits256::from_hex_str(&bits.to_hex_str())?, bits);
+        assert_eq!(Bits256::from_base64(&BASE64.encode(bits.0))?, bits);
+        assert_eq!(Bits256::from(Bytes32::from(bits)), bits);
+
+        Ok(())
+    }
+
     #[test]
     fn test_param_type_evm_addr() {
         assert_eq!(
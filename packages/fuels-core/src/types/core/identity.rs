@@ -2,7 +2,10 @@ use fuel_types::{Address, ContractId};
 use fuels_macros::{Parameterize, Tokenizable, TryFrom};
 use serde::{Deserialize, Serialize};
 
-use crate::types::bech32::{Bech32Address, Bech32ContractId};
+use crate::types::{
+    bech32::{Bech32Address, Bech32ContractId},
+    Bits256,
+};
 
 #[derive(
     Debug,
@@ -24,6 +27,41 @@ pub enum Identity {
     ContractId(ContractId),
 }
 
+impl Identity {
+    pub fn as_address(&self) -> Option<&Address> {
+        match self {
+            Self::Address(address) => Some(address),
+            Self::ContractId(_) => None,
+        }
+    }
+
+    pub fn as_contract_id(&self) -> Option<&ContractId> {
+        match self {
+            Self::Address(_) => None,
+            Self::ContractId(contract_id) => Some(contract_id),
+        }
+    }
+
+    pub fn is_address(&self) -> bool {
+        matches!(self, Self::Address(_))
+    }
+
+    pub fn is_contract(&self) -> bool {
+        matches!(self, Self::ContractId(_))
+    }
+
+    /// The 32 bytes of the address or contract id, e.g. to use as a storage key. An address and a
+    /// contract id with the same bytes map to the same value.
+    pub fn to_bits256(&self) -> Bits256 {
+        let bytes = match self {
+            Self::Address(address) => **address,
+            Self::ContractId(contract_id) => **contract_id,
+        };
+
+        Bits256(bytes)
+    }
+}
+
 impl Default for Identity {
     fn default() -> Self {
         Self::Address(Address::default())
@@ -89,6 +127,27 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn projections_match_the_variant() {
+        // given
+        let address = Identity::Address(Address::from([1; 32]));
+        let contract_id = Identity::ContractId(ContractId::from([2; 32]));
+
+        // then
+        assert_eq!(address.as_address(), Some(&Address::from([1; 32])));
+        assert_eq!(address.as_contract_id(), None);
+        assert!(address.is_address() && !address.is_contract());
+        assert_eq!(address.to_bits256(), Bits256([1; 32]));
+
+        assert_eq!(contract_id.as_address(), None);
+        assert_eq!(
+            contract_id.as_contract_id(),
+            Some(&ContractId::from([2; 32]))
+        );
+        assert!(contract_id.is_contract() && !contract_id.is_address());
+        assert_eq!(contract_id.to_bits256(), Bits256([2; 32]));
+    }
+
     #[test]
     fn test_bech32() {
         let b32_str = "fuel1dved7k25uxadatl7l5kql309jnw07dcn4t3a6x9hm9nxyjcpqqns50p7n2";
@@ -1,4 +1,4 @@
-#![allow(clippy::assign_op_pattern)]
+#![allow(clippy::assign_op_pattern, clippy::manual_div_ceil)]
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use uint::construct_uint;
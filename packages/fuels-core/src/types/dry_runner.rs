@@ -25,7 +25,17 @@ impl DryRun {
 pub trait DryRunner: Send + Sync {
     async fn dry_run(&self, tx: FuelTransaction) -> Result<DryRun>;
     async fn estimate_gas_price(&self, block_horizon: u32) -> Result<u64>;
-    fn consensus_parameters(&self) -> &ConsensusParameters;
+    /// The `percentile` of the tips paid in the last `blocks` blocks, see
+    /// [`GasPriceStrategy::Percentile`](crate::types::GasPriceStrategy::Percentile).
+    async fn estimate_tip(&self, _percentile: u8, _blocks: u32) -> Result<u64> {
+        Ok(0)
+    }
+    fn consensus_parameters(&self) -> ConsensusParameters;
+    /// Like [`DryRunner::consensus_parameters`], for dry runners that fetch the parameters on
+    /// first use or refetch them once stale, e.g. a provider.
+    async fn fetch_consensus_parameters(&self) -> Result<ConsensusParameters> {
+        Ok(self.consensus_parameters())
+    }
     async fn maybe_estimate_predicates(
         &self,
         tx: &FuelTransaction,
@@ -44,10 +54,18 @@ impl<T: DryRunner> DryRunner for &T {
         (*self).estimate_gas_price(block_horizon).await
     }
 
-    fn consensus_parameters(&self) -> &ConsensusParameters {
+    async fn estimate_tip(&self, percentile: u8, blocks: u32) -> Result<u64> {
+        (*self).estimate_tip(percentile, blocks).await
+    }
+
+    fn consensus_parameters(&self) -> ConsensusParameters {
         (*self).consensus_parameters()
     }
 
+    async fn fetch_consensus_parameters(&self) -> Result<ConsensusParameters> {
+        (*self).fetch_consensus_parameters().await
+    }
+
     async fn maybe_estimate_predicates(
         &self,
         tx: &FuelTransaction,
@@ -1,10 +1,12 @@
 use std::{array::TryFromSliceError, str::Utf8Error};
 
-use fuel_tx::{Receipt, ValidityError};
+use fuel_tx::{ContractId, Receipt, ValidityError};
 use fuel_vm::checked_transaction::CheckError;
 use hex::FromHexError;
 use thiserror::Error;
 
+use crate::{traits::Tokenizable, types::Token};
+
 pub mod transaction {
     use super::*;
 
@@ -16,15 +18,74 @@ pub mod transaction {
         Validation(String),
         #[error("squeezedOut: {0}")]
         SqueezedOut(String),
+        /// Construct with [`Reason::reverted`] outside of this crate. Fields may be added, match
+        /// with `..`.
+        #[non_exhaustive]
         #[error("reverted: {reason}, receipts: {receipts:?}")]
         Reverted {
             reason: String,
             revert_id: u64,
             receipts: Vec<Receipt>,
+            /// Set when the revert comes from a transaction sent by the SDK, see
+            /// [`Error::decoded_revert`](super::Error::decoded_revert).
+            decoded: Option<Box<DecodedRevert>>,
+        },
+        /// The `main` of a script returned `Err`. Unlike a revert, the transaction went through
+        /// and its effects were kept. Construct with [`Reason::script_returned_err`] outside of
+        /// this crate.
+        #[non_exhaustive]
+        #[error("script returned `Err({})`", .decoded.message)]
+        ScriptReturnedErr {
+            decoded: Box<DecodedRevert>,
+            /// The `Err` value, see [`Error::script_err`](super::Error::script_err).
+            err: Token,
+            receipts: Vec<Receipt>,
+        },
+        #[error("post condition #{index} failed, receipts: {receipts:?}")]
+        PostConditionFailed {
+            index: usize,
+            receipts: Vec<Receipt>,
         },
         #[error(": {0}")]
         Other(String),
     }
+
+    /// A revert, taken apart so that it can be handled without parsing the error message.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DecodedRevert {
+        /// The contract that reverted, zeroed if the script did.
+        pub contract_id: ContractId,
+        /// The name of the type of the value a failed `require` logged, e.g. the error enum of
+        /// the contract, or of the `Err` a script returned. `None` for other reverts, or if the
+        /// value couldn't be decoded.
+        pub error_type: Option<String>,
+        /// The decoded value a failed `require` logged, or a description of the revert.
+        pub message: String,
+    }
+
+    impl Reason {
+        /// A revert that wasn't decoded, e.g. one reported by a mocked node.
+        pub fn reverted(reason: String, revert_id: u64, receipts: Vec<Receipt>) -> Self {
+            Self::Reverted {
+                reason,
+                revert_id,
+                receipts,
+                decoded: None,
+            }
+        }
+
+        pub fn script_returned_err(
+            decoded: DecodedRevert,
+            err: Token,
+            receipts: Vec<Receipt>,
+        ) -> Self {
+            Self::ScriptReturnedErr {
+                decoded: Box::new(decoded),
+                err,
+                receipts,
+            }
+        }
+    }
 }
 use transaction::Reason;
 
@@ -42,6 +103,76 @@ pub enum Error {
     Other(String),
 }
 
+impl Error {
+    /// The details of the revert behind the error, if a transaction sent by the SDK reverted or
+    /// its script returned `Err`.
+    pub fn decoded_revert(&self) -> Option<&transaction::DecodedRevert> {
+        match self {
+            Self::Transaction(Reason::Reverted {
+                decoded: Some(decoded),
+                ..
+            })
+            | Self::Transaction(Reason::ScriptReturnedErr { decoded, .. }) => Some(decoded),
+            _ => None,
+        }
+    }
+
+    /// The receipts of the transaction behind the error, if it was executed.
+    pub fn receipts(&self) -> Option<&[Receipt]> {
+        match self {
+            Self::Transaction(
+                Reason::Reverted { receipts, .. }
+                | Reason::ScriptReturnedErr { receipts, .. }
+                | Reason::PostConditionFailed { receipts, .. },
+            ) => Some(receipts),
+            _ => None,
+        }
+    }
+
+    /// The `Err` value returned by the `main` of a script, e.g. its error enum. `None` if the
+    /// error isn't a [`Reason::ScriptReturnedErr`].
+    pub fn script_err<E: Tokenizable>(&self) -> Option<Result<E>> {
+        match self {
+            Self::Transaction(Reason::ScriptReturnedErr { err, .. }) => {
+                Some(E::from_token(err.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Prefixes the message of the error with `context`, e.g. the label of the account it
+    /// happened with. The variant is kept, so matching on it still works.
+    pub fn with_context(self, context: impl std::fmt::Display) -> Self {
+        let prefixed = |message: String| format!("{context}: {message}");
+
+        match self {
+            Self::IO(message) => Self::IO(prefixed(message)),
+            Self::Codec(message) => Self::Codec(prefixed(message)),
+            Self::Provider(message) => Self::Provider(prefixed(message)),
+            Self::Other(message) => Self::Other(prefixed(message)),
+            Self::Transaction(reason) => Self::Transaction(match reason {
+                Reason::Builder(message) => Reason::Builder(prefixed(message)),
+                Reason::Validation(message) => Reason::Validation(prefixed(message)),
+                Reason::SqueezedOut(message) => Reason::SqueezedOut(prefixed(message)),
+                Reason::Reverted {
+                    reason,
+                    revert_id,
+                    receipts,
+                    decoded,
+                } => Reason::Reverted {
+                    reason: prefixed(reason),
+                    revert_id,
+                    receipts,
+                    decoded,
+                },
+                Reason::Other(message) => Reason::Other(prefixed(message)),
+                reason
+                @ (Reason::PostConditionFailed { .. } | Reason::ScriptReturnedErr { .. }) => reason,
+            }),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(value: std::io::Error) -> Self {
         Self::IO(value.to_string())
@@ -100,3 +231,46 @@ impl_error_from!(Other, serde_json::Error);
 impl_error_from!(Other, FromHexError);
 impl_error_from!(Other, TryFromSliceError);
 impl_error_from!(Other, Utf8Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_is_prepended_and_the_variant_kept() {
+        let err = error!(Provider, "not enough coins").with_context("account `relayer-3`");
+        let reverted =
+            Error::Transaction(Reason::reverted("assertion failed".to_string(), 1, vec![]))
+                .with_context("account `relayer-3`");
+
+        assert_eq!(
+            err.to_string(),
+            "provider: account `relayer-3`: not enough coins"
+        );
+        assert!(matches!(
+            reverted,
+            Error::Transaction(Reason::Reverted { reason, revert_id: 1, .. })
+                if reason == "account `relayer-3`: assertion failed"
+        ));
+    }
+
+    #[test]
+    fn the_err_of_a_script_is_recovered_typed() {
+        let receipts = vec![Receipt::ret(ContractId::zeroed(), 0, 0, 0)];
+        let err = Error::Transaction(Reason::script_returned_err(
+            transaction::DecodedRevert {
+                contract_id: ContractId::zeroed(),
+                error_type: Some("u64".to_string()),
+                message: "3".to_string(),
+            },
+            Token::U64(3),
+            receipts.clone(),
+        ));
+
+        assert_eq!(err.script_err::<u64>().transpose().unwrap(), Some(3));
+        assert!(err.script_err::<bool>().unwrap().is_err());
+        assert_eq!(err.decoded_revert().unwrap().message, "3");
+        assert_eq!(err.receipts(), Some(receipts.as_slice()));
+        assert_eq!(err.to_string(), "transaction script returned `Err(3)`");
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+use fuel_tx::{Bytes32, ConsensusParameters};
+
+use crate::{
+    types::{
+        errors::{error, Result},
+        transaction::Transaction,
+    },
+    utils::calculate_witnesses_size,
+};
+
+/// The gas attributed to one part of a transaction and the fee it costs at the given gas price.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeComponent {
+    pub gas: u64,
+    pub fee: u64,
+}
+
+/// The max fee of a transaction split into its components.
+///
+/// Each component's fee is rounded up on its own, so their sum can exceed `max_fee` by a few
+/// units. `max_fee` is always what the node charges at most.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub gas_price: u64,
+    /// Serialized transaction bytes, excluding witnesses.
+    pub bytes: FeeComponent,
+    /// Witness bytes, counted up to the witness limit since the limit is what gets charged.
+    pub witnesses: FeeComponent,
+    /// Gas used to run the predicates of the inputs.
+    pub predicates: FeeComponent,
+    /// The script gas limit. Zero for transactions without a script.
+    pub script: FeeComponent,
+    /// VM initialization, signature checks and transaction id hashing.
+    pub intrinsic: FeeComponent,
+    pub tip: u64,
+    pub max_gas: u64,
+    pub max_fee: u64,
+}
+
+impl Display for FeeBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "gas price: {}", self.gas_price)?;
+        for (name, component) in [
+            ("bytes", self.bytes),
+            ("witnesses", self.witnesses),
+            ("predicates", self.predicates),
+            ("script", self.script),
+            ("intrinsic", self.intrinsic),
+        ] {
+            writeln!(f, "{name}: {} gas, {} fee", component.gas, component.fee)?;
+        }
+        writeln!(f, "tip: {}", self.tip)?;
+        write!(f, "max fee: {} ({} gas)", self.max_fee, self.max_gas)
+    }
+}
+
+/// Explains the max fee of a transaction using the fee and gas cost parameters of the chain.
+#[derive(Debug, Clone)]
+pub struct FeeCalculator<'a> {
+    consensus_parameters: &'a ConsensusParameters,
+}
+
+impl<'a> FeeCalculator<'a> {
+    pub fn new(consensus_parameters: &'a ConsensusParameters) -> Self {
+        Self {
+            consensus_parameters,
+        }
+    }
+
+    pub fn breakdown<T: Transaction>(&self, tx: &T, gas_price: u64) -> Result<FeeBreakdown> {
+        let fee_params = self.consensus_parameters.fee_params();
+        let gas_per_byte = fee_params.gas_per_byte();
+
+        let max_fee = tx
+            .fee_checked_from_tx(self.consensus_parameters, gas_price)
+            .ok_or_else(|| error!(Other, "fee calculation overflowed"))?
+            .max_fee();
+        let min_gas = tx.min_gas(self.consensus_parameters);
+        let max_gas = tx.max_gas(self.consensus_parameters);
+
+        let witnesses_size = calculate_witnesses_size(tx.witnesses()) as u64;
+        let witness_limit = tx.witness_limit().unwrap_or_default().max(witnesses_size);
+        let unused_witness_gas = (witness_limit - witnesses_size).saturating_mul(gas_per_byte);
+
+        let bytes_gas = (tx.metered_bytes_size() as u64)
+            .saturating_sub(witnesses_size)
+            .saturating_mul(gas_per_byte);
+        let witnesses_gas = witness_limit.saturating_mul(gas_per_byte);
+        let predicates_gas = tx
+            .inputs()
+            .iter()
+            .filter_map(|input| input.predicate_gas_used())
+            .fold(0u64, u64::saturating_add);
+        let script_gas = max_gas
+            .saturating_sub(min_gas)
+            .saturating_sub(unused_witness_gas);
+        let intrinsic_gas = min_gas
+            .saturating_sub(bytes_gas)
+            .saturating_sub(witnesses_size.saturating_mul(gas_per_byte))
+            .saturating_sub(predicates_gas);
+
+        let component = |gas| FeeComponent {
+            gas,
+            fee: gas_to_fee(gas, gas_price, fee_params.gas_price_factor()),
+        };
+
+        Ok(FeeBreakdown {
+            gas_price,
+            bytes: component(bytes_gas),
+            witnesses: component(witnesses_gas),
+            predicates: component(predicates_gas),
+            script: component(script_gas),
+            intrinsic: component(intrinsic_gas),
+            tip: tx.tip().unwrap_or_default(),
+            max_gas,
+            max_fee,
+        })
+    }
+
+    /// The fee charged for `tx` once executed, when its script used `script_gas_used` gas. Unlike
+    /// the max fee, only the gas actually used by the script is paid for.
+    pub fn fee_for_gas_used<T: Transaction>(
+        &self,
+        tx: &T,
+        script_gas_used: u64,
+        gas_price: u64,
+    ) -> u64 {
+        let gas = tx
+            .min_gas(self.consensus_parameters)
+            .saturating_add(script_gas_used);
+        let gas_price_factor = self.consensus_parameters.fee_params().gas_price_factor();
+
+        gas_to_fee(gas, gas_price, gas_price_factor).saturating_add(tx.tip().unwrap_or_default())
+    }
+
+    /// The gas charged for writing `new_slots` storage slots a contract doesn't have yet, on top
+    /// of the cost of the instructions writing them, and the fee it costs at `gas_price`. Every
+    /// new slot is charged by the byte for its key and its value. Overwriting existing slots
+    /// doesn't grow the storage and isn't charged this.
+    pub fn storage_growth(&self, new_slots: u64, gas_price: u64) -> FeeComponent {
+        let bytes = new_slots.saturating_mul(2 * Bytes32::LEN as u64);
+        let gas =
+            bytes.saturating_mul(self.consensus_parameters.gas_costs().new_storage_per_byte());
+        let gas_price_factor = self.consensus_parameters.fee_params().gas_price_factor();
+
+        FeeComponent {
+            gas,
+            fee: gas_to_fee(gas, gas_price, gas_price_factor),
+        }
+    }
+}
+
+fn gas_to_fee(gas: u64, gas_price: u64, gas_price_factor: u64) -> u64 {
+    let total_price = gas as u128 * gas_price as u128;
+    let fee = total_price.div_ceil(gas_price_factor.max(1) as u128);
+
+    fee.try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{policies::Policies, FeeParameters, Transaction as FuelTransaction, Witness};
+
+    use super::*;
+    use crate::types::transaction::ScriptTransaction;
+
+    #[test]
+    fn fee_is_rounded_up() {
+        assert_eq!(gas_to_fee(10, 3, 4), 8);
+        assert_eq!(gas_to_fee(10, 4, 4), 10);
+        assert_eq!(gas_to_fee(0, 4, 4), 0);
+    }
+
+    #[test]
+    fn components_add_up_to_max_gas() -> Result<()> {
+        // given
+        let mut consensus_parameters = ConsensusParameters::default();
+        consensus_parameters.set_fee_params(
+            FeeParameters::default()
+                .with_gas_per_byte(2)
+                .with_gas_price_factor(1),
+        );
+
+        let tx: ScriptTransaction = FuelTransaction::script(
+            1_000,
+            vec![],
+            vec![],
+            Policies::new().with_tip(7).with_witness_limit(200),
+            vec![],
+            vec![],
+            vec![Witness::from(vec![1u8; 64])],
+        )
+        .into();
+
+        // when
+        let breakdown = FeeCalculator::new(&consensus_parameters).breakdown(&tx, 3)?;
+
+        // then
+        let components = [
+            breakdown.bytes,
+            breakdown.witnesses,
+            breakdown.predicates,
+            breakdown.script,
+            breakdown.intrinsic,
+        ];
+        let total_gas: u64 = components.iter().map(|component| component.gas).sum();
+        let total_fee: u64 = components.iter().map(|component| component.fee).sum();
+
+        assert_eq!(breakdown.script.gas, 1_000);
+        assert_eq!(breakdown.witnesses.gas, 400);
+        assert_eq!(breakdown.tip, 7);
+        assert_eq!(total_gas, breakdown.max_gas);
+        assert_eq!(total_fee + breakdown.tip, breakdown.max_fee);
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_the_used_script_gas_is_charged() {
+        // given
+        let mut consensus_parameters = ConsensusParameters::default();
+        consensus_parameters.set_fee_params(
+            FeeParameters::default()
+                .with_gas_per_byte(2)
+                .with_gas_price_factor(1),
+        );
+        let tx: ScriptTransaction = FuelTransaction::script(
+            1_000,
+            vec![],
+            vec![],
+            Policies::new().with_tip(7),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .into();
+        let calculator = FeeCalculator::new(&consensus_parameters);
+        let min_gas = tx.min_gas(&consensus_parameters);
+
+        // when
+        let fee = calculator.fee_for_gas_used(&tx, 100, 3);
+
+        // then
+        assert_eq!(fee, (min_gas + 100) * 3 + 7);
+    }
+
+    #[test]
+    fn storage_growth_is_charged_per_new_slot() {
+        // given
+        let mut consensus_parameters = ConsensusParameters::default();
+        consensus_parameters.set_fee_params(FeeParameters::default().with_gas_price_factor(1));
+        let per_byte = consensus_parameters.gas_costs().new_storage_per_byte();
+        let calculator = FeeCalculator::new(&consensus_parameters);
+
+        // when
+        let growth = calculator.storage_growth(3, 2);
+
+        // then
+        assert_eq!(growth.gas, 3 * 64 * per_byte);
+        assert_eq!(growth.fee, growth.gas * 2);
+        assert_eq!(calculator.storage_growth(0, 2), FeeComponent::default());
+    }
+}
@@ -0,0 +1,53 @@
+/// How transaction builders price a transaction whose `TxPolicies` leave the tip or the max fee
+/// out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GasPriceStrategy {
+    /// Pays no tip, and sets the max fee for the gas price the node estimates `block_horizon`
+    /// blocks ahead. With a horizon of 0 this is what builders do by default.
+    Horizon(u32),
+    /// Tips the `percentile` (from 0 to 100) of the tips paid by the transactions in the last
+    /// `blocks` blocks, and sets the max fee as [`GasPriceStrategy::Horizon`] does.
+    Percentile {
+        percentile: u8,
+        blocks: u32,
+        block_horizon: u32,
+    },
+}
+
+impl GasPriceStrategy {
+    pub fn block_horizon(&self) -> u32 {
+        match self {
+            Self::Horizon(block_horizon) | Self::Percentile { block_horizon, .. } => *block_horizon,
+        }
+    }
+}
+
+/// The nearest-rank `percentile` of `tips`, 0 if there are none.
+pub fn tip_percentile(mut tips: Vec<u64>, percentile: u8) -> u64 {
+    if tips.is_empty() {
+        return 0;
+    }
+    tips.sort_unstable();
+
+    let percentile = usize::from(percentile.min(100));
+    let rank = (percentile * tips.len()).div_ceil(100).max(1);
+
+    tips[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tip_percentile_uses_the_nearest_rank() {
+        let tips = vec![50, 10, 40, 20, 30];
+
+        assert_eq!(tip_percentile(tips.clone(), 0), 10);
+        assert_eq!(tip_percentile(tips.clone(), 50), 30);
+        assert_eq!(tip_percentile(tips.clone(), 90), 50);
+        assert_eq!(tip_percentile(tips, 100), 50);
+        assert_eq!(tip_percentile(vec![], 50), 0);
+    }
+}
@@ -0,0 +1,36 @@
+//! Hashes as computed by the Sway standard library, returned as [`Bytes32`]. See also
+//! [`Bits256::sha256`](crate::types::Bits256::sha256) and
+//! [`Bits256::keccak256`](crate::types::Bits256::keccak256).
+
+use fuel_crypto::Hasher;
+use fuel_types::Bytes32;
+use sha3::{Digest, Keccak256};
+
+/// The SHA-256 hash of `data`.
+pub fn sha256(data: impl AsRef<[u8]>) -> Bytes32 {
+    Hasher::hash(data)
+}
+
+/// The Keccak-256 hash of `data`.
+pub fn keccak256(data: impl AsRef<[u8]>) -> Bytes32 {
+    let hash: [u8; 32] = Keccak256::digest(data).into();
+
+    Bytes32::new(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_match_known_digests() {
+        assert_eq!(
+            hex::encode(sha256("")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(keccak256("")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+}
@@ -38,11 +38,27 @@ pub enum ReturnLocation {
     ReturnData,
 }
 
+// deserialized through `UncheckedEnumVariants` to reject empty variants while keeping the
+// `{"variants": [...]}` shape of the derived implementation
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "UncheckedEnumVariants")]
 pub struct EnumVariants {
     variants: Vec<NamedParamType>,
 }
 
+#[derive(serde::Deserialize)]
+struct UncheckedEnumVariants {
+    variants: Vec<NamedParamType>,
+}
+
+impl TryFrom<UncheckedEnumVariants> for EnumVariants {
+    type Error = crate::types::errors::Error;
+
+    fn try_from(unchecked: UncheckedEnumVariants) -> Result<Self> {
+        Self::new(unchecked.variants)
+    }
+}
+
 impl EnumVariants {
     pub fn new(variants: Vec<NamedParamType>) -> Result<EnumVariants> {
         if variants.is_empty() {
@@ -0,0 +1,195 @@
+pub use fuel_tx::Receipt;
+use fuel_types::{AssetId, ContractId};
+
+use crate::{
+    codec::ABIDecoder,
+    traits::{Parameterize, Tokenizable},
+    types::{errors::Result, Identity},
+};
+
+/// Accessors for [`Receipt`]s on top of the ones `fuel-tx` provides, such as `amount()`, `to()`
+/// or `contract_id()`.
+pub trait ReceiptExt {
+    /// The data of a `LogData` or `ReturnData` receipt decoded as a `T`, `None` for other
+    /// receipts.
+    fn decoded_data<T: Tokenizable + Parameterize>(&self) -> Result<Option<T>>;
+}
+
+impl ReceiptExt for Receipt {
+    fn decoded_data<T: Tokenizable + Parameterize>(&self) -> Result<Option<T>> {
+        let (Receipt::LogData {
+            data: Some(data), ..
+        }
+        | Receipt::ReturnData {
+            data: Some(data), ..
+        }) = self
+        else {
+            return Ok(None);
+        };
+
+        decode(data).map(Some)
+    }
+}
+
+/// An asset transfer made by a contract, from a `Transfer` receipt if it went to a contract or a
+/// `TransferOut` receipt if it went to an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferReceipt {
+    pub from: ContractId,
+    pub to: Identity,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// A value logged by a contract or a script, from a `LogData` receipt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogReceipt<'a> {
+    /// Zeroed for logs of scripts.
+    pub contract_id: ContractId,
+    /// The id the ABI gives to the type of the value.
+    pub log_id: u64,
+    pub data: &'a [u8],
+}
+
+impl LogReceipt<'_> {
+    pub fn decode<T: Tokenizable + Parameterize>(&self) -> Result<T> {
+        decode(self.data)
+    }
+}
+
+/// Iterators over the receipts of a transaction that pick out one kind of receipt.
+pub trait ReceiptsExt {
+    fn transfers(&self) -> impl Iterator<Item = TransferReceipt> + '_;
+    fn logs(&self) -> impl Iterator<Item = LogReceipt<'_>> + '_;
+}
+
+impl ReceiptsExt for [Receipt] {
+    fn transfers(&self) -> impl Iterator<Item = TransferReceipt> + '_ {
+        self.iter().filter_map(|receipt| match receipt {
+            Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } => Some(TransferReceipt {
+                from: *id,
+                to: Identity::ContractId(*to),
+                asset_id: *asset_id,
+                amount: *amount,
+            }),
+            Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } => Some(TransferReceipt {
+                from: *id,
+                to: Identity::Address(*to),
+                asset_id: *asset_id,
+                amount: *amount,
+            }),
+            _ => None,
+        })
+    }
+
+    fn logs(&self) -> impl Iterator<Item = LogReceipt<'_>> + '_ {
+        self.iter().filter_map(|receipt| match receipt {
+            Receipt::LogData {
+                id,
+                rb,
+                data: Some(data),
+                ..
+            } => Some(LogReceipt {
+                contract_id: *id,
+                log_id: *rb,
+                data,
+            }),
+            _ => None,
+        })
+    }
+}
+
+fn decode<T: Tokenizable + Parameterize>(data: &[u8]) -> Result<T> {
+    let token = ABIDecoder::default().decode(&T::param_type(), data)?;
+
+    T::from_token(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_types::Address;
+
+    use super::*;
+
+    #[test]
+    fn transfers_to_contracts_and_addresses_are_listed() {
+        // given
+        let from = ContractId::from([1; 32]);
+        let to_contract = ContractId::from([2; 32]);
+        let to_address = Address::from([3; 32]);
+        let asset_id = AssetId::from([4; 32]);
+        let receipts = [
+            Receipt::transfer(from, to_contract, 10, asset_id, 0, 0),
+            Receipt::ret(from, 0, 0, 0),
+            Receipt::transfer_out(from, to_address, 20, asset_id, 0, 0),
+        ];
+
+        // when
+        let transfers = receipts.transfers().collect::<Vec<_>>();
+
+        // then
+        assert_eq!(
+            transfers,
+            [
+                TransferReceipt {
+                    from,
+                    to: Identity::ContractId(to_contract),
+                    asset_id,
+                    amount: 10,
+                },
+                TransferReceipt {
+                    from,
+                    to: Identity::Address(to_address),
+                    asset_id,
+                    amount: 20,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn logged_values_are_decoded() -> Result<()> {
+        // given
+        let contract_id = ContractId::from([1; 32]);
+        let data = 42u64.to_be_bytes().to_vec();
+        let receipt = Receipt::log_data_with_len(
+            contract_id,
+            0,
+            7,
+            0,
+            data.len() as u64,
+            Default::default(),
+            0,
+            0,
+            Some(data),
+        );
+
+        // when
+        let receipts = [receipt.clone()];
+        let log = receipts.logs().next().expect("has a log");
+
+        // then
+        assert_eq!(log.contract_id, contract_id);
+        assert_eq!(log.log_id, 7);
+        assert_eq!(log.decode::<u64>()?, 42);
+        assert_eq!(receipt.decoded_data::<u64>()?, Some(42));
+        assert_eq!(
+            Receipt::ret(contract_id, 0, 0, 0).decoded_data::<u64>()?,
+            None
+        );
+
+        Ok(())
+    }
+}
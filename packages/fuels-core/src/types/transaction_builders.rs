@@ -11,6 +11,12 @@ use fuel_asm::{op, GTFArgs, RegId};
 use fuel_crypto::{Hasher, Message as CryptoMessage, Signature};
 use fuel_tx::{
     field::{Outputs, Policies as PoliciesField, ScriptGasLimit, Witnesses},
+    input::{
+        coin::{CoinPredicate, CoinSigned},
+        message::{
+            MessageCoinPredicate, MessageCoinSigned, MessageDataPredicate, MessageDataSigned,
+        },
+    },
     policies::{Policies, PolicyType},
     Chargeable, ConsensusParameters, Create, Input as FuelInput, Output, Script, StorageSlot,
     Transaction as FuelTransaction, TransactionFee, TxPointer, UniqueIdentifier, Upgrade, Upload,
@@ -19,6 +25,7 @@ use fuel_tx::{
 pub use fuel_tx::{UpgradePurpose, UploadSubsection};
 use fuel_types::{bytes::padded_len_usize, Bytes32, Salt};
 use itertools::Itertools;
+#[cfg(feature = "estimation")]
 use script_dry_runner::ScriptDryRunner;
 
 use crate::{
@@ -30,16 +37,17 @@ use crate::{
         coin_type::CoinType,
         errors::{error, error_transaction, Result},
         input::Input,
-        message::Message,
+        message::{Message, MessageStatus},
         transaction::{
             CreateTransaction, EstimablePredicates, ScriptTransaction, Transaction, TxPolicies,
             UpgradeTransaction, UploadTransaction,
         },
-        Address, AssetId, ContractId, DryRunner,
+        Address, AssetId, CoinSelectionPolicy, ContractId, DryRunner, GasPriceStrategy,
     },
     utils::{calculate_witnesses_size, sealed},
 };
 
+#[cfg(feature = "estimation")]
 mod script_dry_runner;
 
 #[derive(Debug, Clone, Default)]
@@ -149,6 +157,23 @@ pub trait TransactionBuilder: BuildableTransaction + Send + sealed::Sealed {
     fn witnesses(&self) -> &Vec<Witness>;
     fn witnesses_mut(&mut self) -> &mut Vec<Witness>;
     fn with_estimation_horizon(self, block_horizon: u32) -> Self;
+    /// Prices the transaction according to `strategy` where its `TxPolicies` leave the tip or the
+    /// max fee out. Overrides the estimation horizon.
+    fn with_gas_price_strategy(self, strategy: GasPriceStrategy) -> Self;
+    /// Makes accounts select the coins funding the transaction fee according to `policy`,
+    /// overriding their own policy.
+    fn with_coin_selection_policy(self, policy: CoinSelectionPolicy) -> Self;
+    fn coin_selection_policy(&self) -> Option<&CoinSelectionPolicy>;
+    /// Sends the change of the inputs owned by `owner` to `change_to`, for transactions whose
+    /// inputs come from several accounts.
+    ///
+    /// Once a change address is set, building adds a change output for every asset put in
+    /// without one and checks that each owner of inputs gets an output of every asset it put in,
+    /// going to its change address or to itself. A transaction has a single change output per
+    /// asset, so owners sharing an asset with others are given their change with coin outputs.
+    fn with_change_address(self, owner: &Bech32Address, change_to: &Bech32Address) -> Self;
+    /// Where the change of `owner` goes, `owner` itself unless set otherwise.
+    fn change_address<'a>(&'a self, owner: &'a Bech32Address) -> &'a Bech32Address;
 }
 
 macro_rules! impl_tx_trait {
@@ -200,7 +225,7 @@ macro_rules! impl_tx_trait {
                     tx.estimate_predicates(&provider, None).await?;
                 }
 
-                let consensus_parameters = provider.consensus_parameters();
+                let consensus_parameters = provider.fetch_consensus_parameters().await?;
 
                 Ok(TransactionFee::checked_from_tx(
                     &consensus_parameters.gas_costs(),
@@ -262,9 +287,102 @@ macro_rules! impl_tx_trait {
 
                 self
             }
+
+            fn with_gas_price_strategy(mut self, strategy: GasPriceStrategy) -> Self {
+                self.gas_price_estimation_block_horizon = strategy.block_horizon();
+                self.gas_price_strategy = Some(strategy);
+
+                self
+            }
+
+            fn with_coin_selection_policy(mut self, policy: CoinSelectionPolicy) -> Self {
+                self.coin_selection_policy = Some(policy);
+
+                self
+            }
+
+            fn coin_selection_policy(&self) -> Option<&CoinSelectionPolicy> {
+                self.coin_selection_policy.as_ref()
+            }
+
+            fn with_change_address(
+                mut self,
+                owner: &Bech32Address,
+                change_to: &Bech32Address,
+            ) -> Self {
+                self.change_addresses
+                    .insert(owner.clone(), change_to.clone());
+
+                self
+            }
+
+            fn change_address<'a>(&'a self, owner: &'a Bech32Address) -> &'a Bech32Address {
+                self.change_addresses.get(owner).unwrap_or(owner)
+            }
         }
 
         impl $ty {
+            async fn apply_gas_price_strategy(&mut self, provider: impl DryRunner) -> Result<()> {
+                if let (
+                    Some(GasPriceStrategy::Percentile {
+                        percentile, blocks, ..
+                    }),
+                    None,
+                ) = (self.gas_price_strategy, self.tx_policies.tip())
+                {
+                    let tip = provider.estimate_tip(percentile, blocks).await?;
+                    self.tx_policies = self.tx_policies.with_tip(tip);
+                }
+
+                Ok(())
+            }
+
+            fn resolve_change_outputs(&mut self, base_asset_id: &AssetId) -> Result<()> {
+                let contributions = contributions(&self.inputs, base_asset_id);
+
+                if !self.change_addresses.is_empty() {
+                    for (owner, asset_id) in &contributions {
+                        let has_change = self.outputs.iter().any(|output| {
+                            matches!(output, Output::Change { asset_id: id, .. } if id == asset_id)
+                        });
+                        if !has_change {
+                            let change_to = self.change_address(owner).into();
+                            self.outputs.push(Output::change(change_to, 0, *asset_id));
+                        }
+                    }
+                }
+
+                self.validate_change_outputs(&contributions)
+            }
+
+            /// Once a change address is set, checks that every owner of inputs gets an output of
+            /// each asset it put in, going to its change address or to itself. Without change
+            /// addresses, the change goes wherever the outputs say, as always.
+            fn validate_change_outputs(
+                &self,
+                contributions: &[(Bech32Address, AssetId)],
+            ) -> Result<()> {
+                if self.change_addresses.is_empty() {
+                    return Ok(());
+                }
+
+                for (owner, asset_id) in contributions {
+                    let change_to = self.change_address(owner);
+                    let gets_change = self.outputs.iter().any(|output| {
+                        returns_to(output, &change_to.into(), asset_id)
+                            || returns_to(output, &owner.into(), asset_id)
+                    });
+                    if !gets_change {
+                        return Err(error_transaction!(
+                            Builder,
+                            "no output gives asset `{asset_id}` back to `{change_to}`, where the change of `{owner}` goes. The change output of the asset goes to another owner, add a coin output with the change"
+                        ));
+                    }
+                }
+
+                Ok(())
+            }
+
             fn set_witness_indexes(&mut self) {
                 self.unresolved_witness_indexes.owner_to_idx_offset = self
                     .inputs()
@@ -281,9 +399,16 @@ macro_rules! impl_tx_trait {
             }
 
             fn generate_fuel_policies(&self) -> Result<Policies> {
+                let witnesses_size = self.calculate_witnesses_size()?;
                 let witness_limit = match self.tx_policies.witness_limit() {
+                    Some(limit) if limit < witnesses_size => {
+                        return Err(error_transaction!(
+                            Builder,
+                            "witness limit {limit} is below the {witnesses_size} bytes taken by the transaction's witnesses"
+                        ));
+                    }
                     Some(limit) => limit,
-                    None => self.calculate_witnesses_size()?,
+                    None => witnesses_size,
                 };
                 let mut policies = Policies::default().with_witness_limit(witness_limit);
 
@@ -324,6 +449,7 @@ macro_rules! impl_tx_trait {
                 Ok(padded_len as u64)
             }
 
+            #[cfg(feature = "estimation")]
             async fn set_max_fee_policy<T: Clone + PoliciesField + Chargeable + Into<$tx_ty>>(
                 tx: &mut T,
                 provider: impl DryRunner,
@@ -337,7 +463,7 @@ macro_rules! impl_tx_trait {
                 }
 
                 let gas_price = provider.estimate_gas_price(block_horizon).await?;
-                let consensus_parameters = provider.consensus_parameters();
+                let consensus_parameters = provider.fetch_consensus_parameters().await?;
 
                 let tx_fee = TransactionFee::checked_from_tx(
                     &consensus_parameters.gas_costs(),
@@ -355,6 +481,19 @@ macro_rules! impl_tx_trait {
 
                 Ok(())
             }
+
+            #[cfg(not(feature = "estimation"))]
+            async fn set_max_fee_policy<T: Clone + PoliciesField + Chargeable + Into<$tx_ty>>(
+                _tx: &mut T,
+                _provider: impl DryRunner,
+                _block_horizon: u32,
+                _is_using_predicates: bool,
+            ) -> Result<()> {
+                Err(error_transaction!(
+                    Builder,
+                    "the max fee must be set in the `TxPolicies` without the `estimation` feature"
+                ))
+            }
         }
     };
 }
@@ -380,7 +519,8 @@ impl Debug for dyn Signer + Send + Sync {
 ///
 /// It is advised to avoid relying on automatic estimation of variable outputs if the script
 /// contains logic that dynamically adjusts based on the number of outputs.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum VariableOutputPolicy {
     /// Perform a dry run of the transaction estimating the minimum number of variable outputs to
     /// add.
@@ -405,6 +545,9 @@ pub struct ScriptTransactionBuilder {
     pub tx_policies: TxPolicies,
     pub gas_estimation_tolerance: f32,
     pub gas_price_estimation_block_horizon: u32,
+    pub gas_price_strategy: Option<GasPriceStrategy>,
+    pub coin_selection_policy: Option<CoinSelectionPolicy>,
+    pub change_addresses: HashMap<Bech32Address, Bech32Address>,
     pub variable_output_policy: VariableOutputPolicy,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
@@ -421,6 +564,9 @@ pub struct CreateTransactionBuilder {
     pub tx_policies: TxPolicies,
     pub salt: Salt,
     pub gas_price_estimation_block_horizon: u32,
+    pub gas_price_strategy: Option<GasPriceStrategy>,
+    pub coin_selection_policy: Option<CoinSelectionPolicy>,
+    pub change_addresses: HashMap<Bech32Address, Bech32Address>,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
 }
@@ -442,6 +588,9 @@ pub struct UploadTransactionBuilder {
     pub witnesses: Vec<Witness>,
     pub tx_policies: TxPolicies,
     pub gas_price_estimation_block_horizon: u32,
+    pub gas_price_strategy: Option<GasPriceStrategy>,
+    pub coin_selection_policy: Option<CoinSelectionPolicy>,
+    pub change_addresses: HashMap<Bech32Address, Bech32Address>,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
 }
@@ -454,6 +603,9 @@ pub struct UpgradeTransactionBuilder {
     pub witnesses: Vec<Witness>,
     pub tx_policies: TxPolicies,
     pub gas_price_estimation_block_horizon: u32,
+    pub gas_price_strategy: Option<GasPriceStrategy>,
+    pub coin_selection_policy: Option<CoinSelectionPolicy>,
+    pub change_addresses: HashMap<Bech32Address, Bech32Address>,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
 }
@@ -469,6 +621,9 @@ impl Default for UpgradeTransactionBuilder {
             witnesses: Default::default(),
             tx_policies: Default::default(),
             gas_price_estimation_block_horizon: Default::default(),
+            gas_price_strategy: Default::default(),
+            coin_selection_policy: Default::default(),
+            change_addresses: Default::default(),
             unresolved_witness_indexes: Default::default(),
             unresolved_signers: Default::default(),
         }
@@ -481,17 +636,43 @@ impl_tx_trait!(UploadTransactionBuilder, UploadTransaction);
 impl_tx_trait!(UpgradeTransactionBuilder, UpgradeTransaction);
 
 impl ScriptTransactionBuilder {
-    async fn build(self, provider: impl DryRunner) -> Result<ScriptTransaction> {
-        Ok(ScriptTransaction {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "build_tx",
+            skip_all,
+            fields(
+                tx_id = tracing::field::Empty,
+                script_gas_limit = tracing::field::Empty,
+                max_fee = tracing::field::Empty
+            )
+        )
+    )]
+    async fn build(mut self, provider: impl DryRunner) -> Result<ScriptTransaction> {
+        self.apply_gas_price_strategy(&provider).await?;
+        self.resolve_change_outputs(provider.fetch_consensus_parameters().await?.base_asset_id())?;
+
+        let tx = ScriptTransaction {
             is_using_predicates: self.is_using_predicates(),
             tx: self.resolve_fuel_tx(&provider).await?,
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            let chain_id = provider.fetch_consensus_parameters().await?.chain_id();
+            span.record("tx_id", tracing::field::display(tx.id(chain_id)));
+            span.record("script_gas_limit", *tx.tx.script_gas_limit());
+            span.record("max_fee", tx.max_fee());
+        }
+
+        Ok(tx)
     }
 
     async fn resolve_fuel_tx(self, dry_runner: impl DryRunner) -> Result<Script> {
+        self.tx_policies
+            .validate(&dry_runner.fetch_consensus_parameters().await?)?;
         let num_resolved_witnesses = self.num_witnesses()?;
-        let predefined_witnesses = self.witnesses.clone();
-        let mut script_dry_runner = self.script_dry_runner(predefined_witnesses, &dry_runner);
 
         let mut tx = FuelTransaction::script(
             0, // default value - will be overwritten
@@ -507,12 +688,19 @@ impl ScriptTransactionBuilder {
             vec![],
         );
 
-        self.add_variable_outputs(&mut script_dry_runner, &mut tx)
-            .await?;
+        #[cfg(feature = "estimation")]
+        {
+            let mut script_dry_runner = self.script_dry_runner(self.witnesses.clone(), &dry_runner);
 
-        // should come after variable outputs because it can then reuse the dry run made for variable outputs
-        self.set_script_gas_limit(&mut script_dry_runner, &mut tx)
-            .await?;
+            self.add_variable_outputs(&mut script_dry_runner, &mut tx)
+                .await?;
+
+            // should come after variable outputs because it can then reuse the dry run made for variable outputs
+            self.set_script_gas_limit(&mut script_dry_runner, &mut tx)
+                .await?;
+        }
+        #[cfg(not(feature = "estimation"))]
+        self.set_specified_outputs_and_gas(&mut tx)?;
 
         if let Some(max_fee) = self.tx_policies.max_fee() {
             tx.policies_mut().set(PolicyType::MaxFee, Some(max_fee));
@@ -533,7 +721,7 @@ impl ScriptTransactionBuilder {
 
     async fn set_witnesses(self, tx: &mut fuel_tx::Script, provider: impl DryRunner) -> Result<()> {
         let missing_witnesses = generate_missing_witnesses(
-            tx.id(&provider.consensus_parameters().chain_id()),
+            tx.id(&provider.fetch_consensus_parameters().await?.chain_id()),
             &self.unresolved_signers,
         )
         .await?;
@@ -541,6 +729,15 @@ impl ScriptTransactionBuilder {
         Ok(())
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "estimate_script_gas",
+            skip_all,
+            fields(script_gas_limit = tracing::field::Empty)
+        )
+    )]
+    #[cfg(feature = "estimation")]
     async fn set_script_gas_limit(
         &self,
         dry_runner: &mut ScriptDryRunner<&impl DryRunner>,
@@ -568,10 +765,14 @@ impl ScriptTransactionBuilder {
             dry_run.gas_with_tolerance(self.gas_estimation_tolerance)
         };
 
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("script_gas_limit", script_gas_limit);
+
         *tx.script_gas_limit_mut() = script_gas_limit;
         Ok(())
     }
 
+    #[cfg(feature = "estimation")]
     fn script_dry_runner<D>(
         &self,
         predefined_witnesses: Vec<Witness>,
@@ -584,6 +785,7 @@ impl ScriptTransactionBuilder {
         ScriptDryRunner::new(dry_runner, predefined_witnesses, num_unresolved_witnesses)
     }
 
+    #[cfg(feature = "estimation")]
     async fn add_variable_outputs(
         &self,
         dry_runner: &mut ScriptDryRunner<&impl DryRunner>,
@@ -600,6 +802,33 @@ impl ScriptTransactionBuilder {
         Ok(())
     }
 
+    /// Without estimation, the variable outputs and the script gas limit are taken as given.
+    #[cfg(not(feature = "estimation"))]
+    fn set_specified_outputs_and_gas(&self, tx: &mut fuel_tx::Script) -> Result<()> {
+        let VariableOutputPolicy::Exactly(variable_outputs) = self.variable_output_policy else {
+            return Err(error_transaction!(
+                Builder,
+                "estimating variable outputs requires the `estimation` feature"
+            ));
+        };
+        add_variable_outputs(tx, variable_outputs);
+
+        let script_gas_limit = match self.tx_policies.script_gas_limit() {
+            Some(gas_limit) => gas_limit,
+            None if self.script.is_empty() => 0,
+            None => {
+                return Err(error_transaction!(
+                    Builder,
+                    "the script gas limit must be set in the `TxPolicies` without the \
+                    `estimation` feature"
+                ))
+            }
+        };
+        *tx.script_gas_limit_mut() = script_gas_limit;
+
+        Ok(())
+    }
+
     pub fn with_variable_output_policy(mut self, variable_outputs: VariableOutputPolicy) -> Self {
         self.variable_output_policy = variable_outputs;
         self
@@ -712,6 +941,38 @@ impl ScriptTransactionBuilder {
             .with_outputs(outputs)
     }
 
+    /// Turns an existing transaction, e.g. one that failed or expired, back into a builder so
+    /// that it can be modified and issued again.
+    ///
+    /// The script, script data, inputs, outputs, tip and maturity are kept. Witnesses are
+    /// dropped, so signed inputs need their signers added again with
+    /// [`TransactionBuilder::add_signer`]. Variable outputs are dropped as well and re-added by
+    /// the builder according to its [`VariableOutputPolicy`], while the script gas limit, the
+    /// max fee and the witness limit are estimated anew when building.
+    pub fn from_existing(tx: &ScriptTransaction) -> Self {
+        let (variable_outputs, outputs): (Vec<_>, Vec<_>) = tx
+            .outputs()
+            .iter()
+            .cloned()
+            .partition(|output| matches!(output, Output::Variable { .. }));
+
+        let mut tx_policies = TxPolicies::default();
+        if let Some(tip) = tx.tip() {
+            tx_policies = tx_policies.with_tip(tip);
+        }
+        if tx.maturity() != 0 {
+            tx_policies = tx_policies.with_maturity(tx.maturity().into());
+        }
+
+        ScriptTransactionBuilder::default()
+            .with_script(tx.script().clone())
+            .with_script_data(tx.script_data().clone())
+            .with_inputs(tx.inputs().iter().cloned().map(input_from_fuel).collect())
+            .with_outputs(outputs)
+            .with_tx_policies(tx_policies)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(variable_outputs.len()))
+    }
+
     fn clone_without_signers(&self) -> Self {
         Self {
             script: self.script.clone(),
@@ -724,12 +985,20 @@ impl ScriptTransactionBuilder {
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
+            gas_price_strategy: self.gas_price_strategy,
+            coin_selection_policy: self.coin_selection_policy.clone(),
+            change_addresses: self.change_addresses.clone(),
             variable_output_policy: self.variable_output_policy,
         }
     }
 }
 
-fn add_variable_outputs(tx: &mut fuel_tx::Script, variable_outputs: usize) {
+/// Appends `variable_outputs` empty [`Output::Variable`]s to `tx`. The VM fills them in when
+/// the script transfers or mints assets to an address.
+///
+/// This is what [`ScriptTransactionBuilder`] does according to its [`VariableOutputPolicy`] and
+/// is part of the public API so that custom builders don't have to replicate it.
+pub fn add_variable_outputs<T: Outputs>(tx: &mut T, variable_outputs: usize) {
     tx.outputs_mut().extend(
         repeat(Output::Variable {
             amount: 0,
@@ -741,7 +1010,10 @@ fn add_variable_outputs(tx: &mut fuel_tx::Script, variable_outputs: usize) {
 }
 
 impl CreateTransactionBuilder {
-    pub async fn build(self, provider: impl DryRunner) -> Result<CreateTransaction> {
+    pub async fn build(mut self, provider: impl DryRunner) -> Result<CreateTransaction> {
+        self.apply_gas_price_strategy(&provider).await?;
+        self.resolve_change_outputs(provider.fetch_consensus_parameters().await?.base_asset_id())?;
+
         Ok(CreateTransaction {
             is_using_predicates: self.is_using_predicates(),
             tx: self.resolve_fuel_tx(&provider).await?,
@@ -749,7 +1021,9 @@ impl CreateTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, provider: impl DryRunner) -> Result<Create> {
-        let chain_id = provider.consensus_parameters().chain_id();
+        let consensus_parameters = &provider.fetch_consensus_parameters().await?;
+        self.tx_policies.validate(consensus_parameters)?;
+        let chain_id = consensus_parameters.chain_id();
         let num_witnesses = self.num_witnesses()?;
         let policies = self.generate_fuel_policies()?;
         let is_using_predicates = self.is_using_predicates();
@@ -840,12 +1114,18 @@ impl CreateTransactionBuilder {
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
+            gas_price_strategy: self.gas_price_strategy,
+            coin_selection_policy: self.coin_selection_policy.clone(),
+            change_addresses: self.change_addresses.clone(),
         }
     }
 }
 
 impl UploadTransactionBuilder {
-    pub async fn build(self, provider: impl DryRunner) -> Result<UploadTransaction> {
+    pub async fn build(mut self, provider: impl DryRunner) -> Result<UploadTransaction> {
+        self.apply_gas_price_strategy(&provider).await?;
+        self.resolve_change_outputs(provider.fetch_consensus_parameters().await?.base_asset_id())?;
+
         Ok(UploadTransaction {
             is_using_predicates: self.is_using_predicates(),
             tx: self.resolve_fuel_tx(&provider).await?,
@@ -853,7 +1133,9 @@ impl UploadTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, provider: impl DryRunner) -> Result<Upload> {
-        let chain_id = provider.consensus_parameters().chain_id();
+        let consensus_parameters = &provider.fetch_consensus_parameters().await?;
+        self.tx_policies.validate(consensus_parameters)?;
+        let chain_id = consensus_parameters.chain_id();
         let num_witnesses = self.num_witnesses()?;
         let policies = self.generate_fuel_policies()?;
         let is_using_predicates = self.is_using_predicates();
@@ -955,13 +1237,19 @@ impl UploadTransactionBuilder {
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
+            gas_price_strategy: self.gas_price_strategy,
+            coin_selection_policy: self.coin_selection_policy.clone(),
+            change_addresses: self.change_addresses.clone(),
             proof_set: vec![],
         }
     }
 }
 
 impl UpgradeTransactionBuilder {
-    pub async fn build(self, provider: impl DryRunner) -> Result<UpgradeTransaction> {
+    pub async fn build(mut self, provider: impl DryRunner) -> Result<UpgradeTransaction> {
+        self.apply_gas_price_strategy(&provider).await?;
+        self.resolve_change_outputs(provider.fetch_consensus_parameters().await?.base_asset_id())?;
+
         Ok(UpgradeTransaction {
             is_using_predicates: self.is_using_predicates(),
             tx: self.resolve_fuel_tx(&provider).await?,
@@ -969,7 +1257,9 @@ impl UpgradeTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, provider: impl DryRunner) -> Result<Upgrade> {
-        let chain_id = provider.consensus_parameters().chain_id();
+        let consensus_parameters = &provider.fetch_consensus_parameters().await?;
+        self.tx_policies.validate(consensus_parameters)?;
+        let chain_id = consensus_parameters.chain_id();
         let num_witnesses = self.num_witnesses()?;
         let policies = self.generate_fuel_policies()?;
         let is_using_predicates = self.is_using_predicates();
@@ -1043,6 +1333,9 @@ impl UpgradeTransactionBuilder {
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
+            gas_price_strategy: self.gas_price_strategy,
+            coin_selection_policy: self.coin_selection_policy.clone(),
+            change_addresses: self.change_addresses.clone(),
         }
     }
 }
@@ -1082,6 +1375,128 @@ fn resolve_fuel_inputs(
         .collect()
 }
 
+/// The owners of the coins and messages among `inputs`, paired with the assets they put in.
+fn contributions(inputs: &[Input], base_asset_id: &AssetId) -> Vec<(Bech32Address, AssetId)> {
+    inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. } => {
+                let asset_id = resource.coin_asset_id().unwrap_or(*base_asset_id);
+
+                Some((resource.owner().clone(), asset_id))
+            }
+            Input::Contract { .. } => None,
+        })
+        .unique()
+        .collect()
+}
+
+fn returns_to(output: &Output, address: &Address, asset: &AssetId) -> bool {
+    match output {
+        Output::Change { to, asset_id, .. } | Output::Coin { to, asset_id, .. } => {
+            to == address && asset_id == asset
+        }
+        _ => false,
+    }
+}
+
+/// The inverse of [`resolve_fuel_inputs`], leaving out the witness indexes and the predicate gas
+/// which are resolved again when building.
+fn input_from_fuel(input: FuelInput) -> Input {
+    let coin = |utxo_id, owner: Address, amount, asset_id| {
+        CoinType::Coin(Coin {
+            amount,
+            asset_id,
+            utxo_id,
+            owner: owner.into(),
+            ..Default::default()
+        })
+    };
+    let message = |sender: Address, recipient: Address, amount, nonce, data| {
+        CoinType::Message(Message {
+            amount,
+            sender: sender.into(),
+            recipient: recipient.into(),
+            nonce,
+            data,
+            da_height: 0,
+            status: MessageStatus::Unspent,
+        })
+    };
+
+    match input {
+        FuelInput::CoinSigned(CoinSigned {
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            ..
+        }) => Input::resource_signed(coin(utxo_id, owner, amount, asset_id)),
+        FuelInput::CoinPredicate(CoinPredicate {
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            predicate,
+            predicate_data,
+            ..
+        }) => Input::resource_predicate(
+            coin(utxo_id, owner, amount, asset_id),
+            predicate.to_vec(),
+            predicate_data.to_vec(),
+        ),
+        FuelInput::MessageCoinSigned(MessageCoinSigned {
+            sender,
+            recipient,
+            amount,
+            nonce,
+            ..
+        }) => Input::resource_signed(message(sender, recipient, amount, nonce, vec![])),
+        FuelInput::MessageDataSigned(MessageDataSigned {
+            sender,
+            recipient,
+            amount,
+            nonce,
+            data,
+            ..
+        }) => Input::resource_signed(message(sender, recipient, amount, nonce, data.to_vec())),
+        FuelInput::MessageCoinPredicate(MessageCoinPredicate {
+            sender,
+            recipient,
+            amount,
+            nonce,
+            predicate,
+            predicate_data,
+            ..
+        }) => Input::resource_predicate(
+            message(sender, recipient, amount, nonce, vec![]),
+            predicate.to_vec(),
+            predicate_data.to_vec(),
+        ),
+        FuelInput::MessageDataPredicate(MessageDataPredicate {
+            sender,
+            recipient,
+            amount,
+            nonce,
+            data,
+            predicate,
+            predicate_data,
+            ..
+        }) => Input::resource_predicate(
+            message(sender, recipient, amount, nonce, data.to_vec()),
+            predicate.to_vec(),
+            predicate_data.to_vec(),
+        ),
+        FuelInput::Contract(contract) => Input::contract(
+            contract.utxo_id,
+            contract.balance_root,
+            contract.state_root,
+            contract.tx_pointer,
+            contract.contract_id,
+        ),
+    }
+}
+
 fn resolve_signed_resource(
     resource: CoinType,
     num_witnesses: u16,
@@ -1126,6 +1541,7 @@ fn resolve_predicate_resource(resource: CoinType, code: Vec<u8>, data: Vec<u8>)
     }
 }
 
+/// Creates a signed coin input whose signature is expected at `witness_index`.
 pub fn create_coin_input(coin: Coin, witness_index: u16) -> FuelInput {
     FuelInput::coin_signed(
         coin.utxo_id,
@@ -1137,6 +1553,8 @@ pub fn create_coin_input(coin: Coin, witness_index: u16) -> FuelInput {
     )
 }
 
+/// Creates a signed message input whose signature is expected at `witness_index`. Messages
+/// without data are turned into message coins.
 pub fn create_coin_message_input(message: Message, witness_index: u16) -> FuelInput {
     if message.data.is_empty() {
         FuelInput::message_coin_signed(
@@ -1158,6 +1576,8 @@ pub fn create_coin_message_input(message: Message, witness_index: u16) -> FuelIn
     }
 }
 
+/// Creates a coin input owned by the predicate with the given `code` and `predicate_data`.
+/// The predicate gas used is left at `0` to be estimated later.
 pub fn create_coin_predicate(
     asset_id: AssetId,
     coin: Coin,
@@ -1176,6 +1596,8 @@ pub fn create_coin_predicate(
     )
 }
 
+/// Creates a message input owned by the predicate with the given `code` and `predicate_data`.
+/// The predicate gas used is left at `0` to be estimated later.
 pub fn create_coin_message_predicate(
     message: Message,
     code: Vec<u8>,
@@ -1205,7 +1627,12 @@ pub fn create_coin_message_predicate(
     }
 }
 
-async fn generate_missing_witnesses(
+/// Signs the transaction `id` with every signer, in order, returning the resulting witnesses.
+///
+/// The builders use this when resolving the signers added through
+/// [`TransactionBuilder::add_signer`]. The witnesses have to be placed at the indexes the signed
+/// inputs point to.
+pub async fn generate_missing_witnesses(
     id: Bytes32,
     unresolved_signatures: &[Box<dyn Signer + Send + Sync>],
 ) -> Result<Vec<Witness>> {
@@ -1225,10 +1652,10 @@ mod tests {
     use std::iter::repeat_with;
 
     use fuel_crypto::Signature;
-    use fuel_tx::{input::coin::CoinSigned, ConsensusParameters, UtxoId};
+    use fuel_tx::{ConsensusParameters, UtxoId};
 
     use super::*;
-    use crate::types::{bech32::Bech32Address, message::MessageStatus, DryRun};
+    use crate::types::{bech32::Bech32Address, DryRun};
 
     #[test]
     fn storage_slots_are_sorted_when_set() {
@@ -1248,6 +1675,72 @@ mod tests {
         StorageSlot::new(bytes_32, Default::default())
     }
 
+    #[test]
+    fn variable_outputs_are_appended() {
+        let existing_output = Output::change(Address::zeroed(), 0, AssetId::zeroed());
+        let mut tx = FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![],
+            vec![existing_output],
+            vec![],
+        );
+
+        add_variable_outputs(&mut tx, 2);
+
+        assert_eq!(tx.outputs().len(), 3);
+        assert_eq!(tx.outputs()[0], existing_output);
+        assert!(tx.outputs()[1..]
+            .iter()
+            .all(|output| matches!(output, Output::Variable { amount: 0, .. })));
+    }
+
+    #[tokio::test]
+    async fn missing_witnesses_are_generated_in_signer_order() -> Result<()> {
+        // given
+        let signers: Vec<Box<dyn Signer + Send + Sync>> =
+            vec![Box::new(ConstSigner::new(1)), Box::new(ConstSigner::new(2))];
+
+        // when
+        let witnesses = generate_missing_witnesses(Bytes32::zeroed(), &signers).await?;
+
+        // then
+        let expected: Vec<Witness> = [1, 2]
+            .map(|byte| Signature::from_bytes([byte; 64]).as_ref().into())
+            .to_vec();
+        assert_eq!(witnesses, expected);
+
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    struct ConstSigner {
+        byte: u8,
+        address: Bech32Address,
+    }
+
+    impl ConstSigner {
+        fn new(byte: u8) -> Self {
+            Self {
+                byte,
+                address: Bech32Address::new("fuel", [byte; 32]),
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Signer for ConstSigner {
+        async fn sign(&self, _message: CryptoMessage) -> Result<Signature> {
+            Ok(Signature::from_bytes([self.byte; 64]))
+        }
+        fn address(&self) -> &Bech32Address {
+            &self.address
+        }
+    }
+
     #[test]
     fn create_message_coin_signed_if_data_is_empty() {
         assert!(matches!(
@@ -1280,6 +1773,56 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn existing_transaction_is_turned_back_into_a_builder() {
+        // given
+        let Input::ResourceSigned { resource } = given_inputs(1).remove(0) else {
+            unreachable!()
+        };
+        let CoinType::Coin(coin) = resource.clone() else {
+            unreachable!()
+        };
+        let message = given_a_message(vec![42]);
+        let change = Output::change(Address::zeroed(), 0, AssetId::zeroed());
+        let mut fuel_tx = FuelTransaction::script(
+            100,
+            vec![1, 2],
+            vec![3],
+            Policies::default().with_tip(5).with_max_fee(1000),
+            vec![
+                create_coin_input(coin, 0),
+                create_coin_message_predicate(message.clone(), vec![4], vec![5]),
+            ],
+            vec![change],
+            vec![Witness::from(vec![6])],
+        );
+        add_variable_outputs(&mut fuel_tx, 2);
+        let tx = ScriptTransaction::from(fuel_tx);
+
+        // when
+        let builder = ScriptTransactionBuilder::from_existing(&tx);
+
+        // then
+        assert_eq!(builder.script, vec![1, 2]);
+        assert_eq!(builder.script_data, vec![3]);
+        assert_eq!(
+            builder.inputs,
+            vec![
+                Input::resource_signed(resource),
+                Input::resource_predicate(CoinType::Message(message), vec![4], vec![5]),
+            ]
+        );
+        assert_eq!(builder.outputs, vec![change]);
+        assert!(builder.witnesses.is_empty());
+        assert_eq!(builder.tx_policies.tip(), Some(5));
+        assert_eq!(builder.tx_policies.max_fee(), None);
+        assert_eq!(builder.tx_policies.script_gas_limit(), None);
+        assert!(matches!(
+            builder.variable_output_policy,
+            VariableOutputPolicy::Exactly(2)
+        ));
+    }
+
     fn given_a_message(data: Vec<u8>) -> Message {
         Message {
             sender: Bech32Address::default(),
@@ -1333,14 +1876,18 @@ mod tests {
             })
         }
 
-        fn consensus_parameters(&self) -> &ConsensusParameters {
-            &self.c_param
+        fn consensus_parameters(&self) -> ConsensusParameters {
+            self.c_param.clone()
         }
 
         async fn estimate_gas_price(&self, _block_horizon: u32) -> Result<u64> {
             Ok(0)
         }
 
+        async fn estimate_tip(&self, _percentile: u8, _blocks: u32) -> Result<u64> {
+            Ok(7)
+        }
+
         async fn maybe_estimate_predicates(
             &self,
             _tx: &FuelTransaction,
@@ -1362,7 +1909,7 @@ mod tests {
 
         // when
         let tx = tb
-            .build_without_signatures(&MockDryRunner::default())
+            .build_without_signatures(MockDryRunner::default())
             .await?;
 
         // then
@@ -1397,7 +1944,7 @@ mod tests {
 
         // when
         let tx = tb
-            .build_without_signatures(&MockDryRunner::default())
+            .build_without_signatures(MockDryRunner::default())
             .await?;
 
         // then
@@ -1420,6 +1967,122 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn change_is_returned_to_every_contributor() -> Result<()> {
+        // given
+        let mut inputs = given_inputs(2);
+        let [first, second] = [0, 1].map(|byte| Bech32Address::new("fuel", [byte; 32]));
+        let other_asset = AssetId::new([7; 32]);
+        inputs.push(Input::resource_signed(CoinType::Coin(Coin {
+            utxo_id: UtxoId::new([2; 32].into(), 0),
+            owner: second.clone(),
+            asset_id: other_asset,
+            ..Default::default()
+        })));
+        let change_to = Bech32Address::new("fuel", [9; 32]);
+        let tb = ScriptTransactionBuilder::default()
+            .with_inputs(inputs)
+            .with_change_address(&first, &change_to);
+
+        // when
+        let missing_change = tb
+            .clone_without_signers()
+            .build_without_signatures(MockDryRunner::default())
+            .await;
+        let tx = tb
+            .with_outputs(vec![Output::coin((&second).into(), 10, AssetId::zeroed())])
+            .build_without_signatures(MockDryRunner::default())
+            .await?;
+
+        // then
+        let error = missing_change.expect_err("the second owner gets no base asset back");
+        assert!(error.to_string().contains(&second.to_string()));
+        assert_eq!(
+            tx.outputs()[1..],
+            [
+                Output::change((&change_to).into(), 0, AssetId::zeroed()),
+                Output::change((&second).into(), 0, other_asset),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn percentile_strategy_sets_the_tip_unless_given() -> Result<()> {
+        // given
+        let strategy = GasPriceStrategy::Percentile {
+            percentile: 50,
+            blocks: 10,
+            block_horizon: 2,
+        };
+        let tb = ScriptTransactionBuilder::default()
+            .with_inputs(given_inputs(1))
+            .with_gas_price_strategy(strategy);
+        let tb_with_tip = ScriptTransactionBuilder::default()
+            .with_inputs(given_inputs(1))
+            .with_tx_policies(TxPolicies::default().with_tip(3))
+            .with_gas_price_strategy(strategy);
+
+        // when
+        let tx = tb
+            .build_without_signatures(MockDryRunner::default())
+            .await?;
+        let tx_with_tip = tb_with_tip
+            .build_without_signatures(MockDryRunner::default())
+            .await?;
+
+        // then
+        assert_eq!(tx.tip(), Some(7));
+        assert_eq!(tx_with_tip.tip(), Some(3));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tx_policies_are_checked_against_chain_limits() {
+        let dry_runner = MockDryRunner::default();
+        let max_gas_per_tx = dry_runner
+            .consensus_parameters()
+            .tx_params()
+            .max_gas_per_tx();
+
+        let cases = [
+            (
+                TxPolicies::default().with_script_gas_limit(max_gas_per_tx + 1),
+                "exceeds the chain's max gas per tx",
+            ),
+            (
+                TxPolicies::default().with_tip(10).with_max_fee(5),
+                "tip 10 is greater than the max fee 5",
+            ),
+            (
+                TxPolicies::default().with_maturity(u64::MAX),
+                "is not a valid block height",
+            ),
+            (
+                TxPolicies::default().with_witness_limit(0),
+                "is below the 72 bytes taken by the transaction's witnesses",
+            ),
+        ];
+
+        for (tx_policies, expected_error) in cases {
+            let tb = ScriptTransactionBuilder::default()
+                .with_inputs(given_inputs(1))
+                .with_tx_policies(tx_policies);
+
+            let err = tb
+                .build_without_signatures(&dry_runner)
+                .await
+                .expect_err("should fail");
+
+            assert!(
+                err.to_string().contains(expected_error),
+                "{err} should contain {expected_error}"
+            );
+        }
+    }
+
     #[derive(Clone, Debug, Default)]
     struct MockSigner {
         address: Bech32Address,
@@ -4,7 +4,7 @@ use fuel_crypto::Signature;
 use fuel_tx::{
     field::{Inputs, Outputs, ScriptGasLimit, WitnessLimit, Witnesses},
     input::coin::{CoinPredicate, CoinSigned},
-    AssetId, Chargeable, Input as FuelInput, TxPointer, Witness,
+    AssetId, Chargeable, ConsensusParameters, Input as FuelInput, TxPointer, Witness,
 };
 use itertools::Itertools;
 
@@ -42,11 +42,13 @@ impl<R: DryRunner> ScriptDryRunner<R> {
         saturate_variable_outputs: bool,
     ) -> Result<DryRun> {
         self.add_fake_witnesses(&mut tx);
-        self.add_fake_coins(&mut tx);
+
+        let consensus_params = &self.dry_runner.fetch_consensus_parameters().await?;
+        Self::add_fake_coins(&mut tx, consensus_params);
         if saturate_variable_outputs {
-            self.saturate_with_variable_outputs(&mut tx);
+            Self::saturate_with_variable_outputs(&mut tx, consensus_params);
         }
-        self.set_script_gas_limit_to_max(&mut tx);
+        Self::set_script_gas_limit_to_max(&mut tx, consensus_params);
 
         self._run(tx).await
     }
@@ -61,15 +63,18 @@ impl<R: DryRunner> ScriptDryRunner<R> {
         Ok(dry_run)
     }
 
-    fn set_script_gas_limit_to_max(&mut self, tx: &mut fuel_tx::Script) {
-        let consensus_params = self.dry_runner.consensus_parameters();
+    fn set_script_gas_limit_to_max(
+        tx: &mut fuel_tx::Script,
+        consensus_params: &ConsensusParameters,
+    ) {
         let max_gas = tx.max_gas(consensus_params.gas_costs(), consensus_params.fee_params()) + 1;
         *tx.script_gas_limit_mut() = consensus_params.tx_params().max_gas_per_tx() - max_gas;
     }
 
-    fn saturate_with_variable_outputs(&mut self, tx: &mut fuel_tx::Script) {
-        let consensus_params = self.dry_runner.consensus_parameters();
-
+    fn saturate_with_variable_outputs(
+        tx: &mut fuel_tx::Script,
+        consensus_params: &ConsensusParameters,
+    ) {
         let max_outputs = usize::from(consensus_params.tx_params().max_outputs());
         let used_outputs = tx.outputs().len();
 
@@ -92,9 +97,7 @@ impl<R: DryRunner> ScriptDryRunner<R> {
         *tx.witnesses_mut() = [predefined_witnesses, dry_run_witnesses].concat();
     }
 
-    fn add_fake_coins(&mut self, tx: &mut fuel_tx::Script) {
-        let consensus_params = self.dry_runner.consensus_parameters();
-
+    fn add_fake_coins(tx: &mut fuel_tx::Script, consensus_params: &ConsensusParameters) {
         if let Some(fake_input) =
             Self::needs_fake_base_input(tx.inputs(), consensus_params.base_asset_id())
         {
@@ -6,13 +6,16 @@ use fuel_abi_types::error_codes::{
 use fuel_core_client::client::types::TransactionStatus as ClientTransactionStatus;
 #[cfg(feature = "std")]
 use fuel_core_types::services::executor::{TransactionExecutionResult, TransactionExecutionStatus};
-use fuel_tx::Receipt;
+use fuel_tx::{ContractId, Receipt};
 #[cfg(feature = "std")]
 use fuel_vm::state::ProgramState;
 
 use crate::{
     codec::LogDecoder,
-    types::errors::{transaction::Reason, Error, Result},
+    types::errors::{
+        transaction::{DecodedRevert, Reason},
+        Error, Result,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -52,10 +55,17 @@ impl TxStatus {
         id: u64,
         log_decoder: Option<&LogDecoder>,
     ) -> Result<()> {
+        let mut error_type = None;
         let reason = match (id, log_decoder) {
-            (FAILED_REQUIRE_SIGNAL, Some(log_decoder)) => log_decoder
-                .decode_last_log(receipts)
-                .unwrap_or_else(|err| format!("failed to decode log from require revert: {err}")),
+            (FAILED_REQUIRE_SIGNAL, Some(log_decoder)) => {
+                match log_decoder.decode_last_log_with_type_name(receipts) {
+                    Ok((log, type_name)) => {
+                        error_type = type_name;
+                        log
+                    }
+                    Err(err) => format!("failed to decode log from require revert: {err}"),
+                }
+            }
             (FAILED_ASSERT_EQ_SIGNAL, Some(log_decoder)) => {
                 match log_decoder.decode_last_two_logs(receipts) {
                     Ok((lhs, rhs)) => format!(
@@ -72,10 +82,17 @@ impl TxStatus {
             _ => reason.to_string(),
         };
 
+        let decoded = DecodedRevert {
+            contract_id: reverting_contract(receipts),
+            error_type,
+            message: reason.clone(),
+        };
+
         Err(Error::Transaction(Reason::Reverted {
             reason,
             revert_id: id,
             receipts: receipts.to_vec(),
+            decoded: Some(Box::new(decoded)),
         }))
     }
 
@@ -92,6 +109,18 @@ impl TxStatus {
     }
 }
 
+/// The contract of the last `Revert` or `Panic` receipt, zeroed if the script failed.
+fn reverting_contract(receipts: &[Receipt]) -> ContractId {
+    receipts
+        .iter()
+        .rev()
+        .find_map(|receipt| match receipt {
+            Receipt::Revert { id, .. } | Receipt::Panic { id, .. } => Some(*id),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(feature = "std")]
 impl From<ClientTransactionStatus> for TxStatus {
     fn from(client_status: ClientTransactionStatus) -> Self {
@@ -0,0 +1,192 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::types::{
+    errors::{error, Result},
+    param_types::ParamType,
+    Token,
+};
+
+/// The version of the format written by [`Versioned::to_json`].
+pub const SERIALIZATION_FORMAT_VERSION: u32 = 1;
+
+/// A stable JSON format for [`Token`]s and [`ParamType`]s, so decoded values and type
+/// descriptions can be shipped between processes.
+///
+/// Values are wrapped in an envelope carrying the format version: `{"version": 1, "value": ...}`.
+/// Within `value`, version 1 uses serde's externally tagged representation:
+///
+/// * variants without data are strings: `"Unit"`, `"U64"`, `"Bytes"`.
+/// * variants with data are single key objects: `{"U64": 5}`, `{"Array": ["U8", 2]}`,
+///   `{"Struct": {"name": "Foo", "fields": [["a", "U64"]], "generics": []}}`.
+/// * `Token::U256` is a decimal string, `Token::B256` an array of 32 bytes.
+/// * enum variants are `{"variants": [[name, type], ...]}` objects, as serialized before the
+///   format was versioned, and must not be empty.
+/// * `Token::Enum` is `[discriminant, token, variants]`.
+///
+/// Deserializing a different version fails instead of guessing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub version: u32,
+    pub value: T,
+}
+
+impl<T: Serialize + DeserializeOwned> Versioned<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            version: SERIALIZATION_FORMAT_VERSION,
+            value,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| error!(Codec, "failed to serialize: {e}"))
+    }
+
+    /// Parses `json`, failing if it was written in a different format version.
+    pub fn from_json(json: &str) -> Result<T> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            version: u32,
+        }
+
+        let Envelope { version } =
+            serde_json::from_str(json).map_err(|e| error!(Codec, "missing format version: {e}"))?;
+
+        if version != SERIALIZATION_FORMAT_VERSION {
+            return Err(error!(
+                Codec,
+                "unsupported format version {version}, expected {SERIALIZATION_FORMAT_VERSION}"
+            ));
+        }
+
+        let versioned: Self =
+            serde_json::from_str(json).map_err(|e| error!(Codec, "failed to deserialize: {e}"))?;
+
+        Ok(versioned.value)
+    }
+}
+
+impl Token {
+    pub fn to_versioned_json(&self) -> Result<String> {
+        Versioned::new(self.clone()).to_json()
+    }
+
+    pub fn from_versioned_json(json: &str) -> Result<Self> {
+        Versioned::from_json(json)
+    }
+}
+
+impl ParamType {
+    pub fn to_versioned_json(&self) -> Result<String> {
+        Versioned::new(self.clone()).to_json()
+    }
+
+    pub fn from_versioned_json(json: &str) -> Result<Self> {
+        Versioned::from_json(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{param_types::EnumVariants, StaticStringToken, U256};
+
+    #[test]
+    fn token_format_is_stable() -> Result<()> {
+        let token = Token::Struct(vec![
+            Token::U64(1),
+            Token::Bool(true),
+            Token::Unit,
+            Token::U256(U256::from(2)),
+        ]);
+
+        let json = token.to_versioned_json()?;
+
+        assert_eq!(
+            json,
+            r#"{"version":1,"value":{"Struct":[{"U64":1},{"Bool":true},"Unit",{"U256":"2"}]}}"#
+        );
+        assert_eq!(Token::from_versioned_json(&json)?, token);
+
+        Ok(())
+    }
+
+    #[test]
+    fn param_type_format_is_stable() -> Result<()> {
+        let param_type = ParamType::Enum {
+            name: "E".to_string(),
+            enum_variants: EnumVariants::new(vec![
+                ("A".to_string(), ParamType::Unit),
+                (
+                    "B".to_string(),
+                    ParamType::Array(Box::new(ParamType::U8), 2),
+                ),
+            ])?,
+            generics: vec![],
+        };
+
+        let json = param_type.to_versioned_json()?;
+
+        assert_eq!(
+            json,
+            r#"{"version":1,"value":{"Enum":{"name":"E","enum_variants":{"variants":[["A","Unit"],["B",{"Array":["U8",2]}]]},"generics":[]}}}"#
+        );
+        assert_eq!(ParamType::from_versioned_json(&json)?, param_type);
+
+        Ok(())
+    }
+
+    #[test]
+    fn enum_variants_keep_their_unversioned_shape() -> Result<()> {
+        // given
+        let unversioned =
+            r#"{"Enum":{"name":"E","enum_variants":{"variants":[["A","Unit"]]},"generics":[]}}"#;
+
+        // when
+        let param_type: ParamType = serde_json::from_str(unversioned)?;
+
+        // then
+        assert_eq!(serde_json::to_string(&param_type)?, unversioned);
+
+        Ok(())
+    }
+
+    #[test]
+    fn all_tokens_round_trip() -> Result<()> {
+        let variants = EnumVariants::new(vec![("A".to_string(), ParamType::U8)])?;
+        let tokens = [
+            Token::U128(u128::MAX),
+            Token::B256([7; 32]),
+            Token::Bytes(vec![1, 2]),
+            Token::String("fuel".to_string()),
+            Token::RawSlice(vec![3]),
+            Token::StringArray(StaticStringToken::new("abc".to_string(), Some(3))),
+            Token::StringSlice(StaticStringToken::new("abc".to_string(), None)),
+            Token::Tuple(vec![Token::U8(1), Token::U16(2)]),
+            Token::Array(vec![Token::U32(3)]),
+            Token::Vector(vec![]),
+            Token::Enum(Box::new((0, Token::U8(1), variants))),
+        ];
+
+        for token in tokens {
+            let json = token.to_versioned_json()?;
+            assert_eq!(Token::from_versioned_json(&json)?, token);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_versions_and_empty_enums_are_rejected() {
+        let err =
+            Token::from_versioned_json(r#"{"version":2,"value":"Unit"}"#).expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "codec: unsupported format version 2, expected 1"
+        );
+
+        let empty_enum = r#"{"version":1,"value":{"Enum":{"name":"E","enum_variants":{"variants":[]},"generics":[]}}}"#;
+        let err = ParamType::from_versioned_json(empty_enum).expect_err("should fail");
+        assert!(err.to_string().contains("enum variants cannot be empty"));
+    }
+}
@@ -0,0 +1,308 @@
+#![cfg(feature = "relay")]
+//! Relaying messages sent from Fuel to the base layer, e.g. by
+//! `Account::withdraw_to_base_layer`, once the block that sent them is committed there.
+
+use tai64::Tai64;
+
+use crate::types::{
+    block::Header,
+    errors::{error, Result},
+    hashing,
+    message_proof::{MerkleProof, MessageProof},
+};
+
+/// The Solidity signature of `relayMessage` on the `FuelMessagePortal` base layer contract.
+///
+/// Its arguments are the structs of the portal's Solidity sources in `FuelLabs/fuel-bridge`, in
+/// `packages/solidity-contracts/contracts/fuelchain`:
+/// - `Message`: `sender`, `recipient`, `nonce`, `uint64 amount`, `bytes data`.
+/// - `FuelBlockHeaderLite`: `prevRoot`, `uint32 height`, `uint64 timestamp`, `applicationHash`.
+/// - `FuelBlockHeader`: `prevRoot`, `uint32 height`, `uint64 timestamp`, `uint64 daHeight`,
+///   `uint16 txCount`, `uint32 outputMessagesCount`, `txRoot`, `outputMessagesRoot`,
+///   `uint32 consensusParametersVersion`, `uint32 stateTransitionBytecodeVersion`.
+/// - `MerkleProof`: `uint256 key`, `bytes32[] proof`, once for the block and once for the message.
+pub const RELAY_MESSAGE_SIGNATURE: &str = "relayMessage((bytes32,bytes32,bytes32,uint64,bytes),(bytes32,uint32,uint64,bytes32),(bytes32,uint32,uint64,uint64,uint16,uint32,bytes32,bytes32,uint32,uint32),(uint256,bytes32[]),(uint256,bytes32[]))";
+
+/// The calldata of a `relayMessage` call on the `FuelMessagePortal` contract, which executes the
+/// message of `proof` on the base layer. Send it to the portal in a transaction of your base
+/// layer client.
+///
+/// The commit block of the proof has to be the one committed to the base layer, so request the
+/// proof with the height of the latest commitment.
+pub fn build_relay_payload(proof: &MessageProof) -> Result<Vec<u8>> {
+    let message = SolValue::Tuple(vec![
+        word(proof.sender.hash().as_slice()),
+        word(proof.recipient.hash().as_slice()),
+        word(proof.nonce.as_slice()),
+        word(&proof.amount.to_be_bytes()),
+        SolValue::Bytes(proof.data.clone()),
+    ]);
+
+    let root_block_header = &proof.commit_block_header;
+    let root_block_header_lite = SolValue::Tuple(vec![
+        word(root_block_header.prev_root.as_slice()),
+        word(&root_block_header.height.to_be_bytes()),
+        word(&tai64_timestamp(root_block_header)?.to_be_bytes()),
+        word(root_block_header.application_hash.as_slice()),
+    ]);
+
+    let block_header = &proof.message_block_header;
+    let message_block_header = SolValue::Tuple(vec![
+        word(block_header.prev_root.as_slice()),
+        word(&block_header.height.to_be_bytes()),
+        word(&tai64_timestamp(block_header)?.to_be_bytes()),
+        word(&block_header.da_height.to_be_bytes()),
+        word(&block_header.transactions_count.to_be_bytes()),
+        word(&block_header.message_receipt_count.to_be_bytes()),
+        word(block_header.transactions_root.as_slice()),
+        word(block_header.message_outbox_root.as_slice()),
+        word(&block_header.consensus_parameters_version.to_be_bytes()),
+        word(&block_header.state_transition_bytecode_version.to_be_bytes()),
+    ]);
+
+    let args = SolValue::Tuple(vec![
+        message,
+        root_block_header_lite,
+        message_block_header,
+        merkle_proof(&proof.block_proof),
+        merkle_proof(&proof.message_proof),
+    ]);
+
+    let selector = hashing::keccak256(RELAY_MESSAGE_SIGNATURE);
+
+    Ok([&selector[..4], &args.encode()].concat())
+}
+
+fn tai64_timestamp(header: &Header) -> Result<u64> {
+    let time = header
+        .time
+        .ok_or_else(|| error!(Other, "header of block {} has no time", header.height))?;
+
+    Ok(Tai64::from_unix(time.timestamp()).0)
+}
+
+fn merkle_proof(proof: &MerkleProof) -> SolValue {
+    SolValue::Tuple(vec![
+        word(&proof.proof_index.to_be_bytes()),
+        SolValue::Array(
+            proof
+                .proof_set
+                .iter()
+                .map(|node| word(node.as_slice()))
+                .collect(),
+        ),
+    ])
+}
+
+/// Right-aligns big-endian `bytes` in a word, as Solidity does for integers. 32 bytes are kept
+/// as is.
+fn word(bytes: &[u8]) -> SolValue {
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+
+    SolValue::Word(word)
+}
+
+/// The subset of the Solidity ABI needed for `relayMessage`.
+enum SolValue {
+    Word([u8; 32]),
+    Bytes(Vec<u8>),
+    Array(Vec<SolValue>),
+    Tuple(Vec<SolValue>),
+}
+
+impl SolValue {
+    fn is_dynamic(&self) -> bool {
+        match self {
+            SolValue::Word(_) => false,
+            SolValue::Bytes(_) | SolValue::Array(_) => true,
+            SolValue::Tuple(values) => values.iter().any(SolValue::is_dynamic),
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            SolValue::Word(word) => word.to_vec(),
+            SolValue::Bytes(bytes) => {
+                let padding = (32 - bytes.len() % 32) % 32;
+                [length(bytes.len()), bytes.clone(), vec![0; padding]].concat()
+            }
+            SolValue::Array(values) => [length(values.len()), encode_sequence(values)].concat(),
+            SolValue::Tuple(values) => encode_sequence(values),
+        }
+    }
+}
+
+fn length(len: usize) -> Vec<u8> {
+    word(&(len as u64).to_be_bytes()).encode()
+}
+
+// static values are encoded in place, dynamic ones after all of the heads, behind an offset
+fn encode_sequence(values: &[SolValue]) -> Vec<u8> {
+    let encoded: Vec<_> = values.iter().map(SolValue::encode).collect();
+    let heads_len: usize = values
+        .iter()
+        .zip(&encoded)
+        .map(|(value, encoded)| {
+            if value.is_dynamic() {
+                32
+            } else {
+                encoded.len()
+            }
+        })
+        .sum();
+
+    let mut heads = vec![];
+    let mut tails = vec![];
+    for (value, encoded) in values.iter().zip(encoded) {
+        if value.is_dynamic() {
+            heads.extend(length(heads_len + tails.len()));
+            tails.extend(encoded);
+        } else {
+            heads.extend(encoded);
+        }
+    }
+
+    [heads, tails].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use fuel_tx::Bytes32;
+
+    use super::*;
+    use crate::types::bech32::{Bech32Address, FUEL_BECH32_HRP};
+
+    // encoded with an independent implementation of the Solidity ABI, one word per line
+    const GOLDEN_CALLDATA: &str = concat!(
+        "61772923",
+        "0000000000000000000000000000000000000000000000000000000000000220",
+        "4444444444444444444444444444444444444444444444444444444444444444",
+        "000000000000000000000000000000000000000000000000000000000000000a",
+        "000000000000000000000000000000000000000000000000400000006553f10a",
+        "5555555555555555555555555555555555555555555555555555555555555555",
+        "6666666666666666666666666666666666666666666666666666666666666666",
+        "0000000000000000000000000000000000000000000000000000000000000005",
+        "000000000000000000000000000000000000000000000000400000006553ed22",
+        "0000000000000000000000000000000000000000000000000000000000000007",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "7777777777777777777777777777777777777777777777777777777777777777",
+        "8888888888888888888888888888888888888888888888888888888888888888",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000300",
+        "0000000000000000000000000000000000000000000000000000000000000380",
+        "1111111111111111111111111111111111111111111111111111111111111111",
+        "2222222222222222222222222222222222222222222222222222222222222222",
+        "3333333333333333333333333333333333333333333333333333333333333333",
+        "00000000000000000000000000000000000000000000000000000000000003e8",
+        "00000000000000000000000000000000000000000000000000000000000000a0",
+        "0000000000000000000000000000000000000000000000000000000000000004",
+        "deadbeef00000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000005",
+        "0000000000000000000000000000000000000000000000000000000000000040",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "9999999999999999999999999999999999999999999999999999999999999999",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000040",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+    );
+
+    fn header(height: u32, unix_time: i64) -> Header {
+        Header {
+            id: Bytes32::zeroed(),
+            da_height: 0,
+            transactions_count: 0,
+            message_receipt_count: 0,
+            transactions_root: Bytes32::zeroed(),
+            message_outbox_root: Bytes32::zeroed(),
+            event_inbox_root: Bytes32::zeroed(),
+            consensus_parameters_version: 0,
+            state_transition_bytecode_version: 0,
+            height,
+            prev_root: Bytes32::zeroed(),
+            time: DateTime::from_timestamp(unix_time, 0),
+            application_hash: Bytes32::zeroed(),
+        }
+    }
+
+    #[test]
+    fn relay_payload_matches_the_portal_abi() -> Result<()> {
+        // given
+        let proof = MessageProof {
+            message_proof: MerkleProof {
+                proof_set: vec![Bytes32::new([0xaa; 32]), Bytes32::new([0xbb; 32])],
+                proof_index: 1,
+            },
+            block_proof: MerkleProof {
+                proof_set: vec![Bytes32::new([0x99; 32])],
+                proof_index: 5,
+            },
+            message_block_header: Header {
+                prev_root: Bytes32::new([0x66; 32]),
+                da_height: 7,
+                transactions_count: 3,
+                message_receipt_count: 2,
+                transactions_root: Bytes32::new([0x77; 32]),
+                message_outbox_root: Bytes32::new([0x88; 32]),
+                consensus_parameters_version: 1,
+                state_transition_bytecode_version: 2,
+                ..header(5, 1_699_999_000)
+            },
+            commit_block_header: Header {
+                prev_root: Bytes32::new([0x44; 32]),
+                application_hash: Bytes32::new([0x55; 32]),
+                ..header(10, 1_700_000_000)
+            },
+            sender: Bech32Address::new(FUEL_BECH32_HRP, [0x11; 32]),
+            recipient: Bech32Address::new(FUEL_BECH32_HRP, [0x22; 32]),
+            nonce: [0x33; 32].into(),
+            amount: 1000,
+            data: vec![0xde, 0xad, 0xbe, 0xef],
+        };
+
+        // when
+        let payload = build_relay_payload(&proof)?;
+
+        // then
+        assert_eq!(hex::encode(payload), GOLDEN_CALLDATA);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dynamic_values_are_encoded_behind_offsets() {
+        // given
+        let value = SolValue::Tuple(vec![
+            word(&[1]),
+            SolValue::Bytes(b"abc".to_vec()),
+            SolValue::Array(vec![word(&[2]), word(&[3])]),
+        ]);
+
+        // when
+        let encoded = value.encode();
+
+        // then
+        let words: Vec<_> = encoded.chunks(32).map(|word| word[31]).collect();
+        // head: 1, offset of the bytes, offset of the array
+        // tail: bytes length, "abc" padded, array length, 2, 3
+        assert_eq!(words, vec![1, 0x60, 0xa0, 3, 0, 2, 2, 3]);
+        assert_eq!(&encoded[4 * 32..4 * 32 + 3], b"abc");
+    }
+
+    #[test]
+    fn static_tuples_are_encoded_in_place() {
+        let value = SolValue::Tuple(vec![
+            SolValue::Tuple(vec![word(&[1]), word(&[2])]),
+            SolValue::Bytes(vec![]),
+        ]);
+
+        let words: Vec<_> = value.encode().chunks(32).map(|word| word[31]).collect();
+
+        assert_eq!(words, vec![1, 2, 0x60, 0]);
+    }
+}
@@ -5,6 +5,18 @@ use fuel_core_client::client::types::{
     block::{Block as ClientBlock, Header as ClientHeader},
     primitives::Bytes32,
 };
+#[cfg(feature = "verification")]
+use fuel_crypto::Hasher;
+#[cfg(feature = "verification")]
+use fuel_merkle::binary::root_calculator::MerkleRootCalculator;
+#[cfg(feature = "verification")]
+use tai64::Tai64;
+
+#[cfg(feature = "verification")]
+use crate::types::{
+    errors::{error, Result},
+    message_proof::MerkleProof,
+};
 
 #[derive(Debug, Clone)]
 pub struct Header {
@@ -45,6 +57,162 @@ impl From<ClientHeader> for Header {
     }
 }
 
+#[cfg(feature = "verification")]
+impl Header {
+    /// Hashes the application part of the header: its DA height, versions, counts and roots.
+    pub fn compute_application_hash(&self) -> Bytes32 {
+        // the versions are encoded as padded words, the counts as their big-endian bytes
+        Hasher::default()
+            .chain(self.da_height.to_be_bytes())
+            .chain(u64::from(self.consensus_parameters_version).to_be_bytes())
+            .chain(u64::from(self.state_transition_bytecode_version).to_be_bytes())
+            .chain(self.transactions_count.to_be_bytes())
+            .chain(self.message_receipt_count.to_be_bytes())
+            .chain(self.transactions_root)
+            .chain(self.message_outbox_root)
+            .chain(self.event_inbox_root)
+            .digest()
+    }
+
+    /// Computes the block id from the header's fields. Fails if the header has no time.
+    pub fn compute_id(&self) -> Result<Bytes32> {
+        let time = self
+            .time
+            .ok_or_else(|| error!(Other, "header of block {} has no time", self.height))?;
+
+        Ok(Hasher::default()
+            .chain(self.prev_root)
+            .chain(self.height.to_be_bytes())
+            .chain(Tai64::from_unix(time.timestamp()).0.to_be_bytes())
+            .chain(self.compute_application_hash())
+            .digest())
+    }
+
+    /// Checks that the header's id and application hash match its fields.
+    pub fn verify(&self) -> Result<()> {
+        let application_hash = self.compute_application_hash();
+        if application_hash != self.application_hash {
+            return Err(error!(
+                Other,
+                "header of block {} has application hash `{}` but its fields hash to `{application_hash}`",
+                self.height,
+                self.application_hash
+            ));
+        }
+
+        let id = self.compute_id()?;
+        if id != self.id {
+            return Err(error!(
+                Other,
+                "header of block {} has id `{}` but its fields hash to `{id}`",
+                self.height,
+                self.id
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks a segment of consecutive block headers: every header must be valid on its own and
+/// follow the previous one.
+///
+/// The `prev_root` of a header commits to the ids of all earlier blocks, so it is only checked
+/// when the segment starts at the genesis block. Use [`verify_block_inclusion`] with a proof from
+/// the node to check a block against a later header you trust.
+#[cfg(feature = "verification")]
+pub fn verify_header_chain(headers: &[Header]) -> Result<()> {
+    // the ids of the blocks preceding the current one, if known since genesis
+    let mut history = headers
+        .first()
+        .filter(|header| header.height == 0)
+        .map(|_| vec![]);
+
+    for (i, header) in headers.iter().enumerate() {
+        header.verify()?;
+
+        if let Some(previous) = i.checked_sub(1).map(|i| &headers[i]) {
+            if header.height != previous.height + 1 {
+                return Err(error!(
+                    Other,
+                    "block {} follows block {}, heights are not consecutive",
+                    header.height,
+                    previous.height
+                ));
+            }
+        }
+
+        if let Some(history) = &mut history {
+            let prev_root = merkle_root(history.iter());
+            if header.height > 0 && header.prev_root != prev_root {
+                return Err(error!(
+                    Other,
+                    "block {} has prev root `{}` but the preceding blocks have root `{prev_root}`",
+                    header.height,
+                    header.prev_root
+                ));
+            }
+            history.push(header.id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a proof that the block `block_id` at `block_height` is part of the history committed to
+/// by `commit_header`, a later header you trust.
+#[cfg(feature = "verification")]
+pub fn verify_block_inclusion(
+    block_id: &Bytes32,
+    block_height: u32,
+    proof: &MerkleProof,
+    commit_header: &Header,
+) -> Result<()> {
+    if block_height >= commit_header.height {
+        return Err(error!(
+            Other,
+            "block {block_height} is not older than the commit block {}", commit_header.height
+        ));
+    }
+    if proof.proof_index != u64::from(block_height) {
+        return Err(error!(
+            Other,
+            "proof is for block {} instead of block {block_height}", proof.proof_index
+        ));
+    }
+
+    // `prev_root` commits to the blocks `0..height`
+    proof.verify(
+        &commit_header.prev_root,
+        block_id,
+        u64::from(commit_header.height),
+    )
+}
+
+/// Checks a proof that the transaction `tx_id` is part of the block with the given header.
+#[cfg(feature = "verification")]
+pub fn verify_transaction_inclusion(
+    tx_id: &Bytes32,
+    proof: &MerkleProof,
+    header: &Header,
+) -> Result<()> {
+    proof.verify(
+        &header.transactions_root,
+        tx_id,
+        u64::from(header.transactions_count),
+    )
+}
+
+#[cfg(feature = "verification")]
+fn merkle_root<'a>(leaves: impl Iterator<Item = &'a Bytes32>) -> Bytes32 {
+    let mut calculator = MerkleRootCalculator::new();
+    for leaf in leaves {
+        calculator.push(leaf.as_ref());
+    }
+
+    calculator.root().into()
+}
+
 #[derive(Debug, Clone)]
 pub struct Block {
     pub id: Bytes32,
@@ -52,6 +220,31 @@ pub struct Block {
     pub transactions: Vec<Bytes32>,
 }
 
+#[cfg(feature = "verification")]
+impl Block {
+    /// Computes the root of the binary Merkle tree over the block's transaction ids.
+    pub fn compute_transactions_root(&self) -> Bytes32 {
+        merkle_root(self.transactions.iter())
+    }
+
+    /// Checks the header and that it commits to the block's transactions.
+    pub fn verify(&self) -> Result<()> {
+        self.header.verify()?;
+
+        let transactions_root = self.compute_transactions_root();
+        if self.header.transactions_count as usize != self.transactions.len()
+            || transactions_root != self.header.transactions_root
+        {
+            return Err(error!(
+                Other,
+                "transactions of block {} don't match its header", self.header.height
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl From<ClientBlock> for Block {
     fn from(client_block: ClientBlock) -> Self {
         Self {
@@ -61,3 +254,74 @@ impl From<ClientBlock> for Block {
         }
     }
 }
+
+#[cfg(all(test, feature = "verification"))]
+mod tests {
+    use super::*;
+
+    fn header(height: u32, prev_root: Bytes32) -> Header {
+        let mut header = Header {
+            id: Bytes32::zeroed(),
+            da_height: 1,
+            transactions_count: 1,
+            message_receipt_count: 0,
+            transactions_root: Bytes32::new([height as u8; 32]),
+            message_outbox_root: Bytes32::zeroed(),
+            event_inbox_root: Bytes32::zeroed(),
+            consensus_parameters_version: 0,
+            state_transition_bytecode_version: 0,
+            height,
+            prev_root,
+            time: DateTime::from_timestamp(1_700_000_000 + i64::from(height), 0),
+            application_hash: Bytes32::zeroed(),
+        };
+        header.application_hash = header.compute_application_hash();
+        header.id = header.compute_id().expect("has a time");
+
+        header
+    }
+
+    fn chain(len: u32) -> Vec<Header> {
+        let mut headers: Vec<Header> = vec![];
+        for height in 0..len {
+            let prev_root = merkle_root(headers.iter().map(|header| &header.id));
+            headers.push(header(height, prev_root));
+        }
+
+        headers
+    }
+
+    #[test]
+    fn consistent_chains_are_accepted() -> Result<()> {
+        let headers = chain(4);
+
+        verify_header_chain(&headers)?;
+        // without genesis the prev roots can't be checked
+        verify_header_chain(&headers[1..])?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn tampered_chains_are_rejected() {
+        let headers = chain(4);
+
+        let mut tampered_field = headers.clone();
+        tampered_field[2].da_height += 1;
+
+        let mut gap = headers.clone();
+        gap.remove(2);
+
+        let mut wrong_prev_root = headers.clone();
+        wrong_prev_root[3] = header(3, Bytes32::zeroed());
+
+        for (headers, expected) in [
+            (tampered_field, "has application hash"),
+            (gap, "heights are not consecutive"),
+            (wrong_prev_root, "but the preceding blocks have root"),
+        ] {
+            let err = verify_header_chain(&headers).expect_err("should fail");
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+}
@@ -3,9 +3,15 @@
 use fuel_core_client::client::types::{
     primitives::Nonce, MerkleProof as ClientMerkleProof, MessageProof as ClientMessageProof,
 };
-use fuel_types::Bytes32;
+use fuel_tx::input::message::compute_message_id;
+use fuel_types::{Bytes32, MessageId};
 
 use crate::types::{bech32::Bech32Address, block::Header};
+#[cfg(feature = "verification")]
+use crate::types::{
+    block::verify_block_inclusion,
+    errors::{error, Result},
+};
 
 #[derive(Debug)]
 pub struct MerkleProof {
@@ -15,6 +21,25 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+#[cfg(feature = "verification")]
+impl MerkleProof {
+    /// Checks that `leaf` is leaf number `proof_index` of the binary Merkle tree with `num_leaves`
+    /// leaves and the given `root`.
+    pub fn verify(&self, root: &Bytes32, leaf: &Bytes32, num_leaves: u64) -> Result<()> {
+        let proof_set = self.proof_set.iter().map(|node| **node).collect::<Vec<_>>();
+
+        if !fuel_merkle::binary::verify(root, leaf, &proof_set, self.proof_index, num_leaves) {
+            return Err(error!(
+                Other,
+                "merkle proof of `{leaf}` at index {} doesn't match root `{root}`",
+                self.proof_index
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl From<ClientMerkleProof> for MerkleProof {
     fn from(client_merkle_proof: ClientMerkleProof) -> Self {
         Self {
@@ -43,6 +68,41 @@ pub struct MessageProof {
     pub data: Vec<u8>,
 }
 
+impl MessageProof {
+    pub fn message_id(&self) -> MessageId {
+        compute_message_id(
+            &(&self.sender).into(),
+            &(&self.recipient).into(),
+            &self.nonce,
+            self.amount,
+            &self.data,
+        )
+    }
+
+    /// Checks the proof end to end: the message is part of the message block, which is part of
+    /// the history committed to by the commit block. Trusting the commit block header is up to
+    /// you, e.g. by comparing it with the one settled on the base layer.
+    #[cfg(feature = "verification")]
+    pub fn verify(&self) -> Result<()> {
+        self.message_block_header.verify()?;
+        self.commit_block_header.verify()?;
+
+        let message_id = Bytes32::from(*self.message_id());
+        self.message_proof.verify(
+            &self.message_block_header.message_outbox_root,
+            &message_id,
+            u64::from(self.message_block_header.message_receipt_count),
+        )?;
+
+        verify_block_inclusion(
+            &self.message_block_header.id,
+            self.message_block_header.height,
+            &self.block_proof,
+            &self.commit_block_header,
+        )
+    }
+}
+
 impl From<ClientMessageProof> for MessageProof {
     fn from(client_message_proof: ClientMessageProof) -> Self {
         Self {
@@ -25,7 +25,7 @@ use fuel_vm::checked_transaction::{
 use itertools::Itertools;
 
 use crate::{
-    traits::Signer,
+    traits::{SignatureExt, Signer},
     types::{
         bech32::Bech32Address,
         errors::{error, error_transaction, Error, Result},
@@ -104,7 +104,8 @@ impl MintTransaction {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
 //ANCHOR: tx_policies_struct
 pub struct TxPolicies {
     tip: Option<u64>,
@@ -176,6 +177,82 @@ impl TxPolicies {
     pub fn script_gas_limit(&self) -> Option<u64> {
         self.script_gas_limit
     }
+
+    /// Checks the policies against the limits of the chain, catching mistakes the node would
+    /// otherwise reject the transaction for. Transaction builders call this when building.
+    pub fn validate(&self, consensus_parameters: &ConsensusParameters) -> Result<()> {
+        let tx_params = consensus_parameters.tx_params();
+
+        if let Some(script_gas_limit) = self.script_gas_limit {
+            let max_gas_per_tx = tx_params.max_gas_per_tx();
+            if script_gas_limit > max_gas_per_tx {
+                return Err(error_transaction!(
+                    Builder,
+                    "script gas limit {script_gas_limit} exceeds the chain's max gas per tx of {max_gas_per_tx}"
+                ));
+            }
+        }
+
+        if let Some(witness_limit) = self.witness_limit {
+            let max_size = tx_params.max_size();
+            if witness_limit > max_size {
+                return Err(error_transaction!(
+                    Builder,
+                    "witness limit {witness_limit} exceeds the chain's max tx size of {max_size} bytes"
+                ));
+            }
+        }
+
+        if let (Some(tip), Some(max_fee)) = (self.tip, self.max_fee) {
+            if tip > max_fee {
+                return Err(error_transaction!(
+                    Builder,
+                    "tip {tip} is greater than the max fee {max_fee}"
+                ));
+            }
+        }
+
+        if let Some(maturity) = self.maturity {
+            if u32::try_from(maturity).is_err() {
+                return Err(error_transaction!(
+                    Builder,
+                    "maturity {maturity} is not a valid block height"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// How much a replacement transaction raises the fees of the one it replaces. The node only lets
+/// a transaction spending the same coins replace a pending one if it tips more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeBump {
+    /// Raises the tip and the max fee by this percentage. The tip grows by at least 1, and the max
+    /// fee by at least as much as the tip.
+    Percent(u64),
+    /// Sets the tip and the max fee to these amounts.
+    To { tip: u64, max_fee: u64 },
+}
+
+impl FeeBump {
+    /// The tip and the max fee replacing `tip` and `max_fee`.
+    pub fn apply(&self, tip: u64, max_fee: u64) -> (u64, u64) {
+        match *self {
+            Self::Percent(percent) => {
+                let raise = |amount: u64| {
+                    let raised = u128::from(amount) * u128::from(percent.saturating_add(100)) / 100;
+                    u64::try_from(raised).unwrap_or(u64::MAX)
+                };
+                let new_tip = raise(tip).max(tip.saturating_add(1));
+                let new_max_fee = raise(max_fee).max(max_fee.saturating_add(new_tip - tip));
+
+                (new_tip, new_max_fee)
+            }
+            Self::To { tip, max_fee } => (tip, max_fee),
+        }
+    }
 }
 
 use fuel_tx::field::{BytecodeWitnessIndex, Salt, StorageSlots};
@@ -239,6 +316,8 @@ pub trait Transaction:
 
     fn max_gas(&self, consensus_parameters: &ConsensusParameters) -> u64;
 
+    fn min_gas(&self, consensus_parameters: &ConsensusParameters) -> u64;
+
     /// Performs all stateless transaction validity checks. This includes the validity
     /// of fields according to rules in the specification and validity of signatures.
     /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/tx-format/transaction.md>
@@ -283,6 +362,58 @@ pub trait Transaction:
         signer: &(impl Signer + Send + Sync),
         chain_id: ChainId,
     ) -> Result<Signature>;
+
+    /// Raises the tip and the max fee as `bump` describes and signs the transaction anew, e.g. to
+    /// replace it while it waits in the txpool. Every signature must come from `signer`, others
+    /// couldn't be renewed. The inputs must cover the raised max fee.
+    async fn bump_fee(
+        &mut self,
+        bump: FeeBump,
+        signer: &(impl Signer + Send + Sync),
+        chain_id: ChainId,
+    ) -> Result<()>;
+
+    /// The address that signed the transaction id with the signature held by the witness at
+    /// `index`. Fails if that witness is not a signature.
+    fn witness_signer(&self, index: usize, chain_id: ChainId) -> Result<Bech32Address> {
+        let witness = self
+            .witnesses()
+            .get(index)
+            .ok_or_else(|| error_transaction!(Validation, "no witness at index {index}"))?;
+        let signature = <[u8; Signature::LEN]>::try_from(witness.as_ref())
+            .map(Signature::from_bytes)
+            .map_err(|_| {
+                error_transaction!(Validation, "witness at index {index} is not a signature")
+            })?;
+
+        signature.recover_address(&Message::from_bytes(*self.id(chain_id)))
+    }
+
+    /// Checks that every input spent with a signature was signed by its owner, e.g. before
+    /// sending a transaction co-signed by someone else. Returns the owners, one per such input.
+    fn verify_signatures(&self, chain_id: ChainId) -> Result<Vec<Bech32Address>> {
+        self.inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(input_index, input)| {
+                let witness_index = input.witness_index()?;
+                let owner = extract_owner_or_recipient(input).expect("signed inputs have an owner");
+
+                Some((input_index, witness_index, owner))
+            })
+            .map(|(input_index, witness_index, owner)| {
+                let signer = self.witness_signer(witness_index as usize, chain_id)?;
+                if signer != owner {
+                    return Err(error_transaction!(
+                        Validation,
+                        "input {input_index} is owned by `{owner}` but signed by `{signer}`"
+                    ));
+                }
+
+                Ok(owner)
+            })
+            .collect()
+    }
 }
 
 impl From<TransactionType> for FuelTransaction {
@@ -401,6 +532,13 @@ macro_rules! impl_tx_wrapper {
                 )
             }
 
+            fn min_gas(&self, consensus_parameters: &ConsensusParameters) -> u64 {
+                self.tx.min_gas(
+                    consensus_parameters.gas_costs(),
+                    consensus_parameters.fee_params(),
+                )
+            }
+
             fn fee_checked_from_tx(
                 &self,
                 consensus_parameters: &ConsensusParameters,
@@ -529,6 +667,47 @@ macro_rules! impl_tx_wrapper {
 
                 Ok(signature)
             }
+
+            async fn bump_fee(
+                &mut self,
+                bump: FeeBump,
+                signer: &(impl Signer + Send + Sync),
+                chain_id: ChainId,
+            ) -> Result<()> {
+                let signature_indexes = self
+                    .inputs()
+                    .iter()
+                    .filter_map(|input| input.witness_index())
+                    .map(usize::from)
+                    .unique()
+                    .collect::<Vec<_>>();
+                for &index in &signature_indexes {
+                    let witness_signer = self.witness_signer(index, chain_id)?;
+                    if &witness_signer != signer.address() {
+                        return Err(error_transaction!(
+                            Builder,
+                            "cannot bump the fee: witness {index} is signed by `{witness_signer}`, \
+                            not by `{}`",
+                            signer.address()
+                        ));
+                    }
+                }
+
+                let (tip, max_fee) =
+                    bump.apply(self.tip().unwrap_or_default(), self.max_fee().unwrap_or_default());
+                self.tx.policies_mut().set(PolicyType::Tip, Some(tip));
+                self.tx.policies_mut().set(PolicyType::MaxFee, Some(max_fee));
+                // the id cached by a previous precompute is stale
+                self.precompute(&chain_id)?;
+
+                let message = Message::from_bytes(*self.id(chain_id));
+                let signature = signer.sign(message).await?;
+                for index in signature_indexes {
+                    self.tx.witnesses_mut()[index] = signature.as_ref().into();
+                }
+
+                Ok(())
+            }
         }
     };
 }
@@ -553,7 +732,7 @@ impl EstimablePredicates for UploadTransaction {
             tx.as_upload().expect("is upload").clone_into(&mut self.tx);
         } else {
             self.tx.estimate_predicates(
-                &provider.consensus_parameters().into(),
+                &provider.fetch_consensus_parameters().await?.into(),
                 MemoryInstance::new(),
             )?;
         }
@@ -579,7 +758,7 @@ impl EstimablePredicates for UpgradeTransaction {
                 .clone_into(&mut self.tx);
         } else {
             self.tx.estimate_predicates(
-                &provider.consensus_parameters().into(),
+                &provider.fetch_consensus_parameters().await?.into(),
                 MemoryInstance::new(),
             )?;
         }
@@ -603,7 +782,7 @@ impl EstimablePredicates for CreateTransaction {
             tx.as_create().expect("is create").clone_into(&mut self.tx);
         } else {
             self.tx.estimate_predicates(
-                &provider.consensus_parameters().into(),
+                &provider.fetch_consensus_parameters().await?.into(),
                 MemoryInstance::new(),
             )?;
         }
@@ -641,7 +820,7 @@ impl EstimablePredicates for ScriptTransaction {
             tx.as_script().expect("is script").clone_into(&mut self.tx);
         } else {
             self.tx.estimate_predicates(
-                &provider.consensus_parameters().into(),
+                &provider.fetch_consensus_parameters().await?.into(),
                 MemoryInstance::new(),
             )?;
         }
@@ -704,8 +883,10 @@ impl ScriptTransaction {
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
 
-    use fuel_tx::policies::Policies;
+    use fuel_crypto::SecretKey;
+    use fuel_tx::{policies::Policies, Address};
 
     use super::*;
 
@@ -733,4 +914,74 @@ mod test {
 
         assert_eq!(&err.to_string(), expected_err_str);
     }
+
+    #[test]
+    fn signatures_are_verified_against_the_input_owners() -> Result<()> {
+        // given
+        let owner_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let other_key = SecretKey::from_str(
+            "99ad179d4f892ff3124ccd817408ff8a4452e9c16bb1e4d3a6b2a5a6a0a8f2a1",
+        )?;
+        let owner = Bech32Address::from(Address::from(*owner_key.public_key().hash()));
+        let chain_id = ChainId::default();
+
+        let signed_by = |key: &SecretKey| {
+            let input = Input::coin_signed(
+                Default::default(),
+                owner.clone().into(),
+                100,
+                AssetId::zeroed(),
+                Default::default(),
+                0,
+            );
+            let mut tx = ScriptTransaction {
+                tx: FuelTransaction::script(
+                    0,
+                    vec![],
+                    vec![],
+                    Policies::default(),
+                    vec![input],
+                    vec![],
+                    vec![],
+                ),
+                is_using_predicates: false,
+            };
+            let signature = Signature::sign(key, &Message::from_bytes(*tx.id(chain_id)));
+            tx.tx.witnesses_mut().push(signature.as_ref().into());
+
+            tx
+        };
+
+        // when
+        let properly_signed = signed_by(&owner_key);
+        let wrongly_signed = signed_by(&other_key);
+
+        // then
+        assert_eq!(properly_signed.witness_signer(0, chain_id)?, owner);
+        assert_eq!(properly_signed.verify_signatures(chain_id)?, vec![owner]);
+
+        let err = wrongly_signed
+            .verify_signatures(chain_id)
+            .expect_err("should fail");
+        assert!(err.to_string().contains("input 0 is owned by"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_bumps_always_raise_the_tip() {
+        assert_eq!(FeeBump::Percent(10).apply(100, 1_000), (110, 1_100));
+        assert_eq!(FeeBump::Percent(10).apply(0, 1_000), (1, 1_100));
+        assert_eq!(FeeBump::Percent(0).apply(5, 5), (6, 6));
+        assert_eq!(
+            FeeBump::To {
+                tip: 20,
+                max_fee: 2_000
+            }
+            .apply(100, 1_000),
+            (20, 2_000)
+        );
+    }
 }
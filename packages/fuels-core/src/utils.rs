@@ -1,4 +1,5 @@
 pub mod constants;
+pub mod contract_id;
 pub mod offsets;
 
 use constants::{WITNESS_STATIC_SIZE, WORD_SIZE};
@@ -0,0 +1,73 @@
+//! Pure functions for computing contract ids.
+//!
+//! A contract id only depends on the salt, the code root of the bytecode and the state root of
+//! the initial storage slots. These helpers allow precomputing ids (e.g. in CI) without building a
+//! `Contract` or reading anything from disk.
+
+use fuel_tx::{Bytes32, Contract as FuelContract, ContractId, Salt, StorageSlot};
+
+/// Merkle root of the contract's bytecode.
+pub fn code_root(binary: &[u8]) -> Bytes32 {
+    FuelContract::from(binary).root()
+}
+
+/// Sparse merkle root of the contract's initial storage. The order of the slots does not matter.
+pub fn state_root(storage_slots: &[StorageSlot]) -> Bytes32 {
+    FuelContract::initial_state_root(storage_slots.iter())
+}
+
+/// Computes the id of a contract from its already computed roots.
+pub fn contract_id_from_roots(
+    salt: &Salt,
+    code_root: &Bytes32,
+    state_root: &Bytes32,
+) -> ContractId {
+    // `id` does not depend on the bytecode held by `FuelContract`, only on the given roots
+    FuelContract::from(Vec::<u8>::new()).id(salt, code_root, state_root)
+}
+
+/// Computes the id a contract will have once deployed with the given `salt` and `storage_slots`.
+pub fn contract_id(binary: &[u8], salt: &Salt, storage_slots: &[StorageSlot]) -> ContractId {
+    contract_id_from_roots(salt, &code_root(binary), &state_root(storage_slots))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_id_is_derived_from_roots() {
+        // given
+        let binary = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        let salt = Salt::from([7u8; 32]);
+        let slots = vec![StorageSlot::new([1u8; 32].into(), [2u8; 32].into())];
+
+        // when
+        let id = contract_id(&binary, &salt, &slots);
+
+        // then
+        let expected = contract_id_from_roots(&salt, &code_root(&binary), &state_root(&slots));
+        assert_eq!(id, expected);
+    }
+
+    #[test]
+    fn state_root_does_not_depend_on_slot_order() {
+        let first = StorageSlot::new([1u8; 32].into(), [2u8; 32].into());
+        let second = StorageSlot::new([3u8; 32].into(), [4u8; 32].into());
+
+        let root = state_root(&[first.clone(), second.clone()]);
+        let reversed_root = state_root(&[second, first]);
+
+        assert_eq!(root, reversed_root);
+    }
+
+    #[test]
+    fn salt_changes_contract_id() {
+        let binary = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+        let id = contract_id(&binary, &Salt::zeroed(), &[]);
+        let salted_id = contract_id(&binary, &Salt::from([1u8; 32]), &[]);
+
+        assert_ne!(id, salted_id);
+    }
+}
@@ -1,7 +1,7 @@
-use fuels_code_gen::{Abi, AbigenTarget, ProgramType};
+use fuels_code_gen::{utils::TypePath, Abi, AbigenTarget, ProgramType};
 use syn::{
     parse::{Parse, ParseStream},
-    LitStr, Result,
+    Error, LitStr, Result,
 };
 
 use crate::parse_utils::{Command, UniqueNameValues};
@@ -14,11 +14,20 @@ impl From<MacroAbigenTargets> for Vec<AbigenTarget> {
 
 impl From<MacroAbigenTarget> for AbigenTarget {
     fn from(macro_target: MacroAbigenTarget) -> Self {
-        AbigenTarget::new(
+        let target = AbigenTarget::new(
             macro_target.name,
             macro_target.source,
             macro_target.program_type,
-        )
+        );
+
+        macro_target
+            .type_substitutions
+            .iter()
+            .fold(target, |target, (original, substitute)| {
+                target
+                    .with_type_substitution(original, substitute)
+                    .expect("type substitutions are validated while parsing")
+            })
     }
 }
 
@@ -29,6 +38,7 @@ pub(crate) struct MacroAbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub program_type: ProgramType,
+    pub(crate) type_substitutions: Vec<(String, String)>,
 }
 
 pub(crate) struct MacroAbigenTargets {
@@ -51,19 +61,63 @@ impl MacroAbigenTarget {
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "abi"])?;
+        name_values.validate_has_no_other_names(&["name", "abi", "substitute"])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
         let abi_lit_str = name_values.get_as_lit_str("abi")?;
         let source = Self::parse_inline_or_load_abi(abi_lit_str)?;
 
+        let type_substitutions = if name_values.try_get("substitute").is_some() {
+            Self::parse_type_substitutions(name_values.get_as_lit_str("substitute")?)?
+        } else {
+            vec![]
+        };
+
         Ok(Self {
             name,
             source,
             program_type,
+            type_substitutions,
         })
     }
 
+    // e.g. "FixedPoint = ::my_crate::Decimal, some_lib::Price = crate::Price"
+    fn parse_type_substitutions(lit_str: &LitStr) -> Result<Vec<(String, String)>> {
+        lit_str
+            .value()
+            .split(',')
+            .map(str::trim)
+            .filter(|substitution| !substitution.is_empty())
+            .map(|substitution| {
+                let (original, substitute) = substitution
+                    .split_once('=')
+                    .map(|(original, substitute)| (original.trim(), substitute.trim()))
+                    .ok_or_else(|| {
+                        Error::new(
+                            lit_str.span(),
+                            format!("expected `Original = ::path::to::Substitute`, got `{substitution}`"),
+                        )
+                    })?;
+
+                for path in [original, substitute] {
+                    TypePath::new(path)
+                        .map_err(|e| Error::new(lit_str.span(), e.to_string()))?;
+                }
+                if !substitute.starts_with("::") && !substitute.starts_with("crate::") {
+                    return Err(Error::new(
+                        lit_str.span(),
+                        format!(
+                            "the substitute `{substitute}` must be an absolute path, e.g. \
+                             `::my_crate::{substitute}` or `crate::{substitute}`"
+                        ),
+                    ));
+                }
+
+                Ok((original.to_string(), substitute.to_string()))
+            })
+            .collect()
+    }
+
     fn parse_inline_or_load_abi(abi_lit_str: &LitStr) -> Result<Abi> {
         let abi_string = abi_lit_str.value();
         let abi_str = abi_string.trim();
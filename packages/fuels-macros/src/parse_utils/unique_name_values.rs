@@ -4,7 +4,7 @@ use fuels_code_gen::utils::ident;
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
 use syn::{
-    parse::Parser, punctuated::Punctuated, spanned::Spanned, Error, Expr, Lit, LitStr,
+    parse::Parser, punctuated::Punctuated, spanned::Spanned, Error, Expr, Lit, LitInt, LitStr,
     MetaNameValue,
 };
 
@@ -70,6 +70,21 @@ impl UniqueNameValues {
         }
     }
 
+    pub fn get_as_lit_int(&self, name: &str) -> syn::Result<&LitInt> {
+        let value = self
+            .try_get(name)
+            .ok_or_else(|| Error::new(self.span, format!("missing attribute '{name}'")))?;
+
+        if let Lit::Int(lit_int) = value {
+            Ok(lit_int)
+        } else {
+            Err(Error::new_spanned(
+                value.clone(),
+                format!("expected the attribute '{name}' to have an integer value"),
+            ))
+        }
+    }
+
     fn extract_name_values<T: Iterator<Item = MetaNameValue>>(
         name_value_metas: T,
     ) -> syn::Result<Vec<(Ident, Lit)>> {
@@ -220,6 +235,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn can_get_lit_ints() -> syn::Result<()> {
+        let name_values = extract_name_values(quote! {SomeCommand(amount=1_000, name="value")})?;
+
+        let lit_int = name_values.get_as_lit_int("amount")?;
+        assert_eq!(lit_int.base10_parse::<u64>()?, 1000);
+
+        let err = name_values
+            .get_as_lit_int("name")
+            .expect_err("should have failed");
+        assert_eq!(
+            err.to_string(),
+            "expected the attribute 'name' to have an integer value"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn lit_str_getter_complains_value_is_missing() -> syn::Result<()> {
         let name_values = extract_name_values(quote! {SomeCommand(name=true)})?;
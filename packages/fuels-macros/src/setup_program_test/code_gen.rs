@@ -9,8 +9,8 @@ use quote::quote;
 use syn::LitStr;
 
 use crate::setup_program_test::parsing::{
-    AbigenCommand, BuildProfile, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
-    SetOptionsCommand, TestProgramCommands,
+    AbigenCommand, BuildProfile, DeployContractCommand, FundCommand, InitializeWalletCommand,
+    LoadScriptCommand, SetOptionsCommand, SetupCallCommand, TestProgramCommands,
 };
 
 pub(crate) fn generate_setup_program_test_code(
@@ -22,20 +22,29 @@ pub(crate) fn generate_setup_program_test_code(
         generate_bindings,
         deploy_contract,
         load_scripts,
+        fund,
+        setup_calls,
     } = commands;
 
-    let SetOptionsCommand { profile } = set_options.unwrap_or_default();
+    let SetOptionsCommand { profile, build } = set_options.unwrap_or_default();
     let project_lookup = generate_project_lookup(&generate_bindings, profile)?;
+    if build {
+        build_projects(project_lookup.values())?;
+    }
     let abigen_code = abigen_code(&project_lookup)?;
-    let wallet_code = wallet_initialization_code(initialize_wallets);
+    let wallet_code = wallet_initialization_code(initialize_wallets, &fund);
     let deploy_code = contract_deploying_code(&deploy_contract, &project_lookup);
     let script_code = script_loading_code(&load_scripts, &project_lookup);
+    let funding_code = contract_funding_code(&fund, &deploy_contract);
+    let setup_code = setup_calls_code(&setup_calls);
 
     Ok(quote! {
        #abigen_code
        #wallet_code
        #deploy_code
        #script_code
+       #funding_code
+       #setup_code
     })
 }
 
@@ -55,6 +64,19 @@ fn generate_project_lookup(
     Ok(pairs.into_iter().collect())
 }
 
+#[cfg(feature = "forc")]
+fn build_projects<'a>(projects: impl IntoIterator<Item = &'a Project>) -> syn::Result<()> {
+    projects.into_iter().try_for_each(Project::build)
+}
+
+#[cfg(not(feature = "forc"))]
+fn build_projects<'a>(_projects: impl IntoIterator<Item = &'a Project>) -> syn::Result<()> {
+    Err(syn::Error::new(
+        Span::call_site(),
+        "building projects with `Options(build = true)` requires the `forc` feature of `fuels`",
+    ))
+}
+
 fn abigen_code(project_lookup: &HashMap<String, Project>) -> syn::Result<TokenStream> {
     let targets = parse_abigen_targets(project_lookup)?;
 
@@ -79,7 +101,10 @@ fn parse_abigen_targets(
         .collect()
 }
 
-fn wallet_initialization_code(maybe_command: Option<InitializeWalletCommand>) -> TokenStream {
+fn wallet_initialization_code(
+    maybe_command: Option<InitializeWalletCommand>,
+    fund_commands: &[FundCommand],
+) -> TokenStream {
     let command = if let Some(command) = maybe_command {
         command
     } else {
@@ -93,9 +118,14 @@ fn wallet_initialization_code(maybe_command: Option<InitializeWalletCommand>) ->
     }
 
     let num_wallets = wallet_names.len();
+    let wallet_assets = command
+        .names
+        .iter()
+        .map(|name| wallet_assets_code(&name.value(), fund_commands));
+
     quote! {
-        let [#(#wallet_names),*]: [_; #num_wallets] = ::fuels::test_helpers::launch_custom_provider_and_get_wallets(
-            ::fuels::test_helpers::WalletsConfig::new(Some(#num_wallets as u64), None, None),
+        let [#(#wallet_names),*]: [_; #num_wallets] = ::fuels::test_helpers::launch_provider_and_get_wallets_with_assets(
+            ::std::vec![#(#wallet_assets),*],
             None,
             None,
         )
@@ -106,6 +136,54 @@ fn wallet_initialization_code(maybe_command: Option<InitializeWalletCommand>) ->
     }
 }
 
+// Wallets get the default amount of the base asset unless they are funded with it explicitly.
+fn wallet_assets_code(wallet: &str, fund_commands: &[FundCommand]) -> TokenStream {
+    let funds = fund_commands
+        .iter()
+        .filter(|command| command.to.value() == wallet)
+        .collect::<Vec<_>>();
+
+    let funds_base_asset = funds
+        .iter()
+        .any(|command| command.asset.unwrap_or_default() == [0; 32]);
+    let default_base_asset = (!funds_base_asset).then(|| {
+        quote! {
+            ::fuels::test_helpers::AssetConfig {
+                id: ::fuels::types::AssetId::zeroed(),
+                num_coins: ::fuels::test_helpers::DEFAULT_NUM_COINS,
+                coin_amount: ::fuels::test_helpers::DEFAULT_COIN_AMOUNT,
+            }
+        }
+    });
+
+    let asset_configs = default_base_asset
+        .into_iter()
+        .chain(funds.iter().map(|command| {
+            let asset_id = asset_id_code(command.asset);
+            let num_coins = command.coins.unwrap_or(1);
+            let coin_amount = command.amount;
+
+            quote! {
+                ::fuels::test_helpers::AssetConfig {
+                    id: #asset_id,
+                    num_coins: #num_coins,
+                    coin_amount: #coin_amount,
+                }
+            }
+        }));
+
+    quote! {
+        ::std::vec![#(#asset_configs),*]
+    }
+}
+
+fn asset_id_code(asset: Option<[u8; 32]>) -> TokenStream {
+    match asset {
+        Some(bytes) => quote! { ::fuels::types::AssetId::new([#(#bytes),*]) },
+        None => quote! { ::fuels::types::AssetId::zeroed() },
+    }
+}
+
 fn extract_wallet_names(command: &InitializeWalletCommand) -> Vec<Ident> {
     command
         .names
@@ -129,6 +207,11 @@ fn contract_deploying_code(
                 .get(&command.contract.value())
                 .expect("Project should be in lookup");
             let bin_path = project.bin_path();
+            let configurables = command.configurables.as_ref().map(|configurables| {
+                quote! {
+                    let load_config = load_config.with_configurables(#configurables);
+                }
+            });
 
             quote! {
                 // Generate random salt for contract deployment.
@@ -138,6 +221,7 @@ fn contract_deploying_code(
 
                 let #contract_instance_name = {
                     let load_config = ::fuels::programs::contract::LoadConfiguration::default().with_salt(salt);
+                    #configurables
 
                     let loaded_contract = ::fuels::programs::contract::Contract::load_from(
                         #bin_path,
@@ -190,6 +274,57 @@ fn script_loading_code(
         .unwrap_or_default()
 }
 
+fn contract_funding_code(
+    fund_commands: &[FundCommand],
+    deploy_commands: &[DeployContractCommand],
+) -> TokenStream {
+    fund_commands
+        .iter()
+        .filter_map(|command| {
+            let deploy_command = deploy_commands
+                .iter()
+                .find(|deploy| deploy.name == command.to.value())?;
+
+            let contract_instance_name = ident(&deploy_command.name);
+            let wallet_name = command
+                .from
+                .as_ref()
+                .map(|from| ident(&from.value()))
+                .unwrap_or_else(|| ident(&deploy_command.wallet));
+            let amount = command.amount;
+            let asset_id = asset_id_code(command.asset);
+
+            Some(quote! {
+                ::fuels::accounts::Account::force_transfer_to_contract(
+                    &#wallet_name,
+                    #contract_instance_name.contract_id(),
+                    #amount,
+                    #asset_id,
+                    ::fuels::types::transaction::TxPolicies::default(),
+                )
+                .await
+                .expect("Failed to fund the contract");
+            })
+        })
+        .collect()
+}
+
+fn setup_calls_code(commands: &[SetupCallCommand]) -> TokenStream {
+    commands
+        .iter()
+        .map(|SetupCallCommand { call }| {
+            let description = quote! {#call}.to_string();
+
+            quote! {
+                (#call)
+                    .call()
+                    .await
+                    .unwrap_or_else(|e| ::std::panic!("Setup call `{}` failed: {}", #description, e));
+            }
+        })
+        .collect()
+}
+
 struct Project {
     program_type: ProgramType,
     path: PathBuf,
@@ -237,6 +372,39 @@ impl Project {
             .expect("failed to convert project name to string")
     }
 
+    /// Builds the project with `forc`. Every project is built at most once per profile while
+    /// compiling a crate, however many macro invocations use it.
+    #[cfg(feature = "forc")]
+    fn build(&self) -> syn::Result<()> {
+        use std::{
+            collections::HashSet,
+            sync::{Mutex, OnceLock},
+        };
+
+        use fuels_code_gen::forc::ForcBuild;
+
+        static BUILT_PROJECTS: OnceLock<Mutex<HashSet<(PathBuf, String)>>> = OnceLock::new();
+
+        let key = (self.path.clone(), self.profile.to_string());
+        let mut built_projects = BUILT_PROJECTS
+            .get_or_init(Default::default)
+            .lock()
+            .expect("lock should not be poisoned");
+
+        if built_projects.contains(&key) {
+            return Ok(());
+        }
+
+        ForcBuild::new(&self.path)
+            .with_release(self.profile == BuildProfile::Release)
+            .run()
+            .map_err(|e| syn::Error::new(self.path_span, e.to_string()))?;
+
+        built_projects.insert(key);
+
+        Ok(())
+    }
+
     fn abi_path(&self) -> String {
         self.compile_file_path("-abi.json", "the ABI file")
     }
@@ -1,6 +1,6 @@
 pub(crate) use commands::{
-    AbigenCommand, BuildProfile, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
-    SetOptionsCommand, TestProgramCommands,
+    AbigenCommand, BuildProfile, DeployContractCommand, FundCommand, InitializeWalletCommand,
+    LoadScriptCommand, SetOptionsCommand, SetupCallCommand, TestProgramCommands,
 };
 
 mod command_parser;
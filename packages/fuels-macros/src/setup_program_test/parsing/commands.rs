@@ -1,9 +1,11 @@
 pub(crate) use abigen::AbigenCommand;
 pub(crate) use deploy_contract::DeployContractCommand;
+pub(crate) use fund::FundCommand;
 pub(crate) use initialize_wallet::InitializeWalletCommand;
 use itertools::Itertools;
 pub(crate) use load_script::LoadScriptCommand;
 pub(crate) use set_options::{BuildProfile, SetOptionsCommand};
+pub(crate) use setup_call::SetupCallCommand;
 use syn::{
     parse::{Parse, ParseStream},
     Result,
@@ -13,15 +15,18 @@ use crate::setup_program_test::parsing::{
     command_parser::command_parser,
     validations::{
         extract_the_abigen_command, validate_all_contracts_are_known,
-        validate_all_scripts_are_known, validate_zero_or_one_wallet_command_present,
+        validate_all_scripts_are_known, validate_fund_commands,
+        validate_zero_or_one_wallet_command_present,
     },
 };
 
 mod abigen;
 mod deploy_contract;
+mod fund;
 mod initialize_wallet;
 mod load_script;
 mod set_options;
+mod setup_call;
 
 // Contains the result of parsing the input to the `setup_program_test` macro.
 // Contents represent the users wishes with regards to wallet initialization,
@@ -32,6 +37,8 @@ pub(crate) struct TestProgramCommands {
     pub(crate) generate_bindings: AbigenCommand,
     pub(crate) deploy_contract: Vec<DeployContractCommand>,
     pub(crate) load_scripts: Vec<LoadScriptCommand>,
+    pub(crate) fund: Vec<FundCommand>,
+    pub(crate) setup_calls: Vec<SetupCallCommand>,
 }
 
 command_parser!(
@@ -39,7 +46,9 @@ command_parser!(
     Wallets -> InitializeWalletCommand,
     Abigen -> AbigenCommand,
     Deploy -> DeployContractCommand,
-    LoadScript -> LoadScriptCommand
+    LoadScript -> LoadScriptCommand,
+    Fund -> FundCommand,
+    Setup -> SetupCallCommand
 );
 
 impl Parse for TestProgramCommands {
@@ -56,12 +65,20 @@ impl Parse for TestProgramCommands {
 
         validate_zero_or_one_wallet_command_present(&parsed_commands.Wallets)?;
 
+        validate_fund_commands(
+            parsed_commands.Wallets.first(),
+            &parsed_commands.Deploy,
+            &parsed_commands.Fund,
+        )?;
+
         Ok(Self {
             set_options: parsed_commands.Options.pop(),
             initialize_wallets: parsed_commands.Wallets.pop(),
             generate_bindings: abigen_command,
             deploy_contract: parsed_commands.Deploy,
             load_scripts: parsed_commands.LoadScript,
+            fund: parsed_commands.Fund,
+            setup_calls: parsed_commands.Setup,
         })
     }
 }
@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use syn::{Error, LitStr};
+use syn::{Error, Expr, LitStr};
 
 use crate::parse_utils::{Command, UniqueNameValues};
 
@@ -9,6 +9,9 @@ pub struct DeployContractCommand {
     pub name: String,
     pub contract: LitStr,
     pub wallet: String,
+    /// Evaluates to the configurables of the contract, e.g.
+    /// `MyContractConfigurables::default().with_value(5).unwrap()`.
+    pub configurables: Option<Expr>,
 }
 
 impl TryFrom<Command> for DeployContractCommand {
@@ -16,16 +19,26 @@ impl TryFrom<Command> for DeployContractCommand {
 
     fn try_from(command: Command) -> Result<Self, Self::Error> {
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "contract", "wallet"])?;
+        name_values.validate_has_no_other_names(&[
+            "name",
+            "contract",
+            "wallet",
+            "configurables",
+        ])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
         let contract = name_values.get_as_lit_str("contract")?.clone();
         let wallet = name_values.get_as_lit_str("wallet")?.value();
+        let configurables = name_values
+            .try_get("configurables")
+            .map(|_| name_values.get_as_lit_str("configurables")?.parse())
+            .transpose()?;
 
         Ok(Self {
             name,
             contract,
             wallet,
+            configurables,
         })
     }
 }
@@ -0,0 +1,86 @@
+use std::convert::TryFrom;
+
+use proc_macro2::Span;
+use syn::{Error, LitStr};
+
+use crate::parse_utils::{Command, UniqueNameValues};
+
+#[derive(Debug, Clone)]
+pub struct FundCommand {
+    pub span: Span,
+    /// A wallet from `Wallets` or a contract instance from `Deploy`.
+    pub to: LitStr,
+    pub amount: u64,
+    /// Defaults to the base asset.
+    pub asset: Option<[u8; 32]>,
+    /// Only for wallets, which are funded at genesis with `coins` coins of `amount` each.
+    pub coins: Option<u64>,
+    /// Only for contracts, defaults to the wallet that deployed the contract.
+    pub from: Option<LitStr>,
+}
+
+impl TryFrom<Command> for FundCommand {
+    type Error = Error;
+
+    fn try_from(command: Command) -> Result<Self, Self::Error> {
+        let span = command.name.span();
+        let name_values = UniqueNameValues::new(command.contents)?;
+        name_values.validate_has_no_other_names(&["to", "amount", "asset", "coins", "from"])?;
+
+        let to = name_values.get_as_lit_str("to")?.clone();
+        let amount = name_values.get_as_lit_int("amount")?.base10_parse()?;
+        let asset = name_values
+            .try_get("asset")
+            .map(|_| {
+                let asset = name_values.get_as_lit_str("asset")?;
+                parse_asset_id(&asset.value())
+                    .ok_or_else(|| Error::new_spanned(asset, "expected a 32 byte hex asset id"))
+            })
+            .transpose()?;
+        let coins = name_values
+            .try_get("coins")
+            .map(|_| name_values.get_as_lit_int("coins")?.base10_parse())
+            .transpose()?;
+        let from = name_values
+            .try_get("from")
+            .map(|_| name_values.get_as_lit_str("from").cloned())
+            .transpose()?;
+
+        Ok(Self {
+            span,
+            to,
+            amount,
+            asset,
+            coins,
+            from,
+        })
+    }
+}
+
+fn parse_asset_id(hex: &str) -> Option<[u8; 32]> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() != 64 || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut asset_id = [0; 32];
+    for (byte, chunk) in asset_id.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+    }
+
+    Some(asset_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_ids_are_parsed_from_hex() {
+        let asset_id = parse_asset_id(&format!("0x{}", "0a".repeat(32)));
+        assert_eq!(asset_id, Some([10; 32]));
+
+        assert_eq!(parse_asset_id("0x0a"), None);
+        assert_eq!(parse_asset_id(&"zz".repeat(32)), None);
+    }
+}
@@ -1,6 +1,6 @@
 use std::{convert::TryFrom, fmt, str::FromStr};
 
-use syn::Error;
+use syn::{Error, Lit};
 
 use crate::parse_utils::{Command, UniqueNameValues};
 
@@ -39,6 +39,7 @@ impl fmt::Display for BuildProfile {
 #[derive(Debug, Clone, Default)]
 pub struct SetOptionsCommand {
     pub profile: BuildProfile,
+    pub build: bool,
 }
 
 impl TryFrom<Command> for SetOptionsCommand {
@@ -46,15 +47,31 @@ impl TryFrom<Command> for SetOptionsCommand {
 
     fn try_from(command: Command) -> Result<Self, Self::Error> {
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["profile"])?;
+        name_values.validate_has_no_other_names(&["profile", "build"])?;
 
-        let profile = name_values.get_as_lit_str("profile")?;
-        let profile = profile
-            .value()
-            .as_str()
-            .parse()
-            .map_err(|msg| Error::new(profile.span(), msg))?;
+        let profile = match name_values.try_get("profile") {
+            Some(_) => {
+                let profile = name_values.get_as_lit_str("profile")?;
+                profile
+                    .value()
+                    .as_str()
+                    .parse()
+                    .map_err(|msg| Error::new(profile.span(), msg))?
+            }
+            None => BuildProfile::default(),
+        };
+
+        let build = match name_values.try_get("build") {
+            Some(Lit::Bool(lit_bool)) => lit_bool.value(),
+            Some(other) => {
+                return Err(Error::new_spanned(
+                    other.clone(),
+                    "expected the attribute 'build' to have a boolean value",
+                ))
+            }
+            None => false,
+        };
 
-        Ok(Self { profile })
+        Ok(Self { profile, build })
     }
 }
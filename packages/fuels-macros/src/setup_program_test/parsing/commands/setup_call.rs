@@ -0,0 +1,25 @@
+use std::convert::TryFrom;
+
+use syn::{Error, Expr};
+
+use crate::parse_utils::{Command, UniqueNameValues};
+
+#[derive(Debug, Clone)]
+pub struct SetupCallCommand {
+    /// Evaluates to a contract or script call handler, e.g.
+    /// `token.methods().initialize(42)`.
+    pub call: Expr,
+}
+
+impl TryFrom<Command> for SetupCallCommand {
+    type Error = Error;
+
+    fn try_from(command: Command) -> Result<Self, Self::Error> {
+        let name_values = UniqueNameValues::new(command.contents)?;
+        name_values.validate_has_no_other_names(&["call"])?;
+
+        let call = name_values.get_as_lit_str("call")?.parse()?;
+
+        Ok(Self { call })
+    }
+}
@@ -7,7 +7,8 @@ use syn::{Error, LitStr, Result};
 use crate::{
     parse_utils::ErrorsExt,
     setup_program_test::parsing::{
-        AbigenCommand, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
+        AbigenCommand, DeployContractCommand, FundCommand, InitializeWalletCommand,
+        LoadScriptCommand,
     },
 };
 
@@ -92,6 +93,51 @@ pub(crate) fn validate_zero_or_one_wallet_command_present(
     }
 }
 
+pub(crate) fn validate_fund_commands(
+    wallet_command: Option<&InitializeWalletCommand>,
+    deploy_commands: &[DeployContractCommand],
+    fund_commands: &[FundCommand],
+) -> Result<()> {
+    let wallets: HashSet<String> = wallet_command
+        .map(|command| command.names.iter().map(LitStr::value).collect())
+        .unwrap_or_default();
+    let contract_instances: HashSet<&str> = deploy_commands
+        .iter()
+        .map(|command| command.name.as_str())
+        .collect();
+
+    fund_commands
+        .iter()
+        .flat_map(|command| {
+            let to = command.to.value();
+            let mut errors = vec![];
+
+            if wallets.contains(&to) {
+                if let Some(from) = &command.from {
+                    errors.push(Error::new_spanned(
+                        from,
+                        "wallets are funded at genesis, `from` only applies to contracts",
+                    ));
+                }
+            } else if contract_instances.contains(to.as_str()) {
+                if command.coins.is_some() {
+                    errors.push(Error::new(
+                        command.span,
+                        "`coins` only applies to wallets, contracts receive a single transfer",
+                    ));
+                }
+            } else {
+                errors.push(Error::new_spanned(
+                    &command.to,
+                    "expected a wallet from `Wallets` or a contract instance from `Deploy`",
+                ));
+            }
+
+            errors
+        })
+        .validate_no_errors()
+}
+
 fn names_of_program_bindings(
     commands: &AbigenCommand,
     program_type: ProgramType,
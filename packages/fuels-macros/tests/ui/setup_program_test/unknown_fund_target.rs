@@ -0,0 +1,9 @@
+use fuels_macros::setup_program_test;
+
+setup_program_test!(
+    Wallets("wallet"),
+    Abigen(Contract(project = "some_project", name = "MyContract")),
+    Fund(to = "unknown", amount = 100)
+);
+
+fn main() {}
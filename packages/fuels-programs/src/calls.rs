@@ -1,10 +1,29 @@
+mod call_gas_profile;
 mod call_handler;
+mod call_history;
+mod call_trace;
 mod contract_call;
+mod decoded_block;
+mod failure_check;
+mod fee_payer;
+mod funding_hook;
+mod gas_profile;
+mod multicall_template;
 pub mod receipt_parser;
 mod script_call;
+mod slippage;
 pub mod traits;
 pub mod utils;
 
+pub use call_gas_profile::*;
 pub use call_handler::*;
+pub use call_history::*;
+pub use call_trace::*;
 pub use contract_call::*;
+pub use decoded_block::*;
+pub use failure_check::*;
+pub use funding_hook::*;
+pub use gas_profile::*;
+pub use multicall_template::*;
 pub use script_call::*;
+pub use slippage::*;
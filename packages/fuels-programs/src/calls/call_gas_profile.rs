@@ -0,0 +1,232 @@
+use std::fmt::{self, Debug};
+
+use fuel_tx::{ConsensusParameters, ContractId, Receipt};
+use fuels_accounts::Account;
+use fuels_core::{
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::Result,
+        transaction::{ScriptTransaction, Transaction},
+        FeeCalculator,
+    },
+};
+use serde::Serialize;
+
+use crate::calls::{traits::TransactionTuner, CallFrame, CallHandler, CallTraceBuilder};
+
+/// Where the gas of a call goes, measured with a dry run by [`CallHandler::profile`].
+///
+/// The node reports the gas used by the script as a whole, including the contracts it called.
+/// It doesn't count instructions or report the gas used by each call frame, so the frames only
+/// tell how much gas they were given.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CallGasProfile {
+    /// Gas used running the script and the contracts it called.
+    pub script_gas_used: u64,
+    /// Gas charged by the byte for the transaction, excluding its witnesses.
+    pub bytes_gas: u64,
+    /// Gas charged by the byte for the witnesses, up to the witness limit.
+    pub witnesses_gas: u64,
+    /// Gas used to run the predicates of the inputs.
+    pub predicates_gas: u64,
+    /// VM initialization, signature checks and transaction id hashing.
+    pub intrinsic_gas: u64,
+    /// The size of the transaction the gas per byte is charged for.
+    pub metered_bytes: u64,
+    /// The contract calls, depth first.
+    pub calls: Vec<FrameGas>,
+    /// What the storage instructions cost on this chain, to weigh the storage accesses of the
+    /// profiled functions.
+    pub storage_costs: StorageGasCosts,
+}
+
+/// A contract call of a [`CallGasProfile`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FrameGas {
+    pub contract_id: ContractId,
+    /// Only known for the calls the script makes directly.
+    pub function: Option<String>,
+    /// `1` for the calls the script makes, `2` for the calls those make, and so on.
+    pub depth: usize,
+    pub gas_forwarded: u64,
+}
+
+/// The gas cost of the storage instructions of a chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct StorageGasCosts {
+    /// Reading a slot, `SRW`.
+    pub read_slot: u64,
+    /// Writing a slot, `SWW`.
+    pub write_slot: u64,
+    /// Charged on top for every byte of key and value of a slot that didn't exist before.
+    pub new_storage_per_byte: u64,
+}
+
+impl CallGasProfile {
+    /// Profiles `tx` from the `receipts` of its dry run.
+    pub fn new(
+        tx: &ScriptTransaction,
+        receipts: &[Receipt],
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Self> {
+        let trace = CallTraceBuilder::from_receipts(receipts)
+            .with_script(tx)
+            .build()?;
+        // only the gas of the components is used, which doesn't depend on the gas price
+        let breakdown = FeeCalculator::new(consensus_parameters).breakdown(tx, 0)?;
+        let gas_costs = consensus_parameters.gas_costs();
+
+        let mut calls = vec![];
+        for frame in &trace.root.calls {
+            collect_calls(frame, 1, &mut calls);
+        }
+
+        Ok(Self {
+            script_gas_used: trace.gas_used.unwrap_or_default(),
+            bytes_gas: breakdown.bytes.gas,
+            witnesses_gas: breakdown.witnesses.gas,
+            predicates_gas: breakdown.predicates.gas,
+            intrinsic_gas: breakdown.intrinsic.gas,
+            metered_bytes: tx.metered_bytes_size() as u64,
+            calls,
+            storage_costs: StorageGasCosts {
+                read_slot: gas_costs.srw(),
+                write_slot: gas_costs.sww(),
+                new_storage_per_byte: gas_costs.new_storage_per_byte(),
+            },
+        })
+    }
+
+    /// The gas the transaction is charged for: the script plus everything charged before it
+    /// runs.
+    pub fn total_gas(&self) -> u64 {
+        [
+            self.script_gas_used,
+            self.bytes_gas,
+            self.witnesses_gas,
+            self.predicates_gas,
+            self.intrinsic_gas,
+        ]
+        .into_iter()
+        .fold(0, u64::saturating_add)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("the profile is valid JSON")
+    }
+}
+
+impl fmt::Display for CallGasProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "total: {} gas", self.total_gas())?;
+        writeln!(f, "script: {} gas", self.script_gas_used)?;
+        writeln!(
+            f,
+            "bytes: {} gas for {} bytes",
+            self.bytes_gas, self.metered_bytes
+        )?;
+        writeln!(f, "witnesses: {} gas", self.witnesses_gas)?;
+        writeln!(f, "predicates: {} gas", self.predicates_gas)?;
+        write!(f, "intrinsic: {} gas", self.intrinsic_gas)?;
+
+        for call in &self.calls {
+            let indent = "  ".repeat(call.depth);
+            let function = call.function.as_deref().unwrap_or("<unknown>");
+            write!(
+                f,
+                "\n{indent}{}::{function}: {} gas forwarded",
+                call.contract_id, call.gas_forwarded
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn collect_calls(frame: &CallFrame, depth: usize, calls: &mut Vec<FrameGas>) {
+    calls.push(FrameGas {
+        contract_id: frame.contract_id,
+        function: frame.function.clone(),
+        depth,
+        gas_forwarded: frame.gas_forwarded.unwrap_or_default(),
+    });
+
+    for callee in &frame.calls {
+        collect_calls(callee, depth + 1, calls);
+    }
+}
+
+impl<A, C, T> CallHandler<A, C, T>
+where
+    A: Account,
+    C: TransactionTuner,
+    T: Tokenizable + Parameterize + Debug,
+{
+    /// Dry runs the call and breaks down the gas it would be charged for. Fails like
+    /// [`simulate`](Self::simulate) if the call reverts.
+    pub async fn profile(&self) -> Result<CallGasProfile> {
+        let tx = self.build_dry_run_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        let receipts = provider
+            .dry_run(tx.clone())
+            .await?
+            .take_receipts_checked(Some(&self.log_decoder))?;
+
+        CallGasProfile::new(
+            &tx,
+            &receipts,
+            &provider.fetch_consensus_parameters().await?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{policies::Policies, AssetId, ScriptExecutionResult};
+
+    use super::*;
+
+    fn call(from: ContractId, to: ContractId, gas: u64) -> Receipt {
+        Receipt::call(from, to, 0, AssetId::zeroed(), gas, 0, 0, 0, 0)
+    }
+
+    #[test]
+    fn nested_calls_are_listed_depth_first() -> Result<()> {
+        // given
+        let tx: ScriptTransaction = fuel_tx::Transaction::script(
+            1_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .into();
+        let [caller, callee] = [1, 2].map(|byte| ContractId::new([byte; 32]));
+        let receipts = [
+            call(ContractId::zeroed(), caller, 500),
+            call(caller, callee, 200),
+            Receipt::ret(callee, 0, 0, 0),
+            Receipt::ret(caller, 0, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 321),
+        ];
+
+        // when
+        let profile = CallGasProfile::new(&tx, &receipts, &ConsensusParameters::default())?;
+
+        // then
+        let depths_and_gas = profile
+            .calls
+            .iter()
+            .map(|call| (call.contract_id, call.depth, call.gas_forwarded))
+            .collect::<Vec<_>>();
+        assert_eq!(depths_and_gas, vec![(caller, 1, 500), (callee, 2, 200)]);
+        assert_eq!(profile.script_gas_used, 321);
+        assert!(profile.total_gas() > profile.script_gas_used);
+        assert!(profile.to_json().contains("\"script_gas_used\": 321"));
+
+        Ok(())
+    }
+}
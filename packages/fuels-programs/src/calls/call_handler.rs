@@ -1,28 +1,42 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    sync::{Arc, OnceLock},
+};
 
 use fuel_tx::{AssetId, Bytes32, Receipt};
-use fuels_accounts::{provider::TransactionCost, Account};
+use fuel_types::ChainId;
+#[cfg(feature = "estimation")]
+use fuels_accounts::provider::{EstimationReport, Provider, TransactionCost};
+use fuels_accounts::{in_account_context, resource_manager::spent_resource_ids, Account};
 use fuels_core::{
-    codec::{ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
+    codec::{ABIEncoder, AbiRegistry, DecoderConfig, EncoderConfig, LogDecoder},
+    metrics,
     traits::{Parameterize, Tokenizable},
     types::{
         bech32::{Bech32Address, Bech32ContractId},
+        coin_type_id::CoinTypeId,
         errors::{error, transaction::Reason, Error, Result},
         input::Input,
         output::Output,
         transaction::{ScriptTransaction, Transaction, TxPolicies},
-        transaction_builders::{ScriptTransactionBuilder, VariableOutputPolicy},
+        transaction_builders::{
+            BuildableTransaction, ScriptTransactionBuilder, VariableOutputPolicy,
+        },
         tx_status::TxStatus,
-        Selector, Token,
+        GasPriceStrategy, Selector, Token,
     },
 };
+use futures::{stream, StreamExt};
 
 use crate::{
     calls::{
+        fee_payer::FeePayer,
+        funding_hook::acquire_missing_assets,
         receipt_parser::ReceiptParser,
         traits::{ContractDependencyConfigurator, ResponseParser, TransactionTuner},
         utils::find_id_of_missing_contract,
-        CallParameters, ContractCall, ScriptCall,
+        CallParameters, ContractCall, FundingHook, ScriptCall,
     },
     responses::{CallResponse, SubmitResponse},
 };
@@ -34,6 +48,105 @@ pub trait ContractDependency {
     fn log_decoder(&self) -> LogDecoder;
 }
 
+/// A check run against the receipts of a successful call. See [`CallHandler::with_post_condition`].
+#[derive(Clone)]
+pub(crate) struct PostCondition(Arc<ReceiptsCheck>);
+
+type ReceiptsCheck = dyn Fn(&[Receipt]) -> bool + Send + Sync;
+
+impl Debug for PostCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PostCondition")
+    }
+}
+
+fn check_post_conditions(post_conditions: &[PostCondition], receipts: &[Receipt]) -> Result<()> {
+    match post_conditions
+        .iter()
+        .position(|condition| !(condition.0)(receipts))
+    {
+        Some(index) => Err(Error::Transaction(Reason::PostConditionFailed {
+            index,
+            receipts: receipts.to_vec(),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Adds the transaction id and the contracts it calls to the span of the current call.
+#[cfg(feature = "tracing")]
+fn record_call_span(tx: &ScriptTransaction, chain_id: ChainId) {
+    let contract_ids = tx
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            fuel_tx::Input::Contract(contract) => {
+                Some(Bech32ContractId::from(contract.contract_id).to_string())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    tracing::Span::current()
+        .record("tx_id", tracing::field::display(tx.id(chain_id)))
+        .record("contract_ids", tracing::field::debug(contract_ids));
+}
+
+#[cfg(feature = "tracing")]
+fn record_gas_used(receipts: &[Receipt]) {
+    let gas_used = receipts.iter().rev().find_map(|receipt| match receipt {
+        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+        _ => None,
+    });
+
+    tracing::Span::current().record("gas_used", gas_used);
+}
+
+/// Receives the [`EstimationReport`]s of a call. See [`CallHandler::with_estimation_report`].
+#[cfg(feature = "estimation")]
+#[derive(Clone)]
+pub(crate) struct EstimationReporter(Arc<dyn Fn(&EstimationReport) + Send + Sync>);
+
+#[cfg(feature = "estimation")]
+impl Debug for EstimationReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EstimationReporter")
+    }
+}
+
+/// Sends `tx`, first estimating its cost if there is a `reporter` to report to once it executed.
+///
+/// Calls whose cost can't be estimated, e.g. because their dry run fails, are sent without being
+/// reported, so that monitoring never gets in the way of the call itself.
+#[cfg(feature = "estimation")]
+async fn send_and_report(
+    provider: &Provider,
+    tx: ScriptTransaction,
+    reporter: Option<&EstimationReporter>,
+) -> Result<TxStatus> {
+    let Some(reporter) = reporter else {
+        return provider.send_transaction_and_await_commit(tx).await;
+    };
+
+    let estimated = provider
+        .estimate_transaction_cost(tx.clone(), None, None)
+        .await
+        .ok();
+    let tx_status = provider
+        .send_transaction_and_await_commit(tx.clone())
+        .await?;
+
+    if let (Some(estimated), TxStatus::Success { receipts } | TxStatus::Revert { receipts, .. }) =
+        (estimated, &tx_status)
+    {
+        if let Ok(report) = provider.estimation_report(&tx, estimated, receipts).await {
+            (reporter.0)(&report);
+        }
+    }
+
+    Ok(tx_status)
+}
+
 #[derive(Debug, Clone)]
 #[must_use = "contract calls do nothing unless you `call` them"]
 /// Helper that handles submitting a call to a client and formatting the response
@@ -46,7 +159,18 @@ pub struct CallHandler<A, C, T> {
     decoder_config: DecoderConfig,
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
+    // Only set if `include_tx_in_response` is enabled
+    cached_tx: Option<ScriptTransaction>,
+    include_tx_in_response: bool,
     variable_output_policy: VariableOutputPolicy,
+    post_conditions: Vec<PostCondition>,
+    #[cfg(feature = "estimation")]
+    estimation_reporter: Option<EstimationReporter>,
+    gas_price_strategy: Option<GasPriceStrategy>,
+    // the tip of a percentile strategy, estimated on the first build and reused by later ones
+    estimated_tip: Arc<OnceLock<u64>>,
+    funding_hook: Option<Arc<dyn FundingHook>>,
+    fee_payer: Option<Arc<dyn FeePayer>>,
 }
 
 impl<A, C, T> CallHandler<A, C, T> {
@@ -67,6 +191,51 @@ impl<A, C, T> CallHandler<A, C, T> {
         self
     }
 
+    /// Decodes the logs and revert messages of the contracts the call reaches with the ABIs of
+    /// `abi_registry`, on top of the ones registered globally. Unlike
+    /// `with_contracts`, the contracts aren't added to the transaction.
+    pub fn with_abi_registry(mut self, abi_registry: AbiRegistry) -> Self {
+        self.log_decoder.set_abi_registry(abi_registry);
+        self
+    }
+
+    /// Prices the call according to `strategy` where its `TxPolicies` leave the tip or the max
+    /// fee out, e.g. to tip like recent transactions did:
+    ///
+    /// ```ignore
+    /// let strategy = GasPriceStrategy::Percentile { percentile: 75, blocks: 10, block_horizon: 5 };
+    /// my_contract_instance.my_method(...).with_gas_price_strategy(strategy).call()
+    /// ```
+    ///
+    /// A percentile tip is estimated once, on the first build of the call, and reused by the
+    /// simulations, estimations and submissions that follow.
+    pub fn with_gas_price_strategy(mut self, strategy: GasPriceStrategy) -> Self {
+        self.gas_price_strategy = Some(strategy);
+        self.estimated_tip = Default::default();
+        self
+    }
+
+    /// Lets `payer` pay the fee of the call, i.e. sponsor its gas: it provides and signs the base
+    /// asset inputs covering the fee, while the account of the call only provides the forwarded
+    /// assets. The base asset change goes to `payer`, unless the call forwards the base asset too,
+    /// in which case it goes to the account of the call.
+    ///
+    /// ```ignore
+    /// my_contract_instance.my_method(...).with_fee_payer(sponsor_wallet).call()
+    /// ```
+    pub fn with_fee_payer(mut self, payer: impl Account + 'static) -> Self {
+        self.fee_payer = Some(Arc::new(payer));
+        self
+    }
+
+    /// Lets `hook` acquire the forwarded assets the account lacks, e.g. by swapping another asset,
+    /// in a preparatory transaction sent before the call. Simulations never run the hook.
+    /// See [`FundingHook`].
+    pub fn with_funding_hook(mut self, hook: impl FundingHook + 'static) -> Self {
+        self.funding_hook = Some(Arc::new(hook));
+        self
+    }
+
     /// If this method is not called, the default policy is to not add any variable outputs.
     ///
     /// # Parameters
@@ -78,6 +247,71 @@ impl<A, C, T> CallHandler<A, C, T> {
         self.variable_output_policy = variable_outputs;
         self
     }
+
+    /// Adds a check that is run against the receipts once the call executed successfully,
+    /// both when calling and when simulating. If the check returns `false` the call fails with
+    /// [`Reason::PostConditionFailed`], which holds the index of the failed check (in the order
+    /// they were added) and the receipts.
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .with_post_condition(|receipts| {
+    ///         receipts
+    ///             .iter()
+    ///             .filter(|r| matches!(r, Receipt::Transfer { to, .. } if *to == recipient))
+    ///             .count()
+    ///             == 1
+    ///     })
+    ///     .call()
+    /// ```
+    pub fn with_post_condition(
+        mut self,
+        post_condition: impl Fn(&[Receipt]) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.post_conditions
+            .push(PostCondition(Arc::new(post_condition)));
+        self
+    }
+
+    /// Estimates the cost of the call before sending it and passes `reporter` how the estimate
+    /// compares to what the call actually used, e.g. to monitor the quality of the estimates
+    /// across SDK releases. Estimating costs an extra dry run per call.
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .with_estimation_report(|report| {
+    ///         if report.gas_drift().abs() > 0.2 {
+    ///             eprintln!("{report}");
+    ///         }
+    ///     })
+    ///     .call()
+    /// ```
+    #[cfg(feature = "estimation")]
+    pub fn with_estimation_report(
+        mut self,
+        reporter: impl Fn(&EstimationReport) + Send + Sync + 'static,
+    ) -> Self {
+        self.estimation_reporter = Some(EstimationReporter(Arc::new(reporter)));
+        self
+    }
+
+    /// If `true`, the signed transaction that was sent to the node is kept in
+    /// [`CallResponse::tx`], e.g. to archive exactly what was submitted. Disabled by default.
+    pub fn with_tx_in_response(mut self, include: bool) -> Self {
+        self.include_tx_in_response = include;
+        self
+    }
+
+    fn cache_tx(&mut self, tx: &ScriptTransaction, chain_id: ChainId) {
+        self.cached_tx_id = Some(tx.id(chain_id));
+        #[cfg(feature = "tracing")]
+        record_call_span(tx, chain_id);
+        if self.include_tx_in_response {
+            self.cached_tx = Some(tx.clone());
+        }
+    }
 }
 
 impl<A, C, T> CallHandler<A, C, T>
@@ -86,26 +320,165 @@ where
     C: TransactionTuner,
     T: Tokenizable + Parameterize + Debug,
 {
+    /// The `TxPolicies` of the call, with the tip of a percentile strategy filled in unless one
+    /// was given.
+    async fn priced_tx_policies(&self) -> Result<TxPolicies> {
+        let Some(GasPriceStrategy::Percentile {
+            percentile, blocks, ..
+        }) = self.gas_price_strategy
+        else {
+            return Ok(self.tx_policies);
+        };
+        if self.tx_policies.tip().is_some() {
+            return Ok(self.tx_policies);
+        }
+
+        let tip = match self.estimated_tip.get() {
+            Some(tip) => *tip,
+            None => {
+                let tip = self
+                    .account
+                    .try_provider()?
+                    .estimate_tip(percentile, blocks)
+                    .await?;
+                *self.estimated_tip.get_or_init(|| tip)
+            }
+        };
+
+        Ok(self.tx_policies.with_tip(tip))
+    }
+
     pub async fn transaction_builder(&self) -> Result<ScriptTransactionBuilder> {
         self.call
-            .transaction_builder(self.tx_policies, self.variable_output_policy, &self.account)
+            .transaction_builder(
+                self.priced_tx_policies().await?,
+                self.variable_output_policy,
+                self.gas_price_strategy,
+                &self.account,
+            )
             .await
     }
 
     /// Returns the script that executes the contract call
     pub async fn build_tx(&self) -> Result<ScriptTransaction> {
-        self.call
-            .build_tx(self.tx_policies, self.variable_output_policy, &self.account)
-            .await
+        self.build_tx_for(&self.account, false).await
+    }
+
+    /// Like [`build_tx`](Self::build_tx), but for a transaction that is only dry run, so the
+    /// resources it selects aren't reserved. See [`Account::for_dry_run`].
+    pub(crate) async fn build_dry_run_tx(&self) -> Result<ScriptTransaction> {
+        self.build_tx_for(&self.account.for_dry_run(), true).await
+    }
+
+    async fn build_tx_for(&self, account: &A, dry_run: bool) -> Result<ScriptTransaction> {
+        let Some(fee_payer) = &self.fee_payer else {
+            return self
+                .call
+                .build_tx(
+                    self.priced_tx_policies().await?,
+                    self.variable_output_policy,
+                    self.gas_price_strategy,
+                    account,
+                )
+                .await;
+        };
+
+        let mut tb = self
+            .call
+            .transaction_builder(
+                self.priced_tx_policies().await?,
+                self.variable_output_policy,
+                self.gas_price_strategy,
+                account,
+            )
+            .await?;
+        let provider = account.try_provider()?;
+        let base_asset_id = *provider.fetch_consensus_parameters().await?.base_asset_id();
+        let used_base_amount = self
+            .call
+            .required_assets(base_asset_id)
+            .into_iter()
+            .find_map(|(asset_id, amount)| (asset_id == base_asset_id).then_some(amount))
+            .unwrap_or_default();
+
+        account.add_witnesses(&mut tb)?;
+        fee_payer
+            .sponsor(&mut tb, used_base_amount, dry_run)
+            .await?;
+
+        tb.build(provider).await
+    }
+
+    /// Releases the resources spent by a transaction once it is done, see
+    /// [`Account::release_resources`].
+    pub(crate) fn release_resources(&self, ids: &[CoinTypeId]) {
+        self.account.release_resources(ids);
+        if let Some(fee_payer) = &self.fee_payer {
+            fee_payer.release_resources(ids);
+        }
+    }
+
+    /// Sends `tx` without waiting for it to be committed, releasing its resources right away if
+    /// it can't be sent.
+    async fn send(&self, tx: ScriptTransaction) -> Result<Bytes32> {
+        let resources = spent_resource_ids(tx.inputs());
+        let result = async {
+            self.account.record_spending(&tx)?;
+            self.account.try_provider()?.send_transaction(tx).await
+        }
+        .await;
+        if result.is_err() {
+            self.release_resources(&resources);
+        }
+
+        result
+    }
+
+    /// Sends `tx` and waits for it to be committed, then releases its resources.
+    async fn send_and_await_commit(&self, tx: ScriptTransaction) -> Result<TxStatus> {
+        let resources = spent_resource_ids(tx.inputs());
+        let result = async {
+            self.account.record_spending(&tx)?;
+            let provider = self.account.try_provider()?;
+            #[cfg(feature = "estimation")]
+            return send_and_report(provider, tx, self.estimation_reporter.as_ref()).await;
+            #[cfg(not(feature = "estimation"))]
+            return provider.send_transaction_and_await_commit(tx).await;
+        }
+        .await;
+        self.release_resources(&resources);
+
+        result
+    }
+
+    /// Sends the preparatory transactions of the funding hook, if any.
+    async fn acquire_missing_assets(&self) -> Result<()> {
+        let Some(hook) = &self.funding_hook else {
+            return Ok(());
+        };
+        let base_asset_id = *self
+            .account
+            .try_provider()?
+            .fetch_consensus_parameters()
+            .await?
+            .base_asset_id();
+
+        acquire_missing_assets(
+            &self.account,
+            self.call.required_assets(base_asset_id),
+            hook.as_ref(),
+        )
+        .await
     }
 
     /// Get a call's estimated cost
+    #[cfg(feature = "estimation")]
     pub async fn estimate_transaction_cost(
         &self,
         tolerance: Option<f64>,
         block_horizon: Option<u32>,
     ) -> Result<TransactionCost> {
-        let tx = self.build_tx().await?;
+        let tx = self.build_dry_run_tx().await?;
         let provider = self.account.try_provider()?;
 
         let transaction_cost = provider
@@ -164,13 +537,19 @@ where
     }
 
     pub async fn submit(mut self) -> Result<SubmitResponse<A, C, T>> {
+        self.acquire_missing_assets().await?;
         let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
-
-        let tx_id = provider.send_transaction(tx.clone()).await?;
-        self.cached_tx_id = Some(tx_id);
-
-        Ok(SubmitResponse::<A, C, T>::new(tx_id, self))
+        let chain_id = self
+            .account
+            .try_provider()?
+            .fetch_consensus_parameters()
+            .await?
+            .chain_id();
+        self.cache_tx(&tx, chain_id);
+        let resources = spent_resource_ids(tx.inputs());
+        let tx_id = self.send(tx).await?;
+
+        Ok(SubmitResponse::<A, C, T>::new(tx_id, self).with_resources(resources))
     }
 
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
@@ -179,24 +558,59 @@ where
         self.call_or_simulate(true).await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "call",
+            skip_all,
+            fields(
+                simulate = simulate,
+                account = self.account.label(),
+                tx_id = tracing::field::Empty,
+                contract_ids = tracing::field::Empty,
+                gas_used = tracing::field::Empty
+            )
+        )
+    )]
     async fn call_or_simulate(&mut self, simulate: bool) -> Result<CallResponse<T>> {
-        let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
+        if !simulate {
+            self.acquire_missing_assets().await?;
+        }
+        let result = self.submit_or_dry_run(simulate).await;
+        let receipts = in_account_context(&self.account, result)?;
+        #[cfg(feature = "tracing")]
+        record_gas_used(&receipts);
 
-        self.cached_tx_id = Some(tx.id(provider.chain_id()));
+        self.get_response(receipts)
+    }
+
+    async fn submit_or_dry_run(&mut self, simulate: bool) -> Result<Vec<Receipt>> {
+        let tx = if simulate {
+            self.build_dry_run_tx().await?
+        } else {
+            self.build_tx().await?
+        };
+        let chain_id = self
+            .account
+            .try_provider()?
+            .fetch_consensus_parameters()
+            .await?
+            .chain_id();
+        self.cache_tx(&tx, chain_id);
 
         let tx_status = if simulate {
-            provider.dry_run(tx).await?
+            self.account.try_provider()?.dry_run(tx).await?
         } else {
-            provider.send_transaction_and_await_commit(tx).await?
+            self.send_and_await_commit(tx).await?
         };
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
 
-        self.get_response(receipts)
+        tx_status.take_receipts_checked(Some(&self.log_decoder))
     }
 
     /// Create a [`CallResponse`] from call receipts
     pub fn get_response(&self, receipts: Vec<Receipt>) -> Result<CallResponse<T>> {
+        check_post_conditions(&self.post_conditions, &receipts)?;
+
         let token = self
             .call
             .parse_call(&receipts, self.decoder_config, &T::param_type())?;
@@ -206,7 +620,8 @@ where
             receipts,
             self.log_decoder.clone(),
             self.cached_tx_id,
-        ))
+        )
+        .with_tx(self.cached_tx.clone()))
     }
 
     /// Create a [`CallResponse`] from `TxStatus`
@@ -216,6 +631,7 @@ where
         self.get_response(receipts)
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn determine_missing_contracts(mut self, max_attempts: Option<u64>) -> Result<Self> {
         let attempts = max_attempts.unwrap_or(10);
 
@@ -225,7 +641,10 @@ where
 
                 Err(Error::Transaction(Reason::Reverted { ref receipts, .. })) => {
                     if let Some(contract_id) = find_id_of_missing_contract(receipts) {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(%contract_id, "adding missing contract");
                         self.call.append_external_contract(contract_id);
+                        metrics::increment_counter(metrics::ESTIMATION_RETRIES, &[], 1);
                     }
                 }
 
@@ -237,6 +656,27 @@ where
     }
 }
 
+/// Simulates every call in its own dry run, running up to `concurrency` dry runs at a time.
+///
+/// Meant for read-heavy batches that don't fit into a single multi-call transaction because of
+/// size or gas limits. Results are returned in the order of `calls`, each call failing on its own
+/// without affecting the others.
+pub async fn simulate_many<A, C, T>(
+    calls: Vec<CallHandler<A, C, T>>,
+    concurrency: usize,
+) -> Vec<Result<CallResponse<T>>>
+where
+    A: Account,
+    C: ContractDependencyConfigurator + TransactionTuner + ResponseParser,
+    T: Tokenizable + Parameterize + Debug,
+{
+    stream::iter(calls)
+        .map(|mut call| async move { call.simulate().await })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
 impl<A, T> CallHandler<A, ContractCall, T>
 where
     A: Account,
@@ -269,7 +709,16 @@ where
             datatype: PhantomData,
             decoder_config: DecoderConfig::default(),
             cached_tx_id: None,
+            cached_tx: None,
+            include_tx_in_response: false,
             variable_output_policy: VariableOutputPolicy::default(),
+            post_conditions: vec![],
+            #[cfg(feature = "estimation")]
+            estimation_reporter: None,
+            gas_price_strategy: None,
+            estimated_tip: Default::default(),
+            funding_hook: None,
+            fee_payer: None,
         }
     }
 
@@ -280,7 +729,8 @@ where
     /// - `asset_id`: The unique identifier of the asset being added.
     /// - `amount`: The amount of the asset being added.
     /// - `address`: The optional account address that the output amount will be sent to.
-    ///              If not provided, the asset will be sent to the users account address.
+    ///   If not provided, the asset will be sent to the users account address.
+    ///
     /// Note that this is a builder method, i.e. use it as a chain:
     ///
     /// ```ignore
@@ -317,6 +767,102 @@ where
 
         Ok(self)
     }
+
+    /// Forwards several assets to this payable method in one transaction.
+    ///
+    /// The VM forwards a single asset per contract call, so the method is called once per asset,
+    /// in the given order, bundled into a multi-call. The call parameters other than the amount
+    /// and asset, as well as the policies and custom assets of this call, carry over. The
+    /// response holds a tuple with the value returned by each call:
+    ///
+    /// ```ignore
+    /// let (first, second): (u64, u64) = my_contract_instance
+    ///     .methods()
+    ///     .deposit()
+    ///     .forward_assets([(base_asset_id, 100), (other_asset_id, 200)])?
+    ///     .call()
+    ///     .await?
+    ///     .value;
+    /// ```
+    pub fn forward_assets(
+        self,
+        assets: impl IntoIterator<Item = (AssetId, u64)>,
+    ) -> Result<CallHandler<A, Vec<ContractCall>, ()>> {
+        if !self.is_payable() {
+            return Err(error!(Other, "assets forwarded to non-payable method"));
+        }
+        let assets: Vec<_> = assets.into_iter().collect();
+        validate_forwarded_assets(&assets)?;
+
+        let calls = assets
+            .into_iter()
+            .enumerate()
+            .map(|(i, (asset_id, amount))| {
+                let mut call = self.call.clone();
+                call.call_parameters = call
+                    .call_parameters
+                    .with_amount(amount)
+                    .with_asset_id(asset_id);
+                // the custom assets are for the whole transaction, not per call
+                if i > 0 {
+                    call.custom_assets.clear();
+                }
+
+                call
+            })
+            .collect();
+
+        Ok(self.to_multi_call(calls))
+    }
+
+    /// A multi call making `calls` with the settings of this call, e.g. its transaction policies.
+    pub(crate) fn to_multi_call(
+        &self,
+        calls: Vec<ContractCall>,
+    ) -> CallHandler<A, Vec<ContractCall>, ()> {
+        CallHandler {
+            account: self.account.clone(),
+            call: calls,
+            tx_policies: self.tx_policies,
+            log_decoder: self.log_decoder.clone(),
+            datatype: PhantomData,
+            decoder_config: self.decoder_config,
+            cached_tx_id: None,
+            cached_tx: None,
+            include_tx_in_response: self.include_tx_in_response,
+            variable_output_policy: self.variable_output_policy,
+            post_conditions: self.post_conditions.clone(),
+            #[cfg(feature = "estimation")]
+            estimation_reporter: self.estimation_reporter.clone(),
+            gas_price_strategy: self.gas_price_strategy,
+            estimated_tip: self.estimated_tip.clone(),
+            funding_hook: self.funding_hook.clone(),
+            fee_payer: self.fee_payer.clone(),
+        }
+    }
+}
+
+fn validate_forwarded_assets(assets: &[(AssetId, u64)]) -> Result<()> {
+    if assets.is_empty() {
+        return Err(error!(Other, "no assets to forward"));
+    }
+
+    for (i, (asset_id, amount)) in assets.iter().enumerate() {
+        if *amount == 0 {
+            return Err(error!(
+                Other,
+                "forwarded amount of asset `{asset_id}` is zero"
+            ));
+        }
+        if assets[..i].iter().any(|(seen, _)| seen == asset_id) {
+            return Err(error!(
+                Other,
+                "asset `{asset_id}` is forwarded more than once"
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 impl<A, T> CallHandler<A, ScriptCall, T>
@@ -346,7 +892,16 @@ where
             datatype: PhantomData,
             decoder_config: DecoderConfig::default(),
             cached_tx_id: None,
+            cached_tx: None,
+            include_tx_in_response: false,
             variable_output_policy: VariableOutputPolicy::default(),
+            post_conditions: vec![],
+            #[cfg(feature = "estimation")]
+            estimation_reporter: None,
+            gas_price_strategy: None,
+            estimated_tip: Default::default(),
+            funding_hook: None,
+            fee_payer: None,
         }
     }
 
@@ -361,6 +916,25 @@ where
     }
 }
 
+impl<A, T, E> CallHandler<A, ScriptCall, std::result::Result<T, E>>
+where
+    A: Account,
+    T: Parameterize + Tokenizable + Debug,
+    E: Parameterize + Tokenizable + Debug + 'static,
+{
+    /// Like [`call`](Self::call), but an `Err` returned by the script fails the call, see
+    /// [`CallResponse::ok_or_script_err`]. The transaction still went through.
+    pub async fn call_ok(self) -> Result<CallResponse<T>> {
+        self.call().await?.ok_or_script_err()
+    }
+
+    /// Like [`simulate`](Self::simulate), but an `Err` returned by the script fails the call,
+    /// see [`CallResponse::ok_or_script_err`].
+    pub async fn simulate_ok(&mut self) -> Result<CallResponse<T>> {
+        self.simulate().await?.ok_or_script_err()
+    }
+}
+
 impl<A> CallHandler<A, Vec<ContractCall>, ()>
 where
     A: Account,
@@ -374,7 +948,16 @@ where
             datatype: PhantomData,
             decoder_config: DecoderConfig::default(),
             cached_tx_id: None,
+            cached_tx: None,
+            include_tx_in_response: false,
             variable_output_policy: VariableOutputPolicy::default(),
+            post_conditions: vec![],
+            #[cfg(feature = "estimation")]
+            estimation_reporter: None,
+            gas_price_strategy: None,
+            estimated_tip: Default::default(),
+            funding_hook: None,
+            fee_payer: None,
         }
     }
 
@@ -401,6 +984,7 @@ where
         call_handler: CallHandler<impl Account, ContractCall, impl Tokenizable>,
     ) -> Self {
         self.log_decoder.merge(call_handler.log_decoder);
+        self.post_conditions.extend(call_handler.post_conditions);
         self.call.push(call_handler.call);
 
         self
@@ -412,13 +996,19 @@ where
     }
 
     pub async fn submit(mut self) -> Result<SubmitResponse<A, Vec<ContractCall>, ()>> {
+        self.acquire_missing_assets().await?;
         let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
-
-        let tx_id = provider.send_transaction(tx).await?;
-        self.cached_tx_id = Some(tx_id);
-
-        Ok(SubmitResponse::<A, Vec<ContractCall>, ()>::new(tx_id, self))
+        let chain_id = self
+            .account
+            .try_provider()?
+            .fetch_consensus_parameters()
+            .await?
+            .chain_id();
+        self.cache_tx(&tx, chain_id);
+        let resources = spent_resource_ids(tx.inputs());
+        let tx_id = self.send(tx).await?;
+
+        Ok(SubmitResponse::<A, Vec<ContractCall>, ()>::new(tx_id, self).with_resources(resources))
     }
 
     /// Call contract methods on the node, in a simulated manner, meaning the state of the
@@ -430,30 +1020,62 @@ where
         self.call_or_simulate(true).await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "call",
+            skip_all,
+            fields(
+                simulate = simulate,
+                account = self.account.label(),
+                tx_id = tracing::field::Empty,
+                contract_ids = tracing::field::Empty,
+                gas_used = tracing::field::Empty
+            )
+        )
+    )]
     async fn call_or_simulate<T: Tokenizable + Debug>(
         &mut self,
         simulate: bool,
     ) -> Result<CallResponse<T>> {
-        let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
+        if !simulate {
+            self.acquire_missing_assets().await?;
+        }
+        let result = self.submit_or_dry_run(simulate).await;
+        let receipts = in_account_context(&self.account, result)?;
+        #[cfg(feature = "tracing")]
+        record_gas_used(&receipts);
 
-        self.cached_tx_id = Some(tx.id(provider.chain_id()));
+        self.get_response(receipts)
+    }
 
-        let tx_status = if simulate {
-            provider.dry_run(tx).await?
+    async fn submit_or_dry_run(&mut self, simulate: bool) -> Result<Vec<Receipt>> {
+        let tx = if simulate {
+            self.build_dry_run_tx().await?
         } else {
-            provider.send_transaction_and_await_commit(tx).await?
+            self.build_tx().await?
         };
+        let chain_id = self
+            .account
+            .try_provider()?
+            .fetch_consensus_parameters()
+            .await?
+            .chain_id();
+        self.cache_tx(&tx, chain_id);
 
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+        let tx_status = if simulate {
+            self.account.try_provider()?.dry_run(tx).await?
+        } else {
+            self.send_and_await_commit(tx).await?
+        };
 
-        self.get_response(receipts)
+        tx_status.take_receipts_checked(Some(&self.log_decoder))
     }
 
     /// Simulates a call without needing to resolve the generic for the return type
     async fn simulate_without_decode(&self) -> Result<()> {
         let provider = self.account.try_provider()?;
-        let tx = self.build_tx().await?;
+        let tx = self.build_dry_run_tx().await?;
 
         provider.dry_run(tx).await?.check(None)?;
 
@@ -465,6 +1087,8 @@ where
         &self,
         receipts: Vec<Receipt>,
     ) -> Result<CallResponse<T>> {
+        check_post_conditions(&self.post_conditions, &receipts)?;
+
         let mut receipt_parser = ReceiptParser::new(&receipts, self.decoder_config);
 
         let final_tokens = self
@@ -479,13 +1103,15 @@ where
             receipts,
             self.log_decoder.clone(),
             self.cached_tx_id,
-        );
+        )
+        .with_tx(self.cached_tx.clone());
 
         Ok(response)
     }
 
     /// Simulates the call and attempts to resolve missing contract outputs.
     /// Forwards the received error if it cannot be fixed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn determine_missing_contracts(mut self, max_attempts: Option<u64>) -> Result<Self> {
         let attempts = max_attempts.unwrap_or(10);
 
@@ -495,7 +1121,10 @@ where
 
                 Err(Error::Transaction(Reason::Reverted { ref receipts, .. })) => {
                     if let Some(contract_id) = find_id_of_missing_contract(receipts) {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(%contract_id, "adding missing contract");
                         self = self.append_external_contract(contract_id)?;
+                        metrics::increment_counter(metrics::ESTIMATION_RETRIES, &[], 1);
                     }
                 }
 
@@ -506,3 +1135,75 @@ where
         self.simulate_without_decode().await.map(|_| self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn return_receipt(val: u64) -> Receipt {
+        Receipt::Return {
+            id: Default::default(),
+            val,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    fn post_condition(f: impl Fn(&[Receipt]) -> bool + Send + Sync + 'static) -> PostCondition {
+        PostCondition(Arc::new(f))
+    }
+
+    #[test]
+    fn forwarded_assets_are_validated() {
+        let asset = |byte| AssetId::new([byte; 32]);
+
+        assert!(validate_forwarded_assets(&[(asset(1), 10), (asset(2), 20)]).is_ok());
+
+        for (assets, expected) in [
+            (vec![], "no assets to forward"),
+            (vec![(asset(1), 10), (asset(2), 0)], "is zero"),
+            (
+                vec![(asset(1), 10), (asset(1), 20)],
+                "is forwarded more than once",
+            ),
+        ] {
+            let err = validate_forwarded_assets(&assets).expect_err("should fail");
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn passing_post_conditions_are_ok() -> Result<()> {
+        let receipts = vec![return_receipt(1)];
+        let post_conditions = vec![
+            post_condition(|receipts| receipts.len() == 1),
+            post_condition(|receipts| matches!(receipts[0], Receipt::Return { val: 1, .. })),
+        ];
+
+        check_post_conditions(&post_conditions, &receipts)
+    }
+
+    #[test]
+    fn failed_post_condition_reports_index_and_receipts() {
+        // given
+        let receipts = vec![return_receipt(1)];
+        let post_conditions = vec![
+            post_condition(|receipts| receipts.len() == 1),
+            post_condition(|receipts| receipts.is_empty()),
+        ];
+
+        // when
+        let err = check_post_conditions(&post_conditions, &receipts).expect_err("should fail");
+
+        // then
+        let Error::Transaction(Reason::PostConditionFailed {
+            index,
+            receipts: err_receipts,
+        }) = err
+        else {
+            panic!("expected a failed post condition, got: {err:?}");
+        };
+        assert_eq!(index, 1);
+        assert_eq!(err_receipts, receipts);
+    }
+}
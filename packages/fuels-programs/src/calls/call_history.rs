@@ -0,0 +1,155 @@
+use fuel_core_client::client::pagination::{PaginatedResult, PaginationRequest};
+use fuel_tx::{AssetId, ContractId, TxId};
+use fuel_types::{BlockHeight, ChainId};
+use fuels_accounts::provider::Provider;
+use fuels_core::{
+    codec::ABIDecoder,
+    types::{
+        bech32::Bech32ContractId,
+        errors::Result,
+        param_types::ParamType,
+        transaction::{Transaction, TransactionType},
+        transaction_response::TransactionResponse,
+        tx_status::TxStatus,
+        Token,
+    },
+};
+
+use crate::calls::utils::decode_script_data_of_contract_calls;
+
+/// The name and the inputs of a contract method, as declared in its ABI. Contract bindings
+/// generated by `abigen!` list them in `function_signatures()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSignature {
+    pub name: &'static str,
+    pub inputs: Vec<(&'static str, ParamType)>,
+}
+
+/// A call to a contract made by a past transaction.
+#[derive(Debug, Clone)]
+pub struct HistoricalCall {
+    pub tx_id: TxId,
+    pub block_height: Option<BlockHeight>,
+    pub status: TxStatus,
+    pub function: String,
+    /// The arguments paired with their names from the ABI. `None` if the function isn't in the
+    /// ABI or its arguments don't decode as the ABI describes them.
+    pub args: Option<Vec<(String, Token)>>,
+    /// The amount of `asset_id` forwarded to the contract.
+    pub amount: u64,
+    pub asset_id: AssetId,
+}
+
+impl HistoricalCall {
+    pub fn succeeded(&self) -> bool {
+        matches!(self.status, TxStatus::Success { .. })
+    }
+}
+
+/// Fetches a page of the chain's transactions and returns the calls they made to `contract_id`,
+/// decoding their arguments according to `functions`.
+///
+/// Only scripts built by the SDK are recognized, the calls are reconstructed from their script
+/// data. The node can't filter transactions by the contracts they use, so a page may hold fewer
+/// calls than transactions requested, or none at all.
+pub async fn call_history(
+    provider: &Provider,
+    contract_id: &Bech32ContractId,
+    functions: &[FunctionSignature],
+    request: PaginationRequest<String>,
+) -> Result<PaginatedResult<HistoricalCall, String>> {
+    let page = provider.get_transactions(request).await?;
+    let chain_id = provider.fetch_consensus_parameters().await?.chain_id();
+    let contract_id = ContractId::from(contract_id);
+
+    let results = page
+        .results
+        .into_iter()
+        .flat_map(|response| calls_made_by(response, chain_id, &contract_id, functions))
+        .collect();
+
+    Ok(PaginatedResult {
+        cursor: page.cursor,
+        results,
+        has_next_page: page.has_next_page,
+        has_previous_page: page.has_previous_page,
+    })
+}
+
+fn calls_made_by(
+    response: TransactionResponse,
+    chain_id: ChainId,
+    contract_id: &ContractId,
+    functions: &[FunctionSignature],
+) -> Vec<HistoricalCall> {
+    let TransactionType::Script(tx) = &response.transaction else {
+        return vec![];
+    };
+    let Some(calls) = decode_script_data_of_contract_calls(tx.script_data()) else {
+        return vec![];
+    };
+    let tx_id = tx.id(chain_id);
+
+    calls
+        .into_iter()
+        .filter(|call| &call.contract_id == contract_id)
+        .map(|call| HistoricalCall {
+            tx_id,
+            block_height: response.block_height,
+            status: response.status.clone(),
+            args: decode_args(&call.function, &call.encoded_args, functions),
+            function: call.function,
+            amount: call.amount,
+            asset_id: call.asset_id,
+        })
+        .collect()
+}
+
+fn decode_args(
+    function: &str,
+    encoded_args: &[u8],
+    functions: &[FunctionSignature],
+) -> Option<Vec<(String, Token)>> {
+    let signature = functions
+        .iter()
+        .find(|signature| signature.name == function)?;
+    let (names, param_types): (Vec<_>, Vec<_>) = signature.inputs.iter().cloned().unzip();
+    let tokens = ABIDecoder::default()
+        .decode_multiple(&param_types, encoded_args)
+        .ok()?;
+
+    Some(
+        names
+            .into_iter()
+            .map(ToString::to_string)
+            .zip(tokens)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args_are_named_after_the_abi() {
+        // given
+        let functions = [FunctionSignature {
+            name: "transfer",
+            inputs: vec![("amount", ParamType::U64), ("burn", ParamType::Bool)],
+        }];
+        let encoded_args = [42u64.to_be_bytes().as_slice(), &[1]].concat();
+
+        // when
+        let args = decode_args("transfer", &encoded_args, &functions);
+        let unknown = decode_args("mint", &encoded_args, &functions);
+
+        // then
+        let expected = vec![
+            ("amount".to_string(), Token::U64(42)),
+            ("burn".to_string(), Token::Bool(true)),
+        ];
+        assert_eq!(args, Some(expected));
+        assert_eq!(unknown, None);
+    }
+}
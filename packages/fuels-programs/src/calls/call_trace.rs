@@ -0,0 +1,402 @@
+use std::fmt;
+
+use fuel_asm::PanicReason;
+use fuel_tx::{AssetId, ContractId, Receipt};
+use fuels_core::{
+    codec::AbiRegistry,
+    types::{
+        errors::{error, Result},
+        transaction::ScriptTransaction,
+        tx_status::TxStatus,
+        ReceiptsExt, Token, TransferReceipt,
+    },
+};
+
+use crate::calls::{
+    decoded_block::{decode_log, DecodedLog},
+    utils::{decode_script_data_of_contract_calls, DecodedCallData},
+};
+
+/// How a frame of a [`CallTrace`] ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameOutcome {
+    /// `value` is the decoded return value, if the function and its ABI are known.
+    Returned {
+        value: Option<Token>,
+    },
+    Reverted {
+        revert_id: u64,
+    },
+    Panicked {
+        reason: PanicReason,
+    },
+    /// Stopped by a frame it called that reverted or panicked.
+    Aborted,
+    /// No receipt tells how the frame ended.
+    Unfinished,
+}
+
+/// The script at the root of a [`CallTrace`], or a contract called within it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallFrame {
+    /// Zeroed for the script.
+    pub contract_id: ContractId,
+    /// Only known for the calls a script built by the SDK makes directly, see
+    /// [`CallTraceBuilder::with_script`]. Contracts calling each other don't leave the
+    /// functions they call in the receipts.
+    pub function: Option<String>,
+    /// The arguments paired with their names, if the function and its ABI are known.
+    pub args: Option<Vec<(String, Token)>>,
+    /// The amount of `asset_id` forwarded with the call.
+    pub amount: u64,
+    pub asset_id: AssetId,
+    /// The gas the caller made available to the frame, `None` for the script. The VM doesn't
+    /// report how much of it the frame used.
+    pub gas_forwarded: Option<u64>,
+    pub outcome: FrameOutcome,
+    /// The assets the frame transferred, in order.
+    pub transfers: Vec<TransferReceipt>,
+    /// The values the frame logged, in order.
+    pub logs: Vec<DecodedLog>,
+    /// The contracts the frame called, in order.
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    fn new(contract_id: ContractId) -> Self {
+        Self {
+            contract_id,
+            function: None,
+            args: None,
+            amount: 0,
+            asset_id: AssetId::zeroed(),
+            gas_forwarded: None,
+            outcome: FrameOutcome::Unfinished,
+            transfers: vec![],
+            logs: vec![],
+            calls: vec![],
+        }
+    }
+
+    /// This frame and the frames below it, depth first.
+    pub fn frames(&self) -> Vec<&CallFrame> {
+        std::iter::once(self)
+            .chain(self.calls.iter().flat_map(CallFrame::frames))
+            .collect()
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+
+        if depth == 0 {
+            write!(f, "{indent}script")?;
+        } else {
+            let function = self.function.as_deref().unwrap_or("<unknown>");
+            write!(f, "{indent}{}::{function}", self.contract_id)?;
+            if let Some(args) = &self.args {
+                let args = args
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}"))
+                    .collect::<Vec<_>>();
+                write!(f, "({})", args.join(", "))?;
+            }
+        }
+        match &self.outcome {
+            FrameOutcome::Returned { value: Some(value) } => writeln!(f, " -> {value}")?,
+            FrameOutcome::Returned { value: None } => writeln!(f)?,
+            FrameOutcome::Reverted { revert_id } => writeln!(f, " reverted with {revert_id}")?,
+            FrameOutcome::Panicked { reason } => writeln!(f, " panicked: {reason:?}")?,
+            FrameOutcome::Aborted => writeln!(f, " aborted")?,
+            FrameOutcome::Unfinished => writeln!(f, " unfinished")?,
+        }
+
+        for transfer in &self.transfers {
+            writeln!(
+                f,
+                "{indent}  transferred {} of {} to {:?}",
+                transfer.amount, transfer.asset_id, transfer.to
+            )?;
+        }
+        for log in &self.logs {
+            match &log.value {
+                Some(value) => writeln!(f, "{indent}  logged {value}")?,
+                None => writeln!(f, "{indent}  logged {:?}", log.data)?,
+            }
+        }
+        for call in &self.calls {
+            call.fmt_indented(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The tree of contract calls a transaction made, rebuilt from its receipts. Printing it shows
+/// one frame per line, indented by depth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallTrace {
+    pub root: CallFrame,
+    /// The gas used by the whole transaction, if its receipts tell.
+    pub gas_used: Option<u64>,
+}
+
+impl CallTrace {
+    /// Traces the receipts of an executed transaction.
+    pub fn builder(status: &TxStatus) -> CallTraceBuilder<'_> {
+        let receipts = match status {
+            TxStatus::Success { receipts } | TxStatus::Revert { receipts, .. } => receipts,
+            _ => &[][..],
+        };
+
+        CallTraceBuilder::from_receipts(receipts)
+    }
+}
+
+impl fmt::Display for CallTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.root.fmt_indented(f, 0)
+    }
+}
+
+/// Builds a [`CallTrace`], see [`CallTrace::builder`].
+#[derive(Debug, Clone)]
+pub struct CallTraceBuilder<'a> {
+    receipts: &'a [Receipt],
+    script_calls: Vec<DecodedCallData>,
+    abi_registry: Option<&'a AbiRegistry>,
+}
+
+impl<'a> CallTraceBuilder<'a> {
+    /// Traces `receipts`, e.g. the ones of a `CallResponse`.
+    pub fn from_receipts(receipts: &'a [Receipt]) -> Self {
+        Self {
+            receipts,
+            script_calls: vec![],
+            abi_registry: None,
+        }
+    }
+
+    /// Names the calls `tx` makes directly, if it was built by the SDK.
+    pub fn with_script(mut self, tx: &ScriptTransaction) -> Self {
+        self.script_calls =
+            decode_script_data_of_contract_calls(tx.script_data()).unwrap_or_default();
+        self
+    }
+
+    /// Decodes arguments, return values and logs with the ABIs registered in `abi_registry`. By
+    /// default, only the ABIs registered globally are used.
+    pub fn with_abi_registry(mut self, abi_registry: &'a AbiRegistry) -> Self {
+        self.abi_registry = Some(abi_registry);
+        self
+    }
+
+    pub fn build(self) -> Result<CallTrace> {
+        if self.receipts.is_empty() {
+            return Err(error!(Other, "no receipts to trace"));
+        }
+
+        let no_abis = AbiRegistry::default();
+        let abi_registry = self.abi_registry.unwrap_or(&no_abis);
+        let mut script_calls = self.script_calls.into_iter();
+        let mut stack = vec![CallFrame::new(ContractId::zeroed())];
+        let mut gas_used = None;
+
+        for receipt in self.receipts {
+            let current = stack.last_mut().expect("the script frame is never popped");
+
+            match receipt {
+                Receipt::Call {
+                    id,
+                    to,
+                    amount,
+                    asset_id,
+                    gas,
+                    ..
+                } => {
+                    expect_frame_of(current, id, receipt)?;
+
+                    let mut frame = CallFrame::new(*to);
+                    frame.amount = *amount;
+                    frame.asset_id = *asset_id;
+                    frame.gas_forwarded = Some(*gas);
+                    if stack.len() == 1 {
+                        if let Some(call) =
+                            script_calls.next().filter(|call| call.contract_id == *to)
+                        {
+                            frame.args = abi_registry
+                                .decode_call_args(to, &call.function, &call.encoded_args)
+                                .and_then(Result::ok);
+                            frame.function = Some(call.function);
+                        }
+                    }
+                    stack.push(frame);
+                }
+                Receipt::Return { id, val, .. } => {
+                    expect_frame_of(current, id, receipt)?;
+                    return_from(&mut stack, &val.to_be_bytes(), abi_registry);
+                }
+                Receipt::ReturnData { id, data, .. } => {
+                    expect_frame_of(current, id, receipt)?;
+                    return_from(
+                        &mut stack,
+                        data.as_deref().unwrap_or_default(),
+                        abi_registry,
+                    );
+                }
+                Receipt::Revert { id, ra, .. } => {
+                    expect_frame_of(current, id, receipt)?;
+                    current.outcome = FrameOutcome::Reverted { revert_id: *ra };
+                    unwind(&mut stack);
+                }
+                Receipt::Panic { id, reason, .. } => {
+                    expect_frame_of(current, id, receipt)?;
+                    current.outcome = FrameOutcome::Panicked {
+                        reason: *reason.reason(),
+                    };
+                    unwind(&mut stack);
+                }
+                Receipt::Transfer { .. } | Receipt::TransferOut { .. } => {
+                    current
+                        .transfers
+                        .extend(std::slice::from_ref(receipt).transfers());
+                }
+                Receipt::Log { .. } | Receipt::LogData { .. } => {
+                    current.logs.extend(decode_log(receipt, abi_registry));
+                }
+                Receipt::ScriptResult { gas_used: used, .. } => gas_used = Some(*used),
+                _ => {}
+            }
+        }
+
+        while stack.len() > 1 {
+            pop_into_caller(&mut stack);
+        }
+        let root = stack.pop().expect("the script frame is never popped");
+
+        Ok(CallTrace { root, gas_used })
+    }
+}
+
+fn expect_frame_of(frame: &CallFrame, id: &ContractId, receipt: &Receipt) -> Result<()> {
+    if &frame.contract_id != id {
+        return Err(error!(
+            Other,
+            "receipts don't form a call tree: expected a receipt of `{}`, got `{receipt:?}`",
+            frame.contract_id
+        ));
+    }
+
+    Ok(())
+}
+
+fn return_from(stack: &mut Vec<CallFrame>, data: &[u8], abi_registry: &AbiRegistry) {
+    let current = stack.last_mut().expect("the script frame is never popped");
+    let value = current.function.as_deref().and_then(|function| {
+        abi_registry
+            .decode_output(&current.contract_id, function, data)
+            .and_then(Result::ok)
+    });
+    current.outcome = FrameOutcome::Returned { value };
+
+    if stack.len() > 1 {
+        pop_into_caller(stack);
+    }
+}
+
+/// A revert or a panic ends the whole transaction, the frames that were running are aborted.
+fn unwind(stack: &mut [CallFrame]) {
+    for frame in stack.iter_mut() {
+        if frame.outcome == FrameOutcome::Unfinished {
+            frame.outcome = FrameOutcome::Aborted;
+        }
+    }
+}
+
+fn pop_into_caller(stack: &mut Vec<CallFrame>) {
+    let frame = stack.pop().expect("called with a frame above the script");
+    stack
+        .last_mut()
+        .expect("the script frame is never popped")
+        .calls
+        .push(frame);
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::ScriptExecutionResult;
+
+    use super::*;
+
+    fn call(from: ContractId, to: ContractId, gas: u64) -> Receipt {
+        Receipt::call(from, to, 0, AssetId::zeroed(), gas, 0, 0, 0, 0)
+    }
+
+    #[test]
+    fn nested_calls_are_rebuilt_into_a_tree() -> Result<()> {
+        // given
+        let script = ContractId::zeroed();
+        let [router, pool] = [1, 2].map(|byte| ContractId::new([byte; 32]));
+        let receipts = [
+            call(script, router, 1000),
+            call(router, pool, 500),
+            Receipt::transfer(pool, router, 10, AssetId::zeroed(), 0, 0),
+            Receipt::ret(pool, 1, 0, 0),
+            Receipt::ret(router, 2, 0, 0),
+            Receipt::ret(script, 0, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 1234),
+        ];
+
+        // when
+        let trace = CallTraceBuilder::from_receipts(&receipts).build()?;
+
+        // then
+        assert_eq!(trace.gas_used, Some(1234));
+        let router_frame = &trace.root.calls[0];
+        assert_eq!(router_frame.contract_id, router);
+        assert_eq!(router_frame.gas_forwarded, Some(1000));
+        let pool_frame = &router_frame.calls[0];
+        assert_eq!(pool_frame.contract_id, pool);
+        assert_eq!(pool_frame.transfers.len(), 1);
+        assert_eq!(pool_frame.outcome, FrameOutcome::Returned { value: None });
+        assert_eq!(trace.root.frames().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn frames_above_a_revert_are_aborted() -> Result<()> {
+        // given
+        let script = ContractId::zeroed();
+        let [router, pool] = [1, 2].map(|byte| ContractId::new([byte; 32]));
+        let receipts = [
+            call(script, router, 1000),
+            call(router, pool, 500),
+            Receipt::revert(pool, 42, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Revert, 1234),
+        ];
+
+        // when
+        let trace = CallTraceBuilder::from_receipts(&receipts).build()?;
+
+        // then
+        let router_frame = &trace.root.calls[0];
+        assert_eq!(trace.root.outcome, FrameOutcome::Aborted);
+        assert_eq!(router_frame.outcome, FrameOutcome::Aborted);
+        assert_eq!(
+            router_frame.calls[0].outcome,
+            FrameOutcome::Reverted { revert_id: 42 }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn receipts_out_of_order_are_rejected() {
+        let router = ContractId::new([1; 32]);
+        let receipts = [Receipt::ret(router, 0, 0, 0)];
+
+        let result = CallTraceBuilder::from_receipts(&receipts).build();
+
+        assert!(result.is_err());
+    }
+}
@@ -48,7 +48,8 @@ impl ContractCall {
 
 impl sealed::Sealed for ContractCall {}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct CallParameters {
     amount: u64,
     asset_id: Option<AssetId>,
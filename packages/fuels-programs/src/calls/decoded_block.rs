@@ -0,0 +1,241 @@
+use fuel_tx::{AssetId, Bytes32, ContractId, Receipt, TxId};
+use fuel_types::BlockHeight;
+use fuels_accounts::provider::Provider;
+use fuels_core::{
+    codec::AbiRegistry,
+    types::{
+        block::Header,
+        errors::{error, Result},
+        transaction::TransactionType,
+        tx_status::TxStatus,
+        Token,
+    },
+};
+
+use crate::calls::utils::decode_script_data_of_contract_calls;
+
+/// A block along with its transactions, decoded with the ABIs of an [`AbiRegistry`]. See
+/// [`BlockDecodingExt::block_with_decoded_txs`].
+#[derive(Debug, Clone)]
+pub struct DecodedBlock {
+    pub id: Bytes32,
+    pub header: Header,
+    /// In the order of the block.
+    pub transactions: Vec<DecodedTransaction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DecodedTransaction {
+    pub tx_id: TxId,
+    pub transaction: TransactionType,
+    pub status: TxStatus,
+    /// The contract calls made by the transaction. Only scripts built by the SDK are
+    /// recognized, the calls of other transactions are left out.
+    pub calls: Vec<DecodedCall>,
+    /// The values logged by contracts and scripts, in the order they were logged.
+    pub logs: Vec<DecodedLog>,
+}
+
+/// A call to a contract found in the script data of a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCall {
+    pub contract_id: ContractId,
+    pub function: String,
+    /// The arguments paired with their names from the ABI. `None` if no ABI is registered for
+    /// the contract, it doesn't have the function or the arguments don't decode as described.
+    pub args: Option<Vec<(String, Token)>>,
+    /// The amount of `asset_id` forwarded to the contract.
+    pub amount: u64,
+    pub asset_id: AssetId,
+}
+
+/// A value logged by a contract, or by a script if `contract_id` is zeroed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedLog {
+    pub contract_id: ContractId,
+    pub log_id: u64,
+    pub data: Vec<u8>,
+    /// `None` if no ABI is registered for the contract, it doesn't log this log id or the data
+    /// doesn't decode as described.
+    pub value: Option<Token>,
+}
+
+/// Decoding of blocks on top of what [`Provider`] offers, e.g. to build explorers.
+#[async_trait::async_trait]
+pub trait BlockDecodingExt {
+    /// Fetches the block at `height`, its transactions and their receipts, and decodes the
+    /// contract calls and logs of the contracts registered in `abi_registry`. `None` if there
+    /// is no block at `height` yet.
+    async fn block_with_decoded_txs(
+        &self,
+        height: u32,
+        abi_registry: &AbiRegistry,
+    ) -> Result<Option<DecodedBlock>>;
+}
+
+#[async_trait::async_trait]
+impl BlockDecodingExt for Provider {
+    async fn block_with_decoded_txs(
+        &self,
+        height: u32,
+        abi_registry: &AbiRegistry,
+    ) -> Result<Option<DecodedBlock>> {
+        let Some(block) = self.block_by_height(BlockHeight::from(height)).await? else {
+            return Ok(None);
+        };
+
+        let mut transactions = Vec::with_capacity(block.transactions.len());
+        for tx_id in &block.transactions {
+            let response = self
+                .get_transaction_by_id(tx_id)
+                .await?
+                .ok_or_else(|| error!(Provider, "transaction `{tx_id}` not found"))?;
+
+            transactions.push(decode_transaction(
+                *tx_id,
+                response.transaction,
+                response.status,
+                abi_registry,
+            ));
+        }
+
+        Ok(Some(DecodedBlock {
+            id: block.id,
+            header: block.header,
+            transactions,
+        }))
+    }
+}
+
+fn decode_transaction(
+    tx_id: TxId,
+    transaction: TransactionType,
+    status: TxStatus,
+    abi_registry: &AbiRegistry,
+) -> DecodedTransaction {
+    let calls = match &transaction {
+        TransactionType::Script(tx) => decode_script_data_of_contract_calls(tx.script_data())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|call| DecodedCall {
+                args: abi_registry
+                    .decode_call_args(&call.contract_id, &call.function, &call.encoded_args)
+                    .and_then(Result::ok),
+                contract_id: call.contract_id,
+                function: call.function,
+                amount: call.amount,
+                asset_id: call.asset_id,
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    let logs = match &status {
+        TxStatus::Success { receipts } | TxStatus::Revert { receipts, .. } => {
+            decode_logs(receipts, abi_registry)
+        }
+        _ => vec![],
+    };
+
+    DecodedTransaction {
+        tx_id,
+        transaction,
+        status,
+        calls,
+        logs,
+    }
+}
+
+fn decode_logs(receipts: &[Receipt], abi_registry: &AbiRegistry) -> Vec<DecodedLog> {
+    receipts
+        .iter()
+        .filter_map(|receipt| decode_log(receipt, abi_registry))
+        .collect()
+}
+
+/// `None` if `receipt` isn't a `Log` or `LogData` receipt.
+pub(crate) fn decode_log(receipt: &Receipt, abi_registry: &AbiRegistry) -> Option<DecodedLog> {
+    let (contract_id, log_id, data) = match receipt {
+        Receipt::LogData {
+            id,
+            rb,
+            data: Some(data),
+            ..
+        } => (*id, *rb, data.clone()),
+        Receipt::Log { id, ra, rb, .. } => (*id, *rb, ra.to_be_bytes().to_vec()),
+        _ => return None,
+    };
+
+    Some(DecodedLog {
+        value: abi_registry
+            .decode_log(&contract_id, log_id, &data)
+            .and_then(Result::ok),
+        contract_id,
+        log_id,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"{
+        "types": [{"typeId": 0, "type": "u64", "components": null, "typeParameters": null}],
+        "functions": [],
+        "loggedTypes": [
+            {"logId": "3", "loggedType": {"name": "", "type": 0, "typeArguments": null}}
+        ],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn logs_of_unregistered_contracts_are_kept_undecoded() -> Result<()> {
+        // given
+        let registered = ContractId::from([1; 32]);
+        let unregistered = ContractId::from([2; 32]);
+        let mut abi_registry = AbiRegistry::new();
+        abi_registry.register(registered, ABI)?;
+
+        let data = 9u64.to_be_bytes().to_vec();
+        let log_data = |contract_id| {
+            Receipt::log_data_with_len(
+                contract_id,
+                0,
+                3,
+                0,
+                data.len() as u64,
+                Default::default(),
+                0,
+                0,
+                Some(data.clone()),
+            )
+        };
+        let receipts = [log_data(registered), log_data(unregistered)];
+
+        // when
+        let logs = decode_logs(&receipts, &abi_registry);
+
+        // then
+        assert_eq!(
+            logs,
+            vec![
+                DecodedLog {
+                    contract_id: registered,
+                    log_id: 3,
+                    data: data.clone(),
+                    value: Some(Token::U64(9)),
+                },
+                DecodedLog {
+                    contract_id: unregistered,
+                    log_id: 3,
+                    data: data.clone(),
+                    value: None,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
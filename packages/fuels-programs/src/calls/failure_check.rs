@@ -0,0 +1,202 @@
+use std::fmt::Debug;
+
+use fuel_tx::{PanicReason, Receipt};
+use fuels_accounts::Account;
+use fuels_core::{
+    traits::{Parameterize, Tokenizable},
+    types::errors::{transaction::Reason, Error, Result},
+};
+
+use crate::calls::{
+    traits::{ContractDependencyConfigurator, ResponseParser, TransactionTuner},
+    CallHandler,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// The caller isn't allowed to make the call, e.g. it isn't the owner of the contract.
+    Permission,
+    /// The caller, or a contract it calls, lacks the assets the call needs.
+    Funds,
+    /// Any other revert of the contract.
+    Logic,
+}
+
+/// Why a dry run of a call failed. See [`CallHandler::check_call`].
+#[derive(Debug)]
+pub struct CallFailure {
+    pub kind: FailureKind,
+    pub error: Error,
+}
+
+/// Sorts failed calls into [`FailureKind`]s by looking at the error types the contract reverted
+/// with, as decoded from its ABI, e.g. the `NotOwner` variant of an `AccessError` enum.
+///
+/// Names are matched by the words they contain, ignoring case and underscores. The defaults
+/// cover the usual ownership and access control errors; add the names your contracts use with
+/// [`FailureClassifier::with_permission_marker`].
+#[derive(Debug, Clone)]
+pub struct FailureClassifier {
+    permission_markers: Vec<String>,
+    funds_markers: Vec<String>,
+}
+
+impl Default for FailureClassifier {
+    fn default() -> Self {
+        let markers = |markers: &[&str]| markers.iter().map(|marker| normalize(marker)).collect();
+
+        Self {
+            permission_markers: markers(&[
+                "unauthorized",
+                "notowner",
+                "onlyowner",
+                "ownership",
+                "accesserror",
+                "accessdenied",
+                "authorizationerror",
+                "permission",
+                "forbidden",
+                "notallowed",
+                "notadmin",
+            ]),
+            funds_markers: markers(&["insufficient", "notenough", "allowance", "balancetoolow"]),
+        }
+    }
+}
+
+impl FailureClassifier {
+    /// Classifies reverts whose reason contains `marker` as [`FailureKind::Permission`].
+    pub fn with_permission_marker(mut self, marker: &str) -> Self {
+        self.permission_markers.push(normalize(marker));
+        self
+    }
+
+    /// Classifies reverts whose reason contains `marker` as [`FailureKind::Funds`].
+    pub fn with_funds_marker(mut self, marker: &str) -> Self {
+        self.funds_markers.push(normalize(marker));
+        self
+    }
+
+    /// The kind of failure behind `error`, or `None` if it doesn't come from the call itself,
+    /// e.g. when the node can't be reached.
+    pub fn classify(&self, error: &Error) -> Option<FailureKind> {
+        match error {
+            Error::Transaction(Reason::Reverted {
+                reason, receipts, ..
+            }) => Some(self.classify_revert(reason, receipts)),
+            Error::Transaction(Reason::ScriptReturnedErr {
+                decoded, receipts, ..
+            }) => {
+                let error_type = decoded.error_type.as_deref().unwrap_or_default();
+                let reason = format!("{error_type}::{}", decoded.message);
+
+                Some(self.classify_revert(&reason, receipts))
+            }
+            // the account can't cover the amounts forwarded or the fee
+            Error::Provider(message) if is_lack_of_coins(message) => Some(FailureKind::Funds),
+            _ => None,
+        }
+    }
+
+    fn classify_revert(&self, reason: &str, receipts: &[Receipt]) -> FailureKind {
+        // a contract transferring more than it holds panics
+        let lacks_balance = receipts.iter().any(|receipt| match receipt {
+            Receipt::Panic { reason, .. } => *reason.reason() == PanicReason::NotEnoughBalance,
+            _ => false,
+        });
+        let reason = normalize(reason);
+        let contains_any =
+            |markers: &[String]| markers.iter().any(|marker| reason.contains(marker));
+
+        if contains_any(&self.permission_markers) {
+            FailureKind::Permission
+        } else if lacks_balance || contains_any(&self.funds_markers) {
+            FailureKind::Funds
+        } else {
+            FailureKind::Logic
+        }
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_lack_of_coins(message: &str) -> bool {
+    let message = message.to_lowercase();
+
+    message.contains("not enough coins") || message.contains("insufficient")
+}
+
+impl<A, C, T> CallHandler<A, C, T>
+where
+    A: Account,
+    C: ContractDependencyConfigurator + TransactionTuner + ResponseParser,
+    T: Tokenizable + Parameterize + Debug,
+{
+    /// Dry runs the call and, if it fails, tells whether the caller lacks permissions or funds,
+    /// or the contract reverted for another reason, e.g. to show "you are not the owner" before
+    /// the user signs anything. Returns `None` if the call would succeed.
+    ///
+    /// Fails with errors unrelated to the call, such as network errors.
+    pub async fn check_call(
+        &mut self,
+        classifier: &FailureClassifier,
+    ) -> Result<Option<CallFailure>> {
+        match self.simulate().await {
+            Ok(_) => Ok(None),
+            Err(error) => match classifier.classify(&error) {
+                Some(kind) => Ok(Some(CallFailure { kind, error })),
+                None => Err(error),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::{errors::transaction::DecodedRevert, Token};
+
+    use super::*;
+
+    fn revert(reason: &str) -> Error {
+        Error::Transaction(Reason::reverted(reason.to_string(), 0, vec![]))
+    }
+
+    #[test]
+    fn reverts_are_classified_by_their_error_types() {
+        let classifier = FailureClassifier::default().with_permission_marker("NOT_MINTER");
+
+        let kind = |reason: &str| classifier.classify(&revert(reason));
+
+        assert_eq!(kind("NotOwner"), Some(FailureKind::Permission));
+        assert_eq!(
+            kind("Unauthorized { sender: Address(0x00) }"),
+            Some(FailureKind::Permission)
+        );
+        assert_eq!(kind("NotMinter"), Some(FailureKind::Permission));
+        assert_eq!(kind("InsufficientBalance"), Some(FailureKind::Funds));
+        assert_eq!(kind("Expired"), Some(FailureKind::Logic));
+        assert_eq!(classifier.classify(&Error::Other("timeout".into())), None);
+    }
+
+    #[test]
+    fn script_errors_are_classified_like_reverts() {
+        let script_err = Error::Transaction(Reason::script_returned_err(
+            DecodedRevert {
+                contract_id: Default::default(),
+                error_type: Some("AccessError".to_string()),
+                message: "NotOwner".to_string(),
+            },
+            Token::Unit,
+            vec![],
+        ));
+
+        let kind = FailureClassifier::default().classify(&script_err);
+
+        assert_eq!(kind, Some(FailureKind::Permission));
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::Debug;
+
+use fuels_accounts::Account;
+use fuels_core::types::{
+    coin_type_id::CoinTypeId, errors::Result, transaction_builders::ScriptTransactionBuilder,
+};
+
+/// The account paying the fee of a call, see
+/// [`CallHandler::with_fee_payer`](crate::calls::CallHandler::with_fee_payer). Erases the type of
+/// the account so that the call keeps its own.
+#[async_trait::async_trait]
+pub(crate) trait FeePayer: Debug + Send + Sync {
+    /// Sponsors `tb`, without reserving the selected resources if it is only dry run. See
+    /// [`Account::for_dry_run`].
+    async fn sponsor(
+        &self,
+        tb: &mut ScriptTransactionBuilder,
+        used_base_amount: u64,
+        dry_run: bool,
+    ) -> Result<()>;
+
+    fn release_resources(&self, ids: &[CoinTypeId]);
+}
+
+#[async_trait::async_trait]
+impl<A: Account> FeePayer for A {
+    async fn sponsor(
+        &self,
+        tb: &mut ScriptTransactionBuilder,
+        used_base_amount: u64,
+        dry_run: bool,
+    ) -> Result<()> {
+        if dry_run {
+            Account::sponsor(&self.for_dry_run(), tb, used_base_amount).await
+        } else {
+            Account::sponsor(self, tb, used_base_amount).await
+        }
+    }
+
+    fn release_resources(&self, ids: &[CoinTypeId]) {
+        Account::release_resources(self, ids)
+    }
+}
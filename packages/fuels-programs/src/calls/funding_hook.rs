@@ -0,0 +1,104 @@
+use std::fmt::Debug;
+
+use fuel_tx::AssetId;
+use fuels_accounts::Account;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+    transaction::{ScriptTransaction, Transaction},
+};
+
+/// Acquires assets a call forwards but its account lacks, e.g. by swapping another asset through
+/// a DEX contract. Registered with
+/// [`CallHandler::with_funding_hook`](crate::calls::CallHandler::with_funding_hook).
+///
+/// Before a call is sent, the balance of its account is checked against the amounts it forwards.
+/// For every asset falling short, the hook builds a preparatory transaction, which is sent and
+/// committed before the call is built, so that the call spends the acquired coins.
+#[async_trait::async_trait]
+pub trait FundingHook: Debug + Send + Sync {
+    /// Returns a signed transaction leaving `shortfall.owner` with at least
+    /// [`shortfall.missing()`](FundingShortfall::missing) more of `shortfall.asset_id`, such as
+    /// the one returned by `build_tx` on a call to the swap method of a DEX.
+    async fn preparatory_tx(&self, shortfall: &FundingShortfall) -> Result<ScriptTransaction>;
+}
+
+/// An asset the account of a call doesn't hold enough of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundingShortfall {
+    pub owner: Bech32Address,
+    pub asset_id: AssetId,
+    /// The amount forwarded by the call.
+    pub required: u64,
+    pub available: u64,
+}
+
+impl FundingShortfall {
+    pub fn missing(&self) -> u64 {
+        self.required.saturating_sub(self.available)
+    }
+}
+
+/// Runs `hook` for every asset in `required` that `account` holds too little of.
+pub(crate) async fn acquire_missing_assets(
+    account: &impl Account,
+    required: Vec<(AssetId, u64)>,
+    hook: &dyn FundingHook,
+) -> Result<()> {
+    let provider = account.try_provider()?;
+
+    for (asset_id, required) in required {
+        let available = account.get_asset_balance(&asset_id).await?;
+        if available >= required {
+            continue;
+        }
+
+        let shortfall = FundingShortfall {
+            owner: account.address().clone(),
+            asset_id,
+            required,
+            available,
+        };
+        let tx = hook.preparatory_tx(&shortfall).await?;
+        let tx_id = tx.id(provider.fetch_consensus_parameters().await?.chain_id());
+        provider
+            .send_transaction_and_await_commit(tx)
+            .await?
+            .check(None)
+            .map_err(|err| {
+                error!(
+                    Other,
+                    "preparatory transaction acquiring asset `{asset_id}` failed: {err}"
+                )
+            })?;
+
+        let acquired = account.get_asset_balance(&asset_id).await?;
+        if acquired < required {
+            return Err(error!(
+                Other,
+                "preparatory transaction `{tx_id}` left {acquired} of asset `{asset_id}`, \
+                but the call forwards {required}"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_amount_never_underflows() {
+        let shortfall = |required, available| FundingShortfall {
+            owner: Bech32Address::default(),
+            asset_id: AssetId::zeroed(),
+            required,
+            available,
+        };
+
+        assert_eq!(shortfall(100, 30).missing(), 70);
+        assert_eq!(shortfall(30, 100).missing(), 0);
+    }
+}
@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, fmt::Debug};
+
+use fuels_accounts::Account;
+use fuels_core::{
+    traits::{Parameterize, Tokenizable},
+    types::errors::{error, Result},
+};
+
+use crate::calls::{
+    traits::{ContractDependencyConfigurator, ResponseParser, TransactionTuner},
+    CallHandler,
+};
+
+/// The gas used by each method of a program, collected by calling it with representative
+/// arguments. Its JSON form is meant to be committed next to the tests, so that CI can compare
+/// every run with it and catch gas regressions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasProfile {
+    gas_used: BTreeMap<String, u64>,
+}
+
+/// A method that used more gas than in the baseline profile, see [`GasProfile::regressions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasRegression {
+    pub method: String,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+impl GasProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Submits `call` and records the gas it used under `method`, replacing an earlier
+    /// measurement of the same method. Calls are submitted for real, so fixtures relying on the
+    /// state left by a previous call can be profiled in order.
+    pub async fn profile<A, C, T>(
+        &mut self,
+        method: impl Into<String>,
+        call: CallHandler<A, C, T>,
+    ) -> Result<u64>
+    where
+        A: Account,
+        C: ContractDependencyConfigurator + TransactionTuner + ResponseParser,
+        T: Tokenizable + Parameterize + Debug,
+    {
+        let gas_used = call.call().await?.gas_used;
+        self.record(method, gas_used);
+
+        Ok(gas_used)
+    }
+
+    pub fn record(&mut self, method: impl Into<String>, gas_used: u64) {
+        self.gas_used.insert(method.into(), gas_used);
+    }
+
+    pub fn gas_used(&self, method: &str) -> Option<u64> {
+        self.gas_used.get(method).copied()
+    }
+
+    /// The profiled methods with the gas they used, sorted by method name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.gas_used
+            .iter()
+            .map(|(method, gas_used)| (method.as_str(), *gas_used))
+    }
+
+    /// A JSON object mapping every method to the gas it used.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.gas_used).expect("a map of numbers is valid JSON")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let gas_used = serde_json::from_str(json)
+            .map_err(|err| error!(Other, "invalid gas profile: {err}"))?;
+
+        Ok(Self { gas_used })
+    }
+
+    /// Rust source declaring a `<METHOD>_GAS` constant for every method, to be checked in and
+    /// used as gas limits or in assertions.
+    pub fn to_rust_constants(&self) -> String {
+        self.iter()
+            .map(|(method, gas_used)| {
+                let name: String = method
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() {
+                            c.to_ascii_uppercase()
+                        } else {
+                            '_'
+                        }
+                    })
+                    .collect();
+
+                format!("pub const {name}_GAS: u64 = {gas_used};\n")
+            })
+            .collect()
+    }
+
+    /// The methods that used more than `tolerance` (a fraction, e.g. `0.05` for 5%) more gas than
+    /// in `baseline`. Methods missing from either profile are ignored.
+    pub fn regressions(&self, baseline: &GasProfile, tolerance: f64) -> Vec<GasRegression> {
+        self.iter()
+            .filter_map(|(method, current)| {
+                let baseline = baseline.gas_used(method)?;
+                let allowed = baseline as f64 * (1.0 + tolerance);
+
+                (current as f64 > allowed).then(|| GasRegression {
+                    method: method.to_string(),
+                    baseline,
+                    current,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regressions_above_the_tolerance_are_reported() -> Result<()> {
+        // given
+        let mut baseline = GasProfile::new();
+        baseline.record("increment", 1000);
+        baseline.record("reset", 500);
+        baseline.record("removed", 10);
+        let baseline = GasProfile::from_json(&baseline.to_json())?;
+
+        let mut current = GasProfile::new();
+        current.record("increment", 1040);
+        current.record("reset", 600);
+        current.record("added", 10);
+
+        // when
+        let regressions = current.regressions(&baseline, 0.05);
+
+        // then
+        assert_eq!(
+            regressions,
+            vec![GasRegression {
+                method: "reset".to_string(),
+                baseline: 500,
+                current: 600,
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rust_constants_are_named_after_the_methods() {
+        let mut profile = GasProfile::new();
+        profile.record("increment_counter", 1234);
+        profile.record("reset", 5);
+
+        assert_eq!(
+            profile.to_rust_constants(),
+            "pub const INCREMENT_COUNTER_GAS: u64 = 1234;\npub const RESET_GAS: u64 = 5;\n"
+        );
+    }
+}
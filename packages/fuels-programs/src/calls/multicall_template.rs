@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{ABIEncoder, EncoderConfig},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::{error, Result},
+        Token,
+    },
+};
+
+use crate::{
+    calls::{CallHandler, ContractCall},
+    responses::CallResponse,
+};
+
+/// Batches of calls to the same contract method that only differ in their arguments, e.g. the
+/// transfers of an airdrop.
+///
+/// The method, its contract and the settings of the call, such as call parameters and transaction
+/// policies, are taken once from a call handler. Each batch then only encodes the arguments of
+/// its calls, instead of going through the contract bindings for every one of them.
+#[derive(Debug, Clone)]
+pub struct MulticallTemplate<A, T> {
+    prototype: CallHandler<A, ContractCall, T>,
+    encoder: ABIEncoder,
+}
+
+impl<A, T> MulticallTemplate<A, T>
+where
+    A: Account,
+    T: Tokenizable + Parameterize + Debug,
+{
+    /// Uses the method and settings of `call_handler`. The arguments it was made with are
+    /// ignored.
+    pub fn new(call_handler: CallHandler<A, ContractCall, T>) -> Self {
+        Self {
+            prototype: call_handler,
+            encoder: ABIEncoder::default(),
+        }
+    }
+
+    pub fn with_encoder_config(mut self, encoder_config: EncoderConfig) -> Self {
+        self.encoder = ABIEncoder::new(encoder_config);
+        self
+    }
+
+    /// A multi call with one call per item of `args`. Methods taking more than one argument are
+    /// given a tuple of them per call.
+    pub fn with_args<Args: Tokenizable>(
+        &self,
+        args: impl IntoIterator<Item = Args>,
+    ) -> CallHandler<A, Vec<ContractCall>, ()> {
+        let calls = args
+            .into_iter()
+            .enumerate()
+            .map(|(i, args)| {
+                let mut call = self.prototype.call.clone();
+                call.encoded_args = self.encoder.encode(&[args.into_token()]);
+                // the custom assets are for the whole transaction, not per call
+                if i > 0 {
+                    call.custom_assets.clear();
+                }
+
+                call
+            })
+            .collect();
+
+        self.prototype.to_multi_call(calls)
+    }
+
+    /// Makes one call per item of `args` in a single transaction and returns their results in
+    /// the same order.
+    pub async fn call<Args: Tokenizable>(
+        &self,
+        args: impl IntoIterator<Item = Args>,
+    ) -> Result<CallResponse<Vec<T>>> {
+        let response = self.with_args(args).call::<Token>().await?;
+
+        Ok(CallResponse {
+            value: values_of(response.value)?,
+            receipts: response.receipts,
+            gas_used: response.gas_used,
+            log_decoder: response.log_decoder,
+            tx_id: response.tx_id,
+            tx: response.tx,
+        })
+    }
+}
+
+fn values_of<T: Tokenizable>(results: Token) -> Result<Vec<T>> {
+    match results {
+        Token::Tuple(tokens) => tokens.into_iter().map(T::from_token).collect(),
+        other => Err(error!(
+            Codec,
+            "expected the results of the calls as a tuple, got `{other:?}`"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_are_decoded_in_call_order() -> Result<()> {
+        let results = Token::Tuple(vec![Token::U64(1), Token::U64(2)]);
+
+        assert_eq!(values_of::<u64>(results)?, vec![1, 2]);
+        assert!(values_of::<u64>(Token::U64(1)).is_err());
+
+        Ok(())
+    }
+}
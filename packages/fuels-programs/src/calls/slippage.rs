@@ -0,0 +1,122 @@
+use std::fmt::Debug;
+
+use fuels_accounts::Account;
+use fuels_core::{
+    traits::{Parameterize, Tokenizable},
+    types::errors::{error, Result},
+};
+
+use crate::{
+    calls::{
+        traits::{ContractDependencyConfigurator, ResponseParser, TransactionTuner},
+        CallHandler,
+    },
+    responses::CallResponse,
+};
+
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// How far the output of a call may fall below its simulated value, in basis points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slippage {
+    basis_points: u64,
+}
+
+impl Slippage {
+    /// `50` is 0.5%. Fails for more than `10_000`, i.e. 100%.
+    pub fn from_basis_points(basis_points: u64) -> Result<Self> {
+        if basis_points > BPS_DENOMINATOR {
+            return Err(error!(
+                Other,
+                "slippage of {basis_points} basis points is above 100%"
+            ));
+        }
+
+        Ok(Self { basis_points })
+    }
+
+    pub fn basis_points(&self) -> u64 {
+        self.basis_points
+    }
+
+    /// The smallest acceptable output given the `expected` one, rounded down.
+    pub fn min_out(&self, expected: u64) -> u64 {
+        let min_out = u128::from(expected) * u128::from(BPS_DENOMINATOR - self.basis_points)
+            / u128::from(BPS_DENOMINATOR);
+
+        min_out as u64
+    }
+}
+
+/// The response of a call made with [`call_with_slippage`].
+#[derive(Debug)]
+pub struct SlippageCallResponse<T> {
+    pub response: CallResponse<T>,
+    /// The output the simulation produced.
+    pub expected_out: u64,
+    /// The guard the call was submitted with.
+    pub min_out: u64,
+}
+
+/// Simulates a call, then submits it guarded against the output dropping by more than `slippage`
+/// in between.
+///
+/// `make_call` builds the call given the minimum output the contract should enforce. It is first
+/// called with `0` for the simulation, then with the minimum derived from what `expected_out`
+/// extracts out of the simulated result.
+///
+/// ```ignore
+/// let swap = call_with_slippage(
+///     |min_out| amm.methods().swap(asset_in, amount_in, min_out),
+///     |amount_out| *amount_out,
+///     Slippage::from_basis_points(50)?,
+/// )
+/// .await?;
+/// ```
+pub async fn call_with_slippage<A, C, T, F, E>(
+    make_call: F,
+    expected_out: E,
+    slippage: Slippage,
+) -> Result<SlippageCallResponse<T>>
+where
+    A: Account,
+    C: ContractDependencyConfigurator + TransactionTuner + ResponseParser,
+    T: Tokenizable + Parameterize + Debug,
+    F: Fn(u64) -> CallHandler<A, C, T>,
+    E: FnOnce(&T) -> u64,
+{
+    let simulation = make_call(0).simulate().await?;
+    let expected_out = expected_out(&simulation.value);
+    let min_out = slippage.min_out(expected_out);
+
+    let response = make_call(min_out).call().await?;
+
+    Ok(SlippageCallResponse {
+        response,
+        expected_out,
+        min_out,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_out_is_rounded_down() -> Result<()> {
+        let slippage = Slippage::from_basis_points(50)?;
+
+        assert_eq!(slippage.min_out(10_000), 9_950);
+        assert_eq!(slippage.min_out(199), 198);
+        assert_eq!(slippage.min_out(u64::MAX), 18_354_510_353_341_003_856);
+        assert_eq!(Slippage::from_basis_points(0)?.min_out(7), 7);
+        assert_eq!(Slippage::from_basis_points(10_000)?.min_out(7), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn slippage_above_100_percent_is_rejected() {
+        assert!(Slippage::from_basis_points(10_001).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use fuel_tx::AssetId;
 use fuels_accounts::Account;
 use fuels_core::types::{
     errors::{error, Result},
@@ -5,10 +6,14 @@ use fuels_core::types::{
     transaction_builders::{
         BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder, VariableOutputPolicy,
     },
+    GasPriceStrategy,
 };
 
 use crate::calls::{
-    utils::{build_tx_from_contract_calls, sealed, transaction_builder_from_contract_calls},
+    utils::{
+        build_tx_from_contract_calls, calculate_required_asset_amounts, sealed,
+        transaction_builder_from_contract_calls,
+    },
     ContractCall, ScriptCall,
 };
 
@@ -18,6 +23,7 @@ pub trait TransactionTuner: sealed::Sealed {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransactionBuilder>;
 
@@ -25,8 +31,12 @@ pub trait TransactionTuner: sealed::Sealed {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransaction>;
+
+    /// The amount of each asset forwarded by the call.
+    fn required_assets(&self, base_asset_id: AssetId) -> Vec<(AssetId, u64)>;
 }
 
 #[async_trait::async_trait]
@@ -35,12 +45,14 @@ impl TransactionTuner for ContractCall {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransactionBuilder> {
         transaction_builder_from_contract_calls(
             std::slice::from_ref(self),
             tx_policies,
             variable_output_policy,
+            gas_price_strategy,
             account,
         )
         .await
@@ -50,16 +62,22 @@ impl TransactionTuner for ContractCall {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransaction> {
         build_tx_from_contract_calls(
             std::slice::from_ref(self),
             tx_policies,
             variable_output_policy,
+            gas_price_strategy,
             account,
         )
         .await
     }
+
+    fn required_assets(&self, base_asset_id: AssetId) -> Vec<(AssetId, u64)> {
+        calculate_required_asset_amounts(std::slice::from_ref(self), base_asset_id)
+    }
 }
 
 #[async_trait::async_trait]
@@ -68,28 +86,40 @@ impl TransactionTuner for ScriptCall {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         _account: &T,
     ) -> Result<ScriptTransactionBuilder> {
         let (inputs, outputs) = self.prepare_inputs_outputs()?;
 
-        Ok(ScriptTransactionBuilder::default()
+        let tb = ScriptTransactionBuilder::default()
             .with_variable_output_policy(variable_output_policy)
             .with_tx_policies(tx_policies)
             .with_script(self.script_binary.clone())
             .with_script_data(self.compute_script_data()?)
             .with_inputs(inputs)
             .with_outputs(outputs)
-            .with_gas_estimation_tolerance(0.05))
+            .with_gas_estimation_tolerance(0.05);
+
+        Ok(match gas_price_strategy {
+            Some(strategy) => tb.with_gas_price_strategy(strategy),
+            None => tb,
+        })
     }
 
     async fn build_tx<T: Account>(
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransaction> {
         let mut tb = self
-            .transaction_builder(tx_policies, variable_output_policy, account)
+            .transaction_builder(
+                tx_policies,
+                variable_output_policy,
+                gas_price_strategy,
+                account,
+            )
             .await?;
 
         account.add_witnesses(&mut tb)?;
@@ -97,6 +127,11 @@ impl TransactionTuner for ScriptCall {
 
         tb.build(account.try_provider()?).await
     }
+
+    /// Scripts spend the inputs they are given instead of forwarding assets.
+    fn required_assets(&self, _base_asset_id: AssetId) -> Vec<(AssetId, u64)> {
+        vec![]
+    }
 }
 
 impl sealed::Sealed for Vec<ContractCall> {}
@@ -107,12 +142,19 @@ impl TransactionTuner for Vec<ContractCall> {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransactionBuilder> {
         validate_contract_calls(self)?;
 
-        transaction_builder_from_contract_calls(self, tx_policies, variable_output_policy, account)
-            .await
+        transaction_builder_from_contract_calls(
+            self,
+            tx_policies,
+            variable_output_policy,
+            gas_price_strategy,
+            account,
+        )
+        .await
     }
 
     /// Returns the script that executes the contract calls
@@ -120,11 +162,23 @@ impl TransactionTuner for Vec<ContractCall> {
         &self,
         tx_policies: TxPolicies,
         variable_output_policy: VariableOutputPolicy,
+        gas_price_strategy: Option<GasPriceStrategy>,
         account: &T,
     ) -> Result<ScriptTransaction> {
         validate_contract_calls(self)?;
 
-        build_tx_from_contract_calls(self, tx_policies, variable_output_policy, account).await
+        build_tx_from_contract_calls(
+            self,
+            tx_policies,
+            variable_output_policy,
+            gas_price_strategy,
+            account,
+        )
+        .await
+    }
+
+    fn required_assets(&self, base_asset_id: AssetId) -> Vec<(AssetId, u64)> {
+        calculate_required_asset_amounts(self, base_asset_id)
     }
 }
 
@@ -19,6 +19,7 @@ use fuels_core::{
             BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
             VariableOutputPolicy,
         },
+        GasPriceStrategy,
     },
 };
 use itertools::{chain, Itertools};
@@ -44,18 +45,20 @@ pub(crate) async fn transaction_builder_from_contract_calls(
     calls: &[ContractCall],
     tx_policies: TxPolicies,
     variable_outputs: VariableOutputPolicy,
+    gas_price_strategy: Option<GasPriceStrategy>,
     account: &impl Account,
 ) -> Result<ScriptTransactionBuilder> {
     let calls_instructions_len = compute_calls_instructions_len(calls)?;
     let provider = account.try_provider()?;
-    let consensus_parameters = provider.consensus_parameters();
-    let data_offset = call_script_data_offset(consensus_parameters, calls_instructions_len)?;
+    let consensus_parameters = provider.fetch_consensus_parameters().await?;
+    let base_asset_id = *consensus_parameters.base_asset_id();
+    let data_offset = call_script_data_offset(&consensus_parameters, calls_instructions_len)?;
 
     let (script_data, call_param_offsets) =
-        build_script_data_from_contract_calls(calls, data_offset, *provider.base_asset_id())?;
+        build_script_data_from_contract_calls(calls, data_offset, base_asset_id)?;
     let script = get_instructions(calls, call_param_offsets)?;
 
-    let required_asset_amounts = calculate_required_asset_amounts(calls, *provider.base_asset_id());
+    let required_asset_amounts = calculate_required_asset_amounts(calls, base_asset_id);
 
     // Find the spendable resources required for those calls
     let mut asset_inputs = vec![];
@@ -66,21 +69,22 @@ pub(crate) async fn transaction_builder_from_contract_calls(
         asset_inputs.extend(resources);
     }
 
-    let (inputs, outputs) = get_transaction_inputs_outputs(
-        calls,
-        asset_inputs,
-        account.address(),
-        *provider.base_asset_id(),
-    );
+    let (inputs, outputs) =
+        get_transaction_inputs_outputs(calls, asset_inputs, account.address(), base_asset_id);
 
-    Ok(ScriptTransactionBuilder::default()
+    let tb = ScriptTransactionBuilder::default()
         .with_variable_output_policy(variable_outputs)
         .with_tx_policies(tx_policies)
         .with_script(script)
         .with_script_data(script_data.clone())
         .with_inputs(inputs)
         .with_outputs(outputs)
-        .with_gas_estimation_tolerance(0.05))
+        .with_gas_estimation_tolerance(0.05);
+
+    Ok(match gas_price_strategy {
+        Some(strategy) => tb.with_gas_price_strategy(strategy),
+        None => tb,
+    })
 }
 
 /// Creates a [`ScriptTransaction`] from contract calls. The internal [Transaction] is
@@ -90,19 +94,28 @@ pub(crate) async fn build_tx_from_contract_calls(
     calls: &[ContractCall],
     tx_policies: TxPolicies,
     variable_outputs: VariableOutputPolicy,
+    gas_price_strategy: Option<GasPriceStrategy>,
     account: &impl Account,
 ) -> Result<ScriptTransaction> {
-    let mut tb =
-        transaction_builder_from_contract_calls(calls, tx_policies, variable_outputs, account)
-            .await?;
+    let mut tb = transaction_builder_from_contract_calls(
+        calls,
+        tx_policies,
+        variable_outputs,
+        gas_price_strategy,
+        account,
+    )
+    .await?;
 
-    let base_asset_id = *account.try_provider()?.base_asset_id();
+    let base_asset_id = *account
+        .try_provider()?
+        .fetch_consensus_parameters()
+        .await?
+        .base_asset_id();
     let required_asset_amounts = calculate_required_asset_amounts(calls, base_asset_id);
 
-    let base_asset_id = account.try_provider()?.base_asset_id();
     let used_base_amount = required_asset_amounts
         .iter()
-        .find_map(|(asset_id, amount)| (asset_id == base_asset_id).then_some(*amount))
+        .find_map(|(asset_id, amount)| (*asset_id == base_asset_id).then_some(*amount))
         .unwrap_or_default();
 
     account.add_witnesses(&mut tb)?;
@@ -261,6 +274,83 @@ pub(crate) fn build_script_data_from_contract_calls(
     Ok((script_data, param_offsets))
 }
 
+/// A call found in the script data of a multi-call script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DecodedCallData {
+    pub amount: u64,
+    pub asset_id: AssetId,
+    pub contract_id: ContractId,
+    pub function: String,
+    /// The encoded arguments, possibly followed by the amount of gas forwarded.
+    pub encoded_args: Vec<u8>,
+}
+
+/// Reverses [`build_script_data_from_contract_calls`]. Returns `None` if `script_data` wasn't
+/// laid out by it, e.g. for scripts not built by the SDK.
+pub(crate) fn decode_script_data_of_contract_calls(
+    script_data: &[u8],
+) -> Option<Vec<DecodedCallData>> {
+    // amount, asset id, contract id, selector offset and calldata offset
+    const HEADER_LEN: usize = WORD_SIZE + AssetId::LEN + ContractId::LEN + 2 * WORD_SIZE;
+    const SELECTOR_OFFSET_AT: usize = HEADER_LEN - 2 * WORD_SIZE;
+
+    let read_word = |at: usize| -> Option<usize> {
+        let bytes = script_data.get(at..at + WORD_SIZE)?;
+        usize::try_from(Word::from_be_bytes(bytes.try_into().ok()?)).ok()
+    };
+    // the offsets are absolute, the script data starts where the first selector offset points to
+    // minus the header
+    let data_offset = read_word(SELECTOR_OFFSET_AT)?.checked_sub(HEADER_LEN)?;
+    let is_segment_start =
+        |at: usize| read_word(at + SELECTOR_OFFSET_AT) == Some(data_offset + at + HEADER_LEN);
+
+    let mut calls = vec![];
+    let mut segment_start = 0;
+    while segment_start < script_data.len() {
+        if !is_segment_start(segment_start) {
+            return None;
+        }
+
+        let amount = read_word(segment_start)? as u64;
+        let asset_id_at = segment_start + WORD_SIZE;
+        let asset_id =
+            AssetId::try_from(script_data.get(asset_id_at..asset_id_at + AssetId::LEN)?).ok()?;
+        let contract_id_at = asset_id_at + AssetId::LEN;
+        let contract_id = ContractId::try_from(
+            script_data.get(contract_id_at..contract_id_at + ContractId::LEN)?,
+        )
+        .ok()?;
+
+        let selector_at = segment_start + HEADER_LEN;
+        let name_len = read_word(selector_at)?;
+        let name_at = selector_at + WORD_SIZE;
+        let function = script_data.get(name_at..name_at.checked_add(name_len)?)?;
+        let function = String::from_utf8(function.to_vec()).ok()?;
+
+        let args_at =
+            read_word(segment_start + SELECTOR_OFFSET_AT + WORD_SIZE)?.checked_sub(data_offset)?;
+        if args_at != name_at + name_len {
+            return None;
+        }
+
+        // segments aren't length-prefixed, the next one starts where the offsets line up again
+        let next_segment_start = (args_at..script_data.len())
+            .find(|&at| is_segment_start(at))
+            .unwrap_or(script_data.len());
+
+        calls.push(DecodedCallData {
+            amount,
+            asset_id,
+            contract_id,
+            function,
+            encoded_args: script_data[args_at..next_segment_start].to_vec(),
+        });
+        segment_start = next_segment_start;
+    }
+
+    Some(calls)
+}
+
 /// Returns the VM instructions for calling a contract method
 /// We use the [`Opcode`] to call a contract: [`CALL`](Opcode::CALL)
 /// pointing at the following registers:
@@ -449,9 +539,12 @@ mod test {
     use std::slice;
 
     use fuels_accounts::wallet::WalletUnlocked;
-    use fuels_core::types::{
-        coin::{Coin, CoinStatus},
-        coin_type::CoinType,
+    use fuels_core::{
+        codec::encode_fn_selector,
+        types::{
+            coin::{Coin, CoinStatus},
+            coin_type::CoinType,
+        },
     };
     use rand::Rng;
 
@@ -475,6 +568,51 @@ mod test {
         Bech32ContractId::new("fuel", rand::thread_rng().gen::<[u8; 32]>())
     }
 
+    #[test]
+    fn script_data_of_contract_calls_is_decoded() -> Result<()> {
+        // given
+        let asset_id = AssetId::new([1; 32]);
+        let calls = [
+            ContractCall {
+                encoded_selector: encode_fn_selector("transfer"),
+                encoded_args: Ok(vec![7; 16]),
+                call_parameters: CallParameters::new(100, asset_id, 5_000),
+                ..new_contract_call_with_random_id()
+            },
+            ContractCall {
+                encoded_selector: encode_fn_selector("total"),
+                ..new_contract_call_with_random_id()
+            },
+        ];
+        let (script_data, _) =
+            build_script_data_from_contract_calls(&calls, 10_360, AssetId::zeroed())?;
+
+        // when
+        let decoded = decode_script_data_of_contract_calls(&script_data);
+
+        // then
+        let expected = vec![
+            DecodedCallData {
+                amount: 100,
+                asset_id,
+                contract_id: calls[0].contract_id.clone().into(),
+                function: "transfer".to_string(),
+                encoded_args: [vec![7; 16], 5_000u64.to_be_bytes().to_vec()].concat(),
+            },
+            DecodedCallData {
+                amount: 0,
+                asset_id: AssetId::zeroed(),
+                contract_id: calls[1].contract_id.clone().into(),
+                function: "total".to_string(),
+                encoded_args: vec![],
+            },
+        ];
+        assert_eq!(decoded, Some(expected));
+        assert_eq!(decode_script_data_of_contract_calls(&[0; 100]), None);
+
+        Ok(())
+    }
+
     #[test]
     fn contract_input_present() {
         let call = new_contract_call_with_random_id();
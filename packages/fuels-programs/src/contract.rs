@@ -1,5 +1,10 @@
+mod deployment;
+mod events;
 mod load;
+mod log_subscription;
+mod proxy;
 mod storage;
+mod verification;
 
 use std::{
     fmt::Debug,
@@ -7,16 +12,24 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use fuel_tx::{Bytes32, Contract as FuelContract, ContractId, Salt, StorageSlot};
+pub use deployment::*;
+pub use events::*;
+use fuel_tx::{Bytes32, ContractId, Salt, StorageSlot};
 use fuels_accounts::Account;
-use fuels_core::types::{
-    bech32::Bech32ContractId,
-    errors::{error, Result},
-    transaction::TxPolicies,
-    transaction_builders::CreateTransactionBuilder,
+use fuels_core::{
+    contract_id,
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        transaction::{CreateTransaction, TxPolicies},
+        transaction_builders::CreateTransactionBuilder,
+    },
 };
 pub use load::*;
+pub use log_subscription::*;
+pub use proxy::*;
 pub use storage::*;
+pub use verification::*;
 
 /// [`Contract`] is a struct to interface with a contract. That includes things such as
 /// compiling, deploying, and running transactions against a contract.
@@ -50,11 +63,10 @@ impl Contract {
         salt: &Salt,
         storage_slots: &[StorageSlot],
     ) -> (ContractId, Bytes32, Bytes32) {
-        let fuel_contract = FuelContract::from(binary);
-        let code_root = fuel_contract.root();
-        let state_root = FuelContract::initial_state_root(storage_slots.iter());
+        let code_root = contract_id::code_root(binary);
+        let state_root = contract_id::state_root(storage_slots);
 
-        let contract_id = fuel_contract.id(salt, &code_root, &state_root);
+        let contract_id = contract_id::contract_id_from_roots(salt, &code_root, &state_root);
 
         (contract_id, code_root, state_root)
     }
@@ -67,6 +79,51 @@ impl Contract {
         self.contract_id
     }
 
+    /// The id [`Contract::deploy`] will return. It only depends on the bytecode, the salt and the
+    /// storage slots, so it is known before the contract is deployed.
+    pub fn precompute_id(&self) -> Bech32ContractId {
+        self.contract_id.into()
+    }
+
+    /// Looks for a salt giving this contract an id that starts with `desired_prefix`, trying at
+    /// most `max_attempts` salts. Deploy with the salt through [`Contract::with_salt`].
+    ///
+    /// The salts are tried in the same order every time, so the same contract and prefix always
+    /// lead to the same salt. Every byte of the prefix makes a match 256 times rarer.
+    pub fn salt_for_target_id(&self, desired_prefix: &[u8], max_attempts: u64) -> Result<Salt> {
+        if desired_prefix.len() > ContractId::LEN {
+            return Err(error!(
+                Other,
+                "a contract id has {} bytes, the prefix has {}",
+                ContractId::LEN,
+                desired_prefix.len()
+            ));
+        }
+
+        (0..max_attempts)
+            .map(|attempt| {
+                let mut salt = [0; Salt::LEN];
+                salt[Salt::LEN - 8..].copy_from_slice(&attempt.to_be_bytes());
+
+                Salt::from(salt)
+            })
+            .find(|salt| {
+                contract_id::contract_id_from_roots(salt, &self.code_root, &self.state_root)
+                    .starts_with(desired_prefix)
+            })
+            .ok_or_else(|| {
+                let prefix = desired_prefix
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>();
+
+                error!(
+                    Other,
+                    "none of the first {max_attempts} salts gives a contract id starting with `0x{prefix}`"
+                )
+            })
+    }
+
     pub fn state_root(&self) -> Bytes32 {
         self.state_root
     }
@@ -75,6 +132,11 @@ impl Contract {
         self.code_root
     }
 
+    /// The storage the contract starts with once deployed.
+    pub fn storage_slots(&self) -> &[StorageSlot] {
+        &self.storage_slots
+    }
+
     /// Deploys a compiled contract to a running node
     /// To deploy a contract, you need an account with enough assets to pay for deployment.
     /// This account will also receive the change.
@@ -83,6 +145,24 @@ impl Contract {
         account: &impl Account,
         tx_policies: TxPolicies,
     ) -> Result<Bech32ContractId> {
+        let contract_id = self.contract_id;
+        let tx = self.build_deployment_tx(account, tx_policies).await?;
+
+        account.record_spending(&tx)?;
+        let tx_status = account
+            .try_provider()?
+            .send_transaction_and_await_commit(tx)
+            .await?;
+        tx_status.check(None)?;
+
+        Ok(contract_id.into())
+    }
+
+    async fn build_deployment_tx(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+    ) -> Result<CreateTransaction> {
         let mut tb = CreateTransactionBuilder::prepare_contract_deployment(
             self.binary,
             self.contract_id,
@@ -95,16 +175,7 @@ impl Contract {
         account.add_witnesses(&mut tb)?;
         account.adjust_for_fee(&mut tb, 0).await?;
 
-        let provider = account.try_provider()?;
-
-        let tx = tb.build(provider).await?;
-
-        provider
-            .send_transaction_and_await_commit(tx)
-            .await?
-            .check(None)?;
-
-        Ok(self.contract_id.into())
+        tb.build(account.try_provider()?).await
     }
 
     pub fn load_from(binary_filepath: impl AsRef<Path>, config: LoadConfiguration) -> Result<Self> {
@@ -167,6 +238,24 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn salt_gives_an_id_with_the_prefix() -> Result<()> {
+        // given
+        let contract = Contract::new(vec![0x24, 0x04, 0x00, 0x00], Salt::zeroed(), vec![]);
+        let prefix = [0xab];
+
+        // when
+        let salt = contract.salt_for_target_id(&prefix, 10_000)?;
+
+        // then
+        let contract = contract.with_salt(salt);
+        assert!(contract.contract_id().starts_with(&prefix));
+        assert_eq!(contract.salt_for_target_id(&prefix, 10_000)?, salt);
+        assert!(contract.salt_for_target_id(&[0xab; 8], 10).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn autoload_storage_slots() {
         // given
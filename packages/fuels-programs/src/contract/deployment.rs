@@ -0,0 +1,183 @@
+use std::{future::Future, time::Duration};
+
+use fuel_tx::TxId;
+use fuels_accounts::{provider::Provider, Account};
+use fuels_core::types::{
+    bech32::Bech32ContractId,
+    errors::{error, Error, Result},
+    transaction::TxPolicies,
+};
+
+use crate::contract::Contract;
+
+/// How long each phase of [`Contract::deploy_if_not_exists`] may take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployDeadlines {
+    existence_check: Duration,
+    deployment: Duration,
+}
+
+impl Default for DeployDeadlines {
+    fn default() -> Self {
+        Self {
+            existence_check: Duration::from_secs(10),
+            deployment: Duration::from_secs(60),
+        }
+    }
+}
+
+impl DeployDeadlines {
+    /// Limits each lookup of the contract on chain.
+    pub fn with_existence_check(mut self, timeout: Duration) -> Self {
+        self.existence_check = timeout;
+        self
+    }
+
+    /// Limits building, submitting and awaiting the commit of the deployment transaction.
+    pub fn with_deployment(mut self, timeout: Duration) -> Self {
+        self.deployment = timeout;
+        self
+    }
+}
+
+/// What [`Contract::deploy_if_not_exists`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeployResponse {
+    /// The contract was already on chain, deployed by someone else or by an earlier run.
+    AlreadyDeployed { contract_id: Bech32ContractId },
+    /// The contract was deployed by the transaction `tx_id`.
+    NewlyDeployed {
+        contract_id: Bech32ContractId,
+        tx_id: TxId,
+    },
+}
+
+impl DeployResponse {
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        match self {
+            Self::AlreadyDeployed { contract_id } | Self::NewlyDeployed { contract_id, .. } => {
+                contract_id
+            }
+        }
+    }
+
+    pub fn tx_id(&self) -> Option<TxId> {
+        match self {
+            Self::AlreadyDeployed { .. } => None,
+            Self::NewlyDeployed { tx_id, .. } => Some(*tx_id),
+        }
+    }
+}
+
+impl Contract {
+    /// Deploys the contract unless it is already on chain, e.g. when deployment scripts are
+    /// rerun. Each phase is bounded by `deadlines`.
+    ///
+    /// Two deployers can both find the contract missing and race to deploy it. The node then
+    /// rejects the later transaction, in which case the contract is looked up again and reported
+    /// as [`DeployResponse::AlreadyDeployed`] instead of failing.
+    pub async fn deploy_if_not_exists(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+        deadlines: DeployDeadlines,
+    ) -> Result<DeployResponse> {
+        let provider = account.try_provider()?;
+        let contract_id = Bech32ContractId::from(self.contract_id());
+
+        if is_deployed(provider, &contract_id, deadlines.existence_check).await? {
+            return Ok(DeployResponse::AlreadyDeployed { contract_id });
+        }
+
+        let deployment = async {
+            let tx = self.build_deployment_tx(account, tx_policies).await?;
+            account.record_spending(&tx)?;
+            let tx_id = provider.send_transaction(tx).await?;
+
+            provider
+                .await_final_tx_status(&tx_id, deadlines.deployment)
+                .await?
+                .check(None)?;
+
+            Ok(tx_id)
+        };
+
+        match within(deadlines.deployment, "deploying the contract", deployment).await {
+            Ok(tx_id) => Ok(DeployResponse::NewlyDeployed { contract_id, tx_id }),
+            Err(err)
+                if is_deployment_race(&err)
+                    && is_deployed(provider, &contract_id, deadlines.existence_check).await? =>
+            {
+                Ok(DeployResponse::AlreadyDeployed { contract_id })
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+async fn is_deployed(
+    provider: &Provider,
+    contract_id: &Bech32ContractId,
+    timeout: Duration,
+) -> Result<bool> {
+    let bytecode = within(
+        timeout,
+        "checking whether the contract exists",
+        provider.contract_bytecode(contract_id),
+    )
+    .await?;
+
+    Ok(bytecode.is_some())
+}
+
+async fn within<T>(
+    timeout: Duration,
+    phase: &str,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_| error!(Provider, "{phase} took longer than {timeout:?}"))?
+}
+
+/// Whether the node rejected the deployment because the contract id is already taken.
+fn is_deployment_race(err: &Error) -> bool {
+    let message = err.to_string().to_lowercase();
+
+    message.contains("contract")
+        && ["already taken", "already deployed", "already exists"]
+            .iter()
+            .any(|marker| message.contains(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taken_contract_ids_are_told_apart_from_other_errors() {
+        let taken = error!(
+            Provider,
+            "Contract with id 0x0101 is already taken by another transaction"
+        );
+        let out_of_funds = error!(Provider, "not enough coins to fit the target");
+
+        assert!(is_deployment_race(&taken));
+        assert!(!is_deployment_race(&out_of_funds));
+    }
+
+    #[tokio::test]
+    async fn slow_phases_time_out() {
+        let result = within(
+            Duration::from_millis(10),
+            "deploying the contract",
+            std::future::pending::<Result<()>>(),
+        )
+        .await;
+
+        let err = result.expect_err("should time out");
+        assert!(err
+            .to_string()
+            .contains("deploying the contract took longer"));
+    }
+}
@@ -0,0 +1,182 @@
+use std::{fmt, marker::PhantomData, sync::Arc};
+
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
+use fuel_tx::{Receipt, TxId};
+use fuels_accounts::provider::Provider;
+use fuels_core::{
+    codec::{LogDecoder, LoggedEvents},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        tx_status::TxStatus,
+    },
+};
+
+const BLOCKS_PER_PAGE: i32 = 100;
+
+/// An event found by [`ContractEvents::fetch`], along with where it was logged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoricalEvent<E> {
+    pub event: E,
+    pub tx_id: TxId,
+    pub block_height: u32,
+}
+
+type EventFilter<E> = dyn Fn(&E) -> bool + Send + Sync;
+
+/// The events logged by a contract, decoded into `E`, usually the `Events` enum `abigen!`
+/// generates for it. Logs of other contracts are skipped.
+pub struct ContractEvents<E> {
+    contract_id: Bech32ContractId,
+    log_decoder: LogDecoder,
+    filter: Option<Arc<EventFilter<E>>>,
+    provider: Option<Provider>,
+    from_block: u32,
+    to_block: Option<u32>,
+    _events: PhantomData<E>,
+}
+
+impl<E> Clone for ContractEvents<E> {
+    fn clone(&self) -> Self {
+        Self {
+            contract_id: self.contract_id.clone(),
+            log_decoder: self.log_decoder.clone(),
+            filter: self.filter.clone(),
+            provider: self.provider.clone(),
+            from_block: self.from_block,
+            to_block: self.to_block,
+            _events: PhantomData,
+        }
+    }
+}
+
+impl<E> fmt::Debug for ContractEvents<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContractEvents")
+            .field("contract_id", &self.contract_id)
+            .field("filtered", &self.filter.is_some())
+            .field("from_block", &self.from_block)
+            .field("to_block", &self.to_block)
+            .finish()
+    }
+}
+
+impl<E: LoggedEvents> ContractEvents<E> {
+    /// `log_decoder` is expected to only know the logs of `contract_id`.
+    pub fn new(contract_id: Bech32ContractId, log_decoder: LogDecoder) -> Self {
+        Self {
+            contract_id,
+            log_decoder,
+            filter: None,
+            provider: None,
+            from_block: 0,
+            to_block: None,
+            _events: PhantomData,
+        }
+    }
+
+    /// The node [`Self::fetch`] reads past events from.
+    pub fn with_provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Makes [`Self::fetch`] start at the block at `height`, the genesis block by default.
+    pub fn from_block(mut self, height: u32) -> Self {
+        self.from_block = height;
+        self
+    }
+
+    /// Makes [`Self::fetch`] stop after the block at `height`, the latest block by default.
+    pub fn to_block(mut self, height: u32) -> Self {
+        self.to_block = Some(height);
+        self
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    /// Only keeps the events for which `filter` returns `true`, e.g.
+    /// `|event| matches!(event, MyContractEvents::Transfer(_))`.
+    pub fn matching(mut self, filter: impl Fn(&E) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// The events the contract logged in `receipts`, in the order they were logged.
+    pub fn decode(&self, receipts: &[Receipt]) -> Result<Vec<E>> {
+        let mut events = self.log_decoder.decode_logs_as::<E>(receipts)?;
+        if let Some(filter) = &self.filter {
+            events.retain(|event| filter(event));
+        }
+
+        Ok(events)
+    }
+
+    /// Goes through the blocks in the range set with [`Self::from_block`] and
+    /// [`Self::to_block`] and returns the events the contract logged in their successful
+    /// transactions, oldest first.
+    ///
+    /// The node can't filter transactions by the contracts they use, so the receipts of every
+    /// transaction in the range are fetched. Keep the range short, or remember the last height
+    /// scanned and continue from there.
+    pub async fn fetch(&self) -> Result<Vec<HistoricalEvent<E>>> {
+        let provider = self.provider.as_ref().ok_or_else(|| {
+            error!(
+                Other,
+                "no provider to fetch the events of `{}` from", self.contract_id
+            )
+        })?;
+        let to_block = match self.to_block {
+            Some(height) => height,
+            None => provider.latest_block_height().await?,
+        };
+
+        let mut events = vec![];
+        // pages start after the block whose height is the cursor
+        let mut cursor = self
+            .from_block
+            .checked_sub(1)
+            .map(|height| height.to_string());
+        loop {
+            let page = provider
+                .get_blocks(PaginationRequest {
+                    cursor,
+                    results: BLOCKS_PER_PAGE,
+                    direction: PageDirection::Forward,
+                })
+                .await?;
+
+            let blocks = page
+                .results
+                .iter()
+                .filter(|block| (self.from_block..=to_block).contains(&block.header.height));
+            for block in blocks {
+                for tx_id in &block.transactions {
+                    // reverted transactions don't keep their logs
+                    let TxStatus::Success { receipts } = provider.tx_status(tx_id).await? else {
+                        continue;
+                    };
+
+                    events.extend(self.decode(&receipts)?.into_iter().map(|event| {
+                        HistoricalEvent {
+                            event,
+                            tx_id: *tx_id,
+                            block_height: block.header.height,
+                        }
+                    }));
+                }
+            }
+
+            let reached_end = page
+                .results
+                .last()
+                .map_or(true, |block| block.header.height >= to_block);
+            if reached_end || !page.has_next_page {
+                return Ok(events);
+            }
+            cursor = page.cursor;
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use fuel_tx::{Bytes32, TxId};
+use fuel_types::BlockHeight;
+use fuels_accounts::provider::Provider;
+use fuels_core::{
+    codec::LogDecoder,
+    traits::{Parameterize, Tokenizable},
+    types::{errors::Result, tx_status::TxStatus},
+};
+use futures::{stream::BoxStream, Stream, StreamExt};
+
+// deeper reorgs are reported from the oldest block still remembered
+const MAX_TRACKED_BLOCKS: usize = 64;
+
+/// A block whose logs a [`LogSubscription`] has gone through. Store the cursor of the latest
+/// update and pass it to [`LogSubscription::start_after`] to pick up where a subscription left
+/// off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogCursor {
+    pub block_height: u32,
+    pub block_id: Bytes32,
+}
+
+/// A value logged by the contract in a new block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscribedLog<T> {
+    pub value: T,
+    pub tx_id: TxId,
+    /// The block the value was logged in.
+    pub cursor: LogCursor,
+}
+
+/// What a [`LogSubscription`] yields.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogUpdate<T> {
+    Logged(SubscribedLog<T>),
+    /// The blocks from `from_height` on were replaced. The logs delivered for them no longer
+    /// hold, and the logs of the blocks that replaced them follow.
+    Reorged {
+        from_height: u32,
+    },
+}
+
+impl<T> LogUpdate<T> {
+    pub fn logged(self) -> Option<SubscribedLog<T>> {
+        match self {
+            Self::Logged(log) => Some(log),
+            Self::Reorged { .. } => None,
+        }
+    }
+}
+
+/// A [`Stream`] of the values of type `T` a contract logs in new blocks, made by the
+/// `subscribe_logs` method of the contract bindings.
+///
+/// The node can't push new blocks yet, so the subscription checks for them every
+/// [poll interval](Self::with_poll_interval). Before moving on, it checks that the blocks it went
+/// through are still part of the chain, and yields [`LogUpdate::Reorged`] when they were
+/// replaced. Only the logs of successful transactions are yielded.
+///
+/// Errors reaching the node are yielded as they happen, and the subscription carries on from
+/// where it was on the next poll.
+pub struct LogSubscription<T> {
+    provider: Provider,
+    log_decoder: LogDecoder,
+    start: Start,
+    poll_interval: Duration,
+    stream: Option<BoxStream<'static, Result<LogUpdate<T>>>>,
+}
+
+impl<T> fmt::Debug for LogSubscription<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogSubscription")
+            .field("start", &self.start)
+            .field("poll_interval", &self.poll_interval)
+            .field("started", &self.stream.is_some())
+            .finish()
+    }
+}
+
+impl<T> LogSubscription<T>
+where
+    T: Tokenizable + Parameterize + Send + Sync + 'static,
+{
+    /// `log_decoder` is expected to only know the logs of the contract to follow.
+    pub fn new(provider: Provider, log_decoder: LogDecoder) -> Self {
+        Self {
+            provider,
+            log_decoder,
+            start: Start::AfterLatest,
+            poll_interval: Duration::from_secs(1),
+            stream: None,
+        }
+    }
+
+    /// Starts with the block after `cursor`. By default, the subscription starts after the block
+    /// that is the latest when it is first polled.
+    pub fn start_after(mut self, cursor: LogCursor) -> Self {
+        self.start = Start::After(cursor);
+        self
+    }
+
+    /// Starts with the block at `height`, e.g. one past the latest block before sending the
+    /// transactions whose logs are awaited.
+    pub fn from_block(mut self, height: u32) -> Self {
+        self.start = Start::AtHeight(height);
+        self
+    }
+
+    /// How long to wait before checking for new blocks again, one second by default.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    fn follow(&self) -> BoxStream<'static, Result<LogUpdate<T>>> {
+        let follower = Follower {
+            provider: self.provider.clone(),
+            log_decoder: self.log_decoder.clone(),
+            poll_interval: self.poll_interval,
+            tracked: match self.start {
+                Start::After(cursor) => VecDeque::from([cursor]),
+                _ => VecDeque::new(),
+            },
+            next_height: match self.start {
+                Start::AfterLatest => None,
+                Start::After(cursor) => Some(cursor.block_height + 1),
+                Start::AtHeight(height) => Some(height),
+            },
+            pending: VecDeque::new(),
+            failed: false,
+            _logs: PhantomData,
+        };
+
+        futures::stream::unfold(follower, |mut follower| async move {
+            loop {
+                if let Some(update) = follower.pending.pop_front() {
+                    return Some((Ok(update), follower));
+                }
+
+                if follower.failed {
+                    tokio::time::sleep(follower.poll_interval).await;
+                }
+                let result = follower.advance().await;
+                follower.failed = result.is_err();
+                if let Err(err) = result {
+                    return Some((Err(err), follower));
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+impl<T> Unpin for LogSubscription<T> {}
+
+impl<T> Stream for LogSubscription<T>
+where
+    T: Tokenizable + Parameterize + Send + Sync + 'static,
+{
+    type Item = Result<LogUpdate<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.stream.is_none() {
+            self.stream = Some(self.follow());
+        }
+
+        self.stream
+            .as_mut()
+            .expect("stream was just started")
+            .poll_next_unpin(cx)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Start {
+    AfterLatest,
+    After(LogCursor),
+    AtHeight(u32),
+}
+
+struct Follower<T> {
+    provider: Provider,
+    log_decoder: LogDecoder,
+    poll_interval: Duration,
+    /// The latest blocks gone through, oldest first.
+    tracked: VecDeque<LogCursor>,
+    /// `None` until the latest block at the start of the subscription is known.
+    next_height: Option<u32>,
+    pending: VecDeque<LogUpdate<T>>,
+    failed: bool,
+    _logs: PhantomData<fn() -> T>,
+}
+
+impl<T: Tokenizable + Parameterize + 'static> Follower<T> {
+    /// Queues the updates of the next block, or waits for one to be produced.
+    async fn advance(&mut self) -> Result<()> {
+        let next_height = match self.next_height {
+            Some(height) => height,
+            None => {
+                let latest = self.latest_cursor().await?;
+                self.tracked.push_back(latest);
+                self.next_height = Some(latest.block_height + 1);
+
+                latest.block_height + 1
+            }
+        };
+
+        if let Some(from_height) = self.unwind_replaced_blocks().await? {
+            self.pending.push_back(LogUpdate::Reorged { from_height });
+            self.next_height = Some(from_height);
+            return Ok(());
+        }
+
+        let Some(block) = self
+            .provider
+            .block_by_height(BlockHeight::from(next_height))
+            .await?
+        else {
+            tokio::time::sleep(self.poll_interval).await;
+            return Ok(());
+        };
+
+        let cursor = LogCursor {
+            block_height: block.header.height,
+            block_id: block.id,
+        };
+        // queued once the whole block went through, so that a failure doesn't repeat logs
+        let mut updates = vec![];
+        for tx_id in &block.transactions {
+            // reverted transactions don't keep their logs
+            let TxStatus::Success { receipts } = self.provider.tx_status(tx_id).await? else {
+                continue;
+            };
+
+            let values = self.log_decoder.decode_logs_with_type::<T>(&receipts)?;
+            updates.extend(values.into_iter().map(|value| {
+                LogUpdate::Logged(SubscribedLog {
+                    value,
+                    tx_id: *tx_id,
+                    cursor,
+                })
+            }));
+        }
+
+        self.pending.extend(updates);
+        self.tracked.push_back(cursor);
+        if self.tracked.len() > MAX_TRACKED_BLOCKS {
+            self.tracked.pop_front();
+        }
+        self.next_height = Some(next_height + 1);
+
+        Ok(())
+    }
+
+    async fn latest_cursor(&self) -> Result<LogCursor> {
+        let latest = self.provider.chain_info().await?.latest_block;
+
+        Ok(LogCursor {
+            block_height: latest.header.height,
+            block_id: latest.id,
+        })
+    }
+
+    /// Drops the tracked blocks that are no longer part of the chain and returns the height of
+    /// the first one, if any.
+    async fn unwind_replaced_blocks(&mut self) -> Result<Option<u32>> {
+        let mut first_replaced = None;
+        while let Some(cursor) = self.tracked.back().copied() {
+            if self.is_canonical(&cursor).await? {
+                break;
+            }
+
+            first_replaced = Some(cursor.block_height);
+            self.tracked.pop_back();
+        }
+
+        Ok(first_replaced)
+    }
+
+    async fn is_canonical(&self, cursor: &LogCursor) -> Result<bool> {
+        let block = self
+            .provider
+            .block_by_height(BlockHeight::from(cursor.block_height))
+            .await?;
+
+        Ok(block.is_some_and(|block| block.id == cursor.block_id))
+    }
+}
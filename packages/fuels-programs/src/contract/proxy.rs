@@ -0,0 +1,336 @@
+use std::fmt::Debug;
+
+use fuel_tx::ContractId;
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{encode_fn_selector, EncoderConfig, LogDecoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        transaction::TxPolicies,
+        Token,
+    },
+};
+
+use crate::{
+    calls::{CallHandler, ContractCall, ContractDependency},
+    contract::Contract,
+};
+
+/// Implemented by the contracts generated by `abigen!` so that [`Proxied`] can add the
+/// implementation of a proxy to every call made through them.
+pub trait ProxyCompatible: ContractDependency {
+    type Methods;
+    type Account: Account;
+
+    /// The contract methods, with `contract_ids` added as dependencies of every call.
+    fn methods_with_contract_ids(&self, contract_ids: &[Bech32ContractId]) -> Self::Methods;
+
+    /// The account the bindings call with, used to ask the proxy for its target.
+    fn account(&self) -> Self::Account;
+}
+
+/// Bindings of a contract called through a proxy.
+///
+/// A proxy forwards the calls it doesn't know to its implementation, which must then be an
+/// input of the transaction. `Proxied` adds it to every call made through
+/// [`Proxied::methods`]. Calling `with_contract_ids` on such a call replaces its dependencies,
+/// so include [`Proxied::implementation_id`] when adding others.
+#[derive(Debug, Clone)]
+pub struct Proxied<C> {
+    contract: C,
+    implementation_id: Bech32ContractId,
+}
+
+impl<C: ProxyCompatible> Proxied<C> {
+    /// `contract` holds the bindings of the implementation, created with the id of the proxy.
+    pub fn new(contract: C, implementation_id: impl Into<Bech32ContractId>) -> Self {
+        Self {
+            contract,
+            implementation_id: implementation_id.into(),
+        }
+    }
+
+    /// Like [`Proxied::new`], asking the proxy for its implementation with the SRC-14
+    /// `proxy_target` method.
+    pub async fn from_target(contract: C) -> Result<Self> {
+        let implementation_id = read_target(&contract.account(), &contract.id()).await?;
+
+        Ok(Self::new(contract, implementation_id))
+    }
+
+    pub fn proxy_id(&self) -> Bech32ContractId {
+        self.contract.id()
+    }
+
+    pub fn implementation_id(&self) -> &Bech32ContractId {
+        &self.implementation_id
+    }
+
+    pub fn contract(&self) -> &C {
+        &self.contract
+    }
+
+    pub fn methods(&self) -> C::Methods {
+        self.contract
+            .methods_with_contract_ids(std::slice::from_ref(&self.implementation_id))
+    }
+
+    /// Asks the proxy for its target again, e.g. after it was upgraded, and returns the new
+    /// implementation id.
+    pub async fn refresh_target(&mut self) -> Result<&Bech32ContractId> {
+        self.implementation_id = read_target(&self.contract.account(), &self.contract.id()).await?;
+
+        Ok(&self.implementation_id)
+    }
+}
+
+/// Settings of the proxy deployed by [`Contract::deploy_as_proxied`].
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    proxy: Contract,
+    initializer: Option<String>,
+    tx_policies: TxPolicies,
+}
+
+impl ProxyConfig {
+    /// `proxy` is an SRC-14 proxy, e.g. the owned proxy of the Sway standards that `forc deploy`
+    /// uses, loaded with its configurables such as its initial owner.
+    pub fn new(proxy: Contract) -> Self {
+        Self {
+            proxy,
+            initializer: None,
+            tx_policies: TxPolicies::default(),
+        }
+    }
+
+    /// A method of the proxy to call without arguments once it is deployed, e.g.
+    /// `initialize_proxy` to have the owned proxy take its owner from its configurables.
+    pub fn with_initializer(mut self, method: impl Into<String>) -> Self {
+        self.initializer = Some(method.into());
+        self
+    }
+
+    /// The policies of every transaction of the deployment.
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+}
+
+/// The contracts deployed by [`Contract::deploy_as_proxied`]. Call the implementation through
+/// the proxy with [`Proxied`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxiedDeployment {
+    pub proxy_id: Bech32ContractId,
+    pub implementation_id: Bech32ContractId,
+}
+
+/// A deployed SRC-14 proxy, managed through the methods of the standard.
+#[derive(Debug, Clone)]
+pub struct ProxyContract<A> {
+    proxy_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account> ProxyContract<A> {
+    pub fn new(proxy_id: impl Into<Bech32ContractId>, account: A) -> Self {
+        Self {
+            proxy_id: proxy_id.into(),
+            account,
+        }
+    }
+
+    pub fn id(&self) -> &Bech32ContractId {
+        &self.proxy_id
+    }
+
+    /// The implementation the proxy currently forwards to.
+    pub async fn target(&self) -> Result<Bech32ContractId> {
+        read_target(&self.account, &self.proxy_id).await
+    }
+
+    /// Points the proxy at `implementation_id` with `set_proxy_target`, which only the owner of
+    /// the proxy may call.
+    pub async fn set_target(
+        &self,
+        implementation_id: &Bech32ContractId,
+        tx_policies: TxPolicies,
+    ) -> Result<()> {
+        let target = ContractId::from(implementation_id).into_token();
+
+        self.method_call::<()>("set_proxy_target", &[target], &[])
+            .with_tx_policies(tx_policies)
+            .call()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deploys `new_contract` and points the proxy at it, rolling back to the previous
+    /// implementation if the migration or the verification of `config` fails.
+    ///
+    /// The new contract runs with the storage of the proxy, so the storage it declares isn't
+    /// there unless the previous implementation wrote it or the migration writes it.
+    pub async fn upgrade_to(
+        &self,
+        new_contract: Contract,
+        config: UpgradeConfig,
+    ) -> Result<ProxyUpgrade> {
+        let tx_policies = config.tx_policies;
+        let previous_implementation_id = self.target().await?;
+
+        let implementation_id = new_contract.deploy(&self.account, tx_policies).await?;
+        self.set_target(&implementation_id, tx_policies).await?;
+
+        if let Err(error) = self.finish_upgrade(&implementation_id, &config).await {
+            if let Err(rollback_error) = self
+                .set_target(&previous_implementation_id, tx_policies)
+                .await
+            {
+                return Err(error!(
+                    Other,
+                    "upgrade to `{implementation_id}` failed: {error}, and rolling back to `{previous_implementation_id}` failed too: {rollback_error}"
+                ));
+            }
+
+            return Err(error!(
+                Other,
+                "upgrade to `{implementation_id}` rolled back to `{previous_implementation_id}`: {error}"
+            ));
+        }
+
+        Ok(ProxyUpgrade {
+            previous_implementation_id,
+            implementation_id,
+        })
+    }
+
+    async fn finish_upgrade(
+        &self,
+        implementation_id: &Bech32ContractId,
+        config: &UpgradeConfig,
+    ) -> Result<()> {
+        let contract_ids = std::slice::from_ref(implementation_id);
+
+        if let Some(migration) = &config.migration {
+            self.method_call::<()>(migration, &[], contract_ids)
+                .with_tx_policies(config.tx_policies)
+                .call()
+                .await?;
+        }
+        if let Some(verification) = &config.verification {
+            self.method_call::<()>(verification, &[], contract_ids)
+                .simulate()
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // a call of a method taking `args`, made through the proxy and returning a `T`
+    fn method_call<T: Tokenizable + Parameterize + Debug>(
+        &self,
+        name: &str,
+        args: &[Token],
+        contract_ids: &[Bech32ContractId],
+    ) -> CallHandler<A, ContractCall, T> {
+        CallHandler::new_contract_call(
+            self.proxy_id.clone(),
+            self.account.clone(),
+            encode_fn_selector(name),
+            args,
+            LogDecoder::default(),
+            false,
+            EncoderConfig::default(),
+        )
+        .with_contract_ids(contract_ids)
+    }
+}
+
+/// How [`ProxyContract::upgrade_to`] completes an upgrade.
+#[derive(Debug, Clone, Default)]
+pub struct UpgradeConfig {
+    tx_policies: TxPolicies,
+    migration: Option<String>,
+    verification: Option<String>,
+}
+
+impl UpgradeConfig {
+    /// The policies of every transaction of the upgrade.
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+
+    /// A method of the new contract, taking no arguments, called through the proxy once it
+    /// points at the new contract, e.g. to write the storage the new contract adds.
+    pub fn with_migration(mut self, method: impl Into<String>) -> Self {
+        self.migration = Some(method.into());
+        self
+    }
+
+    /// A method of the new contract, taking no arguments, dry run through the proxy at the end
+    /// of the upgrade. The upgrade is rolled back if it fails.
+    pub fn with_verification(mut self, method: impl Into<String>) -> Self {
+        self.verification = Some(method.into());
+        self
+    }
+}
+
+/// The outcome of [`ProxyContract::upgrade_to`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyUpgrade {
+    pub previous_implementation_id: Bech32ContractId,
+    pub implementation_id: Bech32ContractId,
+}
+
+impl Contract {
+    /// Deploys the contract behind a new SRC-14 proxy, the way `forc deploy` does for
+    /// upgradeable contracts: deploys the contract, then the proxy of `proxy_config`, calls its
+    /// initializer if any, and points it at the contract.
+    ///
+    /// `account` pays for the deployment and must be allowed to set the target of the proxy,
+    /// e.g. by being its initial owner.
+    pub async fn deploy_as_proxied(
+        self,
+        account: &impl Account,
+        proxy_config: ProxyConfig,
+    ) -> Result<ProxiedDeployment> {
+        let tx_policies = proxy_config.tx_policies;
+
+        let implementation_id = self.deploy(account, tx_policies).await?;
+        let proxy_id = proxy_config.proxy.deploy(account, tx_policies).await?;
+
+        let proxy = ProxyContract::new(proxy_id.clone(), account.clone());
+        if let Some(initializer) = &proxy_config.initializer {
+            proxy
+                .method_call::<()>(initializer, &[], &[])
+                .with_tx_policies(tx_policies)
+                .call()
+                .await?;
+        }
+        proxy.set_target(&implementation_id, tx_policies).await?;
+
+        Ok(ProxiedDeployment {
+            proxy_id,
+            implementation_id,
+        })
+    }
+}
+
+async fn read_target<A: Account>(
+    account: &A,
+    proxy_id: &Bech32ContractId,
+) -> Result<Bech32ContractId> {
+    let target: Option<ContractId> = ProxyContract::new(proxy_id.clone(), account.clone())
+        .method_call("proxy_target", &[], &[])
+        .simulate()
+        .await?
+        .value;
+
+    target
+        .map(Into::into)
+        .ok_or_else(|| error!(Other, "proxy `{proxy_id}` has no SRC-14 target set"))
+}
@@ -1,7 +1,8 @@
-use std::{collections::HashMap, default::Default, fmt::Debug, io, path::Path};
+use std::{collections::HashMap, default::Default, fmt::Debug, io, path::Path, str::FromStr};
 
 use fuel_tx::{Bytes32, StorageSlot};
 use fuels_core::types::errors::{error, Result};
+use serde_json::Value;
 
 /// Configuration for contract storage
 #[derive(Debug, Clone)]
@@ -98,7 +99,7 @@ impl StorageSlots {
             )
         })?;
 
-        let decoded_slots = serde_json::from_str::<Vec<StorageSlot>>(&storage_json_string)?;
+        let decoded_slots = StorageSlotsDecoder::new().decode(&storage_json_string)?;
 
         Ok(StorageSlots::from(decoded_slots))
     }
@@ -108,6 +109,58 @@ impl StorageSlots {
     }
 }
 
+/// Decodes the `-storage_slots.json` files emitted by `forc`: a list of
+/// `{"key": "<hex>", "value": "<hex>"}` objects, the `Serialize` output of `fuel_tx::StorageSlot`.
+/// Hex strings are accepted with or without the `0x` prefix. The layout isn't versioned, so any
+/// other layout is rejected rather than detected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StorageSlotsDecoder;
+
+impl StorageSlotsDecoder {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn decode(&self, json: &str) -> Result<Vec<StorageSlot>> {
+        match parse_json(json)? {
+            Value::Array(slots) => decode_list(&slots),
+            _ => Err(error!(
+                Codec,
+                "expected storage slots to be a list of `key` and `value` objects"
+            )),
+        }
+    }
+}
+
+fn parse_json(json: &str) -> Result<Value> {
+    serde_json::from_str(json).map_err(|e| error!(Codec, "invalid storage slots JSON: {e}"))
+}
+
+fn decode_list(slots: &[Value]) -> Result<Vec<StorageSlot>> {
+    slots
+        .iter()
+        .map(|slot| {
+            let field = |name: &str| {
+                slot.get(name).and_then(Value::as_str).ok_or_else(|| {
+                    error!(Codec, "expected storage slot `{slot}` to have a `{name}`")
+                })
+            };
+
+            Ok(StorageSlot::new(
+                parse_hex(field("key")?)?,
+                parse_hex(field("value")?)?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_hex(hex: &str) -> Result<Bytes32> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+
+    Bytes32::from_str(hex).map_err(|e| error!(Codec, "invalid storage slot hex `{hex}`: {e}"))
+}
+
 pub(crate) fn validate_path_and_extension(file_path: &Path, extension: &str) -> Result<()> {
     if !file_path.exists() {
         return Err(error!(IO, "file {file_path:?} does not exist"));
@@ -152,4 +205,32 @@ mod tests {
             HashSet::from([make_slot(1, 100), make_slot(2, 200), make_slot(3, 200)])
         );
     }
+
+    #[test]
+    fn storage_slots_are_decoded_with_or_without_hex_prefix() -> Result<()> {
+        let key = format!("{}", Bytes32::from([1; 32]));
+        let value = format!("0x{}", Bytes32::from([2; 32]));
+        let json = format!(r#"[{{"key": "{key}", "value": "{value}"}}]"#);
+
+        let slots = StorageSlotsDecoder::new().decode(&json)?;
+
+        assert_eq!(
+            slots,
+            vec![StorageSlot::new([1; 32].into(), [2; 32].into())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_layouts_are_rejected() {
+        let err = StorageSlotsDecoder::new()
+            .decode(r#"{"storage_slots": []}"#)
+            .expect_err("should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: expected storage slots to be a list of `key` and `value` objects"
+        );
+    }
 }
@@ -0,0 +1,107 @@
+use std::ops::Range;
+
+use fuels_accounts::provider::Provider;
+use fuels_core::types::{
+    bech32::Bech32ContractId,
+    errors::{error, Result},
+};
+
+use crate::contract::Contract;
+
+/// How the bytecode deployed on chain differs from a local one. See
+/// [`Contract::verify_deployed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BytecodeDiff {
+    pub local_len: usize,
+    pub deployed_len: usize,
+    /// The byte ranges that differ. When one bytecode is longer than the other, its extra bytes
+    /// make up the last range.
+    pub differing_ranges: Vec<Range<usize>>,
+}
+
+impl BytecodeDiff {
+    pub fn new(local: &[u8], deployed: &[u8]) -> Self {
+        let mut differing_ranges: Vec<Range<usize>> = vec![];
+
+        for (offset, _) in local
+            .iter()
+            .zip(deployed)
+            .enumerate()
+            .filter(|(_, (local, deployed))| local != deployed)
+        {
+            match differing_ranges.last_mut() {
+                Some(range) if range.end == offset => range.end += 1,
+                _ => differing_ranges.push(offset..offset + 1),
+            }
+        }
+
+        let common_len = local.len().min(deployed.len());
+        let max_len = local.len().max(deployed.len());
+        if common_len < max_len {
+            match differing_ranges.last_mut() {
+                Some(range) if range.end == common_len => range.end = max_len,
+                _ => differing_ranges.push(common_len..max_len),
+            }
+        }
+
+        Self {
+            local_len: local.len(),
+            deployed_len: deployed.len(),
+            differing_ranges,
+        }
+    }
+
+    /// Whether the deployed bytecode is the local one.
+    pub fn matches(&self) -> bool {
+        self.differing_ranges.is_empty()
+    }
+
+    pub fn differing_bytes(&self) -> usize {
+        self.differing_ranges
+            .iter()
+            .map(ExactSizeIterator::len)
+            .sum()
+    }
+}
+
+impl Contract {
+    /// Fetches the bytecode deployed at `contract_id` and compares it with the binary of this
+    /// contract, configurables included, e.g. to confirm that an audited build is the one that
+    /// was deployed. Fails if no contract is deployed at `contract_id`.
+    ///
+    /// The salt and the storage slots aren't compared: the contract id already commits to them,
+    /// see [`Contract::precompute_id`].
+    pub async fn verify_deployed(
+        &self,
+        provider: &Provider,
+        contract_id: &Bech32ContractId,
+    ) -> Result<BytecodeDiff> {
+        let deployed = provider
+            .contract_bytecode(contract_id)
+            .await?
+            .ok_or_else(|| error!(Other, "no contract is deployed at `{contract_id}`"))?;
+
+        Ok(BytecodeDiff::new(&self.binary, &deployed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn differences_are_grouped_into_ranges() {
+        // given
+        let local = [0, 1, 2, 3, 4, 5, 6];
+        let deployed = [0, 9, 9, 3, 4, 9];
+
+        // when
+        let diff = BytecodeDiff::new(&local, &deployed);
+
+        // then
+        assert_eq!(diff.differing_ranges, vec![1..3, 5..7]);
+        assert_eq!(diff.differing_bytes(), 4);
+        assert!(!diff.matches());
+        assert!(BytecodeDiff::new(&local, &local).matches());
+    }
+}
@@ -1,10 +1,17 @@
 use std::fmt::Debug;
 
-use fuel_tx::{Bytes32, Receipt};
+use fuel_tx::{Bytes32, ContractId, Receipt, Transaction as FuelTransaction};
+use fuel_types::canonical::Serialize;
 use fuels_core::{
-    codec::{LogDecoder, LogResult},
+    codec::{short_type_name, LogDecoder, LogResult, LoggedEvents},
     traits::{Parameterize, Tokenizable},
-    types::errors::Result,
+    types::{
+        errors::{
+            transaction::{DecodedRevert, Reason},
+            Error, Result,
+        },
+        transaction::ScriptTransaction,
+    },
 };
 
 /// [`CallResponse`] is a struct that is returned by a call to the contract or script. Its value
@@ -18,6 +25,8 @@ pub struct CallResponse<D> {
     pub gas_used: u64,
     pub log_decoder: LogDecoder,
     pub tx_id: Option<Bytes32>,
+    /// The signed transaction, if requested with `with_tx_in_response`.
+    pub tx: Option<ScriptTransaction>,
 }
 // ANCHOR_END: call_response
 
@@ -44,9 +53,24 @@ impl<D> CallResponse<D> {
             receipts,
             log_decoder,
             tx_id,
+            tx: None,
         }
     }
 
+    pub fn with_tx(mut self, tx: Option<ScriptTransaction>) -> Self {
+        self.tx = tx;
+        self
+    }
+
+    /// The canonical encoding of [`Self::tx`], i.e. the bytes that were sent to the node.
+    pub fn tx_bytes(&self) -> Option<Vec<u8>> {
+        self.tx
+            .clone()
+            .map(|tx| FuelTransaction::from(tx).to_bytes())
+    }
+
+    /// Decodes all logs as strings. The logs of contracts the bindings don't know are decoded
+    /// with the `AbiRegistry` of the call, or the global one, and formatted as tokens.
     pub fn decode_logs(&self) -> LogResult {
         self.log_decoder.decode_logs(&self.receipts)
     }
@@ -54,4 +78,52 @@ impl<D> CallResponse<D> {
     pub fn decode_logs_with_type<T: Tokenizable + Parameterize + 'static>(&self) -> Result<Vec<T>> {
         self.log_decoder.decode_logs_with_type::<T>(&self.receipts)
     }
+
+    /// Decodes all logs into one type, e.g. the `Events` enum `abigen!` generates for a program.
+    pub fn decode_logs_as<E: LoggedEvents>(&self) -> Result<Vec<E>> {
+        self.log_decoder.decode_logs_as::<E>(&self.receipts)
+    }
+}
+
+impl<T, E> CallResponse<std::result::Result<T, E>>
+where
+    E: Tokenizable + Parameterize + Debug + 'static,
+{
+    /// Unwraps the `Ok` value of a script whose `main` returns a `Result`, or fails with
+    /// [`Reason::ScriptReturnedErr`]. The `Err` value is recovered with `Error::script_err`,
+    /// and, like the error enums of contracts, described by `Error::decoded_revert`.
+    pub fn ok_or_script_err(self) -> Result<CallResponse<T>> {
+        let Self {
+            value,
+            receipts,
+            gas_used,
+            log_decoder,
+            tx_id,
+            tx,
+        } = self;
+
+        match value {
+            Ok(value) => Ok(CallResponse {
+                value,
+                receipts,
+                gas_used,
+                log_decoder,
+                tx_id,
+                tx,
+            }),
+            Err(err) => {
+                let decoded = DecodedRevert {
+                    contract_id: ContractId::zeroed(),
+                    error_type: Some(short_type_name(std::any::type_name::<E>())),
+                    message: format!("{err:?}"),
+                };
+
+                Err(Error::Transaction(Reason::script_returned_err(
+                    decoded,
+                    err.into_token(),
+                    receipts,
+                )))
+            }
+        }
+    }
 }
@@ -1,10 +1,11 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use fuel_types::Bytes32;
 use fuels_accounts::Account;
 use fuels_core::{
+    error,
     traits::{Parameterize, Tokenizable},
-    types::errors::Result,
+    types::{coin_type_id::CoinTypeId, errors::Result, tx_status::TxStatus},
 };
 
 use crate::{
@@ -37,6 +38,7 @@ use crate::{
 pub struct SubmitResponse<A, C, T> {
     tx_id: Bytes32,
     call_handler: CallHandler<A, C, T>,
+    resources: Vec<CoinTypeId>,
 }
 
 impl<A, C, T> SubmitResponse<A, C, T>
@@ -49,15 +51,49 @@ where
         Self {
             tx_id,
             call_handler,
+            resources: vec![],
         }
     }
 
+    /// The outcome of the transaction, queried once. Fails if the transaction isn't done yet,
+    /// see [`SubmitResponse::response_with_polling`] and [`SubmitResponse::await_commit`] to wait
+    /// for it.
     pub async fn response(self) -> Result<CallResponse<T>> {
         let provider = self.call_handler.account.try_provider()?;
-        let receipts = provider
-            .tx_status(&self.tx_id)
-            .await?
-            .take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+        let tx_status = provider.tx_status(&self.tx_id).await;
+        // the reservations of a pending transaction expire on their own
+        if matches!(tx_status, Ok(TxStatus::Submitted)) {
+            return Err(error!(
+                Provider,
+                "transaction `{}` is still pending", self.tx_id
+            ));
+        }
+        self.release_resources();
+        let receipts = tx_status?.take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+
+        self.call_handler.get_response(receipts)
+    }
+
+    /// Like [`SubmitResponse::response`], but polls the node until the transaction is done,
+    /// backing off as configured by the provider's polling config. With the default config, this
+    /// can block for up to a minute.
+    pub async fn response_with_polling(self) -> Result<CallResponse<T>> {
+        let provider = self.call_handler.account.try_provider()?;
+        let tx_status = provider.poll_tx_status(&self.tx_id).await;
+        self.release_resources();
+        let receipts = tx_status?.take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+
+        self.call_handler.get_response(receipts)
+    }
+
+    /// Like [`SubmitResponse::response`], but is notified of the outcome by the node as soon as
+    /// the transaction is committed or squeezed out, instead of polling for it. Fails if that
+    /// takes longer than `timeout`.
+    pub async fn await_commit(self, timeout: Duration) -> Result<CallResponse<T>> {
+        let provider = self.call_handler.account.try_provider()?;
+        let tx_status = provider.await_final_tx_status(&self.tx_id, timeout).await;
+        self.release_resources();
+        let receipts = tx_status?.take_receipts_checked(Some(&self.call_handler.log_decoder))?;
 
         self.call_handler.get_response(receipts)
     }
@@ -67,21 +103,76 @@ where
     }
 }
 
+impl<A, C, T> SubmitResponse<A, C, T>
+where
+    A: Account,
+    C: TransactionTuner,
+    T: Tokenizable + Parameterize + Debug,
+{
+    /// The resources spent by the submitted transaction, released once its final status is
+    /// known.
+    pub(crate) fn with_resources(mut self, resources: Vec<CoinTypeId>) -> Self {
+        self.resources = resources;
+        self
+    }
+
+    fn release_resources(&self) {
+        self.call_handler.release_resources(&self.resources);
+    }
+}
+
 /// Represents the response of a submitted transaction with multiple contract calls.
 impl<A: Account> SubmitResponse<A, Vec<ContractCall>, ()> {
     pub fn new(tx_id: Bytes32, call_handler: CallHandler<A, Vec<ContractCall>, ()>) -> Self {
         Self {
             tx_id,
             call_handler,
+            resources: vec![],
         }
     }
 
+    /// The outcome of the transaction, queried once. Fails if the transaction isn't done yet,
+    /// see [`response_with_polling`](Self::response_with_polling) and
+    /// [`await_commit`](Self::await_commit) to wait for it.
     pub async fn response<T: Tokenizable + Debug>(self) -> Result<CallResponse<T>> {
         let provider = self.call_handler.account.try_provider()?;
-        let receipts = provider
-            .tx_status(&self.tx_id)
-            .await?
-            .take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+        let tx_status = provider.tx_status(&self.tx_id).await;
+        // the reservations of a pending transaction expire on their own
+        if matches!(tx_status, Ok(TxStatus::Submitted)) {
+            return Err(error!(
+                Provider,
+                "transaction `{}` is still pending", self.tx_id
+            ));
+        }
+        self.release_resources();
+        let receipts = tx_status?.take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+
+        self.call_handler.get_response(receipts)
+    }
+
+    /// Like [`response`](Self::response), but polls the node until the transaction is done,
+    /// backing off as configured by the provider's polling config. With the default config, this
+    /// can block for up to a minute.
+    pub async fn response_with_polling<T: Tokenizable + Debug>(self) -> Result<CallResponse<T>> {
+        let provider = self.call_handler.account.try_provider()?;
+        let tx_status = provider.poll_tx_status(&self.tx_id).await;
+        self.release_resources();
+        let receipts = tx_status?.take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+
+        self.call_handler.get_response(receipts)
+    }
+
+    /// Like [`response`](Self::response), but is notified of the outcome by the node as soon as
+    /// the transaction is committed or squeezed out, instead of polling for it. Fails if that
+    /// takes longer than `timeout`.
+    pub async fn await_commit<T: Tokenizable + Debug>(
+        self,
+        timeout: Duration,
+    ) -> Result<CallResponse<T>> {
+        let provider = self.call_handler.account.try_provider()?;
+        let tx_status = provider.await_final_tx_status(&self.tx_id, timeout).await;
+        self.release_resources();
+        let receipts = tx_status?.take_receipts_checked(Some(&self.call_handler.log_decoder))?;
 
         self.call_handler.get_response(receipts)
     }
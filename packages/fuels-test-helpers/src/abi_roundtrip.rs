@@ -0,0 +1,184 @@
+//! Property-based checks that types survive an ABI encoding round trip.
+
+use std::fmt::Debug;
+
+use fuels_core::{
+    codec::{ABIDecoder, ABIEncoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+        StaticStringToken, Token, U256,
+    },
+};
+use proptest::{
+    collection::vec,
+    prelude::*,
+    test_runner::{Config, TestCaseError, TestRunner},
+};
+
+// upper bound on the length of generated vectors, bytes and strings
+const MAX_LEN: usize = 8;
+
+/// Generates arbitrary tokens that fit `param_type`.
+pub fn arbitrary_token(param_type: &ParamType) -> BoxedStrategy<Token> {
+    match param_type {
+        ParamType::Unit => Just(Token::Unit).boxed(),
+        ParamType::Bool => any::<bool>().prop_map(Token::Bool).boxed(),
+        ParamType::U8 => any::<u8>().prop_map(Token::U8).boxed(),
+        ParamType::U16 => any::<u16>().prop_map(Token::U16).boxed(),
+        ParamType::U32 => any::<u32>().prop_map(Token::U32).boxed(),
+        ParamType::U64 => any::<u64>().prop_map(Token::U64).boxed(),
+        ParamType::U128 => any::<u128>().prop_map(Token::U128).boxed(),
+        ParamType::U256 => any::<[u64; 4]>()
+            .prop_map(|limbs| Token::U256(U256(limbs)))
+            .boxed(),
+        ParamType::B256 => any::<[u8; 32]>().prop_map(Token::B256).boxed(),
+        ParamType::Bytes => bytes().prop_map(Token::Bytes).boxed(),
+        ParamType::RawSlice => bytes().prop_map(Token::RawSlice).boxed(),
+        ParamType::String => vec(any::<char>(), 0..=MAX_LEN)
+            .prop_map(|chars| Token::String(chars.into_iter().collect()))
+            .boxed(),
+        ParamType::StringArray(len) => {
+            let len = *len;
+            ascii(len..=len)
+                .prop_map(move |data| Token::StringArray(StaticStringToken::new(data, Some(len))))
+                .boxed()
+        }
+        ParamType::StringSlice => ascii(0..=MAX_LEN)
+            .prop_map(|data| Token::StringSlice(StaticStringToken::new(data, None)))
+            .boxed(),
+        ParamType::Tuple(param_types) => all_of(param_types).prop_map(Token::Tuple).boxed(),
+        ParamType::Array(param_type, len) => vec(arbitrary_token(param_type), *len)
+            .prop_map(Token::Array)
+            .boxed(),
+        ParamType::Vector(param_type) => vec(arbitrary_token(param_type), 0..=MAX_LEN)
+            .prop_map(Token::Vector)
+            .boxed(),
+        ParamType::Struct { fields, .. } => {
+            let param_types: Vec<_> = fields
+                .iter()
+                .map(|(_, param_type)| param_type.clone())
+                .collect();
+            all_of(&param_types).prop_map(Token::Struct).boxed()
+        }
+        ParamType::Enum { enum_variants, .. } => {
+            let variants = enum_variants
+                .param_types()
+                .enumerate()
+                .map(|(discriminant, param_type)| {
+                    let enum_variants = enum_variants.clone();
+                    arbitrary_token(param_type)
+                        .prop_map(move |token| {
+                            Token::Enum(Box::new((
+                                discriminant as u64,
+                                token,
+                                enum_variants.clone(),
+                            )))
+                        })
+                        .boxed()
+                })
+                .collect::<Vec<_>>();
+
+            proptest::strategy::Union::new(variants).boxed()
+        }
+    }
+}
+
+fn all_of(param_types: &[ParamType]) -> BoxedStrategy<Vec<Token>> {
+    param_types
+        .iter()
+        .map(arbitrary_token)
+        .collect::<Vec<_>>()
+        .boxed()
+}
+
+fn bytes() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..=MAX_LEN)
+}
+
+fn ascii(len: std::ops::RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    vec(0x20u8..0x7f, len).prop_map(|bytes| bytes.into_iter().map(char::from).collect())
+}
+
+/// Checks that `cases` arbitrary values of `T` are decoded back to themselves after being
+/// encoded, panicking with the smallest failing value otherwise. Prefer the
+/// [`assert_abi_roundtrip!`](crate::assert_abi_roundtrip) macro.
+pub fn assert_abi_roundtrip<T>(cases: u32)
+where
+    T: Parameterize + Tokenizable + Debug + PartialEq,
+{
+    let config = Config {
+        cases,
+        failure_persistence: None,
+        ..Config::default()
+    };
+
+    let result = TestRunner::new(config).run(&arbitrary_token(&T::param_type()), |token| {
+        roundtrip::<T>(token).map_err(|e| TestCaseError::fail(e.to_string()))
+    });
+
+    if let Err(err) = result {
+        panic!(
+            "`{}` doesn't survive an ABI round trip: {err}",
+            std::any::type_name::<T>()
+        );
+    }
+}
+
+fn roundtrip<T>(token: Token) -> Result<()>
+where
+    T: Parameterize + Tokenizable + Debug + PartialEq,
+{
+    let expected = T::from_token(token.clone())?;
+
+    let encoded = ABIEncoder::default().encode(&[T::from_token(token)?.into_token()])?;
+    let decoded = T::from_token(ABIDecoder::default().decode(&T::param_type(), &encoded)?)?;
+
+    if decoded != expected {
+        return Err(error!(
+            Codec,
+            "encoded `{expected:?}` but decoded `{decoded:?}`"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks with arbitrary values that a type implementing `Parameterize` and `Tokenizable`, such
+/// as one generated by `abigen!` or a custom implementation, is decoded back to itself after
+/// being encoded. Needs the `proptest` feature.
+///
+/// 256 values are checked unless a number of `cases` is given. On failure, it panics with the
+/// smallest value that doesn't survive the round trip.
+///
+/// ```ignore
+/// #[test]
+/// fn my_struct_survives_the_abi() {
+///     assert_abi_roundtrip!(MyStruct);
+///     assert_abi_roundtrip!(MyEnum, cases = 1000);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_abi_roundtrip {
+    ($type:ty) => {
+        $crate::assert_abi_roundtrip!($type, cases = 256)
+    };
+    ($type:ty, cases = $cases:expr) => {
+        $crate::abi_roundtrip::assert_abi_roundtrip::<$type>($cases)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::{Bits256, Bytes, SizedAsciiString};
+
+    #[test]
+    fn sdk_types_survive_the_roundtrip() {
+        assert_abi_roundtrip!(u64);
+        assert_abi_roundtrip!((bool, [u16; 3], Vec<u8>));
+        assert_abi_roundtrip!(Option<Bits256>, cases = 64);
+        assert_abi_roundtrip!(Result<String, SizedAsciiString<4>>, cases = 64);
+        assert_abi_roundtrip!(Bytes, cases = 64);
+    }
+}
@@ -54,12 +54,44 @@ pub async fn launch_custom_provider_and_get_wallets(
     wallet_config: WalletsConfig,
     node_config: Option<NodeConfig>,
     chain_config: Option<ChainConfig>,
+) -> Result<Vec<WalletUnlocked>> {
+    let wallet_assets = (0..wallet_config.num_wallets())
+        .map(|_| wallet_config.assets().to_vec())
+        .collect();
+
+    launch_provider_and_get_wallets_with_assets(wallet_assets, node_config, chain_config).await
+}
+
+/// Like [`launch_custom_provider_and_get_wallets`], but each wallet gets its own assets: the
+/// `n`-th wallet is funded with the coins described by the `n`-th entry of `wallet_assets`.
+///
+/// # Examples
+/// ```
+/// use fuels_test_helpers::{launch_provider_and_get_wallets_with_assets, AssetConfig};
+/// use fuel_types::AssetId;
+///
+/// async fn wallets_with_different_balances() -> Result<(), Box<dyn std::error::Error>> {
+///   let base_asset = |coin_amount| AssetConfig { id: AssetId::zeroed(), num_coins: 1, coin_amount };
+///
+///   let wallets = launch_provider_and_get_wallets_with_assets(
+///       vec![vec![base_asset(1_000)], vec![base_asset(10)]],
+///       None,
+///       None,
+///   )
+///   .await?;
+///   Ok(())
+/// }
+/// ```
+pub async fn launch_provider_and_get_wallets_with_assets(
+    wallet_assets: Vec<Vec<AssetConfig>>,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
 ) -> Result<Vec<WalletUnlocked>> {
     const SIZE_SECRET_KEY: usize = size_of::<SecretKey>();
     const PADDING_BYTES: usize = SIZE_SECRET_KEY - size_of::<u64>();
     let mut secret_key: [u8; SIZE_SECRET_KEY] = [0; SIZE_SECRET_KEY];
 
-    let mut wallets: Vec<_> = (1..=wallet_config.num_wallets())
+    let mut wallets: Vec<_> = (1..=wallet_assets.len() as u64)
         .map(|wallet_counter| {
             secret_key[PADDING_BYTES..].copy_from_slice(&wallet_counter.to_be_bytes());
 
@@ -73,7 +105,8 @@ pub async fn launch_custom_provider_and_get_wallets(
 
     let all_coins = wallets
         .iter()
-        .flat_map(|wallet| setup_custom_assets_coins(wallet.address(), wallet_config.assets()))
+        .zip(&wallet_assets)
+        .flat_map(|(wallet, assets)| setup_custom_assets_coins(wallet.address(), assets))
         .collect::<Vec<_>>();
 
     let provider = setup_test_provider(all_coins, vec![], node_config, chain_config).await?;
@@ -94,7 +127,10 @@ mod tests {
     use fuels_core::types::{coin_type::CoinType, errors::Result};
     use rand::Fill;
 
-    use crate::{launch_custom_provider_and_get_wallets, AssetConfig, WalletsConfig};
+    use crate::{
+        launch_custom_provider_and_get_wallets, launch_provider_and_get_wallets_with_assets,
+        AssetConfig, WalletsConfig,
+    };
 
     #[tokio::test]
     async fn test_wallet_config() -> Result<()> {
@@ -120,6 +156,28 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn wallets_get_their_own_assets() -> Result<()> {
+        let base_asset = |coin_amount| AssetConfig {
+            id: AssetId::zeroed(),
+            num_coins: 1,
+            coin_amount,
+        };
+
+        let wallets = launch_provider_and_get_wallets_with_assets(
+            vec![vec![base_asset(100)], vec![base_asset(200)]],
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(wallets.len(), 2);
+        assert_eq!(wallets[0].get_asset_balance(&AssetId::zeroed()).await?, 100);
+        assert_eq!(wallets[1].get_asset_balance(&AssetId::zeroed()).await?, 200);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_wallet_config_multiple_assets(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
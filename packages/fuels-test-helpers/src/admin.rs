@@ -0,0 +1,272 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+use fuel_core_chain_config::{ChainConfig, StateConfig};
+use fuel_core_client::client::FuelClient;
+use fuel_crypto::SecretKey;
+use fuel_tx::{Transaction, TxId};
+use fuel_types::BlockHeight;
+use fuels_accounts::{
+    provider::{retry, Backoff, Provider, RetryConfig},
+    wallet::WalletUnlocked,
+    Account,
+};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+    transaction::{TransactionType, TxPolicies},
+    tx_status::TxStatus,
+};
+
+use crate::{
+    constants::FAUCET_PRIVATE_KEY, node_logs::register_node_logs, DbType, FuelService, NodeConfig,
+    Trigger,
+};
+
+// several coins, so that transfers from the faucet don't wait on each other's change
+pub(crate) const FAUCET_NUM_COINS: u64 = 10;
+pub(crate) const FAUCET_COIN_AMOUNT: u64 = 1_000_000_000_000_000;
+
+struct Node {
+    service: FuelService,
+    node_config: NodeConfig,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+}
+
+/// Operates the node a provider was set up with by
+/// [`setup_test_provider`](crate::setup_test_provider), so that long-running development
+/// environments can tweak the chain without tearing everything down.
+///
+/// The node is restarted on the same address, so existing providers and wallets keep working.
+/// Mind that they may hold on to state of the old chain, like coins cached by the `coin-cache`
+/// feature.
+#[derive(Clone)]
+pub struct AdminClient {
+    node: Arc<tokio::sync::Mutex<Node>>,
+    provider: Provider,
+}
+
+impl AdminClient {
+    /// Restarts the node from its genesis state.
+    ///
+    /// Nodes storing their database on disk can't be reset, since their data is kept across
+    /// restarts.
+    pub async fn reset(&self) -> Result<()> {
+        let mut node = self.node.lock().await;
+        if matches!(node.node_config.database_type, DbType::RocksDb(_)) {
+            return Err(error!(
+                Other,
+                "nodes with a `RocksDb` database can't be reset"
+            ));
+        }
+
+        self.restart(&mut node).await
+    }
+
+    /// Makes the node charge `gas_price` for transactions.
+    ///
+    /// The node only reads its gas price on startup, so it is restarted: its chain is kept if it
+    /// stores its database on disk, and reset to genesis otherwise.
+    pub async fn set_gas_price(&self, gas_price: u64) -> Result<()> {
+        let mut node = self.node.lock().await;
+        node.node_config.static_gas_price = gas_price;
+
+        self.restart(&mut node).await
+    }
+
+    /// Sends `amount` of the base asset to `to` from a faucet account funded at genesis.
+    pub async fn fund(&self, to: &Bech32Address, amount: u64) -> Result<TxId> {
+        let faucet = faucet_wallet(Some(self.provider.clone()));
+        let (tx_id, _) = faucet
+            .transfer(
+                to,
+                amount,
+                *self.provider.base_asset_id(),
+                TxPolicies::default(),
+            )
+            .await?;
+
+        Ok(tx_id)
+    }
+
+    /// Regenerates the chain from genesis, replaying the transactions of all blocks but the
+    /// latest `depth` ones, which are replaced with new, empty blocks at the same heights. Useful
+    /// to check how indexers and event listeners cope with blocks they have seen disappearing.
+    ///
+    /// This is not a reorg of the latest blocks only: the node can't roll back blocks, so it is
+    /// restarted from genesis and every block is produced anew, one at a time. All blocks get new
+    /// ids and timestamps, including the replayed ones. With [`Trigger::Instant`], each replayed
+    /// transaction lands in a block of its own, so the heights after a block that held several
+    /// transactions move up.
+    ///
+    /// Like [`reset`](Self::reset), this isn't supported for nodes with a `RocksDb` database.
+    pub async fn regenerate_chain(&self, depth: u32) -> Result<()> {
+        let mut node = self.node.lock().await;
+        if matches!(node.node_config.database_type, DbType::RocksDb(_)) {
+            return Err(error!(
+                Other,
+                "nodes with a `RocksDb` database can't regenerate their chain"
+            ));
+        }
+
+        let latest_height = self.provider.latest_block_height().await?;
+        if depth == 0 || depth > latest_height {
+            return Err(error!(
+                Other,
+                "the number of replaced blocks must be between 1 and the latest block height ({latest_height}), got {depth}"
+            ));
+        }
+
+        let kept_blocks = self.transactions_of_blocks(latest_height - depth).await?;
+
+        self.restart(&mut node).await?;
+
+        let client = FuelClient::from(node.service.bound_address());
+        let instant = matches!(node.node_config.block_production, Trigger::Instant);
+        for txs in kept_blocks {
+            replay_block(&client, txs, instant).await?;
+        }
+        client.produce_blocks(depth, None).await?;
+
+        Ok(())
+    }
+
+    /// The transactions of blocks `1..=up_to`, apart from their mint transactions.
+    async fn transactions_of_blocks(&self, up_to: u32) -> Result<Vec<Vec<Transaction>>> {
+        let mut blocks = Vec::with_capacity(up_to as usize);
+        for height in 1..=up_to {
+            let block = self
+                .provider
+                .block_by_height(BlockHeight::from(height))
+                .await?
+                .ok_or_else(|| error!(Provider, "block at height {height} not found"))?;
+
+            let mut txs = vec![];
+            for tx_id in &block.transactions {
+                let tx = self
+                    .provider
+                    .get_transaction_by_id(tx_id)
+                    .await?
+                    .ok_or_else(|| error!(Provider, "transaction `{tx_id}` not found"))?
+                    .transaction;
+
+                if !matches!(tx, TransactionType::Mint(_)) {
+                    txs.push(Transaction::from(tx));
+                }
+            }
+            blocks.push(txs);
+        }
+
+        Ok(blocks)
+    }
+
+    async fn restart(&self, node: &mut Node) -> Result<()> {
+        node.service.stop().await?;
+
+        let node_config = NodeConfig {
+            addr: node.service.bound_address(),
+            ..node.node_config.clone()
+        };
+        // the port is freed once the old node is fully shut down
+        let retry_config = RetryConfig::new(10, Backoff::Fixed(Duration::from_millis(200)))?;
+        let service = retry(
+            || {
+                FuelService::start(
+                    node_config.clone(),
+                    node.chain_config.clone(),
+                    node.state_config.clone(),
+                )
+            },
+            &retry_config,
+            |result| result.is_err(),
+        )
+        .await?;
+        node.service = service;
+
+        register_node_logs(&self.provider, node.service.node_logs().clone());
+
+        Ok(())
+    }
+}
+
+async fn replay_block(client: &FuelClient, txs: Vec<Transaction>, instant: bool) -> Result<()> {
+    if txs.is_empty() {
+        client.produce_blocks(1, None).await?;
+        return Ok(());
+    }
+
+    if instant {
+        for tx in &txs {
+            let status = client.submit_and_await_commit(tx).await?;
+            TxStatus::from(status).check(None)?;
+        }
+    } else {
+        for tx in &txs {
+            client.submit(tx).await?;
+        }
+        client.produce_blocks(1, None).await?;
+    }
+
+    Ok(())
+}
+
+impl Debug for AdminClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdminClient")
+            .field("url", &self.provider.url())
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) fn faucet_wallet(provider: Option<Provider>) -> WalletUnlocked {
+    let secret_key = SecretKey::try_from(FAUCET_PRIVATE_KEY.as_slice())
+        .expect("faucet private key is a valid secp256k1 key");
+
+    WalletUnlocked::new_from_private_key(secret_key, provider)
+}
+
+fn registry() -> &'static Mutex<HashMap<String, AdminClient>> {
+    static ADMIN_CLIENTS: OnceLock<Mutex<HashMap<String, AdminClient>>> = OnceLock::new();
+    ADMIN_CLIENTS.get_or_init(Default::default)
+}
+
+pub(crate) fn register_admin_client(
+    provider: &Provider,
+    service: FuelService,
+    node_config: NodeConfig,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+) {
+    let admin_client = AdminClient {
+        node: Arc::new(tokio::sync::Mutex::new(Node {
+            service,
+            node_config,
+            chain_config,
+            state_config,
+        })),
+        provider: provider.clone(),
+    };
+
+    registry()
+        .lock()
+        .unwrap()
+        .insert(provider.url().to_string(), admin_client);
+}
+
+/// Gives access to the [`AdminClient`] of the node a provider was set up with by
+/// [`setup_test_provider`](crate::setup_test_provider).
+pub trait AdminClientExt {
+    /// `None` if the provider is not connected to a node launched by the test helpers.
+    fn admin(&self) -> Option<AdminClient>;
+}
+
+impl AdminClientExt for Provider {
+    fn admin(&self) -> Option<AdminClient> {
+        registry().lock().unwrap().get(self.url()).cloned()
+    }
+}
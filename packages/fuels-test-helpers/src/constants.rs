@@ -0,0 +1,90 @@
+//! Well-known, deterministic keys, addresses and ids for tests and examples.
+//!
+//! # Warning
+//!
+//! These private keys are public knowledge. Never use them outside of local tests: anything owned
+//! by their addresses on a real network can be taken by anyone.
+
+use fuel_crypto::SecretKey;
+use fuel_types::{AssetId, ContractId};
+#[cfg(feature = "fuels-accounts")]
+use fuels_accounts::{provider::Provider, wallet::WalletUnlocked};
+use fuels_core::types::bech32::{Bech32Address, FUEL_BECH32_HRP};
+
+/// The raw private keys of the test accounts. They are the keys of the first wallets returned by
+/// `launch_custom_provider_and_get_wallets`, in the same order.
+pub const TEST_PRIVATE_KEYS: [[u8; 32]; 4] = [
+    counter_key(1),
+    counter_key(2),
+    counter_key(3),
+    counter_key(4),
+];
+
+/// The raw private key of the faucet account funded by
+/// [`setup_test_provider`](crate::setup_test_provider), which
+/// [`AdminClient::fund`](crate::AdminClient::fund) sends coins from.
+pub const FAUCET_PRIVATE_KEY: [u8; 32] = [0xfa; 32];
+
+/// Asset ids that are not the base asset, for tests needing a few distinct assets.
+pub const TEST_ASSET_IDS: [AssetId; 3] = [
+    AssetId::new([0xa1; 32]),
+    AssetId::new([0xa2; 32]),
+    AssetId::new([0xa3; 32]),
+];
+
+/// A contract id no contract is deployed at.
+pub const TEST_CONTRACT_ID: ContractId = ContractId::new([0xc1; 32]);
+
+/// The private key of test account `index`.
+///
+/// # Panics
+///
+/// If `index` is not lower than `TEST_PRIVATE_KEYS.len()`.
+pub fn test_secret_key(index: usize) -> SecretKey {
+    SecretKey::try_from(TEST_PRIVATE_KEYS[index].as_slice())
+        .expect("test private keys are valid secp256k1 keys")
+}
+
+/// The address of test account `index`.
+///
+/// # Panics
+///
+/// If `index` is not lower than `TEST_PRIVATE_KEYS.len()`.
+pub fn test_address(index: usize) -> Bech32Address {
+    let public_key = fuel_crypto::PublicKey::from(&test_secret_key(index));
+
+    Bech32Address::new(FUEL_BECH32_HRP, public_key.hash())
+}
+
+/// A wallet for test account `index`.
+///
+/// # Panics
+///
+/// If `index` is not lower than `TEST_PRIVATE_KEYS.len()`.
+#[cfg(feature = "fuels-accounts")]
+pub fn test_wallet(index: usize, provider: Option<Provider>) -> WalletUnlocked {
+    WalletUnlocked::new_from_private_key(test_secret_key(index), provider)
+}
+
+const fn counter_key(counter: u8) -> [u8; 32] {
+    let mut key = [0; 32];
+    key[31] = counter;
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accounts_are_distinct_and_stable() {
+        let addresses = (0..TEST_PRIVATE_KEYS.len())
+            .map(test_address)
+            .collect::<Vec<_>>();
+
+        for (i, address) in addresses.iter().enumerate() {
+            assert!(!addresses[..i].contains(address));
+        }
+        assert_eq!(test_wallet(0, None).address(), &addresses[0]);
+    }
+}
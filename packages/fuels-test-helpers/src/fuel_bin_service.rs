@@ -1,18 +1,28 @@
 use std::{
     net::{IpAddr, SocketAddr},
     path::PathBuf,
+    process::Stdio,
     time::Duration,
 };
 
 use fuel_core_chain_config::{ChainConfig, SnapshotWriter, StateConfig};
 use fuel_core_client::client::FuelClient;
 use fuel_core_services::State;
+use fuels_accounts::provider::{retry, Backoff, RetryConfig};
 use fuels_core::{error, types::errors::Result as FuelResult};
 use portpicker::{is_free, pick_unused_port};
 use tempfile::{tempdir, TempDir};
-use tokio::{process::Command, spawn, task::JoinHandle, time::sleep};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::Command,
+    spawn,
+    task::JoinHandle,
+};
 
-use crate::node_types::{DbType, NodeConfig, Trigger};
+use crate::{
+    node_logs::NodeLogs,
+    node_types::{DbType, NodeConfig, Trigger},
+};
 
 #[derive(Debug)]
 pub(crate) struct ExtendedConfig {
@@ -104,6 +114,7 @@ impl ExtendedConfig {
 
 pub struct FuelService {
     pub bound_address: SocketAddr,
+    pub node_logs: NodeLogs,
     handle: JoinHandle<()>,
 }
 
@@ -139,11 +150,13 @@ impl FuelService {
         };
 
         let addr = extended_config.node_config.addr;
-        let handle = run_node(extended_config).await?;
+        let node_logs = NodeLogs::default();
+        let handle = run_node(extended_config, node_logs.clone()).await?;
         server_health_check(addr).await?;
 
         Ok(FuelService {
             bound_address,
+            node_logs,
             handle,
         })
     }
@@ -157,15 +170,14 @@ impl FuelService {
 async fn server_health_check(address: SocketAddr) -> FuelResult<()> {
     let client = FuelClient::from(address);
 
-    let mut attempts = 5;
-    let mut healthy = client.health().await.unwrap_or(false);
-    let between_attempts = Duration::from_millis(300);
-
-    while attempts > 0 && !healthy {
-        healthy = client.health().await.unwrap_or(false);
-        sleep(between_attempts).await;
-        attempts -= 1;
-    }
+    let retry_config =
+        RetryConfig::new(6, Backoff::Fixed(Duration::from_millis(300)))?.with_jitter(0.1);
+    let healthy = retry(
+        || async { client.health().await.unwrap_or(false) },
+        &retry_config,
+        |healthy| !healthy,
+    )
+    .await;
 
     if !healthy {
         return Err(error!(Other, "could not connect to fuel core server"));
@@ -181,7 +193,10 @@ fn get_socket_address() -> FuelResult<SocketAddr> {
     Ok(SocketAddr::new(address, free_port))
 }
 
-async fn run_node(extended_config: ExtendedConfig) -> FuelResult<JoinHandle<()>> {
+async fn run_node(
+    extended_config: ExtendedConfig,
+    node_logs: NodeLogs,
+) -> FuelResult<JoinHandle<()>> {
     let args = extended_config.args_vec()?;
     let tempdir = extended_config.write_temp_snapshot_files()?;
 
@@ -202,19 +217,40 @@ async fn run_node(extended_config: ExtendedConfig) -> FuelResult<JoinHandle<()>>
         );
     }
 
-    let mut command = Command::new(path);
-    let running_node = command.args(args).kill_on_drop(true).output();
+    let mut running_node = Command::new(path)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| error!(IO, "could not start `{binary_name}`: {e}"))?;
+
+    // the capturing tasks end once the node is killed and its output closes
+    if let Some(stdout) = running_node.stdout.take() {
+        capture(stdout, node_logs.clone());
+    }
+    if let Some(stderr) = running_node.stderr.take() {
+        capture(stderr, node_logs);
+    }
 
     let join_handle = spawn(async move {
         // ensure drop is not called on the tmp dir and it lives throughout the lifetime of the node
         let _unused = tempdir;
-        let result = running_node
+        let status = running_node
+            .wait()
             .await
-            .expect("error: could not find `fuel-core` in PATH`");
-        let stdout = String::from_utf8_lossy(&result.stdout);
-        let stderr = String::from_utf8_lossy(&result.stderr);
-        eprintln!("the exit status from the fuel binary was: {result:?}, stdout: {stdout}, stderr: {stderr}");
+            .expect("error: could not wait on `fuel-core`");
+        eprintln!("the exit status from the fuel binary was: {status}");
     });
 
     Ok(join_handle)
 }
+
+fn capture(output: impl AsyncRead + Unpin + Send + 'static, node_logs: NodeLogs) {
+    spawn(async move {
+        let mut lines = BufReader::new(output).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            node_logs.push(&line);
+        }
+    });
+}
@@ -0,0 +1,118 @@
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use fuels_core::types::errors::Result;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpListener, TcpStream,
+    },
+};
+
+/// Listens on a free local port and forwards connections to `target`, holding back each
+/// response by `latency`. Returns the address to connect to instead of `target`.
+pub(crate) async fn start_latency_proxy(
+    target: SocketAddr,
+    latency: Duration,
+) -> Result<SocketAddr> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+    let address = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        while let Ok((client, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                if let Ok(node) = TcpStream::connect(target).await {
+                    relay(client, node, latency).await;
+                }
+            });
+        }
+    });
+
+    Ok(address)
+}
+
+async fn relay(client: TcpStream, node: TcpStream, latency: Duration) {
+    let (client_reader, client_writer) = client.into_split();
+    let (node_reader, node_writer) = node.into_split();
+    // set by every request, so that only the first bytes of its response wait
+    let awaiting_response = Arc::new(AtomicBool::new(false));
+
+    let requests = forward(client_reader, node_writer, {
+        let awaiting_response = awaiting_response.clone();
+        move || {
+            awaiting_response.store(true, Ordering::SeqCst);
+            None
+        }
+    });
+    let responses = forward(node_reader, client_writer, move || {
+        awaiting_response
+            .swap(false, Ordering::SeqCst)
+            .then_some(latency)
+    });
+
+    tokio::join!(requests, responses);
+}
+
+async fn forward(
+    mut from: OwnedReadHalf,
+    mut to: OwnedWriteHalf,
+    delay_for: impl Fn() -> Option<Duration>,
+) {
+    let mut buffer = vec![0; 16 * 1024];
+    loop {
+        let read = match from.read(&mut buffer).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        if let Some(delay) = delay_for() {
+            tokio::time::sleep(delay).await;
+        }
+        if to.write_all(&buffer[..read]).await.is_err() {
+            break;
+        }
+    }
+
+    let _ = to.shutdown().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::Instant;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn responses_are_delayed() -> Result<()> {
+        // given
+        let echo = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let echo_address = echo.local_addr()?;
+        tokio::spawn(async move {
+            let (mut stream, _) = echo.accept().await.expect("accepts");
+            let mut buffer = [0; 4];
+            stream.read_exact(&mut buffer).await.expect("reads");
+            stream.write_all(&buffer).await.expect("writes");
+        });
+        let latency = Duration::from_millis(200);
+        let proxy_address = start_latency_proxy(echo_address, latency).await?;
+
+        // when
+        let started = Instant::now();
+        let mut stream = TcpStream::connect(proxy_address).await?;
+        stream.write_all(b"ping").await?;
+        let mut response = [0; 4];
+        stream.read_exact(&mut response).await?;
+
+        // then
+        assert_eq!(&response, b"ping");
+        assert!(started.elapsed() >= latency);
+
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@ extern crate core;
 
 #[cfg(feature = "fuels-accounts")]
 pub use accounts::*;
+#[cfg(feature = "fuels-accounts")]
+pub use admin::{AdminClient, AdminClientExt};
 use fuel_tx::{Bytes32, ConsensusParameters, ContractParameters, TxParameters, UtxoId};
 use fuel_types::{AssetId, Nonce};
 use fuels_accounts::provider::Provider;
@@ -12,10 +14,16 @@ use fuels_core::types::{
     errors::Result,
     message::{Message, MessageStatus},
 };
+pub use node_logs::*;
 pub use node_types::*;
 use rand::{rngs::StdRng, Fill, Rng, SeedableRng};
 use utils::{into_coin_configs, into_message_configs};
 pub use wallets_config::*;
+#[cfg(feature = "proptest")]
+pub mod abi_roundtrip;
+pub mod constants;
+mod latency;
+mod node_logs;
 mod node_types;
 
 #[cfg(not(feature = "fuel-core-lib"))]
@@ -23,11 +31,20 @@ pub(crate) mod fuel_bin_service;
 
 #[cfg(feature = "fuels-accounts")]
 mod accounts;
+#[cfg(feature = "fuels-accounts")]
+mod admin;
+
+#[cfg(feature = "fuels-accounts")]
+pub use ordered_block::*;
+#[cfg(feature = "fuels-accounts")]
+mod ordered_block;
 
 pub use service::*;
 mod service;
 
 mod utils;
+pub use vm_dry_runner::*;
+mod vm_dry_runner;
 mod wallets_config;
 
 /// Create a vector of `num_asset`*`coins_per_asset` UTXOs and a vector of the unique corresponding
@@ -132,7 +149,13 @@ pub async fn setup_test_provider(
     let node_config = node_config.unwrap_or_default();
     let chain_config = chain_config.unwrap_or_else(testnet_chain_config);
 
-    let coin_configs = into_coin_configs(coins);
+    let faucet_coins = setup_single_asset_coins(
+        admin::faucet_wallet(None).address(),
+        chain_config.consensus_parameters.base_asset_id().to_owned(),
+        admin::FAUCET_NUM_COINS,
+        admin::FAUCET_COIN_AMOUNT,
+    );
+    let coin_configs = into_coin_configs([coins, faucet_coins].concat());
     let message_configs = into_message_configs(messages);
 
     let state_config = StateConfig {
@@ -141,16 +164,25 @@ pub async fn setup_test_provider(
         ..StateConfig::local_testnet()
     };
 
-    let srv = FuelService::start(node_config, chain_config, state_config).await?;
+    let srv = FuelService::start(
+        node_config.clone(),
+        chain_config.clone(),
+        state_config.clone(),
+    )
+    .await?;
 
-    let address = srv.bound_address();
+    let address = match node_config.response_latency {
+        Some(latency) => latency::start_latency_proxy(srv.bound_address(), latency).await?,
+        None => srv.bound_address(),
+    };
+    let node_logs = srv.node_logs().clone();
 
-    tokio::spawn(async move {
-        let _own_the_handle = srv;
-        let () = futures::future::pending().await;
-    });
+    let provider = Provider::from(address).await?;
+    node_logs::register_node_logs(&provider, node_logs);
+    // owns the node for the rest of the process
+    admin::register_admin_client(&provider, srv, node_config, chain_config, state_config);
 
-    Provider::from(address).await
+    Ok(provider)
 }
 
 // Testnet ChainConfig with increased tx size and contract size limits
@@ -342,7 +374,7 @@ mod tests {
 
         let retrieved_parameters = provider.consensus_parameters();
 
-        assert_eq!(*retrieved_parameters, consensus_parameters);
+        assert_eq!(retrieved_parameters, consensus_parameters);
 
         Ok(())
     }
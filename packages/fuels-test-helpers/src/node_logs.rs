@@ -0,0 +1,147 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+use fuels_accounts::provider::Provider;
+use fuels_core::types::errors::{error, Result};
+
+/// The output of a `fuel-core` node started by the test helpers, captured line by line.
+///
+/// Only nodes running as a separate `fuel-core` binary are captured. With the `fuel-core-lib`
+/// feature the node logs through `tracing` in the test process instead.
+#[derive(Debug, Clone, Default)]
+pub struct NodeLogs {
+    lines: Arc<Mutex<Vec<String>>>,
+}
+
+impl NodeLogs {
+    pub(crate) fn push(&self, line: &str) {
+        self.lines.lock().unwrap().push(strip_ansi_codes(line));
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.lock().unwrap().clone()
+    }
+
+    /// Lines containing `pattern`.
+    pub fn matching(&self, pattern: &str) -> Vec<String> {
+        self.lines
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|line| line.contains(pattern))
+            .cloned()
+            .collect()
+    }
+
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.lines
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|line| line.contains(pattern))
+    }
+
+    /// Panics, printing the captured logs, if no line contains `pattern`.
+    #[track_caller]
+    pub fn assert_contains(&self, pattern: &str) {
+        if !self.contains(pattern) {
+            panic!("node logs don't contain `{pattern}`:\n{}", self.joined());
+        }
+    }
+
+    /// Panics, printing the offending lines, if any line contains `pattern`.
+    #[track_caller]
+    pub fn assert_not_contains(&self, pattern: &str) {
+        let matching = self.matching(pattern);
+        if !matching.is_empty() {
+            panic!(
+                "node logs unexpectedly contain `{pattern}`:\n{}",
+                matching.join("\n")
+            );
+        }
+    }
+
+    /// Waits until a line containing `pattern` is logged and returns it.
+    pub async fn wait_for(&self, pattern: &str, timeout: Duration) -> Result<String> {
+        let poll = async {
+            loop {
+                if let Some(line) = self.matching(pattern).into_iter().next() {
+                    return line;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, poll)
+            .await
+            .map_err(|_| error!(Other, "`{pattern}` was not logged within {timeout:?}"))
+    }
+
+    fn joined(&self) -> String {
+        self.lines().join("\n")
+    }
+}
+
+fn strip_ansi_codes(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // skip the escape sequence up to and including its final letter
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+fn registry() -> &'static Mutex<HashMap<String, NodeLogs>> {
+    static NODE_LOGS: OnceLock<Mutex<HashMap<String, NodeLogs>>> = OnceLock::new();
+    NODE_LOGS.get_or_init(Default::default)
+}
+
+pub(crate) fn register_node_logs(provider: &Provider, logs: NodeLogs) {
+    registry()
+        .lock()
+        .unwrap()
+        .insert(provider.url().to_string(), logs);
+}
+
+/// Gives access to the logs of the node a provider was set up with by
+/// [`setup_test_provider`](crate::setup_test_provider).
+pub trait NodeLogsExt {
+    /// `None` if the provider is not connected to a node launched by the test helpers.
+    fn node_logs(&self) -> Option<NodeLogs>;
+}
+
+impl NodeLogsExt for Provider {
+    fn node_logs(&self) -> Option<NodeLogs> {
+        registry().lock().unwrap().get(self.url()).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captured_lines_can_be_searched() {
+        let logs = NodeLogs::default();
+
+        logs.push("\u{1b}[32m INFO\u{1b}[0m fuel_core: Starting GraphQL service");
+        logs.push(" WARN fuel_core_txpool: transaction rejected");
+
+        assert_eq!(
+            logs.matching("GraphQL"),
+            vec![" INFO fuel_core: Starting GraphQL service"]
+        );
+        logs.assert_contains("transaction rejected");
+        logs.assert_not_contains("ERROR");
+    }
+}
@@ -53,6 +53,10 @@ pub struct NodeConfig {
     pub vm_backtrace: bool,
     pub silent: bool,
     pub static_gas_price: u64,
+    /// Delays every response of the node by this much, e.g. to exercise timeouts and retries.
+    /// Applied by a proxy that [`setup_test_provider`](crate::setup_test_provider) puts in front
+    /// of the node.
+    pub response_latency: Option<Duration>,
 }
 
 impl Default for NodeConfig {
@@ -67,6 +71,7 @@ impl Default for NodeConfig {
             vm_backtrace: false,
             silent: true,
             static_gas_price: 1,
+            response_latency: None,
         }
     }
 }
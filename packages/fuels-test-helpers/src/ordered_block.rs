@@ -0,0 +1,133 @@
+use fuel_tx::TxId;
+use fuels_accounts::{provider::Provider, Account};
+use fuels_core::types::{
+    errors::{error, Result},
+    transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
+};
+
+/// Includes several transactions in a single block, in the order they were queued.
+///
+/// Useful for testing ordering-sensitive logic, e.g. two competing calls, deterministically.
+/// The node has to be started with [`Trigger::Never`](crate::Trigger::Never) so that the
+/// submitted transactions wait in the txpool until the block is produced.
+///
+/// The node includes transactions with a higher tip per unit of gas first, so every transaction
+/// is given a tip larger than the ones queued after it. Since the order is ultimately decided by
+/// the node, the produced block is checked and an error is returned if the order was not kept.
+///
+/// Transactions spending the same coins conflict in the txpool, so queue transactions of
+/// different accounts or make sure their inputs don't overlap.
+///
+/// # Examples
+/// ```ignore
+/// let tx_ids = OrderedBlock::new()
+///     .queue(first_call.transaction_builder().await?, first_wallet, 0)
+///     .queue(second_call.transaction_builder().await?, second_wallet, 0)
+///     .produce(&provider)
+///     .await?;
+/// ```
+#[derive(Debug)]
+pub struct OrderedBlock<A> {
+    queued: Vec<QueuedTransaction<A>>,
+    tip_step: u64,
+}
+
+#[derive(Debug)]
+struct QueuedTransaction<A> {
+    tb: ScriptTransactionBuilder,
+    account: A,
+    used_base_amount: u64,
+}
+
+impl<A> OrderedBlock<A> {
+    pub const DEFAULT_TIP_STEP: u64 = 10_000;
+}
+
+impl<A> Default for OrderedBlock<A> {
+    fn default() -> Self {
+        Self {
+            queued: vec![],
+            tip_step: Self::DEFAULT_TIP_STEP,
+        }
+    }
+}
+
+impl<A: Account> OrderedBlock<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the difference between the tips of two consecutive transactions. Increase it if the
+    /// queued transactions differ a lot in their gas usage.
+    pub fn with_tip_step(mut self, tip_step: u64) -> Self {
+        self.tip_step = tip_step;
+        self
+    }
+
+    /// Queues a transaction to be funded and signed by `account`. `used_base_amount` is the
+    /// amount of the base asset the transaction spends besides the fee, same as in
+    /// [`Account::adjust_for_fee`].
+    pub fn queue(
+        mut self,
+        tb: ScriptTransactionBuilder,
+        account: A,
+        used_base_amount: u64,
+    ) -> Self {
+        self.queued.push(QueuedTransaction {
+            tb,
+            account,
+            used_base_amount,
+        });
+        self
+    }
+
+    /// Submits the queued transactions, produces a single block and returns the ids of the
+    /// included transactions in the order they were queued.
+    pub async fn produce(self, provider: &Provider) -> Result<Vec<TxId>> {
+        let num_queued = self.queued.len() as u64;
+
+        let mut tx_ids = Vec::with_capacity(self.queued.len());
+        for (position, queued_tx) in self.queued.into_iter().enumerate() {
+            let QueuedTransaction {
+                mut tb,
+                account,
+                used_base_amount,
+            } = queued_tx;
+
+            let tip = (num_queued - position as u64) * self.tip_step;
+            tb.tx_policies = tb.tx_policies.with_tip(tip);
+
+            account.add_witnesses(&mut tb)?;
+            account.adjust_for_fee(&mut tb, used_base_amount).await?;
+            let tx = tb.build(provider).await?;
+
+            tx_ids.push(provider.send_transaction(tx).await?);
+        }
+
+        let height = provider.produce_blocks(1, None).await?;
+        let block = provider
+            .block_by_height(height.into())
+            .await?
+            .ok_or_else(|| {
+                error!(
+                    Provider,
+                    "could not fetch produced block at height {height}"
+                )
+            })?;
+
+        let included = block
+            .transactions
+            .into_iter()
+            .filter(|id| tx_ids.contains(id))
+            .collect::<Vec<_>>();
+
+        if included != tx_ids {
+            return Err(error!(
+                Other,
+                "queued transactions were not included in the queued order. Queued: {tx_ids:?}, included: {included:?}"
+            ));
+        }
+
+        Ok(tx_ids)
+    }
+}
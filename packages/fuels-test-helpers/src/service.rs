@@ -10,7 +10,7 @@ use fuels_core::types::errors::{error, Result};
 
 #[cfg(not(feature = "fuel-core-lib"))]
 use crate::fuel_bin_service::FuelService as BinFuelService;
-use crate::NodeConfig;
+use crate::{NodeConfig, NodeLogs};
 
 pub struct FuelService {
     #[cfg(feature = "fuel-core-lib")]
@@ -18,6 +18,7 @@ pub struct FuelService {
     #[cfg(not(feature = "fuel-core-lib"))]
     service: BinFuelService,
     bound_address: SocketAddr,
+    node_logs: NodeLogs,
 }
 
 impl FuelService {
@@ -39,9 +40,15 @@ impl FuelService {
 
         let bound_address = service.bound_address;
 
+        #[cfg(feature = "fuel-core-lib")]
+        let node_logs = NodeLogs::default();
+        #[cfg(not(feature = "fuel-core-lib"))]
+        let node_logs = service.node_logs.clone();
+
         Ok(FuelService {
             service,
             bound_address,
+            node_logs,
         })
     }
 
@@ -59,6 +66,11 @@ impl FuelService {
         self.bound_address
     }
 
+    /// The node's output. Always empty with the `fuel-core-lib` feature, see [`NodeLogs`].
+    pub fn node_logs(&self) -> &NodeLogs {
+        &self.node_logs
+    }
+
     #[cfg(feature = "fuel-core-lib")]
     fn service_config(
         node_config: NodeConfig,
@@ -0,0 +1,228 @@
+use fuel_tx::{
+    ConsensusParameters, Contract, ContractId, Receipt, Script, ScriptExecutionResult, StorageSlot,
+    Transaction as FuelTransaction,
+};
+use fuel_types::{AssetId, BlockHeight};
+use fuel_vm::{
+    checked_transaction::IntoChecked,
+    interpreter::InterpreterParams,
+    prelude::MemoryInstance,
+    storage::{ContractsAssetsStorage, InterpreterStorage, MemoryStorage},
+    transactor::Transactor,
+};
+use fuels_core::types::{
+    errors::{error, Result},
+    DryRun, DryRunner,
+};
+
+/// A [`DryRunner`] that runs scripts in an in-process `fuel-vm` interpreter, against contracts
+/// kept in memory, so that transactions can be built and executed in unit tests without starting
+/// a node.
+///
+/// Every run starts from the contracts added with [`VmDryRunner::with_contract`] and nothing is
+/// committed, so runs are deterministic and independent of each other. Signatures and the coins
+/// spent aren't checked, and predicates are estimated locally by the transaction builders.
+#[derive(Debug, Clone)]
+pub struct VmDryRunner {
+    consensus_parameters: ConsensusParameters,
+    storage: MemoryStorage,
+    block_height: BlockHeight,
+    gas_price: u64,
+}
+
+impl Default for VmDryRunner {
+    fn default() -> Self {
+        Self::new(ConsensusParameters::default())
+    }
+}
+
+impl VmDryRunner {
+    pub fn new(consensus_parameters: ConsensusParameters) -> Self {
+        Self {
+            consensus_parameters,
+            storage: MemoryStorage::default(),
+            block_height: BlockHeight::default(),
+            gas_price: 0,
+        }
+    }
+
+    /// The height of the block the transactions are run in, e.g. to test maturity checks.
+    pub fn with_block_height(mut self, block_height: u32) -> Self {
+        self.block_height = block_height.into();
+        self
+    }
+
+    /// The gas price estimated for the transaction builders. Scripts are always run with a gas
+    /// price of zero, like the dry runs of a node.
+    pub fn with_gas_price(mut self, gas_price: u64) -> Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    /// Deploys `bytecode` as `contract_id`, e.g. the id given by `Contract::contract_id` of the
+    /// contract loaded from the same binary.
+    pub fn with_contract(
+        mut self,
+        contract_id: ContractId,
+        bytecode: Vec<u8>,
+        storage_slots: &[StorageSlot],
+    ) -> Result<Self> {
+        self.storage
+            .deploy_contract_with_id(storage_slots, &Contract::from(bytecode), &contract_id)
+            .map_err(|e| error!(Other, "failed to deploy contract `{contract_id}`: {e:?}"))?;
+
+        Ok(self)
+    }
+
+    /// Gives `contract_id` an `amount` of `asset_id`, e.g. for contracts that transfer assets.
+    pub fn with_contract_balance(
+        mut self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Self> {
+        self.storage
+            .contract_asset_id_balance_insert(&contract_id, &asset_id, amount)
+            .map_err(|e| error!(Other, "failed to set the balance of `{contract_id}`: {e:?}"))?;
+
+        Ok(self)
+    }
+
+    /// Runs the script transaction `tx` and returns its receipts. A revert is reported in the
+    /// receipts, not as an error.
+    pub fn run(&self, tx: impl Into<FuelTransaction>) -> Result<Vec<Receipt>> {
+        let FuelTransaction::Script(script) = tx.into() else {
+            return Err(error!(
+                Other,
+                "only script transactions can be run in the VM"
+            ));
+        };
+
+        let checked = script.into_checked_basic(self.block_height, &self.consensus_parameters)?;
+        let interpreter_params = InterpreterParams::new(0, &self.consensus_parameters);
+        let mut transactor = Transactor::<_, _, Script>::new(
+            MemoryInstance::new(),
+            self.storage.clone(),
+            interpreter_params,
+        );
+
+        transactor.transact(checked);
+        if let Some(err) = transactor.error() {
+            return Err(error!(Other, "failed to run the script in the VM: {err}"));
+        }
+
+        Ok(transactor.receipts().unwrap_or_default().to_vec())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl DryRunner for VmDryRunner {
+    async fn dry_run(&self, tx: FuelTransaction) -> Result<DryRun> {
+        let receipts = self.run(tx)?;
+
+        let script_gas = receipts
+            .iter()
+            .rev()
+            .find_map(Receipt::gas_used)
+            .unwrap_or_default();
+        let variable_outputs = receipts
+            .iter()
+            .filter(
+                |receipt| matches!(receipt, Receipt::TransferOut { amount, .. } if *amount != 0),
+            )
+            .count();
+        let succeeded = receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::ScriptResult {
+                    result: ScriptExecutionResult::Success,
+                    ..
+                }
+            )
+        });
+
+        Ok(DryRun {
+            succeeded,
+            script_gas,
+            variable_outputs,
+        })
+    }
+
+    async fn estimate_gas_price(&self, _block_horizon: u32) -> Result<u64> {
+        Ok(self.gas_price)
+    }
+
+    fn consensus_parameters(&self) -> ConsensusParameters {
+        self.consensus_parameters.clone()
+    }
+
+    async fn maybe_estimate_predicates(
+        &self,
+        _tx: &FuelTransaction,
+        _latest_chain_executor_version: Option<u32>,
+    ) -> Result<Option<FuelTransaction>> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_asm::{op, RegId};
+    use fuel_tx::{policies::Policies, Address, Input, TxPointer, UtxoId, Witness};
+
+    use super::*;
+
+    // a transaction needs a spendable input to be valid, its signature isn't checked
+    fn script(code: Vec<u8>) -> FuelTransaction {
+        let coin = Input::coin_signed(
+            UtxoId::default(),
+            Address::default(),
+            1_000,
+            AssetId::default(),
+            TxPointer::default(),
+            0,
+        );
+
+        FuelTransaction::script(
+            1_000_000,
+            code,
+            vec![],
+            Policies::new().with_max_fee(0),
+            vec![coin],
+            vec![],
+            vec![Witness::default()],
+        )
+        .into()
+    }
+
+    #[tokio::test]
+    async fn scripts_are_run_without_a_node() -> Result<()> {
+        // given
+        let vm = VmDryRunner::default();
+        let returns_one = op::ret(RegId::ONE).to_bytes().to_vec();
+        let reverts = op::rvrt(RegId::ONE).to_bytes().to_vec();
+
+        // when
+        let receipts = vm.run(script(returns_one.clone()))?;
+        let dry_run = vm.dry_run(script(returns_one)).await?;
+        let reverted = vm.dry_run(script(reverts)).await?;
+
+        // then
+        assert!(matches!(
+            receipts.as_slice(),
+            [
+                Receipt::Return { val: 1, .. },
+                Receipt::ScriptResult {
+                    result: ScriptExecutionResult::Success,
+                    ..
+                }
+            ]
+        ));
+        assert!(dry_run.succeeded);
+        assert!(dry_run.script_gas > 0);
+        assert!(!reverted.succeeded);
+
+        Ok(())
+    }
+}
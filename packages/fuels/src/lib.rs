@@ -11,6 +11,14 @@
 //!
 //! Examples on how you can use the types imported by the prelude can be found in
 //! the [test suite](https://github.com/FuelLabs/fuels-rs/tree/master/packages/fuels/tests)
+//!
+//! Smaller preludes are available for code that only needs part of the SDK:
+//!
+//! - `prelude::sdk_core`: types, errors, constants and `abigen!`, available without `std`,
+//! - `prelude::accounts`: providers, wallets, predicates and signing,
+//! - `prelude::programs`: deploying and calling contracts and scripts,
+//! - `prelude::testing`: launching local nodes and setting up test wallets, behind the
+//!   `test-helpers` feature (enabled by default).
 
 pub mod tx {
     pub use fuel_tx::{
@@ -24,7 +32,7 @@ pub mod tx {
 #[cfg(feature = "std")]
 pub mod client {
     pub use fuel_core_client::client::{
-        pagination::{PageDirection, PaginationRequest},
+        pagination::{PageDirection, PaginatedResult, PaginationRequest},
         FuelClient,
     };
 }
@@ -39,7 +47,19 @@ pub mod programs {
 }
 
 pub mod core {
-    pub use fuels_core::{codec, constants, offsets, traits, Configurables};
+    #[cfg(feature = "accounting")]
+    pub use fuels_core::accounting;
+    #[cfg(feature = "config")]
+    pub use fuels_core::config;
+    pub use fuels_core::{
+        codec, constants, contract_id, decode_configurables, diff_configurables, metrics, offsets,
+        traits, ConfigurableChange, ConfigurableConstant, Configurables, DecodedConfigurable,
+    };
+}
+
+#[cfg(feature = "forc")]
+pub mod forc {
+    pub use fuels_code_gen::forc::*;
 }
 
 pub mod crypto {
@@ -54,42 +74,66 @@ pub mod types {
     pub use fuels_core::types::*;
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "test-helpers")]
 pub mod test_helpers {
     pub use fuels_test_helpers::*;
 }
 
 #[doc(hidden)]
 pub mod prelude {
+    pub mod sdk_core {
+        pub use crate::{
+            core::constants::*,
+            macros::abigen,
+            tx::Receipt,
+            types::{
+                bech32::{Bech32Address, Bech32ContractId},
+                errors::{Error, Result},
+                transaction::*,
+                Address, AssetId, Bytes, ContractId, RawSlice, ReceiptExt, ReceiptsExt, Salt,
+            },
+        };
+    }
+
     #[cfg(feature = "std")]
-    pub use super::{
-        accounts::{
-            predicate::Predicate,
-            provider::*,
-            wallet::{generate_mnemonic_phrase, WalletUnlocked},
-            Account, ViewOnlyAccount,
-        },
-        core::{
-            codec::{LogDecoder, LogId, LogResult},
-            traits::Signer,
-        },
-        macros::setup_program_test,
-        programs::{
-            calls::{CallHandler, CallParameters, ContractDependency},
-            contract::{Contract, LoadConfiguration, StorageConfiguration},
-        },
-        test_helpers::*,
-        types::transaction_builders::*,
-    };
-    pub use super::{
-        core::constants::*,
-        macros::abigen,
-        tx::Receipt,
-        types::{
-            bech32::{Bech32Address, Bech32ContractId},
-            errors::{Error, Result},
-            transaction::*,
-            Address, AssetId, Bytes, ContractId, RawSlice, Salt,
-        },
-    };
+    pub mod accounts {
+        pub use crate::{
+            accounts::{
+                predicate::Predicate,
+                provider::*,
+                wallet::{generate_mnemonic_phrase, WalletUnlocked},
+                Account, ViewOnlyAccount,
+            },
+            core::traits::{SignatureExt, Signer},
+            types::transaction_builders::*,
+        };
+
+        #[cfg(feature = "experimental")]
+        pub use crate::accounts::predicate::PredicateTemplates;
+    }
+
+    #[cfg(feature = "std")]
+    pub mod programs {
+        pub use crate::{
+            core::codec::{AbiRegistry, LogDecoder, LogId, LogResult},
+            programs::{
+                calls::{
+                    BlockDecodingExt, CallHandler, CallParameters, ContractDependency, FundingHook,
+                    FundingShortfall,
+                },
+                contract::{Contract, LoadConfiguration, StorageConfiguration},
+            },
+        };
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub mod testing {
+        pub use crate::{macros::setup_program_test, test_helpers::*};
+    }
+
+    pub use self::sdk_core::*;
+    #[cfg(feature = "test-helpers")]
+    pub use self::testing::*;
+    #[cfg(feature = "std")]
+    pub use self::{accounts::*, programs::*};
 }